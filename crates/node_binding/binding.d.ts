@@ -49,8 +49,8 @@ export class JsCompilation {
 
 export class JsStats {
   getAssets(): JsStatsGetAssets
-  getModules(reasons: boolean, moduleAssets: boolean, nestedModules: boolean, source: boolean): Array<JsStatsModule>
-  getChunks(chunkModules: boolean, chunksRelations: boolean, reasons: boolean, moduleAssets: boolean, nestedModules: boolean, source: boolean): Array<JsStatsChunk>
+  getModules(reasons: boolean, moduleAssets: boolean, nestedModules: boolean, source: boolean, optimizationBailout: boolean, usedExports: boolean, providedExports: boolean): Array<JsStatsModule>
+  getChunks(chunkModules: boolean, chunksRelations: boolean, reasons: boolean, moduleAssets: boolean, nestedModules: boolean, source: boolean, optimizationBailout: boolean, usedExports: boolean, providedExports: boolean): Array<JsStatsChunk>
   getEntrypoints(): Array<JsStatsChunkGroup>
   getNamedChunkGroups(): Array<JsStatsChunkGroup>
   getErrors(): Array<JsStatsError>
@@ -166,6 +166,7 @@ export const enum BuiltinPluginName {
   ContainerReferencePlugin = 'ContainerReferencePlugin',
   ProvideSharedPlugin = 'ProvideSharedPlugin',
   ConsumeSharedPlugin = 'ConsumeSharedPlugin',
+  ModuleFederationManifestPlugin = 'ModuleFederationManifestPlugin',
   NamedModuleIdsPlugin = 'NamedModuleIdsPlugin',
   DeterministicModuleIdsPlugin = 'DeterministicModuleIdsPlugin',
   NamedChunkIdsPlugin = 'NamedChunkIdsPlugin',
@@ -176,6 +177,7 @@ export const enum BuiltinPluginName {
   WarnCaseSensitiveModulesPlugin = 'WarnCaseSensitiveModulesPlugin',
   DataUriPlugin = 'DataUriPlugin',
   FileUriPlugin = 'FileUriPlugin',
+  HttpUriPlugin = 'HttpUriPlugin',
   RuntimePlugin = 'RuntimePlugin',
   JsonModulesPlugin = 'JsonModulesPlugin',
   InferAsyncModulesPlugin = 'InferAsyncModulesPlugin',
@@ -188,6 +190,7 @@ export const enum BuiltinPluginName {
   FlagDependencyExportsPlugin = 'FlagDependencyExportsPlugin',
   FlagDependencyUsagePlugin = 'FlagDependencyUsagePlugin',
   MangleExportsPlugin = 'MangleExportsPlugin',
+  ModuleConcatenationPlugin = 'ModuleConcatenationPlugin',
   HttpExternalsRspackPlugin = 'HttpExternalsRspackPlugin',
   CopyRspackPlugin = 'CopyRspackPlugin',
   HtmlRspackPlugin = 'HtmlRspackPlugin',
@@ -429,6 +432,7 @@ export interface JsStatsAsset {
   chunkNames: Array<string>
   info: JsStatsAssetInfo
   emitted: boolean
+  isOverSizeLimit?: boolean
 }
 
 export interface JsStatsAssetInfo {
@@ -462,6 +466,7 @@ export interface JsStatsChunkGroup {
   assets: Array<JsStatsChunkGroupAsset>
   chunks: Array<string | undefined | null>
   assetsSize: number
+  isOverSizeLimit?: boolean
 }
 
 export interface JsStatsChunkGroupAsset {
@@ -511,6 +516,11 @@ export interface JsStatsModule {
   assets?: Array<string>
   source?: string | Buffer
   profile?: JsStatsModuleProfile
+  optimizationBailout?: Array<string>
+  /** `undefined` when the used exports are unknown. */
+  usedExports?: boolean | Array<string>
+  /** `undefined` when the provided exports are unknown. */
+  providedExports?: Array<string>
 }
 
 export interface JsStatsModuleIssuer {
@@ -546,6 +556,21 @@ export interface NodeFS {
   removeFile: (...args: any[]) => any
   mkdir: (...args: any[]) => any
   mkdirp: (...args: any[]) => any
+  rename: (...args: any[]) => any
+  readFile: (...args: any[]) => any
+  stat: (...args: any[]) => any
+  lstat: (...args: any[]) => any
+  readdir: (...args: any[]) => any
+  readlink: (...args: any[]) => any
+  realpath: (...args: any[]) => any
+}
+
+export interface NodeFsStats {
+  isFile: boolean
+  isDirectory: boolean
+  isSymlink: boolean
+  mtimeMs: number
+  size: number
 }
 
 export interface PathData {
@@ -663,6 +688,9 @@ export interface RawCacheOptions {
   cacheLocation: string
   name: string
   version: string
+  compression?: "gzip" | "brotli" | "zstd"
+  idleTimeout?: number
+  maxSize?: number
 }
 
 export interface RawChunkOptionNameCtx {
@@ -736,10 +764,16 @@ export interface RawCssModulesConfig {
   localsConvention: "asIs" | "camelCase" | "camelCaseOnly" | "dashes" | "dashesOnly"
   localIdentName: string
   exportsOnly: boolean
+  mode?: "local" | "global" | "pure" | ((resourcePath: string) => "local" | "global" | "pure")
+  hashPrefix?: string
+  localIdentContext?: string
+  localIdentRegExp?: RawRegexMatcher
+  auto?: RawRegexMatcher
 }
 
 export interface RawCssPluginConfig {
   modules: RawCssModulesConfig
+  ignoreOrder: boolean
 }
 
 export interface RawDecoratorOptions {
@@ -862,6 +896,21 @@ export interface RawHttpExternalsRspackPluginOptions {
   webAsync: boolean
 }
 
+export interface RawHttpResponse {
+  status: number
+  contentType?: string
+  body: Buffer
+}
+
+export interface RawHttpUriPluginOptions {
+  allowedUris: (string | RegExp)[]
+  lockfileLocation: string
+  cacheLocation?: string
+  frozen: boolean
+  upgrade: boolean
+  httpClient: (url: string) => Promise<RawHttpResponse>
+}
+
 export interface RawIncrementalRebuild {
   make: boolean
   emitAsset: boolean
@@ -920,6 +969,27 @@ export interface RawLimitChunkCountPluginOptions {
   maxChunks: number
 }
 
+export interface RawModuleFederationManifestPluginOptions {
+  name: string
+  filename: string
+  remoteType: string
+  remotes: Array<RawRemoteOptions>
+}
+
+export interface RawModuleFilenameTemplateFnCtx {
+  identifier: string
+  shortIdentifier: string
+  resource: string
+  resourcePath: string
+  absoluteResourcePath: string
+  loaders: string
+  allLoaders: string
+  query: string
+  moduleId: string
+  hash: string
+  namespace: string
+}
+
 export interface RawModuleOptions {
   rules: Array<RawModuleRule>
   parser?: Record<string, RawParserOptions>
@@ -993,6 +1063,7 @@ export interface RawOptimizationOptions {
   providedExports: boolean
   innerGraph: boolean
   mangleExports: string
+  concatenateModules: boolean
 }
 
 export interface RawOptions {
@@ -1011,6 +1082,7 @@ export interface RawOptions {
   cache: RawCacheOptions
   experiments: RawExperiments
   node?: RawNodeOption
+  performance: RawPerformanceOptions
   profile: boolean
   bail: boolean
   builtins: RawBuiltins
@@ -1019,6 +1091,7 @@ export interface RawOptions {
 export interface RawOutputOptions {
   path: string
   clean: boolean
+  cleanKeep?: RawPathMatcher
   publicPath: string
   assetModuleFilename: string
   wasmLoading: string
@@ -1062,6 +1135,19 @@ export interface RawParserOptions {
   javascript?: RawJavascriptParserOptions
 }
 
+export interface RawPathMatcher {
+  type: "string" | "regexp"
+  stringMatcher?: string
+  regexpMatcher?: RawRegexMatcher
+}
+
+export interface RawPerformanceOptions {
+  hints?: undefined | 'warning' | 'error'
+  maxAssetSize: number
+  maxEntrypointSize: number
+  assetFilter?: RawRuleSetCondition
+}
+
 export interface RawPluginImportConfig {
   libraryName: string
   libraryDirectory?: string
@@ -1173,6 +1259,8 @@ export interface RawRuleSetLogicalConditions {
 export interface RawSnapshotOptions {
   resolve: RawSnapshotStrategy
   module: RawSnapshotStrategy
+  immutablePaths?: Array<RawPathMatcher>
+  managedPaths?: Array<RawPathMatcher>
 }
 
 export interface RawSnapshotStrategy {
@@ -1187,6 +1275,8 @@ export interface RawSourceMapDevToolPluginOptions {
   columns: boolean
   noSources: boolean
   publicPath?: string
+  moduleFilenameTemplate?: string | ((info: RawModuleFilenameTemplateFnCtx) => string)
+  fallbackModuleFilenameTemplate?: string | ((info: RawModuleFilenameTemplateFnCtx) => string)
 }
 
 export interface RawSplitChunksOptions {
@@ -1198,6 +1288,7 @@ export interface RawSplitChunksOptions {
   automaticNameDelimiter?: string
   maxAsyncRequests?: number
   maxInitialRequests?: number
+  defaultSizeTypes?: Array<string>
   minChunks?: number
   hidePathInfo?: boolean
   minSize?: number
@@ -1210,6 +1301,32 @@ export interface RawSplitChunksOptions {
 
 export interface RawStatsOptions {
   colors: boolean
+  preset?: "none" | "errors-only" | "errors-warnings" | "normal" | "detailed" | "verbose"
+  all?: boolean
+  assets?: boolean
+  chunks?: boolean
+  chunkModules?: boolean
+  chunkRelations?: boolean
+  modules?: boolean
+  nestedModules?: boolean
+  runtimeModules?: boolean
+  reasons?: boolean
+  moduleAssets?: boolean
+  source?: boolean
+  optimizationBailout?: boolean
+  usedExports?: boolean
+  providedExports?: boolean
+  entrypoints?: boolean
+  chunkGroups?: boolean
+  errors?: boolean
+  errorsCount?: boolean
+  warnings?: boolean
+  warningsCount?: boolean
+  hash?: boolean
+  timings?: boolean
+  builtAt?: boolean
+  publicPath?: boolean
+  outputPath?: boolean
 }
 
 export interface RawStyleConfig {
@@ -1252,5 +1369,12 @@ export interface ThreadsafeNodeFS {
   mkdir: (...args: any[]) => any
   mkdirp: (...args: any[]) => any
   removeDirAll: (...args: any[]) => any
+  rename: (...args: any[]) => any
+  readFile: (...args: any[]) => any
+  stat: (...args: any[]) => any
+  lstat: (...args: any[]) => any
+  readdir: (...args: any[]) => any
+  readlink: (...args: any[]) => any
+  realpath: (...args: any[]) => any
 }
 
@@ -1,10 +1,10 @@
 #![recursion_limit = "256"]
 use rspack_core::Compiler;
 use rspack_core::{CompilerOptions, Plugin};
-use rspack_fs::AsyncWritableFileSystem;
+use rspack_fs::AsyncFileSystem;
 
 #[deprecated(note = "please use `rspack_core::Compiler::new` instead")]
-pub fn rspack<T: AsyncWritableFileSystem + Send + Sync>(
+pub fn rspack<T: AsyncFileSystem + Send + Sync>(
   options: CompilerOptions,
   plugins: Vec<Box<dyn Plugin>>,
   output_filesystem: T,
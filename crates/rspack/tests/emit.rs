@@ -0,0 +1,241 @@
+use std::{
+  path::{Path, PathBuf},
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+  },
+  time::Duration,
+};
+
+use futures::future::BoxFuture;
+use rspack_core::{
+  rspack_sources::{RawSource, SourceExt},
+  AssetInfo, CompilationAsset, Compiler, PathMatcher, Plugin, PluginContext,
+  PluginProcessAssetsOutput, ProcessAssetsArgs,
+};
+use rspack_fs::{
+  AsyncReadableFileSystem, AsyncWritableFileSystem, FileMetadata, MemoryFileSystem,
+  ReadableFileSystem, Result, WritableFileSystem,
+};
+use rspack_testing::apply_from_fixture;
+
+/// An in-memory output file system which records the paths it writes.
+#[derive(Debug, Clone, Default)]
+struct RecordingFileSystem {
+  fs: MemoryFileSystem,
+  writes: Arc<Mutex<Vec<PathBuf>>>,
+}
+
+impl RecordingFileSystem {
+  fn take_writes(&self) -> Vec<PathBuf> {
+    std::mem::take(&mut *self.writes.lock().expect("should lock"))
+  }
+}
+
+impl AsyncWritableFileSystem for RecordingFileSystem {
+  fn create_dir<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+    AsyncWritableFileSystem::create_dir(&self.fs, dir)
+  }
+
+  fn create_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+    AsyncWritableFileSystem::create_dir_all(&self.fs, dir)
+  }
+
+  fn write<P: AsRef<Path>, D: AsRef<[u8]>>(&self, file: P, data: D) -> BoxFuture<'_, Result<()>> {
+    self
+      .writes
+      .lock()
+      .expect("should lock")
+      .push(file.as_ref().to_path_buf());
+    AsyncWritableFileSystem::write(&self.fs, file, data)
+  }
+
+  fn remove_file<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<()>> {
+    AsyncWritableFileSystem::remove_file(&self.fs, file)
+  }
+
+  fn remove_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+    AsyncWritableFileSystem::remove_dir_all(&self.fs, dir)
+  }
+
+  fn rename<P: AsRef<Path>, Q: AsRef<Path>>(&self, from: P, to: Q) -> BoxFuture<'_, Result<()>> {
+    AsyncWritableFileSystem::rename(&self.fs, from, to)
+  }
+}
+
+impl AsyncReadableFileSystem for RecordingFileSystem {
+  fn read(&self, file: &Path) -> BoxFuture<'_, Result<Vec<u8>>> {
+    AsyncReadableFileSystem::read(&self.fs, file)
+  }
+
+  fn metadata(&self, path: &Path) -> BoxFuture<'_, Result<FileMetadata>> {
+    AsyncReadableFileSystem::metadata(&self.fs, path)
+  }
+
+  fn symlink_metadata(&self, path: &Path) -> BoxFuture<'_, Result<FileMetadata>> {
+    AsyncReadableFileSystem::symlink_metadata(&self.fs, path)
+  }
+
+  fn read_dir(&self, dir: &Path) -> BoxFuture<'_, Result<Vec<String>>> {
+    AsyncReadableFileSystem::read_dir(&self.fs, dir)
+  }
+
+  fn read_link(&self, path: &Path) -> BoxFuture<'_, Result<PathBuf>> {
+    AsyncReadableFileSystem::read_link(&self.fs, path)
+  }
+
+  fn canonicalize(&self, path: &Path) -> BoxFuture<'_, Result<PathBuf>> {
+    AsyncReadableFileSystem::canonicalize(&self.fs, path)
+  }
+}
+
+/// Emits `stale.js` and `kept.js` in the first compilation only.
+#[derive(Debug, Default)]
+struct EmitOncePlugin {
+  emitted: AtomicBool,
+}
+
+#[async_trait::async_trait]
+impl Plugin for EmitOncePlugin {
+  async fn process_assets_stage_additional(
+    &self,
+    _ctx: PluginContext,
+    args: ProcessAssetsArgs<'_>,
+  ) -> PluginProcessAssetsOutput {
+    if !self.emitted.swap(true, Ordering::SeqCst) {
+      for filename in ["stale.js", "kept.js"] {
+        args.compilation.emit_asset(
+          filename.to_string(),
+          CompilationAsset::new(
+            Some(RawSource::from(filename).boxed()),
+            AssetInfo::default(),
+          ),
+        );
+      }
+    }
+    Ok(())
+  }
+}
+
+fn create_compiler(output_filesystem: RecordingFileSystem) -> Compiler<RecordingFileSystem> {
+  create_compiler_with(output_filesystem, EmitOncePlugin::default())
+}
+
+fn create_compiler_with(
+  output_filesystem: RecordingFileSystem,
+  emit_once: EmitOncePlugin,
+) -> Compiler<RecordingFileSystem> {
+  let fixture_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/emit/basic");
+  let (mut options, mut plugins) = apply_from_fixture(&fixture_path);
+  options.output.clean = true;
+  options.output.clean_keep = Some(PathMatcher::String("kept.js".to_string()));
+  plugins.push(Box::new(emit_once));
+  Compiler::new(options, plugins, output_filesystem)
+}
+
+fn read(fs: &MemoryFileSystem, path: &Path) -> String {
+  String::from_utf8(ReadableFileSystem::read(fs, path).expect("should read"))
+    .expect("should be utf8")
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn emit_through_temp_files() {
+  let output_filesystem = RecordingFileSystem::default();
+  let mut compiler = create_compiler(output_filesystem.clone());
+  let output_path = compiler.options.output.path.clone();
+  compiler.build().await.expect("should build");
+
+  let writes = output_filesystem.take_writes();
+  assert_eq!(writes.len(), 4, "{writes:#?}");
+  for file in &writes {
+    let name = file
+      .file_name()
+      .expect("should have name")
+      .to_string_lossy();
+    assert!(name.starts_with('.') && name.ends_with(".tmp"), "{name}");
+  }
+  // nothing but the emitted files is left
+  assert_eq!(
+    ReadableFileSystem::read_dir(&output_filesystem.fs, &output_path).expect("should read dir"),
+    vec!["kept.js", "main.js", "runtime.js", "stale.js"]
+  );
+  assert_eq!(
+    read(&output_filesystem.fs, &output_path.join("stale.js")),
+    "stale.js"
+  );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn skip_writing_unchanged_files() {
+  let output_filesystem = RecordingFileSystem::default();
+  let mut compiler = create_compiler(output_filesystem.clone());
+  let output_path = compiler.options.output.path.clone();
+  let main_path = output_path.join("main.js");
+
+  // a file from a previous process with the same content is compared once
+  compiler.build().await.expect("should build");
+  let main = read(&output_filesystem.fs, &main_path);
+  let mut compiler = create_compiler(output_filesystem.clone());
+  output_filesystem.take_writes();
+  compiler.build().await.expect("should build");
+  assert!(compiler.compilation.emitted_assets.get("main.js").is_none());
+  assert!(compiler
+    .compilation
+    .compared_for_emit_assets
+    .contains("main.js"));
+
+  // a file known to be emitted is compared by its metadata
+  compiler.build().await.expect("should build");
+  assert!(compiler.compilation.emitted_assets.is_empty());
+  assert!(output_filesystem
+    .take_writes()
+    .iter()
+    .all(|file| !file.to_string_lossy().contains("main.js")));
+
+  // a file changed by others is written again
+  tokio::time::sleep(Duration::from_millis(5)).await;
+  let changed = "x".repeat(main.len());
+  WritableFileSystem::write(&output_filesystem.fs, &main_path, &changed).expect("should write");
+  compiler.build().await.expect("should build");
+  assert!(compiler.compilation.emitted_assets.contains("main.js"));
+  assert_eq!(read(&output_filesystem.fs, &main_path), main);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn clean_stale_files_of_earlier_runs() {
+  let output_filesystem = RecordingFileSystem::default();
+  let mut compiler = create_compiler(output_filesystem.clone());
+  let output_path = compiler.options.output.path.clone();
+  let read_dir =
+    || ReadableFileSystem::read_dir(&output_filesystem.fs, &output_path).expect("should read dir");
+
+  compiler.build().await.expect("should build");
+  assert_eq!(
+    read_dir(),
+    vec!["kept.js", "main.js", "runtime.js", "stale.js"]
+  );
+
+  // stale files are removed unless they match `clean.keep`
+  compiler.build().await.expect("should build");
+  assert_eq!(read_dir(), vec!["kept.js", "main.js", "runtime.js"]);
+
+  // a new compiler, like another `rspack build`, removes the files left by earlier runs
+  WritableFileSystem::write(&output_filesystem.fs, output_path.join("stale.js"), "stale")
+    .expect("should write");
+  WritableFileSystem::create_dir_all(&output_filesystem.fs, output_path.join("nested"))
+    .expect("should create");
+  WritableFileSystem::write(
+    &output_filesystem.fs,
+    output_path.join("nested/other.txt"),
+    "other",
+  )
+  .expect("should write");
+  let mut compiler = create_compiler_with(
+    output_filesystem.clone(),
+    EmitOncePlugin {
+      emitted: AtomicBool::new(true),
+    },
+  );
+  compiler.build().await.expect("should build");
+  assert_eq!(read_dir(), vec!["kept.js", "main.js", "runtime.js"]);
+}
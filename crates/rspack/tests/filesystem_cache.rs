@@ -0,0 +1,156 @@
+use std::{
+  path::{Path, PathBuf},
+  sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+  },
+};
+
+use rspack_core::{
+  BoxLoader, CacheOptions, Compiler, CompilerOptions, FileSystemCacheOptions, LoaderRunnerContext,
+  Plugin, PluginExt, Resolver, SnapshotStrategy,
+};
+use rspack_error::Result;
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_loader_runner::{Identifiable, Identifier, Loader, LoaderContext};
+use rspack_testing::{apply_from_fixture, copy_fixture};
+
+static LOADER_RUNS: AtomicUsize = AtomicUsize::new(0);
+
+/// Appends a comment to the module, and counts how many times it runs.
+struct CountingLoader;
+
+#[async_trait::async_trait]
+impl Loader<LoaderRunnerContext> for CountingLoader {
+  async fn run(&self, loader_context: &mut LoaderContext<'_, LoaderRunnerContext>) -> Result<()> {
+    LOADER_RUNS.fetch_add(1, Ordering::SeqCst);
+    let content = std::mem::take(&mut loader_context.content).expect("Content should be available");
+    let source = content.try_into_string()? + "\n// counted";
+    loader_context.content = Some(source.into());
+    Ok(())
+  }
+}
+
+impl Identifiable for CountingLoader {
+  fn identifier(&self) -> Identifier {
+    "counting-loader".into()
+  }
+}
+
+#[derive(Debug)]
+struct CountingLoaderPlugin;
+
+#[async_trait::async_trait]
+impl Plugin for CountingLoaderPlugin {
+  async fn resolve_loader(
+    &self,
+    _compiler_options: &CompilerOptions,
+    _context: &Path,
+    _resolver: &Resolver,
+    loader_request: &str,
+    _loader_options: Option<&str>,
+  ) -> Result<Option<BoxLoader>> {
+    Ok((loader_request == "counting-loader").then(|| Arc::new(CountingLoader) as BoxLoader))
+  }
+}
+
+async fn build(fixture_path: &Path, cache_location: &Path) -> String {
+  let (mut options, mut plugins) = apply_from_fixture(fixture_path);
+  options.cache = CacheOptions::FileSystem(FileSystemCacheOptions {
+    cache_location: cache_location.to_string_lossy().to_string(),
+    ..Default::default()
+  });
+  // the JS defaults of development mode
+  options.snapshot.resolve = SnapshotStrategy {
+    hash: false,
+    timestamp: true,
+  };
+  options.snapshot.module = SnapshotStrategy {
+    hash: false,
+    timestamp: true,
+  };
+  // resolve the loader before the resolver of builtin loaders
+  plugins.insert(0, CountingLoaderPlugin.boxed());
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  compiler.build().await.expect("should build");
+  let errors = compiler.compilation.get_stats().get_errors();
+  assert!(errors.is_empty(), "{errors:#?}");
+  compiler
+    .compilation
+    .assets()
+    .get("main.js")
+    .and_then(|asset| asset.get_source())
+    .map(|source| source.source().to_string())
+    .expect("should emit main.js")
+  // the cache is stored when the compiler is dropped
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reuse_loader_results_of_last_process() {
+  let fixture_path =
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/filesystem-cache/loader-result");
+  let cache_location =
+    std::env::temp_dir().join(format!("rspack-filesystem-cache-{}", std::process::id()));
+  let _ = std::fs::remove_dir_all(&cache_location);
+
+  let first = build(&fixture_path, &cache_location).await;
+  assert!(first.contains("// counted"));
+  assert_eq!(LOADER_RUNS.load(Ordering::SeqCst), 1);
+
+  // a new compiler has an empty memory cache, the loader result is read from the filesystem
+  let second = build(&fixture_path, &cache_location).await;
+  assert_eq!(LOADER_RUNS.load(Ordering::SeqCst), 1);
+  assert_eq!(first, second);
+
+  let _ = std::fs::remove_dir_all(&cache_location);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn resolve_again_when_a_file_with_an_earlier_extension_is_created() {
+  let fixture_path = copy_fixture(
+    &PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/filesystem-cache/resolve-result"),
+    &PathBuf::from(env!("CARGO_TARGET_TMPDIR")),
+  );
+  let cache_location = std::env::temp_dir().join(format!(
+    "rspack-filesystem-cache-resolve-{}",
+    std::process::id()
+  ));
+  let _ = std::fs::remove_dir_all(&cache_location);
+
+  let first = build(&fixture_path, &cache_location).await;
+  assert!(first.contains("'jsx'"));
+
+  // `.js` comes before `.jsx` in the extensions, so `./lib` resolves to the new file
+  std::fs::write(fixture_path.join("lib.js"), "export const value = 'js';\n")
+    .expect("should write file");
+  let second = build(&fixture_path, &cache_location).await;
+  assert!(second.contains("'js'"), "{second}");
+  assert!(!second.contains("'jsx'"));
+
+  let _ = std::fs::remove_dir_all(&cache_location);
+  let _ = std::fs::remove_dir_all(&fixture_path);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn fail_to_build_with_a_missing_build_dependency() {
+  let fixture_path =
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/filesystem-cache/loader-result");
+  let (mut options, plugins) = apply_from_fixture(&fixture_path);
+  options.cache = CacheOptions::FileSystem(FileSystemCacheOptions {
+    cache_location: std::env::temp_dir()
+      .join(format!(
+        "rspack-filesystem-cache-missing-{}",
+        std::process::id()
+      ))
+      .to_string_lossy()
+      .to_string(),
+    build_dependencies: vec![fixture_path
+      .join("missing.config.js")
+      .to_string_lossy()
+      .to_string()],
+    ..Default::default()
+  });
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  let error = compiler.build().await.expect_err("should fail to build");
+  assert!(error.to_string().contains("missing.config.js"), "{error}");
+}
@@ -0,0 +1,7 @@
+import { value } from "./a";
+
+console.log(value);
+
+module.hot.accept("./a", undefined, err => console.error(err));
+module.hot.dispose("not a handler");
+module.hot.invalidate("a message");
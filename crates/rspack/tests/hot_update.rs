@@ -0,0 +1,102 @@
+use std::{collections::HashSet, path::Path};
+
+use rspack_core::{Compiler, PluginExt};
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_plugin_hmr::HotModuleReplacementPlugin;
+use rspack_testing::{apply_from_fixture, copy_fixture};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn dispose_modules_of_removed_chunks() {
+  let fixture_path = copy_fixture(
+    &Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/hot-update/remove-chunk"),
+    Path::new(env!("CARGO_TARGET_TMPDIR")),
+  );
+  let (mut options, mut plugins) = apply_from_fixture(&fixture_path);
+  options.dev_server.hot = true;
+  plugins.push(HotModuleReplacementPlugin.boxed());
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  compiler.build().await.expect("should build");
+  assert!(compiler.compilation.hot_update_manifests.is_empty());
+
+  let index = fixture_path.join("index.js");
+  std::fs::write(&index, "import(\"./b\");\n").expect("should write index.js");
+  compiler
+    .rebuild(
+      HashSet::from([index.to_string_lossy().into_owned()]),
+      Default::default(),
+    )
+    .await
+    .expect("should rebuild");
+  assert!(compiler.compilation.get_errors().next().is_none());
+
+  let manifests = &compiler.compilation.hot_update_manifests;
+  assert_eq!(manifests.len(), 1, "{manifests:#?}");
+  let manifest = &manifests[0];
+  assert_eq!(manifest.removed_chunk_ids, vec!["a_js"]);
+  assert!(manifest.updated_chunk_ids.contains(&"main".to_string()));
+  // `./shared.js` is still used by the chunk of `./b.js`, so it's kept for later updates
+  assert_eq!(manifest.removed_module_ids, vec!["./a.js"]);
+  assert_eq!(
+    manifest.updated_modules,
+    vec![index.to_string_lossy().as_ref().into()]
+  );
+  // the chunk of `./a.js` is no longer loadable
+  assert!(manifest
+    .updated_runtime_modules
+    .iter()
+    .any(|module| module.ends_with("webpack/runtime/load_chunk_with_block")));
+
+  let emitted = compiler
+    .compilation
+    .assets()
+    .get(&manifest.filename)
+    .and_then(|asset| asset.get_source())
+    .map(|source| source.source().to_string())
+    .expect("should emit the hot update manifest");
+  let emitted: serde_json::Value = serde_json::from_str(&emitted).expect("should be json");
+  assert_eq!(emitted["r"], serde_json::json!(["a_js"]));
+  assert_eq!(
+    emitted["runtimeModules"],
+    serde_json::to_value(&manifest.updated_runtime_modules).expect("should serialize")
+  );
+
+  let _ = std::fs::remove_dir_all(&fixture_path);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn analyze_hot_api_calls() {
+  let (mut options, mut plugins) = apply_from_fixture(
+    &Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/hot-update/accept-error-handler"),
+  );
+  options.dev_server.hot = true;
+  plugins.push(HotModuleReplacementPlugin.boxed());
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  compiler.build().await.expect("should build");
+  assert!(compiler.compilation.get_errors().next().is_none());
+
+  let warnings = compiler
+    .compilation
+    .get_warnings()
+    .map(|warning| warning.render_report(false).expect("should render"))
+    .collect::<Vec<_>>();
+  assert_eq!(warnings.len(), 2, "{warnings:#?}");
+  assert!(
+    warnings[0].contains("module.hot.dispose() expects a function as handler"),
+    "{warnings:#?}"
+  );
+  assert!(warnings[1].contains("module.hot.invalidate() doesn't take arguments"));
+
+  // the `undefined` placeholder of the callback is replaced by the function refreshing `./a.js`
+  let main = compiler
+    .compilation
+    .assets()
+    .get("main.js")
+    .and_then(|asset| asset.get_source())
+    .map(|source| source.source().to_string())
+    .expect("should emit main.js");
+  assert!(
+    main.contains("\"./a.js\", function(){\n/* harmony import */"),
+    "{main}"
+  );
+  assert!(main.contains("}, (err)=>console.error(err));"), "{main}");
+}
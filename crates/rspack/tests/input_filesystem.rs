@@ -0,0 +1,52 @@
+use std::path::PathBuf;
+
+use rspack_core::Compiler;
+use rspack_fs::{AsyncNativeFileSystem, MemoryFileSystem, OverlayFileSystem, WritableFileSystem};
+use rspack_testing::apply_from_fixture;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn build_modules_only_in_the_overlay() {
+  // the fixture only has its config, the modules exist in memory
+  let fixture_path =
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/input-filesystem/overlay");
+  let (mut options, plugins) = apply_from_fixture(&fixture_path);
+  options.experiments.rspack_future.new_resolver = true;
+
+  let input_filesystem = OverlayFileSystem::new(AsyncNativeFileSystem);
+  let upper = input_filesystem.upper();
+  upper
+    .create_dir_all(&fixture_path)
+    .expect("should create dir");
+  upper
+    .write(
+      fixture_path.join("index.js"),
+      "import { value } from './lib';\nconsole.log(value);\n",
+    )
+    .expect("should write file");
+  upper
+    .write(
+      fixture_path.join("lib.js"),
+      "export const value = 'in memory';\n",
+    )
+    .expect("should write file");
+
+  let mut compiler = Compiler::new_with_input_filesystem(
+    options,
+    plugins,
+    MemoryFileSystem::new(),
+    input_filesystem,
+  );
+  compiler.build().await.expect("should build");
+
+  let errors = compiler.compilation.get_stats().get_errors();
+  assert!(errors.is_empty(), "{errors:#?}");
+  let main = compiler
+    .compilation
+    .assets()
+    .get("main.js")
+    .and_then(|asset| asset.get_source())
+    .map(|source| source.source().to_string())
+    .expect("should emit main.js");
+  assert!(main.contains("'in memory'"), "{main}");
+  assert!(!fixture_path.join("index.js").exists());
+}
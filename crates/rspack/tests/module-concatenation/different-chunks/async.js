@@ -0,0 +1,3 @@
+import { value as lib } from "./lib";
+
+export const value = `async ${lib}`;
@@ -0,0 +1,4 @@
+import { value } from "./lib";
+
+console.log(value);
+import("./async").then(m => console.log(m.value));
@@ -0,0 +1,3 @@
+export const value = "lib";
+
+import.meta.webpackHot.accept();
@@ -0,0 +1 @@
+export const value = __webpack_module__;
@@ -0,0 +1,130 @@
+use std::path::Path;
+
+use rspack_core::{Compiler, PluginExt, StatsOptions, UsedExportsOption};
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_plugin_hmr::HotModuleReplacementPlugin;
+use rspack_plugin_javascript::{
+  FlagDependencyExportsPlugin, FlagDependencyUsagePlugin, ModuleConcatenationPlugin,
+};
+use rspack_testing::{copy_fixture, create_compiler_from_fixture, main_js};
+
+async fn build(name: &str, hot: bool) -> Compiler<AsyncNativeFileSystem> {
+  let fixture_path = copy_fixture(
+    &Path::new(env!("CARGO_MANIFEST_DIR"))
+      .join("tests/module-concatenation")
+      .join(name),
+    Path::new(env!("CARGO_TARGET_TMPDIR")),
+  );
+  let mut compiler = create_compiler_from_fixture(&fixture_path, |plugins, options| {
+    options.experiments.rspack_future.new_treeshaking = true;
+    options.optimization.provided_exports = true;
+    options.optimization.used_exports = UsedExportsOption::Global;
+    plugins.push(Box::<FlagDependencyExportsPlugin>::default());
+    plugins.push(FlagDependencyUsagePlugin::new(true).boxed());
+    plugins.push(Box::<ModuleConcatenationPlugin>::default());
+    if hot {
+      options.dev_server.hot = true;
+      plugins.push(HotModuleReplacementPlugin.boxed());
+    }
+  });
+  compiler.build().await.expect("should build");
+  let errors = compiler.compilation.get_stats().get_errors();
+  assert!(errors.is_empty(), "{errors:#?}");
+  compiler
+}
+
+/// Asserts `./lib.js` is emitted as a module of its own, and returns the reasons
+/// why it's not concatenated.
+fn lib_bailouts(compiler: &Compiler<AsyncNativeFileSystem>) -> Vec<String> {
+  let main = main_js(&compiler.compilation);
+  assert!(!main.contains("// CONCATENATED MODULE: ./lib.js"), "{main}");
+  assert!(main.contains("\"./lib.js\": (function"), "{main}");
+
+  let options = StatsOptions {
+    modules: Some(true),
+    optimization_bailout: Some(true),
+    ..Default::default()
+  };
+  let stats = compiler
+    .compilation
+    .get_stats()
+    .to_json(&options)
+    .expect("should get stats");
+  let stats = serde_json::to_value(stats).expect("should serialize stats");
+  let lib = stats["modules"]
+    .as_array()
+    .expect("should have modules")
+    .iter()
+    .find(|module| module["name"] == "./lib.js")
+    .expect("should have ./lib.js");
+  lib["optimizationBailout"]
+    .as_array()
+    .expect("should have optimizationBailout")
+    .iter()
+    .map(|reason| reason.as_str().expect("should be a string").to_string())
+    .collect()
+}
+
+fn assert_bailout(bailouts: &[String], reason: &str) {
+  assert!(
+    bailouts.iter().any(
+      |bailout| bailout.starts_with("ModuleConcatenation bailout: ") && bailout.contains(reason)
+    ),
+    "{bailouts:#?}"
+  );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bailout_non_esm() {
+  let compiler = build("non-esm", false).await;
+  assert_bailout(
+    &lib_bailouts(&compiler),
+    "Module is not an ECMAScript module",
+  );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bailout_eval() {
+  let compiler = build("eval", false).await;
+  assert_bailout(&lib_bailouts(&compiler), "Module uses eval()");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bailout_webpack_module() {
+  let compiler = build("webpack-module", false).await;
+  let bailouts = lib_bailouts(&compiler);
+  assert_bailout(&bailouts, "Module uses __webpack_module__");
+  assert!(
+    !bailouts
+      .iter()
+      .any(|bailout| bailout.contains("__webpack_module__.id")),
+    "{bailouts:#?}"
+  );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bailout_webpack_module_id() {
+  let compiler = build("webpack-module-id", false).await;
+  assert_bailout(
+    &lib_bailouts(&compiler),
+    "Module uses __webpack_module__.id",
+  );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bailout_hot_module_replacement() {
+  let compiler = build("hot", true).await;
+  assert_bailout(
+    &lib_bailouts(&compiler),
+    "Module uses Hot Module Replacement",
+  );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bailout_different_chunks() {
+  let compiler = build("different-chunks", false).await;
+  assert_bailout(
+    &lib_bailouts(&compiler),
+    "is referenced from different chunks by these modules: ./async.js",
+  );
+}
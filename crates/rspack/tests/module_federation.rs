@@ -0,0 +1,241 @@
+use std::{path::PathBuf, sync::Arc};
+
+use rspack_core::{
+  BoxPlugin, Compiler, LibraryName, LibraryNonUmdObject, LibraryOptions, PluginExt,
+};
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_plugin_mf::{
+  ConsumeOptions, ConsumeSharedPlugin, ConsumeSharedPluginOptions, ConsumeVersion, ContainerPlugin,
+  ContainerPluginOptions, ExposeOptions, ModuleFederationManifestPlugin,
+  ModuleFederationManifestPluginOptions, ProvideOptions, ProvideSharedPlugin, ProvideVersion,
+  RemoteOptions, ShareRuntimePlugin,
+};
+use rspack_testing::{apply_from_fixture, copy_fixture};
+use serde_json::json;
+
+fn module_federation_plugins() -> Vec<BoxPlugin> {
+  vec![
+    ShareRuntimePlugin::new(false).boxed(),
+    ContainerPlugin::new(ContainerPluginOptions {
+      name: "container".to_string(),
+      share_scope: "default".to_string(),
+      library: LibraryOptions {
+        name: Some(LibraryName::NonUmdObject(LibraryNonUmdObject::String(
+          "container".to_string(),
+        ))),
+        export: None,
+        library_type: "var".to_string(),
+        umd_named_define: None,
+        auxiliary_comment: None,
+        amd_container: None,
+      },
+      runtime: None,
+      filename: Some("container.js".to_string().into()),
+      exposes: vec![(
+        "./module".to_string(),
+        ExposeOptions {
+          name: None,
+          import: vec!["./module".to_string()],
+        },
+      )],
+      enhanced: false,
+    })
+    .boxed(),
+    ProvideSharedPlugin::new(vec![(
+      "react".to_string(),
+      ProvideOptions {
+        share_key: "react".to_string(),
+        share_scope: "default".to_string(),
+        version: Some(ProvideVersion::Version("18.2.0".to_string())),
+        eager: false,
+      },
+    )])
+    .boxed(),
+    ConsumeSharedPlugin::new(ConsumeSharedPluginOptions {
+      consumes: vec![(
+        "react".to_string(),
+        Arc::new(ConsumeOptions {
+          import: Some("react".to_string()),
+          import_resolved: None,
+          share_key: "react".to_string(),
+          share_scope: "default".to_string(),
+          required_version: Some(ConsumeVersion::Version("^18.2.0".to_string())),
+          package_name: None,
+          strict_version: false,
+          singleton: true,
+          eager: false,
+        }),
+      )],
+      enhanced: false,
+    })
+    .boxed(),
+    ModuleFederationManifestPlugin::new(ModuleFederationManifestPluginOptions {
+      name: "container".to_string(),
+      filename: "mf-manifest.json".to_string(),
+      remote_type: "script".to_string(),
+      remotes: vec![(
+        "remote".to_string(),
+        RemoteOptions {
+          external: vec!["remote@http://localhost:3000/remoteEntry.js".to_string()],
+          share_scope: "default".to_string(),
+        },
+      )],
+    })
+    .boxed(),
+  ]
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn emit_module_federation_manifest() {
+  let fixture_path =
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/module-federation/manifest");
+  let (options, mut plugins) = apply_from_fixture(&fixture_path);
+  plugins.extend(module_federation_plugins());
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  compiler.build().await.expect("should build");
+  let errors = compiler.compilation.get_stats().get_errors();
+  assert!(errors.is_empty(), "{errors:#?}");
+
+  let assets = compiler.compilation.assets();
+  let manifest = assets
+    .get("mf-manifest.json")
+    .and_then(|asset| asset.get_source())
+    .map(|source| source.source().to_string())
+    .expect("should emit mf-manifest.json");
+  let manifest: serde_json::Value = serde_json::from_str(&manifest).expect("should be json");
+
+  assert_eq!(manifest["name"], "container");
+  assert_eq!(
+    manifest["metaData"]["remoteEntry"],
+    json!({ "name": "container.js", "type": "var" })
+  );
+  assert_eq!(manifest["metaData"]["globalName"], "container");
+  assert_eq!(
+    manifest["remotes"],
+    json!([{
+      "alias": "remote",
+      "federationContainerName": "remote",
+      "entry": "http://localhost:3000/remoteEntry.js",
+      "externalType": "script",
+      "shareScope": "default",
+    }])
+  );
+
+  let exposes = manifest["exposes"].as_array().expect("should have exposes");
+  assert_eq!(exposes.len(), 1);
+  assert_eq!(exposes[0]["id"], "container:module");
+  assert_eq!(exposes[0]["path"], "./module");
+  let expose_files = exposes[0]["assets"]["js"]["sync"]
+    .as_array()
+    .expect("should have files");
+  assert!(!expose_files.is_empty());
+  for file in expose_files {
+    assert!(assets.contains_key(file.as_str().expect("should be string")));
+  }
+
+  let shared = manifest["shared"].as_array().expect("should have shared");
+  assert_eq!(shared.len(), 1);
+  assert_eq!(shared[0]["id"], "container:react");
+  assert_eq!(shared[0]["version"], "18.2.0");
+  assert_eq!(shared[0]["requiredVersion"], "^18.2.0");
+  assert_eq!(shared[0]["singleton"], true);
+  let shared_files = shared[0]["assets"]["js"]["sync"]
+    .as_array()
+    .expect("should have files");
+  assert!(!shared_files.is_empty());
+  for file in shared_files {
+    assert!(assets.contains_key(file.as_str().expect("should be string")));
+  }
+}
+
+/// `react@17.0.2` is installed and provided with `provided_version`, or the version in its
+/// package.json, but consumed with `^18.2.0`.
+async fn build_with_required_version(
+  strict_version: bool,
+  singleton: bool,
+  provided_version: Option<&str>,
+) -> (Vec<String>, Vec<String>) {
+  let fixture_path = copy_fixture(
+    &PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/module-federation/unsatisfied-version"),
+    &PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(format!(
+      "strict-version-{strict_version}-singleton-{singleton}-{}",
+      provided_version.unwrap_or("installed")
+    )),
+  );
+  let (options, mut plugins) = apply_from_fixture(&fixture_path);
+  plugins.extend([
+    ShareRuntimePlugin::new(false).boxed(),
+    ProvideSharedPlugin::new(vec![(
+      "react".to_string(),
+      ProvideOptions {
+        share_key: "react".to_string(),
+        share_scope: "default".to_string(),
+        version: provided_version.map(|version| ProvideVersion::Version(version.to_string())),
+        eager: false,
+      },
+    )])
+    .boxed(),
+    ConsumeSharedPlugin::new(ConsumeSharedPluginOptions {
+      consumes: vec![(
+        "react".to_string(),
+        Arc::new(ConsumeOptions {
+          import: Some("react".to_string()),
+          import_resolved: None,
+          share_key: "react".to_string(),
+          share_scope: "default".to_string(),
+          required_version: Some(ConsumeVersion::Version("^18.2.0".to_string())),
+          package_name: None,
+          strict_version,
+          singleton,
+          eager: false,
+        }),
+      )],
+      enhanced: false,
+    })
+    .boxed(),
+  ]);
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  compiler.build().await.expect("should build");
+
+  let stats = compiler.compilation.get_stats();
+  let errors = stats.get_errors().into_iter().map(|e| e.message).collect();
+  let warnings = stats
+    .get_warnings()
+    .into_iter()
+    .map(|w| w.message)
+    .collect();
+  let _ = std::fs::remove_dir_all(&fixture_path);
+  (errors, warnings)
+}
+
+const UNSATISFIED_VERSION: &str = "Unsatisfied version 17.0.2 from ./node_modules/react/index.js of shared singleton module react (required ^18.2.0) in ./module.js";
+
+#[tokio::test(flavor = "multi_thread")]
+async fn warn_unsatisfied_shared_version() {
+  let (errors, warnings) = build_with_required_version(false, true, None).await;
+  assert!(errors.is_empty(), "{errors:#?}");
+  // the provided version and the installed version of the fallback are reported once
+  assert_eq!(warnings.len(), 1, "{warnings:#?}");
+  assert!(warnings[0].contains(UNSATISFIED_VERSION), "{}", warnings[0]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn error_unsatisfied_shared_version_under_strict_version() {
+  let (errors, warnings) = build_with_required_version(true, true, None).await;
+  assert!(warnings.is_empty(), "{warnings:#?}");
+  assert_eq!(errors.len(), 1, "{errors:#?}");
+  assert!(errors[0].contains(UNSATISFIED_VERSION), "{}", errors[0]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn ignore_unsatisfied_fallback_when_a_provided_version_satisfies() {
+  // the fallback is still 17.0.2, but 18.2.0 is provided for the non-singleton module
+  let (errors, warnings) = build_with_required_version(false, false, Some("18.2.0")).await;
+  assert!(errors.is_empty(), "{errors:#?}");
+  assert!(warnings.is_empty(), "{warnings:#?}");
+
+  // a singleton may get the unsatisfied fallback
+  let (errors, warnings) = build_with_required_version(false, true, Some("18.2.0")).await;
+  assert!(errors.is_empty(), "{errors:#?}");
+  assert_eq!(warnings.len(), 1, "{warnings:#?}");
+}
@@ -0,0 +1,77 @@
+use std::{collections::HashSet, path::Path};
+
+use rspack_core::{
+  CacheOptions, Compiler, IncrementalRebuildMakeState, MemoryCacheOptions, SnapshotStrategy,
+};
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_testing::{copy_fixture, create_compiler_from_fixture, main_js};
+
+fn create_compiler(fixture_path: &Path, incremental: bool) -> Compiler<AsyncNativeFileSystem> {
+  create_compiler_from_fixture(fixture_path, |_, options| {
+    options.cache = CacheOptions::Memory(MemoryCacheOptions { max_generations: 1 });
+    options.snapshot.resolve = SnapshotStrategy {
+      hash: true,
+      timestamp: true,
+    };
+    options.snapshot.module = SnapshotStrategy {
+      hash: true,
+      timestamp: true,
+    };
+    if incremental {
+      options.experiments.incremental_rebuild.make = Some(IncrementalRebuildMakeState::default());
+    }
+  })
+}
+
+async fn remove_and_recreate_imported_file(name: &str, incremental: bool) {
+  let fixture_path = copy_fixture(
+    &Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/rebuild/removed-file"),
+    &Path::new(env!("CARGO_TARGET_TMPDIR")).join(name),
+  );
+  let index = fixture_path.join("index.js");
+  let lib = fixture_path.join("lib.js");
+  let lib_path = HashSet::from([lib.to_string_lossy().into_owned()]);
+  let mut compiler = create_compiler(&fixture_path, incremental);
+  compiler.build().await.expect("should build");
+  assert!(compiler.compilation.get_errors().next().is_none());
+  assert!(compiler.compilation.file_dependencies.contains(&lib));
+
+  std::fs::remove_file(&lib).expect("should remove lib.js");
+  compiler
+    .rebuild(Default::default(), lib_path.clone())
+    .await
+    .expect("should rebuild");
+  assert!(compiler.compilation.removed_files.contains(&lib));
+  let errors = compiler.compilation.get_stats().get_errors();
+  assert_eq!(errors.len(), 1, "{errors:#?}");
+  assert_eq!(
+    errors[0].module_identifier.as_deref(),
+    Some(index.to_string_lossy().as_ref())
+  );
+  assert!(errors[0].message.contains("Can't resolve './lib'"));
+  assert!(!compiler.compilation.file_dependencies.contains(&lib));
+  assert!(compiler.compilation.missing_dependencies.contains(&lib));
+  assert!(!main_js(&compiler.compilation).contains("'lib'"));
+
+  std::fs::write(&lib, "export const value = 'lib';").expect("should write lib.js");
+  compiler
+    .rebuild(lib_path, Default::default())
+    .await
+    .expect("should rebuild");
+  assert!(compiler.compilation.get_errors().next().is_none());
+  assert!(compiler.compilation.file_dependencies.contains(&lib));
+  assert!(!compiler.compilation.missing_dependencies.contains(&lib));
+  assert!(main_js(&compiler.compilation).contains("'lib'"));
+
+  let _ = std::fs::remove_dir_all(&fixture_path);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn rebuild_reports_removed_files() {
+  remove_and_recreate_imported_file("rebuild", false).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn incremental_rebuild_reports_removed_files() {
+  remove_and_recreate_imported_file("incremental-rebuild", true).await;
+}
@@ -0,0 +1,7 @@
+import greet, { name as libName } from "./lib";
+import * as math from "./math";
+import { double } from "./reexport";
+
+const name = "index";
+console.log(greet(libName), name, math.add(1, 2), double(2), math);
+import("./async").then(m => m.value);
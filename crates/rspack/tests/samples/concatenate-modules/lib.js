@@ -0,0 +1,5 @@
+const name = "lib";
+export { name };
+export default function (who) {
+	return `hello ${who}`;
+}
@@ -0,0 +1 @@
+export { double } from "./utils";
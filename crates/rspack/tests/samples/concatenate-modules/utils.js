@@ -0,0 +1,5 @@
+const name = "utils";
+export const double = x => x * 2;
+export function label() {
+	return name;
+}
@@ -0,0 +1,76 @@
+use std::path::Path;
+
+use rspack_core::{Compiler, PluginExt, StatsOptions, StatsPreset, UsedExportsOption};
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_plugin_javascript::{FlagDependencyExportsPlugin, FlagDependencyUsagePlugin};
+use rspack_testing::{copy_fixture, create_compiler_from_fixture};
+use serde_json::{json, Value};
+
+async fn build(name: &str) -> Compiler<AsyncNativeFileSystem> {
+  let fixture_path = copy_fixture(
+    &Path::new(env!("CARGO_MANIFEST_DIR"))
+      .join("tests/stats")
+      .join(name),
+    Path::new(env!("CARGO_TARGET_TMPDIR")),
+  );
+  let mut compiler = create_compiler_from_fixture(&fixture_path, |plugins, options| {
+    options.experiments.rspack_future.new_treeshaking = true;
+    options.optimization.provided_exports = true;
+    options.optimization.used_exports = UsedExportsOption::Global;
+    plugins.push(Box::<FlagDependencyExportsPlugin>::default());
+    plugins.push(FlagDependencyUsagePlugin::new(true).boxed());
+  });
+  compiler.build().await.expect("should build");
+  let errors = compiler.compilation.get_stats().get_errors();
+  assert!(errors.is_empty(), "{errors:#?}");
+  compiler
+}
+
+fn find_module<'a>(modules: &'a Value, name: &str) -> &'a Value {
+  modules
+    .as_array()
+    .expect("should have modules")
+    .iter()
+    .find(|module| module["name"] == name)
+    .unwrap_or_else(|| panic!("should have {name}"))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn serialize_exports_of_modules() {
+  let compiler = build("exports").await;
+  let stats = compiler.compilation.get_stats();
+
+  // The fields read by `stats.toJson()` of the JS side
+  let modules = stats
+    .get_modules(false, false, false, false, true, true, true)
+    .expect("should get modules");
+  let modules = serde_json::to_value(modules).expect("should serialize modules");
+  let lib = find_module(&modules, "./lib.js");
+  assert_eq!(lib["providedExports"], json!(["unused", "used"]));
+  assert_eq!(lib["usedExports"], json!(["used"]));
+  assert_eq!(lib["optimizationBailout"], json!([]));
+  let index = find_module(&modules, "./index.js");
+  assert_eq!(index["providedExports"], json!([]));
+
+  let stats = stats
+    .to_json(&StatsOptions::from_preset(StatsPreset::Detailed))
+    .expect("should get stats");
+  let stats = serde_json::to_value(stats).expect("should serialize stats");
+  let lib = find_module(&stats["modules"], "./lib.js");
+  assert_eq!(lib["providedExports"], json!(["unused", "used"]));
+  assert_eq!(lib["usedExports"], json!(["used"]));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn omit_exports_of_modules_unless_requested() {
+  let compiler = build("exports").await;
+  let stats = compiler.compilation.get_stats();
+  let modules = stats
+    .get_modules(false, false, false, false, false, false, false)
+    .expect("should get modules");
+  let modules = serde_json::to_value(modules).expect("should serialize modules");
+  let lib = find_module(&modules, "./lib.js");
+  assert!(lib.get("providedExports").is_none(), "{lib:#?}");
+  assert!(lib.get("usedExports").is_none(), "{lib:#?}");
+  assert!(lib.get("optimizationBailout").is_none(), "{lib:#?}");
+}
@@ -0,0 +1,156 @@
+use std::{
+  ops::ControlFlow,
+  path::{Path, PathBuf},
+  sync::atomic::{AtomicBool, Ordering},
+  time::Duration,
+};
+
+use rspack_core::{
+  Plugin, PluginContext, PluginProcessAssetsOutput, ProcessAssetsArgs, WatchOptions,
+};
+use rspack_testing::{copy_fixture, create_compiler_from_fixture, main_js};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn watch_rebuilds_changed_files() {
+  let fixture_path = copy_fixture(
+    &Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/watch/changed-file"),
+    Path::new(env!("CARGO_TARGET_TMPDIR")),
+  );
+  let lib = fixture_path.join("lib.js");
+
+  let mut compiler = create_compiler_from_fixture(&fixture_path, |_, _| {});
+  let mut builds = 0;
+  let watching = compiler.watch(
+    WatchOptions {
+      ignored: vec!["**/dist".to_string()],
+      ..Default::default()
+    },
+    |result| {
+      let compilation = result.expect("should build");
+      builds += 1;
+      match builds {
+        1 => {
+          assert!(main_js(compilation).contains("'first'"));
+          std::fs::write(&lib, "export const value = 'second';").expect("should write lib.js");
+          ControlFlow::Continue(())
+        }
+        _ => {
+          assert!(main_js(compilation).contains("'second'"));
+          ControlFlow::Break(())
+        }
+      }
+    },
+  );
+  tokio::time::timeout(Duration::from_secs(30), watching)
+    .await
+    .expect("should rebuild after the change")
+    .expect("should watch");
+  assert_eq!(builds, 2);
+
+  let _ = std::fs::remove_dir_all(&fixture_path);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn watch_retries_missing_files_when_created() {
+  let fixture_path = copy_fixture(
+    &Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/watch/missing-file"),
+    Path::new(env!("CARGO_TARGET_TMPDIR")),
+  );
+  let lib = fixture_path.join("src/lib.js");
+
+  let mut compiler = create_compiler_from_fixture(&fixture_path, |_, _| {});
+  let mut builds = 0;
+  let watching = compiler.watch(WatchOptions::default(), |result| {
+    let compilation = result.expect("should build");
+    builds += 1;
+    match builds {
+      1 => {
+        assert_eq!(compilation.get_errors().count(), 1);
+        assert!(compilation.missing_dependencies.contains(&lib));
+        // the directory of the missing file is created with it
+        std::fs::create_dir_all(lib.parent().expect("should have parent"))
+          .expect("should create dir");
+        std::fs::write(&lib, "export const value = 'created';").expect("should write lib.js");
+        ControlFlow::Continue(())
+      }
+      _ => {
+        assert_eq!(compilation.get_errors().count(), 0);
+        assert!(main_js(compilation).contains("'created'"));
+        ControlFlow::Break(())
+      }
+    }
+  });
+  tokio::time::timeout(Duration::from_secs(30), watching)
+    .await
+    .expect("should rebuild after the missing file is created")
+    .expect("should watch");
+  assert_eq!(builds, 2);
+
+  let _ = std::fs::remove_dir_all(&fixture_path);
+}
+
+/// Edits a file after it's built, as if the user saved it during the first build.
+#[derive(Debug)]
+struct EditDuringBuildPlugin {
+  path: PathBuf,
+  edited: AtomicBool,
+}
+
+#[async_trait::async_trait]
+impl Plugin for EditDuringBuildPlugin {
+  async fn process_assets_stage_additional(
+    &self,
+    _ctx: PluginContext,
+    _args: ProcessAssetsArgs<'_>,
+  ) -> PluginProcessAssetsOutput {
+    if !self.edited.swap(true, Ordering::SeqCst) {
+      std::fs::write(&self.path, "export const value = 'second';").expect("should write lib.js");
+    }
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn watch_rebuilds_files_changed_during_build() {
+  let fixture_path = copy_fixture(
+    &Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/watch/changed-during-build"),
+    Path::new(env!("CARGO_TARGET_TMPDIR")),
+  );
+  let lib = fixture_path.join("lib.js");
+
+  let mut compiler = create_compiler_from_fixture(&fixture_path, |plugins, _| {
+    plugins.push(Box::new(EditDuringBuildPlugin {
+      path: lib.clone(),
+      edited: AtomicBool::new(false),
+    }));
+  });
+  let mut builds = 0;
+  let watching = compiler.watch(
+    WatchOptions {
+      ignored: vec!["**/dist".to_string()],
+      ..Default::default()
+    },
+    |result| {
+      let compilation = result.expect("should build");
+      builds += 1;
+      match builds {
+        1 => {
+          // the change is made before lib.js is watched
+          assert!(main_js(compilation).contains("'first'"));
+          ControlFlow::Continue(())
+        }
+        _ => {
+          assert!(main_js(compilation).contains("'second'"));
+          ControlFlow::Break(())
+        }
+      }
+    },
+  );
+  tokio::time::timeout(Duration::from_secs(30), watching)
+    .await
+    .expect("should rebuild the change made during the build")
+    .expect("should watch");
+  assert_eq!(builds, 2);
+
+  let _ = std::fs::remove_dir_all(&fixture_path);
+}
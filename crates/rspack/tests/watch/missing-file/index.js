@@ -0,0 +1,2 @@
+import { value } from './src/lib';
+console.log(value);
@@ -1,8 +1,10 @@
 use napi_derive::napi;
 use rspack_core::{
   CompilerOptions, Context, DevServerOptions, Devtool, Experiments, IncrementalRebuild,
-  IncrementalRebuildMakeState, ModuleOptions, Optimization, OutputOptions, Target, TreeShaking,
+  IncrementalRebuildMakeState, ModuleOptions, Optimization, OutputOptions, PerformanceOptions,
+  PluginExt, Target, TreeShaking,
 };
+use rspack_plugin_size_limits::SizeLimitsPlugin;
 use serde::Deserialize;
 
 mod raw_builtins;
@@ -17,6 +19,7 @@ mod raw_module;
 mod raw_node;
 mod raw_optimization;
 mod raw_output;
+mod raw_performance;
 mod raw_resolve;
 mod raw_snapshot;
 mod raw_split_chunks;
@@ -34,6 +37,7 @@ pub use raw_module::*;
 pub use raw_node::*;
 pub use raw_optimization::*;
 pub use raw_output::*;
+pub use raw_performance::*;
 pub use raw_resolve::*;
 pub use raw_snapshot::*;
 pub use raw_split_chunks::*;
@@ -64,6 +68,7 @@ pub struct RawOptions {
   pub cache: RawCacheOptions,
   pub experiments: RawExperiments,
   pub node: Option<RawNodeOption>,
+  pub performance: RawPerformanceOptions,
   pub profile: bool,
   pub bail: bool,
   pub builtins: RawBuiltins,
@@ -81,8 +86,8 @@ impl RawOptions {
     let devtool: Devtool = self.devtool.into();
     let mode = self.mode.unwrap_or_default().into();
     let module: ModuleOptions = self.module.try_into()?;
-    let target = Target::new(&self.target)?;
-    let cache = self.cache.into();
+    let target = Target::new(&self.target, &context)?;
+    let cache = self.cache.try_into()?;
     let experiments = Experiments {
       incremental_rebuild: IncrementalRebuild {
         make: self
@@ -100,9 +105,13 @@ impl RawOptions {
       .set(&experiments.new_split_chunks, || {
         self.optimization.try_into()
       })?;
-    let stats = self.stats.into();
-    let snapshot = self.snapshot.into();
+    let stats = self.stats.try_into()?;
+    let snapshot = self.snapshot.try_into()?;
     let node = self.node.map(|n| n.into());
+    let performance: PerformanceOptions = self.performance.try_into()?;
+    if performance.hints.is_some() {
+      plugins.push(SizeLimitsPlugin.boxed());
+    }
     let dev_server: DevServerOptions = self.dev_server.into();
 
     let mut builtins = self.builtins.apply(plugins)?;
@@ -126,6 +135,7 @@ impl RawOptions {
       optimization,
       node,
       dev_server,
+      performance,
       profile: self.profile,
       bail: self.bail,
       builtins,
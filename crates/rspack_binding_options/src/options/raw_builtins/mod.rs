@@ -1,6 +1,7 @@
 mod raw_banner;
 mod raw_copy;
 mod raw_html;
+mod raw_http_uri;
 mod raw_limit_chunk_count;
 mod raw_mf;
 mod raw_progress;
@@ -32,15 +33,15 @@ use rspack_plugin_hmr::HotModuleReplacementPlugin;
 use rspack_plugin_html::HtmlRspackPlugin;
 use rspack_plugin_javascript::{
   FlagDependencyExportsPlugin, FlagDependencyUsagePlugin, InferAsyncModulesPlugin, JsPlugin,
-  MangleExportsPlugin, SideEffectsFlagPlugin,
+  MangleExportsPlugin, ModuleConcatenationPlugin, SideEffectsFlagPlugin,
 };
 use rspack_plugin_json::JsonPlugin;
 use rspack_plugin_library::enable_library_plugin;
 use rspack_plugin_limit_chunk_count::LimitChunkCountPlugin;
 use rspack_plugin_merge_duplicate_chunks::MergeDuplicateChunksPlugin;
 use rspack_plugin_mf::{
-  ConsumeSharedPlugin, ContainerPlugin, ContainerReferencePlugin, ProvideSharedPlugin,
-  ShareRuntimePlugin,
+  ConsumeSharedPlugin, ContainerPlugin, ContainerReferencePlugin, ModuleFederationManifestPlugin,
+  ProvideSharedPlugin, ShareRuntimePlugin,
 };
 use rspack_plugin_progress::ProgressPlugin;
 use rspack_plugin_real_content_hash::RealContentHashPlugin;
@@ -49,7 +50,7 @@ use rspack_plugin_runtime::{
   enable_chunk_loading_plugin, ArrayPushCallbackChunkFormatPlugin, ChunkPrefetchPreloadPlugin,
   CommonJsChunkFormatPlugin, ModuleChunkFormatPlugin, RuntimePlugin,
 };
-use rspack_plugin_schemes::{DataUriPlugin, FileUriPlugin};
+use rspack_plugin_schemes::{DataUriPlugin, FileUriPlugin, HttpUriPlugin};
 use rspack_plugin_swc_css_minimizer::SwcCssMinimizerRspackPlugin;
 use rspack_plugin_swc_js_minimizer::SwcJsMinimizerRspackPlugin;
 use rspack_plugin_warn_sensitive_module::WarnCaseSensitiveModulesPlugin;
@@ -58,12 +59,14 @@ use rspack_plugin_web_worker_template::web_worker_template_plugin;
 use rspack_plugin_worker::WorkerPlugin;
 
 use self::raw_mf::{
-  RawConsumeSharedPluginOptions, RawContainerReferencePluginOptions, RawProvideOptions,
+  RawConsumeSharedPluginOptions, RawContainerReferencePluginOptions,
+  RawModuleFederationManifestPluginOptions, RawProvideOptions,
 };
 pub use self::{
   raw_banner::RawBannerPluginOptions, raw_copy::RawCopyRspackPluginOptions,
-  raw_html::RawHtmlRspackPluginOptions, raw_limit_chunk_count::RawLimitChunkCountPluginOptions,
-  raw_mf::RawContainerPluginOptions, raw_progress::RawProgressPluginOptions,
+  raw_html::RawHtmlRspackPluginOptions, raw_http_uri::RawHttpUriPluginOptions,
+  raw_limit_chunk_count::RawLimitChunkCountPluginOptions, raw_mf::RawContainerPluginOptions,
+  raw_progress::RawProgressPluginOptions,
   raw_swc_js_minimizer::RawSwcJsMinimizerRspackPluginOptions,
 };
 use crate::{
@@ -102,6 +105,7 @@ pub enum BuiltinPluginName {
   ContainerReferencePlugin,
   ProvideSharedPlugin,
   ConsumeSharedPlugin,
+  ModuleFederationManifestPlugin,
   NamedModuleIdsPlugin,
   DeterministicModuleIdsPlugin,
   NamedChunkIdsPlugin,
@@ -112,6 +116,7 @@ pub enum BuiltinPluginName {
   WarnCaseSensitiveModulesPlugin,
   DataUriPlugin,
   FileUriPlugin,
+  HttpUriPlugin,
   RuntimePlugin,
   JsonModulesPlugin,
   InferAsyncModulesPlugin,
@@ -124,6 +129,7 @@ pub enum BuiltinPluginName {
   FlagDependencyExportsPlugin,
   FlagDependencyUsagePlugin,
   MangleExportsPlugin,
+  ModuleConcatenationPlugin,
 
   // rspack specific plugins
   HttpExternalsRspackPlugin,
@@ -234,7 +240,7 @@ impl BuiltinPlugin {
       }
       BuiltinPluginName::SplitChunksPlugin => {
         use rspack_plugin_split_chunks_new::SplitChunksPlugin;
-        let options = downcast_into::<RawSplitChunksOptions>(self.options)?.into();
+        let options = downcast_into::<RawSplitChunksOptions>(self.options)?.try_into()?;
         plugins.push(SplitChunksPlugin::new(options).boxed());
       }
       BuiltinPluginName::OldSplitChunksPlugin => {
@@ -273,6 +279,12 @@ impl BuiltinPlugin {
         )
         .boxed(),
       ),
+      BuiltinPluginName::ModuleFederationManifestPlugin => plugins.push(
+        ModuleFederationManifestPlugin::new(
+          downcast_into::<RawModuleFederationManifestPluginOptions>(self.options)?.into(),
+        )
+        .boxed(),
+      ),
       BuiltinPluginName::NamedModuleIdsPlugin => {
         plugins.push(NamedModuleIdsPlugin::default().boxed())
       }
@@ -295,6 +307,10 @@ impl BuiltinPlugin {
       }
       BuiltinPluginName::DataUriPlugin => plugins.push(DataUriPlugin.boxed()),
       BuiltinPluginName::FileUriPlugin => plugins.push(FileUriPlugin.boxed()),
+      BuiltinPluginName::HttpUriPlugin => plugins.push(
+        HttpUriPlugin::new(downcast_into::<RawHttpUriPluginOptions>(self.options)?.try_into()?)
+          .boxed(),
+      ),
       BuiltinPluginName::RuntimePlugin => plugins.push(RuntimePlugin.boxed()),
       BuiltinPluginName::JsonModulesPlugin => plugins.push(JsonPlugin.boxed()),
       BuiltinPluginName::InferAsyncModulesPlugin => plugins.push(InferAsyncModulesPlugin.boxed()),
@@ -305,13 +321,13 @@ impl BuiltinPlugin {
       BuiltinPluginName::AssetModulesPlugin => plugins.push(AssetPlugin.boxed()),
       BuiltinPluginName::SourceMapDevToolPlugin => plugins.push(
         SourceMapDevToolPlugin::new(
-          downcast_into::<RawSourceMapDevToolPluginOptions>(self.options)?.into(),
+          downcast_into::<RawSourceMapDevToolPluginOptions>(self.options)?.try_into()?,
         )
         .boxed(),
       ),
       BuiltinPluginName::EvalSourceMapDevToolPlugin => plugins.push(
         EvalSourceMapDevToolPlugin::new(
-          downcast_into::<RawSourceMapDevToolPluginOptions>(self.options)?.into(),
+          downcast_into::<RawSourceMapDevToolPluginOptions>(self.options)?.try_into()?,
         )
         .boxed(),
       ),
@@ -327,6 +343,9 @@ impl BuiltinPlugin {
       BuiltinPluginName::MangleExportsPlugin => {
         plugins.push(MangleExportsPlugin::new(downcast_into::<bool>(self.options)?).boxed())
       }
+      BuiltinPluginName::ModuleConcatenationPlugin => {
+        plugins.push(ModuleConcatenationPlugin::default().boxed())
+      }
 
       // rspack specific plugins
       BuiltinPluginName::HttpExternalsRspackPlugin => {
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use derivative::Derivative;
+use napi::{bindgen_prelude::Buffer, Either, Env, JsFunction};
+use napi_derive::napi;
+use rspack_error::{error, Result};
+use rspack_napi_shared::{
+  get_napi_env,
+  threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  JsRegExp, JsRegExpExt, NapiResultExt,
+};
+use rspack_plugin_schemes::{AllowedUri, HttpClientFn, HttpResponse, HttpUriPluginOptions};
+
+#[napi(object)]
+pub struct RawHttpResponse {
+  pub status: u32,
+  pub content_type: Option<String>,
+  pub body: Buffer,
+}
+
+type RawAllowedUri = Either<String, JsRegExp>;
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[napi(object)]
+pub struct RawHttpUriPluginOptions {
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(string | RegExp)[]")]
+  pub allowed_uris: Vec<RawAllowedUri>,
+  pub lockfile_location: String,
+  pub cache_location: Option<String>,
+  pub frozen: bool,
+  pub upgrade: bool,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(url: string) => Promise<RawHttpResponse>")]
+  pub http_client: JsFunction,
+}
+
+impl TryFrom<RawHttpUriPluginOptions> for HttpUriPluginOptions {
+  type Error = rspack_error::Error;
+  fn try_from(value: RawHttpUriPluginOptions) -> Result<Self> {
+    let func: napi::Result<ThreadsafeFunction<String, RawHttpResponse>> = try {
+      let env = get_napi_env();
+      let f = value.http_client;
+      rspack_binding_macros::js_fn_into_threadsafe_fn!(f, &Env::from(env))
+    };
+    let func = Arc::new(func.into_rspack_result()?);
+    let http_client: HttpClientFn = Box::new(move |url: String| {
+      let func = func.clone();
+      Box::pin(async move {
+        let response = func
+          .call(url, ThreadsafeFunctionCallMode::NonBlocking)
+          .into_rspack_result()?
+          .await
+          .map_err(|err| error!("Failed to call httpClient function: {err}"))??;
+        Ok(HttpResponse {
+          status: response
+            .status
+            .try_into()
+            .map_err(|_| error!("Invalid status code {}", response.status))?,
+          content_type: response.content_type,
+          body: response.body.to_vec(),
+        })
+      })
+    });
+    Ok(Self {
+      allowed_uris: value
+        .allowed_uris
+        .into_iter()
+        .map(|allowed| match allowed {
+          Either::A(prefix) => AllowedUri::String(prefix),
+          Either::B(regexp) => AllowedUri::Regexp(regexp.to_rspack_regex()),
+        })
+        .collect(),
+      lockfile_location: value.lockfile_location.into(),
+      cache_location: value.cache_location.map(Into::into),
+      frozen: value.frozen,
+      upgrade: value.upgrade,
+      http_client: Some(http_client),
+    })
+  }
+}
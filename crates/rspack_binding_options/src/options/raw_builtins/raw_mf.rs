@@ -4,7 +4,8 @@ use napi::Either;
 use napi_derive::napi;
 use rspack_plugin_mf::{
   ConsumeOptions, ConsumeSharedPluginOptions, ConsumeVersion, ContainerPluginOptions,
-  ContainerReferencePluginOptions, ExposeOptions, ProvideOptions, ProvideVersion, RemoteOptions,
+  ContainerReferencePluginOptions, ExposeOptions, ModuleFederationManifestPluginOptions,
+  ProvideOptions, ProvideVersion, RemoteOptions,
 };
 
 use crate::RawLibraryOptions;
@@ -95,6 +96,26 @@ impl From<RawRemoteOptions> for (String, RemoteOptions) {
   }
 }
 
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawModuleFederationManifestPluginOptions {
+  pub name: String,
+  pub filename: String,
+  pub remote_type: String,
+  pub remotes: Vec<RawRemoteOptions>,
+}
+
+impl From<RawModuleFederationManifestPluginOptions> for ModuleFederationManifestPluginOptions {
+  fn from(value: RawModuleFederationManifestPluginOptions) -> Self {
+    Self {
+      name: value.name,
+      filename: value.filename,
+      remote_type: value.remote_type,
+      remotes: value.remotes.into_iter().map(|e| e.into()).collect(),
+    }
+  }
+}
+
 #[derive(Debug)]
 #[napi(object)]
 pub struct RawProvideOptions {
@@ -1,18 +1,29 @@
-use std::{path::PathBuf, str::FromStr};
+use std::{fmt::Debug, path::PathBuf, str::FromStr, sync::Arc};
 
+use napi::{Either, Env, JsFunction};
 use napi_derive::napi;
 use rspack_core::{Builtins, DecoratorOptions, PluginExt, PresetEnv};
-use rspack_error::error;
+use rspack_error::{error, Result};
+use rspack_napi_shared::{
+  get_napi_env,
+  threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  NapiResultExt,
+};
 use rspack_plugin_css::{
-  plugin::{CssConfig, LocalIdentName, LocalsConvention, ModulesConfig},
+  plugin::{
+    CssConfig, LocalIdentName, LocalsConvention, ModulesConfig, ModulesMode, ModulesModeGetter,
+  },
   CssPlugin,
 };
 use rspack_plugin_dev_friendly_split_chunks::DevFriendlySplitChunksPlugin;
+use rspack_regex::RspackRegex;
 use rspack_swc_visitors::{
   CustomTransform, ImportOptions, ReactOptions, RelayLanguageConfig, RelayOptions, StyleConfig,
 };
 use serde::{Deserialize, Serialize};
 
+use crate::RawRegexMatcher;
+
 #[derive(Deserialize, Debug, Serialize, Default, Clone)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
@@ -188,14 +199,15 @@ impl From<RawRelayConfig> for RelayOptions {
   }
 }
 
-#[derive(Deserialize, Debug, Serialize, Clone)]
+#[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
 pub struct RawCssPluginConfig {
   pub modules: RawCssModulesConfig,
+  pub ignore_order: bool,
 }
 
-#[derive(Deserialize, Debug, Serialize, Clone)]
+#[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
 pub struct RawCssModulesConfig {
@@ -203,6 +215,55 @@ pub struct RawCssModulesConfig {
   pub locals_convention: String,
   pub local_ident_name: String,
   pub exports_only: bool,
+  #[serde(skip_deserializing)]
+  #[napi(
+    ts_type = "\"local\" | \"global\" | \"pure\" | ((resourcePath: string) => \"local\" | \"global\" | \"pure\")"
+  )]
+  pub mode: Option<Either<String, JsFunction>>,
+  pub hash_prefix: Option<String>,
+  pub local_ident_context: Option<String>,
+  pub local_ident_reg_exp: Option<RawRegexMatcher>,
+  pub auto: Option<RawRegexMatcher>,
+}
+
+impl Debug for RawCssModulesConfig {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("RawCssModulesConfig")
+      .field("locals_convention", &self.locals_convention)
+      .field("local_ident_name", &self.local_ident_name)
+      .field("exports_only", &self.exports_only)
+      .field("mode", &"...")
+      .field("hash_prefix", &self.hash_prefix)
+      .field("local_ident_context", &self.local_ident_context)
+      .field("local_ident_reg_exp", &self.local_ident_reg_exp)
+      .field("auto", &self.auto)
+      .finish()
+  }
+}
+
+fn normalize_raw_css_modules_mode(raw: Either<String, JsFunction>) -> Result<ModulesModeGetter> {
+  Ok(match raw {
+    Either::A(mode) => ModulesModeGetter::Mode(ModulesMode::from_str(&mode)?),
+    Either::B(f) => {
+      let func: napi::Result<ThreadsafeFunction<String, String>> = try {
+        let env = get_napi_env();
+        rspack_binding_macros::js_fn_into_threadsafe_fn!(f, &Env::from(env))
+      };
+      let func = Arc::new(func.into_rspack_result()?);
+      ModulesModeGetter::Fn(Arc::new(move |resource_path: &str| {
+        let func = func.clone();
+        let resource_path = resource_path.to_string();
+        Box::pin(async move {
+          let mode = func
+            .call(resource_path, ThreadsafeFunctionCallMode::NonBlocking)
+            .into_rspack_result()?
+            .await
+            .map_err(|err| error!("Failed to call css modules mode function: {err}"))??;
+          ModulesMode::from_str(&mode)
+        })
+      }))
+    }
+  })
 }
 
 impl TryFrom<RawCssModulesConfig> for ModulesConfig {
@@ -213,6 +274,21 @@ impl TryFrom<RawCssModulesConfig> for ModulesConfig {
       locals_convention: LocalsConvention::from_str(&value.locals_convention)?,
       local_ident_name: LocalIdentName::from(value.local_ident_name),
       exports_only: value.exports_only,
+      mode: value
+        .mode
+        .map(normalize_raw_css_modules_mode)
+        .transpose()?
+        .unwrap_or_default(),
+      hash_prefix: value.hash_prefix.unwrap_or_default(),
+      local_ident_context: value.local_ident_context.map(PathBuf::from),
+      local_ident_reg_exp: value
+        .local_ident_reg_exp
+        .map(|regexp| RspackRegex::with_flags(&regexp.source, &regexp.flags))
+        .transpose()?,
+      auto: value
+        .auto
+        .map(|auto| RspackRegex::with_flags(&auto.source, &auto.flags))
+        .transpose()?,
     })
   }
 }
@@ -238,6 +314,7 @@ impl RawBuiltins {
     if let Some(css) = self.css {
       let options = CssConfig {
         modules: css.modules.try_into()?,
+        ignore_order: css.ignore_order,
       };
       plugins.push(CssPlugin::new(options).boxed());
     }
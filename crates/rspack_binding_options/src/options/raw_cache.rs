@@ -1,5 +1,5 @@
 use napi_derive::napi;
-use rspack_core::{CacheOptions, FileSystemCacheOptions, MemoryCacheOptions};
+use rspack_core::{CacheCompression, CacheOptions, FileSystemCacheOptions, MemoryCacheOptions};
 use serde::Deserialize;
 
 #[derive(Deserialize, Debug, Default)]
@@ -15,10 +15,16 @@ pub struct RawCacheOptions {
   pub cache_location: String,
   pub name: String,
   pub version: String,
+  #[napi(ts_type = r#""gzip" | "brotli" | "zstd""#)]
+  pub compression: Option<String>,
+  pub idle_timeout: Option<u32>,
+  pub max_size: Option<i64>,
 }
 
-impl From<RawCacheOptions> for CacheOptions {
-  fn from(value: RawCacheOptions) -> CacheOptions {
+impl TryFrom<RawCacheOptions> for CacheOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawCacheOptions) -> rspack_error::Result<CacheOptions> {
     let RawCacheOptions {
       r#type,
       max_generations,
@@ -29,9 +35,12 @@ impl From<RawCacheOptions> for CacheOptions {
       cache_location,
       name,
       version,
+      compression,
+      idle_timeout,
+      max_size,
     } = value;
 
-    match r#type.as_str() {
+    Ok(match r#type.as_str() {
       "memory" => CacheOptions::Memory(MemoryCacheOptions { max_generations }),
       "filesystem" => CacheOptions::FileSystem(FileSystemCacheOptions {
         max_age,
@@ -41,8 +50,15 @@ impl From<RawCacheOptions> for CacheOptions {
         cache_location,
         name,
         version,
+        compression: compression
+          .as_deref()
+          .map(CacheCompression::try_from)
+          .transpose()?
+          .unwrap_or_default(),
+        idle_timeout: idle_timeout.unwrap_or_default(),
+        max_size: max_size.unwrap_or_default().max(0) as u64,
       }),
       _ => CacheOptions::Disabled,
-    }
+    })
   }
 }
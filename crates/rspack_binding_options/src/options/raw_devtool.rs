@@ -1,7 +1,84 @@
+use std::sync::Arc;
+
+use derivative::Derivative;
+use napi::{Either, Env, JsFunction};
 use napi_derive::napi;
-use rspack_plugin_devtool::SourceMapDevToolPluginOptions;
+use rspack_error::{error, Result};
+use rspack_napi_shared::{
+  get_napi_env,
+  threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  NapiResultExt,
+};
+use rspack_plugin_devtool::{
+  ModuleFilenameTemplate, ModuleFilenameTemplateFnCtx, SourceMapDevToolPluginOptions,
+};
+
+#[napi(object)]
+pub struct RawModuleFilenameTemplateFnCtx {
+  pub identifier: String,
+  pub short_identifier: String,
+  pub resource: String,
+  pub resource_path: String,
+  pub absolute_resource_path: String,
+  pub loaders: String,
+  pub all_loaders: String,
+  pub query: String,
+  pub module_id: String,
+  pub hash: String,
+  pub namespace: String,
+}
+
+impl From<ModuleFilenameTemplateFnCtx> for RawModuleFilenameTemplateFnCtx {
+  fn from(value: ModuleFilenameTemplateFnCtx) -> Self {
+    Self {
+      identifier: value.identifier,
+      short_identifier: value.short_identifier,
+      resource: value.resource,
+      resource_path: value.resource_path,
+      absolute_resource_path: value.absolute_resource_path,
+      loaders: value.loaders,
+      all_loaders: value.all_loaders,
+      query: value.query,
+      module_id: value.module_id,
+      hash: value.hash,
+      namespace: value.namespace,
+    }
+  }
+}
+
+type RawModuleFilenameTemplate = Either<String, JsFunction>;
+struct RawModuleFilenameTemplateWrapper(RawModuleFilenameTemplate);
 
-#[derive(Debug)]
+impl TryFrom<RawModuleFilenameTemplateWrapper> for ModuleFilenameTemplate {
+  type Error = rspack_error::Error;
+  fn try_from(value: RawModuleFilenameTemplateWrapper) -> Result<Self> {
+    match value.0 {
+      Either::A(s) => Ok(Self::String(s)),
+      Either::B(f) => {
+        let func: napi::Result<ThreadsafeFunction<RawModuleFilenameTemplateFnCtx, String>> = try {
+          let env = get_napi_env();
+          rspack_binding_macros::js_fn_into_threadsafe_fn!(f, &Env::from(env))
+        };
+        let func = Arc::new(func.into_rspack_result()?);
+        Ok(ModuleFilenameTemplate::Fn(Box::new(
+          move |ctx: ModuleFilenameTemplateFnCtx| {
+            let func = func.clone();
+            Box::pin(async move {
+              func
+                .call(ctx.into(), ThreadsafeFunctionCallMode::NonBlocking)
+                .into_rspack_result()?
+                .await
+                .map_err(|err| error!("Failed to call moduleFilenameTemplate function: {err}"))?
+            })
+          },
+        )))
+      }
+    }
+  }
+}
+
+#[derive(Derivative)]
+#[derivative(Debug)]
 #[napi(object)]
 pub struct RawSourceMapDevToolPluginOptions {
   pub filename: Option<String>,
@@ -10,17 +87,32 @@ pub struct RawSourceMapDevToolPluginOptions {
   pub columns: bool,
   pub no_sources: bool,
   pub public_path: Option<String>,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "string | ((info: RawModuleFilenameTemplateFnCtx) => string)")]
+  pub module_filename_template: Option<RawModuleFilenameTemplate>,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "string | ((info: RawModuleFilenameTemplateFnCtx) => string)")]
+  pub fallback_module_filename_template: Option<RawModuleFilenameTemplate>,
 }
 
-impl From<RawSourceMapDevToolPluginOptions> for SourceMapDevToolPluginOptions {
-  fn from(value: RawSourceMapDevToolPluginOptions) -> Self {
-    Self {
+impl TryFrom<RawSourceMapDevToolPluginOptions> for SourceMapDevToolPluginOptions {
+  type Error = rspack_error::Error;
+  fn try_from(value: RawSourceMapDevToolPluginOptions) -> Result<Self> {
+    Ok(Self {
       filename: value.filename,
       append: value.append,
       namespace: value.namespace,
       columns: value.columns,
       no_sources: value.no_sources,
       public_path: value.public_path,
-    }
+      module_filename_template: value
+        .module_filename_template
+        .map(|v| RawModuleFilenameTemplateWrapper(v).try_into())
+        .transpose()?,
+      fallback_module_filename_template: value
+        .fallback_module_filename_template
+        .map(|v| RawModuleFilenameTemplateWrapper(v).try_into())
+        .transpose()?,
+    })
   }
 }
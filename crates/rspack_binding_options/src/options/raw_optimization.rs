@@ -15,6 +15,7 @@ pub struct RawOptimizationOptions {
   pub provided_exports: bool,
   pub inner_graph: bool,
   pub mangle_exports: String,
+  pub concatenate_modules: bool,
 }
 
 impl TryFrom<RawOptimizationOptions> for Optimization {
@@ -28,6 +29,7 @@ impl TryFrom<RawOptimizationOptions> for Optimization {
       used_exports: UsedExportsOption::from(value.used_exports.as_str()),
       inner_graph: value.inner_graph,
       mangle_exports: MangleExportsOption::from(value.mangle_exports.as_str()),
+      concatenate_modules: value.concatenate_modules,
     })
   }
 }
@@ -5,6 +5,8 @@ use rspack_core::{
 use rspack_core::{LibraryAuxiliaryComment, OutputOptions, TrustedTypes};
 use serde::Deserialize;
 
+use crate::RawPathMatcher;
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
@@ -151,6 +153,7 @@ impl From<RawCrossOriginLoading> for CrossOriginLoading {
 pub struct RawOutputOptions {
   pub path: String,
   pub clean: bool,
+  pub clean_keep: Option<RawPathMatcher>,
   pub public_path: String,
   pub asset_module_filename: String,
   pub wasm_loading: String,
@@ -196,6 +199,7 @@ impl TryFrom<RawOutputOptions> for OutputOptions {
     Ok(OutputOptions {
       path: value.path.into(),
       clean: value.clean,
+      clean_keep: value.clean_keep.map(TryInto::try_into).transpose()?,
       public_path: value.public_path.into(),
       asset_module_filename: value.asset_module_filename.into(),
       wasm_loading: value.wasm_loading.as_str().into(),
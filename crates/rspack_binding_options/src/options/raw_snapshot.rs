@@ -1,7 +1,10 @@
 use napi_derive::napi;
-use rspack_core::{SnapshotOptions, SnapshotStrategy};
+use rspack_core::{PathMatcher, SnapshotOptions, SnapshotStrategy};
+use rspack_error::error;
 use serde::Deserialize;
 
+use crate::RawRegexMatcher;
+
 #[derive(Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
@@ -19,21 +22,67 @@ impl From<RawSnapshotStrategy> for SnapshotStrategy {
   }
 }
 
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawPathMatcher {
+  #[napi(ts_type = r#""string" | "regexp""#)]
+  pub r#type: String,
+  pub string_matcher: Option<String>,
+  pub regexp_matcher: Option<RawRegexMatcher>,
+}
+
+impl TryFrom<RawPathMatcher> for PathMatcher {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawPathMatcher) -> rspack_error::Result<Self> {
+    match (value.r#type.as_str(), value.string_matcher, value.regexp_matcher) {
+      ("string", Some(path), _) => Ok(Self::String(path)),
+      ("regexp", _, Some(matcher)) => Ok(Self::Regexp(rspack_regex::RspackRegex::with_flags(
+        &matcher.source,
+        &matcher.flags,
+      )?)),
+      (r#type, ..) => Err(error!(
+        "Invalid snapshot path matcher with type {type}, expected a string_matcher or regexp_matcher"
+      )),
+    }
+  }
+}
+
 #[derive(Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
 pub struct RawSnapshotOptions {
   pub resolve: RawSnapshotStrategy,
   pub module: RawSnapshotStrategy,
+  pub immutable_paths: Option<Vec<RawPathMatcher>>,
+  pub managed_paths: Option<Vec<RawPathMatcher>>,
 }
 
-impl From<RawSnapshotOptions> for SnapshotOptions {
-  fn from(value: RawSnapshotOptions) -> Self {
-    let RawSnapshotOptions { resolve, module } = value;
+impl TryFrom<RawSnapshotOptions> for SnapshotOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawSnapshotOptions) -> rspack_error::Result<Self> {
+    let RawSnapshotOptions {
+      resolve,
+      module,
+      immutable_paths,
+      managed_paths,
+    } = value;
 
-    SnapshotOptions {
+    Ok(SnapshotOptions {
       resolve: resolve.into(),
       module: module.into(),
-    }
+      immutable_paths: immutable_paths
+        .unwrap_or_default()
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect::<rspack_error::Result<_>>()?,
+      managed_paths: managed_paths
+        .unwrap_or_default()
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect::<rspack_error::Result<_>>()?,
+    })
   }
 }
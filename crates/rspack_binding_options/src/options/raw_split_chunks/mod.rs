@@ -42,7 +42,7 @@ pub struct RawSplitChunksOptions {
   pub automatic_name_delimiter: Option<String>,
   pub max_async_requests: Option<u32>,
   pub max_initial_requests: Option<u32>,
-  //   pub default_size_types: Option<Vec<SizeType>>,
+  pub default_size_types: Option<Vec<String>>,
   pub min_chunks: Option<u32>,
   pub hide_path_info: Option<bool>,
   pub min_size: Option<f64>,
@@ -164,8 +164,10 @@ pub struct RawCacheGroupOptions {
   pub enforce: Option<bool>,
 }
 
-impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks_new::PluginOptions {
-  fn from(raw_opts: RawSplitChunksOptions) -> Self {
+impl TryFrom<RawSplitChunksOptions> for rspack_plugin_split_chunks_new::PluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(raw_opts: RawSplitChunksOptions) -> rspack_error::Result<Self> {
     use rspack_plugin_split_chunks_new::SplitChunkSizes;
 
     let mut cache_groups = vec![];
@@ -178,7 +180,13 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks_new::PluginOptio
       normalize_raw_chunk_name(name)
     });
 
-    let default_size_types = [SourceType::JavaScript, SourceType::Unknown];
+    let default_size_types = match &raw_opts.default_size_types {
+      Some(size_types) => size_types
+        .iter()
+        .map(|size_type| SourceType::try_from(size_type.as_str()))
+        .collect::<rspack_error::Result<Vec<_>>>()?,
+      None => vec![SourceType::JavaScript, SourceType::Unknown],
+    };
 
     let create_sizes = |size: Option<f64>| {
       size
@@ -296,7 +304,7 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks_new::PluginOptio
       .merge(&overall_max_initial_size)
       .merge(&overall_max_size);
 
-    rspack_plugin_split_chunks_new::PluginOptions {
+    Ok(rspack_plugin_split_chunks_new::PluginOptions {
       cache_groups,
       fallback_cache_group: rspack_plugin_split_chunks_new::FallbackCacheGroup {
         chunks_filter: fallback_chunks_filter.unwrap_or_else(|| {
@@ -312,7 +320,7 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks_new::PluginOptio
           .unwrap_or(overall_automatic_name_delimiter.clone()),
       },
       hide_path_info: raw_opts.hide_path_info,
-    }
+    })
   }
 }
 
@@ -1,5 +1,5 @@
 use napi_derive::napi;
-use rspack_core::StatsOptions;
+use rspack_core::{StatsOptions, StatsPreset};
 use serde::Deserialize;
 
 #[derive(Deserialize, Debug, Default)]
@@ -7,12 +7,74 @@ use serde::Deserialize;
 #[napi(object)]
 pub struct RawStatsOptions {
   pub colors: bool,
+  #[napi(
+    ts_type = r#""none" | "errors-only" | "errors-warnings" | "normal" | "detailed" | "verbose""#
+  )]
+  pub preset: Option<String>,
+  pub all: Option<bool>,
+  pub assets: Option<bool>,
+  pub chunks: Option<bool>,
+  pub chunk_modules: Option<bool>,
+  pub chunk_relations: Option<bool>,
+  pub modules: Option<bool>,
+  pub nested_modules: Option<bool>,
+  pub runtime_modules: Option<bool>,
+  pub reasons: Option<bool>,
+  pub module_assets: Option<bool>,
+  pub source: Option<bool>,
+  pub optimization_bailout: Option<bool>,
+  pub used_exports: Option<bool>,
+  pub provided_exports: Option<bool>,
+  pub entrypoints: Option<bool>,
+  pub chunk_groups: Option<bool>,
+  pub errors: Option<bool>,
+  pub errors_count: Option<bool>,
+  pub warnings: Option<bool>,
+  pub warnings_count: Option<bool>,
+  pub hash: Option<bool>,
+  pub timings: Option<bool>,
+  pub built_at: Option<bool>,
+  pub public_path: Option<bool>,
+  pub output_path: Option<bool>,
 }
 
-impl From<RawStatsOptions> for StatsOptions {
-  fn from(value: RawStatsOptions) -> Self {
-    Self {
+impl TryFrom<RawStatsOptions> for StatsOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawStatsOptions) -> rspack_error::Result<Self> {
+    Ok(Self {
       colors: value.colors,
-    }
+      preset: value
+        .preset
+        .as_deref()
+        .map(StatsPreset::try_from)
+        .transpose()?
+        .unwrap_or_default(),
+      all: value.all,
+      assets: value.assets,
+      chunks: value.chunks,
+      chunk_modules: value.chunk_modules,
+      chunk_relations: value.chunk_relations,
+      modules: value.modules,
+      nested_modules: value.nested_modules,
+      runtime_modules: value.runtime_modules,
+      reasons: value.reasons,
+      module_assets: value.module_assets,
+      source: value.source,
+      optimization_bailout: value.optimization_bailout,
+      used_exports: value.used_exports,
+      provided_exports: value.provided_exports,
+      entrypoints: value.entrypoints,
+      chunk_groups: value.chunk_groups,
+      errors: value.errors,
+      errors_count: value.errors_count,
+      warnings: value.warnings,
+      warnings_count: value.warnings_count,
+      hash: value.hash,
+      timings: value.timings,
+      built_at: value.built_at,
+      public_path: value.public_path,
+      output_path: value.output_path,
+    })
   }
 }
@@ -183,6 +183,7 @@ pub struct JsStatsAsset {
   pub chunk_names: Vec<String>,
   pub info: JsStatsAssetInfo,
   pub emitted: bool,
+  pub is_over_size_limit: Option<bool>,
 }
 
 impl From<rspack_core::StatsAsset> for JsStatsAsset {
@@ -195,6 +196,7 @@ impl From<rspack_core::StatsAsset> for JsStatsAsset {
       chunk_names: stats.chunk_names,
       info: stats.info.into(),
       emitted: stats.emitted,
+      is_over_size_limit: stats.is_over_size_limit,
     }
   }
 }
@@ -235,6 +237,11 @@ pub struct JsStatsModule {
   pub assets: Option<Vec<String>>,
   pub source: Option<Either<String, Buffer>>,
   pub profile: Option<JsStatsModuleProfile>,
+  pub optimization_bailout: Option<Vec<String>>,
+  /// `undefined` when the used exports are unknown.
+  pub used_exports: Option<Either<bool, Vec<String>>>,
+  /// `undefined` when the provided exports are unknown.
+  pub provided_exports: Option<Vec<String>>,
 }
 
 impl TryFrom<rspack_core::StatsModule<'_>> for JsStatsModule {
@@ -274,6 +281,13 @@ impl TryFrom<rspack_core::StatsModule<'_>> for JsStatsModule {
       assets: stats.assets,
       source,
       profile: stats.profile.map(|p| p.into()),
+      optimization_bailout: stats.optimization_bailout,
+      used_exports: stats.used_exports.and_then(|used_exports| match used_exports {
+        rspack_core::StatsUsedExports::Null => None,
+        rspack_core::StatsUsedExports::Bool(used) => Some(Either::A(used)),
+        rspack_core::StatsUsedExports::Names(names) => Some(Either::B(names)),
+      }),
+      provided_exports: stats.provided_exports.flatten(),
     })
   }
 }
@@ -408,6 +422,7 @@ pub struct JsStatsChunkGroup {
   pub assets: Vec<JsStatsChunkGroupAsset>,
   pub chunks: Vec<Option<String>>,
   pub assets_size: f64,
+  pub is_over_size_limit: Option<bool>,
 }
 
 impl From<rspack_core::StatsChunkGroup> for JsStatsChunkGroup {
@@ -417,6 +432,7 @@ impl From<rspack_core::StatsChunkGroup> for JsStatsChunkGroup {
       assets: stats.assets.into_iter().map(Into::into).collect(),
       chunks: stats.chunks,
       assets_size: stats.assets_size,
+      is_over_size_limit: stats.is_over_size_limit,
     }
   }
 }
@@ -467,16 +483,28 @@ impl JsStats {
   }
 
   #[napi]
+  #[allow(clippy::too_many_arguments)]
   pub fn get_modules(
     &self,
     reasons: bool,
     module_assets: bool,
     nested_modules: bool,
     source: bool,
+    optimization_bailout: bool,
+    used_exports: bool,
+    provided_exports: bool,
   ) -> Result<Vec<JsStatsModule>> {
     self
       .inner
-      .get_modules(reasons, module_assets, nested_modules, source)
+      .get_modules(
+        reasons,
+        module_assets,
+        nested_modules,
+        source,
+        optimization_bailout,
+        used_exports,
+        provided_exports,
+      )
       .map_err(|e| napi::Error::from_reason(e.to_string()))?
       .into_iter()
       .map(TryInto::try_into)
@@ -484,6 +512,7 @@ impl JsStats {
   }
 
   #[napi]
+  #[allow(clippy::too_many_arguments)]
   pub fn get_chunks(
     &self,
     chunk_modules: bool,
@@ -492,6 +521,9 @@ impl JsStats {
     module_assets: bool,
     nested_modules: bool,
     source: bool,
+    optimization_bailout: bool,
+    used_exports: bool,
+    provided_exports: bool,
   ) -> Result<Vec<JsStatsChunk>> {
     self
       .inner
@@ -502,6 +534,9 @@ impl JsStats {
         module_assets,
         nested_modules,
         source,
+        optimization_bailout,
+        used_exports,
+        provided_exports,
       )
       .map_err(|e| napi::Error::from_reason(e.to_string()))?
       .into_iter()
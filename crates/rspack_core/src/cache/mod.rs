@@ -6,7 +6,10 @@ use std::{
   },
 };
 
-use crate::CompilerOptions;
+use rspack_error::{error, Result};
+use rspack_fs::AsyncReadableFileSystem;
+
+use crate::{CacheOptions, CompilerOptions};
 
 mod local;
 mod occasion;
@@ -17,12 +20,14 @@ use occasion::{
   BuildModuleOccasion, CodeGenerateOccasion, CreateChunkAssetsOccasion, ResolveModuleOccasion,
 };
 use snapshot::SnapshotManager;
-use storage::new_storage;
+use storage::{new_memory_storage, new_persistent_storage, new_storage};
 
 #[derive(Debug)]
 pub struct Cache {
   is_idle: AtomicBool,
   snapshot_manager: Arc<SnapshotManager>,
+  /// why the persistent cache can not be opened, every build fails with it
+  persistent_cache_error: Option<String>,
   pub resolve_module_occasion: ResolveModuleOccasion,
   pub build_module_occasion: BuildModuleOccasion,
   pub code_generate_occasion: CodeGenerateOccasion,
@@ -30,21 +35,52 @@ pub struct Cache {
 }
 
 impl Cache {
-  pub fn new(options: Arc<CompilerOptions>) -> Self {
-    let snapshot_manager = Arc::new(SnapshotManager::new(options.snapshot.clone()));
-    Self {
+  pub fn new(
+    options: Arc<CompilerOptions>,
+    input_filesystem: Arc<dyn AsyncReadableFileSystem>,
+  ) -> Self {
+    let snapshot_manager = Arc::new(SnapshotManager::new(
+      options.snapshot.clone(),
+      input_filesystem,
+    ));
+    match Self::with_storages(&options.cache, snapshot_manager.clone()) {
+      Ok(cache) => cache,
+      Err(error) => Self {
+        persistent_cache_error: Some(error.to_string()),
+        ..Self::with_storages(&CacheOptions::Memory(Default::default()), snapshot_manager)
+          .expect("memory storages should be created")
+      },
+    }
+  }
+
+  fn with_storages(options: &CacheOptions, snapshot_manager: Arc<SnapshotManager>) -> Result<Self> {
+    Ok(Self {
       is_idle: true.into(),
       snapshot_manager: snapshot_manager.clone(),
+      persistent_cache_error: None,
       resolve_module_occasion: ResolveModuleOccasion::new(
-        new_storage(&options.cache),
+        new_storage(options, "resolve_module")?,
         snapshot_manager.clone(),
       ),
+      // only the results of the loaders are persisted, built modules are kept in memory
       build_module_occasion: BuildModuleOccasion::new(
-        new_storage(&options.cache),
+        new_memory_storage(options),
+        new_persistent_storage(options, "loader_result")?,
         snapshot_manager,
       ),
-      code_generate_occasion: CodeGenerateOccasion::new(new_storage(&options.cache)),
-      create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(new_storage(&options.cache)),
+      code_generate_occasion: CodeGenerateOccasion::new(new_storage(options, "code_generate")?),
+      create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(new_storage(
+        options,
+        "create_chunk_assets",
+      )?),
+    })
+  }
+
+  /// Return the error of opening the persistent cache, such as an unreadable build dependency.
+  pub fn check_persistent_cache(&self) -> Result<()> {
+    match &self.persistent_cache_error {
+      Some(error) => Err(error!(error.clone())),
+      None => Ok(()),
     }
   }
 
@@ -59,10 +95,18 @@ impl Cache {
       .is_ok()
     {
       self.snapshot_manager.clear();
+      self.resolve_module_occasion.begin_idle();
+      self.build_module_occasion.begin_idle();
+      self.code_generate_occasion.begin_idle();
+      self.create_chunk_assets_occasion.begin_idle();
     }
   }
 
   pub fn end_idle(&self) {
     self.is_idle.store(false, Ordering::Release);
+    self.resolve_module_occasion.end_idle();
+    self.build_module_occasion.end_idle();
+    self.code_generate_occasion.end_idle();
+    self.create_chunk_assets_occasion.end_idle();
   }
 }
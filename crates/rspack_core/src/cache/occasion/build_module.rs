@@ -1,13 +1,20 @@
-use std::{collections::HashMap, path::Path, sync::Arc};
+use std::{
+  collections::HashMap,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
 
 use futures::Future;
 use rkyv::AlignedVec;
 use rspack_error::{Result, TWithDiagnosticArray};
 use rspack_identifier::Identifier;
+use rspack_loader_runner::{Content, LoaderResult};
+use rspack_sources::SourceMap;
+use serde::{Deserialize, Serialize};
 
 use crate::{
   cache::snapshot::{Snapshot, SnapshotManager},
-  cache::storage,
+  cache::storage::{self, Persistable},
   BoxModule, BuildExtraDataType, BuildResult, DependencyTemplate, ModuleDependency,
   NormalModuleSource,
 };
@@ -21,7 +28,7 @@ pub struct NormalModuleStorageData {
 
 type NormalModuleStorageExtraData = HashMap<BuildExtraDataType, AlignedVec>;
 
-type Storage = dyn storage::Storage<(
+type StorageItem = (
   // file system info, None when not cacheable
   Option<Snapshot>,
   // build result
@@ -30,26 +37,123 @@ type Storage = dyn storage::Storage<(
   Option<NormalModuleStorageData>,
   // parser and generator data
   Option<NormalModuleStorageExtraData>,
-)>;
+);
+
+// Dependencies are trait objects which can not be serialized yet, so build results are only kept
+// in memory, and the results of the loaders are persisted instead.
+type Storage = dyn storage::Storage<StorageItem>;
+
+/// A loader result with string content, the loaders of the module are skipped while the snapshot
+/// is valid.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersistedLoaderResult {
+  snapshot: Snapshot,
+  content: String,
+  // source map json
+  source_map: Option<String>,
+  file_dependencies: Vec<PathBuf>,
+  context_dependencies: Vec<PathBuf>,
+  missing_dependencies: Vec<PathBuf>,
+  build_dependencies: Vec<PathBuf>,
+  asset_filenames: Vec<String>,
+}
+
+impl PersistedLoaderResult {
+  fn new(snapshot: Snapshot, loader_result: LoaderResult) -> Option<Self> {
+    let Content::String(content) = loader_result.content else {
+      return None;
+    };
+    Some(Self {
+      snapshot,
+      content,
+      source_map: match loader_result.source_map {
+        Some(source_map) => Some(source_map.to_json().ok()?),
+        None => None,
+      },
+      file_dependencies: loader_result.file_dependencies.into_iter().collect(),
+      context_dependencies: loader_result.context_dependencies.into_iter().collect(),
+      missing_dependencies: loader_result.missing_dependencies.into_iter().collect(),
+      build_dependencies: loader_result.build_dependencies.into_iter().collect(),
+      asset_filenames: loader_result.asset_filenames.into_iter().collect(),
+    })
+  }
+
+  fn into_loader_result(self) -> Option<LoaderResult> {
+    Some(LoaderResult {
+      cacheable: true,
+      file_dependencies: self.file_dependencies.into_iter().collect(),
+      context_dependencies: self.context_dependencies.into_iter().collect(),
+      missing_dependencies: self.missing_dependencies.into_iter().collect(),
+      build_dependencies: self.build_dependencies.into_iter().collect(),
+      asset_filenames: self.asset_filenames.into_iter().collect(),
+      content: Content::String(self.content),
+      source_map: match self.source_map {
+        Some(source_map) => Some(SourceMap::from_json(&source_map).ok()?),
+        None => None,
+      },
+      additional_data: Default::default(),
+    })
+  }
+}
+
+impl Persistable for PersistedLoaderResult {
+  fn to_bytes(&self) -> Option<Vec<u8>> {
+    serde_json::to_vec(self).ok()
+  }
+
+  fn from_bytes(bytes: &[u8]) -> Option<Self> {
+    serde_json::from_slice(bytes).ok()
+  }
+}
+
+type LoaderStorage = dyn storage::Storage<PersistedLoaderResult>;
 
 #[derive(Debug)]
 pub struct BuildModuleOccasion {
   storage: Option<Box<Storage>>,
+  /// `None` unless the persistent cache is enabled
+  loader_storage: Option<Box<LoaderStorage>>,
   snapshot_manager: Arc<SnapshotManager>,
 }
 
 impl BuildModuleOccasion {
-  pub fn new(storage: Option<Box<Storage>>, snapshot_manager: Arc<SnapshotManager>) -> Self {
+  pub fn new(
+    storage: Option<Box<Storage>>,
+    loader_storage: Option<Box<LoaderStorage>>,
+    snapshot_manager: Arc<SnapshotManager>,
+  ) -> Self {
     Self {
       storage,
+      loader_storage,
       snapshot_manager,
     }
   }
 
+  pub fn begin_idle(&self) {
+    if let Some(s) = self.storage.as_ref() {
+      s.begin_idle();
+    }
+    if let Some(s) = self.loader_storage.as_ref() {
+      s.begin_idle();
+    }
+  }
+
+  pub fn end_idle(&self) {
+    if let Some(s) = self.storage.as_ref() {
+      s.end_idle();
+    }
+    if let Some(s) = self.loader_storage.as_ref() {
+      s.end_idle();
+    }
+  }
+
   pub fn remove_cache(&self, id: &Identifier) {
     if let Some(s) = self.storage.as_ref() {
       s.remove(id);
     }
+    if let Some(s) = self.loader_storage.as_ref() {
+      s.remove(id);
+    }
   }
 
   pub async fn use_cache<'a, G, F>(
@@ -98,7 +202,22 @@ impl BuildModuleOccasion {
         } else {
           last_build_result = Some(data.inner);
         }
-      };
+      } else if let Some(loader_storage) = &self.loader_storage
+        && let Some(persisted) = loader_storage.get(&id)
+      {
+        // not built in this process yet, reuse the loader result of the last process
+        let valid = self
+          .snapshot_manager
+          .check_snapshot_valid(&persisted.snapshot)
+          .await
+          .unwrap_or(false);
+        if valid
+          && let Some(loader_result) = persisted.into_loader_result()
+          && let Some(module) = module.as_normal_module_mut()
+        {
+          module.set_loader_result(loader_result);
+        }
+      }
       need_cache = true;
     }
 
@@ -108,8 +227,9 @@ impl BuildModuleOccasion {
 
     if need_cache {
       let module = module
-        .as_normal_module()
+        .as_normal_module_mut()
         .expect("Only normal module supports build cache");
+      let loader_result = module.take_loader_result();
       // only resume the build_meta to make sure other modules will not be affected
       if matches!(module.source(), NormalModuleSource::BuiltFailed(_))
         && let Some(last_result) = last_build_result
@@ -157,6 +277,12 @@ impl BuildModuleOccasion {
           .snapshot_manager
           .create_snapshot(&paths, |option| &option.module)
           .await?;
+        if let Some(loader_storage) = &self.loader_storage
+          && let Some(loader_result) = loader_result
+          && let Some(persisted) = PersistedLoaderResult::new(snapshot.clone(), loader_result)
+        {
+          loader_storage.set(id, persisted);
+        }
         let mut extra_data = HashMap::new();
         module.parser_and_generator().store(&mut extra_data);
         storage.set(
@@ -1,11 +1,106 @@
 use rspack_error::Result;
 use rspack_identifier::Identifier;
+use rspack_sources::{
+  BoxSource, MapOptions, RawSource, SourceExt, SourceMap, SourceMapSource, WithoutOriginalOptions,
+};
+use rustc_hash::FxHashMap as HashMap;
+use serde::{Deserialize, Serialize};
 
-use crate::{cache::storage, BoxModule, CodeGenerationResult, Compilation, NormalModuleSource};
-use crate::{RuntimeSpec, RuntimeSpecSet};
+use crate::{
+  cache::storage::{self, Persistable},
+  BoxModule, CodeGenerationResult, Compilation, NormalModuleSource, SourceType,
+};
+use crate::{RuntimeGlobals, RuntimeSpec, RuntimeSpecSet};
 
 type Storage = dyn storage::Storage<Vec<(CodeGenerationResult, RuntimeSpec)>>;
 
+#[derive(Serialize, Deserialize)]
+struct PersistedSource {
+  source: String,
+  // source map json
+  map: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PersistedCodeGenerationResult {
+  sources: HashMap<SourceType, PersistedSource>,
+  runtime_requirements: u64,
+  hash: Option<String>,
+  runtime: Vec<String>,
+}
+
+impl From<&BoxSource> for PersistedSource {
+  fn from(source: &BoxSource) -> Self {
+    Self {
+      source: source.source().to_string(),
+      map: source
+        .map(&MapOptions::default())
+        .and_then(|map| map.to_json().ok()),
+    }
+  }
+}
+
+impl From<PersistedSource> for BoxSource {
+  fn from(source: PersistedSource) -> Self {
+    match source.map.and_then(|map| SourceMap::from_json(&map).ok()) {
+      Some(source_map) => SourceMapSource::new(WithoutOriginalOptions {
+        value: source.source,
+        name: String::new(),
+        source_map,
+      })
+      .boxed(),
+      None => RawSource::from(source.source).boxed(),
+    }
+  }
+}
+
+// Only results without code generation data and chunk init fragments are persisted,
+// they are type erased and can not be serialized.
+impl Persistable for Vec<(CodeGenerationResult, RuntimeSpec)> {
+  fn to_bytes(&self) -> Option<Vec<u8>> {
+    let results = self
+      .iter()
+      .map(|(result, runtime)| {
+        if !result.data.is_empty() || !result.chunk_init_fragments.is_empty() {
+          return None;
+        }
+        Some(PersistedCodeGenerationResult {
+          sources: result
+            .inner
+            .iter()
+            .map(|(source_type, source)| (*source_type, source.into()))
+            .collect(),
+          runtime_requirements: result.runtime_requirements.bits(),
+          hash: result.hash.as_ref().map(|hash| hash.encoded().to_string()),
+          runtime: runtime.iter().map(|r| r.to_string()).collect(),
+        })
+      })
+      .collect::<Option<Vec<_>>>()?;
+    serde_json::to_vec(&results).ok()
+  }
+
+  fn from_bytes(bytes: &[u8]) -> Option<Self> {
+    let results: Vec<PersistedCodeGenerationResult> = serde_json::from_slice(bytes).ok()?;
+    results
+      .into_iter()
+      .map(|result| {
+        let code_generation_result = CodeGenerationResult {
+          inner: result
+            .sources
+            .into_iter()
+            .map(|(source_type, source)| (source_type, source.into()))
+            .collect(),
+          runtime_requirements: RuntimeGlobals::from_bits(result.runtime_requirements)?,
+          hash: result.hash.as_deref().map(Into::into),
+          ..Default::default()
+        };
+        let runtime = result.runtime.into_iter().map(Into::into).collect();
+        Some((code_generation_result, runtime))
+      })
+      .collect()
+  }
+}
+
 #[derive(Debug)]
 pub struct CodeGenerateOccasion {
   storage: Option<Box<Storage>>,
@@ -16,6 +111,18 @@ impl CodeGenerateOccasion {
     Self { storage }
   }
 
+  pub fn begin_idle(&self) {
+    if let Some(s) = self.storage.as_ref() {
+      s.begin_idle();
+    }
+  }
+
+  pub fn end_idle(&self) {
+    if let Some(s) = self.storage.as_ref() {
+      s.end_idle();
+    }
+  }
+
   #[allow(clippy::unwrap_in_result)]
   pub fn use_cache<'a, G>(
     &self,
@@ -41,10 +148,10 @@ impl CodeGenerateOccasion {
       let id = Identifier::from(compilation.chunk_graph.get_module_graph_hash(
         module,
         &compilation.module_graph,
+        &runtimes,
         true,
       ));
 
-      // currently no need to separate module hash by runtime
       if let Some(data) = storage.get(&id) {
         return Ok((data, true));
       }
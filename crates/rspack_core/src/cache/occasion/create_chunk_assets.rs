@@ -2,10 +2,24 @@ use futures::Future;
 use rspack_error::Result;
 use rspack_identifier::Identifier;
 
-use crate::{cache::storage, Chunk, Compilation, NormalModuleSource, RenderManifestEntry};
+use crate::{
+  cache::storage::{self, Persistable},
+  Chunk, Compilation, NormalModuleSource, RenderManifestEntry,
+};
 
 type Storage = dyn storage::Storage<Vec<RenderManifestEntry>>;
 
+// The chunk assets depend on the whole compilation, only keep them in memory.
+impl Persistable for Vec<RenderManifestEntry> {
+  fn to_bytes(&self) -> Option<Vec<u8>> {
+    None
+  }
+
+  fn from_bytes(_bytes: &[u8]) -> Option<Self> {
+    None
+  }
+}
+
 #[derive(Debug)]
 pub struct CreateChunkAssetsOccasion {
   storage: Option<Box<Storage>>,
@@ -16,6 +30,18 @@ impl CreateChunkAssetsOccasion {
     Self { storage }
   }
 
+  pub fn begin_idle(&self) {
+    if let Some(s) = self.storage.as_ref() {
+      s.begin_idle();
+    }
+  }
+
+  pub fn end_idle(&self) {
+    if let Some(s) = self.storage.as_ref() {
+      s.end_idle();
+    }
+  }
+
   pub async fn use_cache<'a, G, F>(
     &self,
     compilation: &Compilation,
@@ -1,17 +1,73 @@
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 
-use futures::Future;
+use futures::future::BoxFuture;
 use rspack_error::Error;
 use rspack_identifier::Identifier;
+use rspack_loader_runner::DescriptionData;
+use serde::{Deserialize, Serialize};
 
 use crate::{
   cache::snapshot::{Snapshot, SnapshotManager},
-  cache::storage,
-  ModuleIdentifier, ResolveArgs, ResolveResult,
+  cache::storage::{self, Persistable},
+  ModuleIdentifier, ResolveArgs, ResolveResult, Resource,
 };
 
 type Storage = dyn storage::Storage<(Snapshot, ResolveResult)>;
 
+#[derive(Serialize, Deserialize)]
+struct PersistedResource {
+  path: PathBuf,
+  query: Option<String>,
+  fragment: Option<String>,
+  description_data: Option<(PathBuf, serde_json::Value)>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PersistedResolveData {
+  snapshot: Snapshot,
+  // None when the request is ignored
+  resource: Option<PersistedResource>,
+}
+
+impl Persistable for (Snapshot, ResolveResult) {
+  fn to_bytes(&self) -> Option<Vec<u8>> {
+    let (snapshot, result) = self;
+    let resource = match result {
+      ResolveResult::Resource(resource) => Some(PersistedResource {
+        path: resource.path.clone(),
+        query: resource.query.clone(),
+        fragment: resource.fragment.clone(),
+        description_data: resource
+          .description_data
+          .as_ref()
+          .map(|data| (data.path().to_path_buf(), data.json().clone())),
+      }),
+      ResolveResult::Ignored => None,
+    };
+    serde_json::to_vec(&PersistedResolveData {
+      snapshot: snapshot.clone(),
+      resource,
+    })
+    .ok()
+  }
+
+  fn from_bytes(bytes: &[u8]) -> Option<Self> {
+    let PersistedResolveData { snapshot, resource } = serde_json::from_slice(bytes).ok()?;
+    let result = match resource {
+      Some(resource) => ResolveResult::Resource(Resource {
+        path: resource.path,
+        query: resource.query,
+        fragment: resource.fragment,
+        description_data: resource
+          .description_data
+          .map(|(path, json)| DescriptionData::new(path, Arc::new(json))),
+      }),
+      None => ResolveResult::Ignored,
+    };
+    Some((snapshot, result))
+  }
+}
+
 #[derive(Debug)]
 pub struct ResolveModuleOccasion {
   storage: Option<Box<Storage>>,
@@ -26,14 +82,25 @@ impl ResolveModuleOccasion {
     }
   }
 
-  pub async fn use_cache<'a, G, F>(
+  pub fn begin_idle(&self) {
+    if let Some(s) = self.storage.as_ref() {
+      s.begin_idle();
+    }
+  }
+
+  pub fn end_idle(&self) {
+    if let Some(s) = self.storage.as_ref() {
+      s.end_idle();
+    }
+  }
+
+  pub async fn use_cache<'a, G>(
     &self,
     args: ResolveArgs<'a>,
     generator: G,
   ) -> Result<(Result<ResolveResult, Error>, bool), Error>
   where
-    G: Fn(ResolveArgs<'a>) -> F,
-    F: Future<Output = Result<ResolveResult, Error>>,
+    G: for<'b> FnOnce(ResolveArgs<'b>) -> BoxFuture<'b, Result<ResolveResult, Error>>,
   {
     let storage = match &self.storage {
       Some(s) => s,
@@ -67,10 +134,42 @@ impl ResolveModuleOccasion {
     }
 
     // run generator and save to cache
-    let data = generator(args).await?;
+    let ResolveArgs {
+      importer,
+      context,
+      specifier,
+      dependency_type,
+      dependency_category,
+      span,
+      resolve_options,
+      resolve_to_context,
+      optional,
+      file_dependencies,
+      missing_dependencies,
+    } = args;
+    let mut resolve_missing_dependencies = Default::default();
+    let data = generator(ResolveArgs {
+      importer,
+      context,
+      specifier,
+      dependency_type,
+      dependency_category,
+      span,
+      resolve_options,
+      resolve_to_context,
+      optional,
+      file_dependencies,
+      missing_dependencies: &mut resolve_missing_dependencies,
+    })
+    .await;
+    missing_dependencies.extend(resolve_missing_dependencies.iter().cloned());
+    let data = data?;
     let mut paths = Vec::new();
     if let ResolveResult::Resource(resource) = &data {
       paths.push(resource.path.as_path());
+      // a new process doesn't know about created files, so the paths which would change the
+      // resolution are checked too
+      paths.extend(resolve_missing_dependencies.iter().map(PathBuf::as_path));
     }
 
     let snapshot = self
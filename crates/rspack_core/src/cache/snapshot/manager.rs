@@ -1,16 +1,26 @@
 use std::{
   hash::BuildHasherDefault,
+  io::ErrorKind,
   path::{Path, PathBuf},
-  time::SystemTime,
+  sync::Arc,
+  time::{Duration, SystemTime},
 };
 
 use dashmap::{DashMap, DashSet};
-use rspack_error::{miette::IntoDiagnostic, Result};
-use rustc_hash::{FxHashMap as HashMap, FxHasher};
+use rspack_error::Result;
+use rspack_fs::{AsyncReadableFileSystem, Error as FsError};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet, FxHasher};
 
 use super::Snapshot;
 use crate::{calc_hash, SnapshotOptions, SnapshotStrategy};
 
+/// The content hash of a path missing on disk.
+const MISSING_HASH: u64 = 0;
+
+fn is_not_found(error: &FsError) -> bool {
+  matches!(error, FsError::Io(e) if e.kind() == ErrorKind::NotFound)
+}
+
 /// SnapshotManager is a tools to create or check snapshot
 ///
 /// this struct has cache to improve create and check speed.
@@ -19,8 +29,10 @@ use crate::{calc_hash, SnapshotOptions, SnapshotStrategy};
 pub struct SnapshotManager {
   /// global snapshot options
   options: SnapshotOptions,
-  /// cache file update time
-  update_time_cache: DashMap<PathBuf, SystemTime, BuildHasherDefault<FxHasher>>,
+  /// the file system which snapshot files are read from
+  fs: Arc<dyn AsyncReadableFileSystem>,
+  /// cache file update time, `None` when the file is missing
+  update_time_cache: DashMap<PathBuf, Option<SystemTime>, BuildHasherDefault<FxHasher>>,
   /// cache file hash
   hash_cache: DashMap<PathBuf, u64, BuildHasherDefault<FxHasher>>,
   /// has modified file
@@ -28,9 +40,10 @@ pub struct SnapshotManager {
 }
 
 impl SnapshotManager {
-  pub fn new(options: SnapshotOptions) -> Self {
+  pub fn new(options: SnapshotOptions, fs: Arc<dyn AsyncReadableFileSystem>) -> Self {
     Self {
       options,
+      fs,
       update_time_cache: Default::default(),
       hash_cache: Default::default(),
       modified_files: Default::default(),
@@ -41,106 +54,135 @@ impl SnapshotManager {
   where
     F: FnOnce(&SnapshotOptions) -> &SnapshotStrategy,
   {
-    // TODO file_paths deduplication && calc immutable path
-    let strategy = f(&self.options);
+    // TODO file_paths deduplication
+    let default_strategy = f(&self.options);
     let mut file_update_times = HashMap::default();
     file_update_times.reserve(paths.len());
     let mut file_hashes = HashMap::default();
     file_hashes.reserve(paths.len());
-    if strategy.timestamp {
-      for &path in paths {
+    let mut missing_files = HashSet::default();
+    for &path in paths {
+      let strategy = self.options.strategy_for(path, default_strategy);
+      if !strategy.timestamp && !strategy.hash {
+        continue;
+      }
+      if self.get_update_time(path).await?.is_none() {
+        missing_files.insert(path.to_owned());
+      }
+      if strategy.timestamp {
         file_update_times.insert(path.to_owned(), SystemTime::now());
       }
-    }
-    if strategy.hash {
-      let hash_cache = &self.hash_cache;
-      for &path in paths {
-        let hash = match hash_cache.get(path) {
-          Some(hash) => *hash,
-          None => {
-            let res = if path.is_dir() {
-              let dir = &mut tokio::fs::read_dir(path).await.into_diagnostic()?;
-              let mut sub_files = vec![];
-              while let Some(entry) = dir.next_entry().await.into_diagnostic()? {
-                let dir_u8 = entry.path().as_os_str().to_string_lossy().to_string();
-                sub_files.push(dir_u8);
-              }
-              calc_hash(&sub_files)
-            } else {
-              calc_hash(&tokio::fs::read(path).await.into_diagnostic()?)
-            };
-            hash_cache.insert(path.to_owned(), res);
-            res
-          }
-        };
-        file_hashes.insert(path.to_owned(), hash);
+      if strategy.hash {
+        file_hashes.insert(path.to_owned(), self.get_hash(path).await?);
       }
     }
 
     Ok(Snapshot {
       file_update_times,
       file_hashes,
+      missing_files,
     })
   }
 
+  /// Check whether the files in snapshot are unchanged.
+  ///
+  /// A path with both timestamp and hash is valid when its update time is unchanged,
+  /// or else when its content hash is unchanged, so touched but unchanged files keep the cache.
+  /// A path is changed when it's created or removed since the snapshot, and unchanged when it's
+  /// missing in both.
   pub async fn check_snapshot_valid(&self, snapshot: &Snapshot) -> Result<bool> {
     let Snapshot {
       file_update_times,
       file_hashes,
-      ..
+      missing_files,
     } = snapshot;
-    if !file_update_times.is_empty() {
-      // check update time
-      let update_time_cache = &self.update_time_cache;
-      for (path, snapshot_time) in file_update_times {
-        if self.modified_files.contains(path) {
-          return Ok(false);
-        }
-
-        let update_time = match update_time_cache.get(path) {
-          Some(t) => *t,
-          None => {
-            let t = tokio::fs::metadata(path)
-              .await
-              .into_diagnostic()?
-              .modified()
-              .into_diagnostic()?;
-            update_time_cache.insert(path.clone(), t);
-            t
-          }
-        };
-
-        if snapshot_time < &update_time {
-          return Ok(false);
-        }
+
+    for path in file_update_times.keys().chain(file_hashes.keys()) {
+      if self.get_update_time(path).await?.is_none() != missing_files.contains(path) {
+        return Ok(false);
+      }
+    }
+
+    for (path, snapshot_time) in file_update_times {
+      if missing_files.contains(path) {
+        continue;
+      }
+      if !self.modified_files.contains(path)
+        && self
+          .get_update_time(path)
+          .await?
+          .is_some_and(|time| snapshot_time >= &time)
+      {
+        continue;
+      }
+      if file_hashes.contains_key(path) {
+        // fallback to check the content hash
+        continue;
       }
+      return Ok(false);
     }
 
-    if !file_hashes.is_empty() {
-      // check file hash
-      let hash_cache = &self.hash_cache;
-      for (path, snapshot_hash) in file_hashes {
-        if self.modified_files.contains(path) {
-          return Ok(false);
-        }
-
-        let current_hash = match hash_cache.get(path) {
-          Some(h) => *h,
-          None => {
-            let res = calc_hash(&tokio::fs::read(path).await.into_diagnostic()?);
-            hash_cache.insert(path.clone(), res);
-            res
-          }
-        };
-        if snapshot_hash != &current_hash {
-          return Ok(false);
-        }
+    for (path, snapshot_hash) in file_hashes {
+      if missing_files.contains(path) {
+        continue;
+      }
+      if let Some(snapshot_time) = file_update_times.get(path)
+        && !self.modified_files.contains(path)
+        && self
+          .get_update_time(path)
+          .await?
+          .is_some_and(|time| snapshot_time >= &time)
+      {
+        // already checked by timestamp
+        continue;
+      }
+      if snapshot_hash != &self.get_hash(path).await? {
+        return Ok(false);
       }
     }
 
     Ok(true)
   }
 
+  /// The update time of `path`, `None` when it's missing.
+  async fn get_update_time(&self, path: &Path) -> Result<Option<SystemTime>> {
+    if let Some(t) = self.update_time_cache.get(path) {
+      return Ok(*t);
+    }
+    let t = match self.fs.metadata(path).await {
+      Ok(metadata) => Some(SystemTime::UNIX_EPOCH + Duration::from_millis(metadata.mtime_ms)),
+      Err(e) if is_not_found(&e) => None,
+      Err(e) => return Err(e.into()),
+    };
+    self.update_time_cache.insert(path.to_owned(), t);
+    Ok(t)
+  }
+
+  async fn get_hash(&self, path: &Path) -> Result<u64> {
+    if let Some(hash) = self.hash_cache.get(path) {
+      return Ok(*hash);
+    }
+    let is_dir = match self.fs.metadata(path).await {
+      Ok(metadata) => metadata.is_directory,
+      Err(e) if is_not_found(&e) => false,
+      Err(e) => return Err(e.into()),
+    };
+    let hash = if is_dir {
+      let mut sub_files = self.fs.read_dir(path).await?;
+      // read_dir has no stable order
+      sub_files.sort();
+      calc_hash(&sub_files)
+    } else {
+      match self.fs.read(path).await {
+        Ok(content) => calc_hash(&content),
+        Err(e) if is_not_found(&e) => MISSING_HASH,
+        Err(e) => return Err(e.into()),
+      }
+    };
+    self.hash_cache.insert(path.to_owned(), hash);
+    Ok(hash)
+  }
+
   pub fn clear(&self) {
     self.update_time_cache.clear();
     self.hash_cache.clear();
@@ -153,3 +195,131 @@ impl SnapshotManager {
     }
   }
 }
+
+#[cfg(test)]
+mod test {
+  use rspack_fs::{AsyncNativeFileSystem, MemoryFileSystem, WritableFileSystem};
+
+  use super::*;
+
+  #[tokio::test]
+  async fn should_fallback_to_hash_when_timestamp_changed() {
+    let dir = std::env::temp_dir().join(format!(
+      "rspack_snapshot_manager_test_{}",
+      std::process::id()
+    ));
+    std::fs::create_dir_all(&dir).expect("should create dir");
+    let file = dir.join("a.js");
+    std::fs::write(&file, "a").expect("should write file");
+    let touch = |file: &Path| {
+      std::fs::File::options()
+        .write(true)
+        .open(file)
+        .and_then(|f| f.set_modified(SystemTime::now() + Duration::from_secs(60)))
+        .expect("should touch file");
+    };
+
+    let manager = SnapshotManager::new(
+      SnapshotOptions {
+        module: SnapshotStrategy {
+          hash: true,
+          timestamp: true,
+        },
+        ..Default::default()
+      },
+      Arc::new(AsyncNativeFileSystem),
+    );
+    let snapshot = manager
+      .create_snapshot(&[&file], |options| &options.module)
+      .await
+      .expect("should create snapshot");
+
+    touch(&file);
+    manager.clear();
+    assert!(manager
+      .check_snapshot_valid(&snapshot)
+      .await
+      .expect("should check snapshot"));
+
+    std::fs::write(&file, "b").expect("should write file");
+    touch(&file);
+    manager.clear();
+    assert!(!manager
+      .check_snapshot_valid(&snapshot)
+      .await
+      .expect("should check snapshot"));
+
+    let _ = std::fs::remove_dir_all(&dir);
+  }
+
+  #[tokio::test]
+  async fn should_invalidate_created_and_removed_paths() {
+    let fs = MemoryFileSystem::new();
+    fs.create_dir_all("/src").expect("should create dir");
+    let file = PathBuf::from("/src/a.js");
+    let options = SnapshotOptions {
+      module: SnapshotStrategy {
+        hash: true,
+        timestamp: true,
+      },
+      ..Default::default()
+    };
+    let manager = SnapshotManager::new(options.clone(), Arc::new(fs.clone()));
+    let snapshot = manager
+      .create_snapshot(&[&file], |options| &options.module)
+      .await
+      .expect("should create snapshot");
+
+    // missing in both
+    manager.clear();
+    manager.set_modified_files(vec![file.clone()]);
+    assert!(manager
+      .check_snapshot_valid(&snapshot)
+      .await
+      .expect("should check snapshot"));
+
+    fs.write(&file, "a").expect("should write file");
+    manager.clear();
+    assert!(!manager
+      .check_snapshot_valid(&snapshot)
+      .await
+      .expect("should check snapshot"));
+
+    // a new process doesn't know the file is removed
+    let snapshot = manager
+      .create_snapshot(&[&file], |options| &options.module)
+      .await
+      .expect("should create snapshot");
+    rspack_fs::AsyncWritableFileSystem::remove_file(&fs, &file)
+      .await
+      .expect("should remove file");
+    let manager = SnapshotManager::new(options, Arc::new(fs.clone()));
+    assert!(!manager
+      .check_snapshot_valid(&snapshot)
+      .await
+      .expect("should check snapshot"));
+
+    // the content hash of a directory changes with its entries
+    let dir = Path::new("/src");
+    let manager = SnapshotManager::new(
+      SnapshotOptions {
+        module: SnapshotStrategy {
+          hash: true,
+          timestamp: false,
+        },
+        ..Default::default()
+      },
+      Arc::new(fs.clone()),
+    );
+    let snapshot = manager
+      .create_snapshot(&[dir], |options| &options.module)
+      .await
+      .expect("should create snapshot");
+    fs.write(&file, "a").expect("should write file");
+    manager.clear();
+    assert!(!manager
+      .check_snapshot_valid(&snapshot)
+      .await
+      .expect("should check snapshot"));
+  }
+}
@@ -0,0 +1,756 @@
+use std::{
+  fs,
+  hash::{BuildHasherDefault, Hash},
+  path::{Path, PathBuf},
+  sync::{
+    atomic::{AtomicUsize, Ordering},
+    mpsc::{self, RecvTimeoutError},
+    Arc, Mutex, Weak,
+  },
+  time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use dashmap::{DashMap, DashSet};
+use once_cell::sync::Lazy;
+use rspack_error::{error, Result};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rspack_identifier::{Identifier, IdentifierHasher};
+use rustc_hash::FxHashMap as HashMap;
+use serde::{Deserialize, Serialize};
+
+use super::{
+  pack::{decode_pack, encode_pack},
+  Persistable, Storage,
+};
+use crate::{CacheCompression, FileSystemCacheOptions};
+
+const INDEX_FILE: &str = "index.json";
+
+static PACK_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+type StoreTask = Box<dyn FnOnce() + Send>;
+
+/// One thread runs the stores scheduled by all storages when the compiler goes idle.
+static STORE_SCHEDULER: Lazy<mpsc::Sender<(Instant, StoreTask)>> = Lazy::new(|| {
+  let (sender, receiver) = mpsc::channel::<(Instant, StoreTask)>();
+  std::thread::Builder::new()
+    .name("rspack-cache-store".to_string())
+    .spawn(move || {
+      let mut scheduled: Vec<(Instant, StoreTask)> = vec![];
+      loop {
+        let received = match scheduled.iter().map(|(at, _)| *at).min() {
+          Some(at) => receiver.recv_timeout(at.saturating_duration_since(Instant::now())),
+          None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match received {
+          Ok(task) => scheduled.push(task),
+          Err(RecvTimeoutError::Timeout) => {}
+          Err(RecvTimeoutError::Disconnected) => return,
+        }
+        let now = Instant::now();
+        let (due, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut scheduled)
+          .into_iter()
+          .partition(|(at, _)| *at <= now);
+        scheduled = pending;
+        for (_, task) in due {
+          task();
+        }
+      }
+    })
+    .expect("should spawn the cache store thread");
+  sender
+});
+
+/// A storage which keeps items in memory and persists them under the cache directory.
+///
+/// Entries are stored in `<cache_location>/<namespace>/<version>/`, where `version` is a hash
+/// of `cache.version`, the rspack version and the content of `cache.buildDependencies`,
+/// so a changed build dependency will never read entries written by an old build.
+///
+/// Changed entries are not written during the build, they are batched into a new pack file
+/// after the compiler has been idle for `cache.idleTimeout`, or when the storage is dropped.
+#[derive(Debug)]
+pub struct FileSystemStorage<Item>
+where
+  Item: Persistable,
+{
+  inner: Arc<Inner<Item>>,
+  idle_timeout: Duration,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct PackInfo {
+  /// size of the pack file in bytes
+  size: u64,
+  /// last time in milliseconds the pack is written or read
+  last_used: u64,
+  /// number of entries written into the pack
+  #[serde(default)]
+  entries: usize,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct PackIndex {
+  packs: HashMap<String, PackInfo>,
+  /// the pack which contains the latest data of an entry
+  entries: HashMap<String, String>,
+}
+
+#[derive(Debug, Default)]
+struct NamespaceIndex {
+  index: PackIndex,
+  /// whether the index differs from the index file
+  changed: bool,
+}
+
+/// Storages with the same cache location and version share one pack store.
+static PACK_STORES: Lazy<Mutex<HashMap<PathBuf, Weak<PackStore>>>> = Lazy::new(Default::default);
+
+/// The packs of all namespaces in a cache location, so `cache.maxSize` limits their total size.
+#[derive(Debug)]
+struct PackStore {
+  root: PathBuf,
+  version: String,
+  compression: CacheCompression,
+  max_age: Option<Duration>,
+  max_size: Option<u64>,
+  /// indexes by namespace, the lock is held while packs are written or removed
+  indexes: Mutex<HashMap<String, NamespaceIndex>>,
+}
+
+impl PackStore {
+  fn shared(options: &FileSystemCacheOptions) -> Result<Arc<Self>> {
+    let root = cache_location(options);
+    let version = cache_version(options)?;
+    let mut stores = PACK_STORES.lock().expect("should get lock");
+    let key = root.join(&version);
+    if let Some(store) = stores.get(&key).and_then(Weak::upgrade) {
+      return Ok(store);
+    }
+    stores.retain(|_, store| store.strong_count() > 0);
+    let store = Arc::new(Self::open(root, version, options));
+    stores.insert(key, Arc::downgrade(&store));
+    Ok(store)
+  }
+
+  /// Read the indexes of all namespaces written with the current version.
+  fn open(root: PathBuf, version: String, options: &FileSystemCacheOptions) -> Self {
+    let mut indexes = HashMap::default();
+    for namespace in fs::read_dir(&root).into_iter().flatten().flatten() {
+      let namespace = namespace.file_name().to_string_lossy().to_string();
+      let index = fs::read(root.join(&namespace).join(&version).join(INDEX_FILE))
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok());
+      if let Some(index) = index {
+        indexes.insert(
+          namespace,
+          NamespaceIndex {
+            index,
+            changed: false,
+          },
+        );
+      }
+    }
+    Self {
+      root,
+      version,
+      compression: options.compression,
+      max_age: (options.max_age > 0).then(|| Duration::from_millis(options.max_age as u64)),
+      max_size: (options.max_size > 0).then_some(options.max_size),
+      indexes: Mutex::new(indexes),
+    }
+  }
+
+  fn dir(&self, namespace: &str) -> PathBuf {
+    self.root.join(namespace).join(&self.version)
+  }
+
+  /// Write the entries into a new pack of the namespace.
+  fn write_pack(
+    &self,
+    dir: &Path,
+    index: &mut PackIndex,
+    entries: &[(Identifier, Vec<u8>)],
+    last_used: u64,
+  ) {
+    let bytes = encode_pack(entries, self.compression);
+    let name = format!(
+      "{:x}-{:x}-{:x}.pack",
+      now(),
+      std::process::id(),
+      PACK_COUNTER.fetch_add(1, Ordering::Relaxed)
+    );
+    if write_atomic(&dir.join(&name), &bytes) {
+      for (id, _) in entries {
+        index.entries.insert(id.to_string(), name.clone());
+      }
+      index.packs.insert(
+        name,
+        PackInfo {
+          size: bytes.len() as u64,
+          last_used,
+          entries: entries.len(),
+        },
+      );
+    }
+  }
+
+  /// Rewrite a pack with its live entries only, keeping its last used time.
+  fn compact(&self, dir: &Path, index: &mut PackIndex, name: &str) {
+    let last_used = index.packs.get(name).map_or(0, |info| info.last_used);
+    let live: Vec<(Identifier, Vec<u8>)> = fs::read(dir.join(name))
+      .ok()
+      .and_then(|bytes| decode_pack(&bytes))
+      .unwrap_or_default()
+      .into_iter()
+      .filter(|(id, _)| {
+        index
+          .entries
+          .get(id.as_str())
+          .is_some_and(|pack| pack == name)
+      })
+      .collect();
+    remove_pack(dir, index, name);
+    if !live.is_empty() {
+      self.write_pack(dir, index, &live, last_used);
+    }
+  }
+
+  /// Remove packs without live entries and packs unused for `max_age`, compact packs whose
+  /// entries are mostly outdated, and then remove the least recently used packs of all
+  /// namespaces until the total size fits in `max_size`.
+  fn gc(&self, indexes: &mut HashMap<String, NamespaceIndex>) {
+    let now = now();
+    for (namespace, NamespaceIndex { index, changed }) in indexes.iter_mut() {
+      let dir = self.dir(namespace);
+      let mut live: HashMap<String, usize> = HashMap::default();
+      for pack in index.entries.values() {
+        *live.entry(pack.clone()).or_default() += 1;
+      }
+      let mut outdated = vec![];
+      let mut sparse = vec![];
+      for (name, info) in &index.packs {
+        let expired = self
+          .max_age
+          .is_some_and(|max_age| now.saturating_sub(info.last_used) > max_age.as_millis() as u64);
+        let live = live.get(name).copied().unwrap_or_default();
+        if live == 0 || expired {
+          outdated.push(name.clone());
+        } else if live * 2 < info.entries {
+          sparse.push(name.clone());
+        }
+      }
+      *changed |= !outdated.is_empty() || !sparse.is_empty();
+      for name in outdated {
+        remove_pack(&dir, index, &name);
+      }
+      for name in sparse {
+        self.compact(&dir, index, &name);
+      }
+    }
+
+    let Some(max_size) = self.max_size else {
+      return;
+    };
+    let mut packs: Vec<(u64, String, String, u64)> = indexes
+      .iter()
+      .flat_map(|(namespace, NamespaceIndex { index, .. })| {
+        index
+          .packs
+          .iter()
+          .map(|(name, info)| (info.last_used, name.clone(), namespace.clone(), info.size))
+      })
+      .collect();
+    // least recently used first
+    packs.sort();
+    let mut total_size: u64 = packs.iter().map(|(_, _, _, size)| size).sum();
+    for (_, name, namespace, size) in packs {
+      if total_size <= max_size {
+        break;
+      }
+      total_size -= size;
+      let NamespaceIndex { index, changed } =
+        indexes.get_mut(&namespace).expect("should have index");
+      remove_pack(&self.dir(&namespace), index, &name);
+      *changed = true;
+    }
+  }
+
+  fn write_indexes(&self, indexes: &mut HashMap<String, NamespaceIndex>) {
+    for (namespace, NamespaceIndex { index, changed }) in indexes.iter_mut() {
+      if !*changed {
+        continue;
+      }
+      let dir = self.dir(namespace);
+      if let Ok(bytes) = serde_json::to_vec(index)
+        && fs::create_dir_all(&dir).is_ok()
+      {
+        write_atomic(&dir.join(INDEX_FILE), &bytes);
+      }
+      *changed = false;
+    }
+  }
+}
+
+#[derive(Debug)]
+struct Inner<Item> {
+  namespace: String,
+  /// the directory of the packs for the current version
+  dir: PathBuf,
+  packs: Arc<PackStore>,
+  memory: DashMap<Identifier, Item, BuildHasherDefault<IdentifierHasher>>,
+  /// entries changed since the last store
+  dirty: DashSet<Identifier, BuildHasherDefault<IdentifierHasher>>,
+  /// payloads of read packs which have not been requested yet
+  loaded: DashMap<Identifier, Vec<u8>, BuildHasherDefault<IdentifierHasher>>,
+  /// increased when the compiler stops being idle, to cancel the scheduled store
+  idle_generation: AtomicUsize,
+}
+
+impl<Item> FileSystemStorage<Item>
+where
+  Item: Persistable,
+{
+  pub fn new(options: &FileSystemCacheOptions, namespace: &str) -> Result<Self> {
+    let packs = PackStore::shared(options)?;
+    let dir = packs.dir(namespace);
+    if let Some(max_age) = packs.max_age {
+      prune_versions(&packs.root.join(namespace), &dir, max_age);
+    }
+    Ok(Self {
+      inner: Arc::new(Inner {
+        namespace: namespace.to_string(),
+        dir,
+        packs,
+        memory: Default::default(),
+        dirty: Default::default(),
+        loaded: Default::default(),
+        idle_generation: AtomicUsize::new(0),
+      }),
+      idle_timeout: Duration::from_millis(options.idle_timeout as u64),
+    })
+  }
+}
+
+impl<Item> Inner<Item>
+where
+  Item: Persistable,
+{
+  fn read_entry(&self, id: &Identifier) -> Option<Item> {
+    if let Some((_, payload)) = self.loaded.remove(id) {
+      return Item::from_bytes(&payload);
+    }
+    let pack = {
+      let mut indexes = self.packs.indexes.lock().expect("should get lock");
+      let NamespaceIndex { index, changed } = indexes.get_mut(&self.namespace)?;
+      let pack = index.entries.get(id.as_str())?.clone();
+      if let Some(info) = index.packs.get_mut(&pack) {
+        info.last_used = now();
+        *changed = true;
+      }
+      pack
+    };
+    let bytes = fs::read(self.dir.join(pack)).ok()?;
+    let mut result = None;
+    for (entry_id, payload) in decode_pack(&bytes)? {
+      if entry_id == *id {
+        result = Some(payload);
+      } else if !self.memory.contains_key(&entry_id) && !self.dirty.contains(&entry_id) {
+        self.loaded.insert(entry_id, payload);
+      }
+    }
+    Item::from_bytes(&result?)
+  }
+
+  /// Write the changed entries into a new pack and remove outdated packs.
+  fn store(&self) {
+    let mut indexes = self.packs.indexes.lock().expect("should get lock");
+    let ids: Vec<Identifier> = self.dirty.iter().map(|id| *id).collect();
+    let mut entries = vec![];
+    let mut removed = vec![];
+    for id in ids {
+      self.dirty.remove(&id);
+      match self.memory.get(&id).and_then(|item| item.to_bytes()) {
+        Some(payload) => entries.push((id, payload)),
+        None => removed.push(id),
+      }
+    }
+    let NamespaceIndex { index, changed } = indexes.entry(self.namespace.clone()).or_default();
+    for id in removed {
+      if index.entries.remove(id.as_str()).is_some() {
+        *changed = true;
+      }
+    }
+    if entries.is_empty() && !*changed {
+      return;
+    }
+    if fs::create_dir_all(&self.dir).is_err() {
+      return;
+    }
+
+    if !entries.is_empty() {
+      self.packs.write_pack(&self.dir, index, &entries, now());
+      *changed = true;
+    }
+    self.packs.gc(&mut indexes);
+    self.packs.write_indexes(&mut indexes);
+  }
+}
+
+impl<Item> Storage<Item> for FileSystemStorage<Item>
+where
+  Item: Persistable + Clone + std::fmt::Debug + Send + Sync + 'static,
+{
+  fn get(&self, id: &Identifier) -> Option<Item> {
+    if let Some(item) = self.inner.memory.get(id) {
+      return Some(item.clone());
+    }
+    let item = self.inner.read_entry(id)?;
+    self.inner.memory.insert(*id, item.clone());
+    Some(item)
+  }
+  fn set(&self, id: Identifier, data: Item) {
+    self.inner.loaded.remove(&id);
+    self.inner.memory.insert(id, data);
+    self.inner.dirty.insert(id);
+  }
+  fn remove(&self, id: &Identifier) {
+    self.inner.loaded.remove(id);
+    self.inner.memory.remove(id);
+    self.inner.dirty.insert(*id);
+  }
+  fn begin_idle(&self) {
+    let inner = self.inner.clone();
+    let generation = inner.idle_generation.load(Ordering::Acquire);
+    let task: StoreTask = Box::new(move || {
+      if inner.idle_generation.load(Ordering::Acquire) == generation {
+        inner.store();
+      }
+    });
+    let _ = STORE_SCHEDULER.send((Instant::now() + self.idle_timeout, task));
+  }
+  fn end_idle(&self) {
+    self.inner.idle_generation.fetch_add(1, Ordering::AcqRel);
+  }
+}
+
+impl<Item> Drop for FileSystemStorage<Item>
+where
+  Item: Persistable,
+{
+  fn drop(&mut self) {
+    // cancel the scheduled store and store the remaining changes before exit
+    self.inner.idle_generation.fetch_add(1, Ordering::AcqRel);
+    self.inner.store();
+  }
+}
+
+fn cache_location(options: &FileSystemCacheOptions) -> PathBuf {
+  if options.cache_location.is_empty() {
+    Path::new(&options.cache_directory).join(&options.name)
+  } else {
+    PathBuf::from(&options.cache_location)
+  }
+}
+
+fn cache_version(options: &FileSystemCacheOptions) -> Result<String> {
+  let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+  env!("CARGO_PKG_VERSION").hash(&mut hasher);
+  options.name.hash(&mut hasher);
+  options.version.hash(&mut hasher);
+  for dependency in &options.build_dependencies {
+    hash_build_dependency(Path::new(dependency), &mut hasher)?;
+  }
+  Ok(hasher.digest(&HashDigest::Hex).encoded().to_string())
+}
+
+/// Hash the path and the content of a build dependency, and of every file in it if it is a
+/// directory.
+fn hash_build_dependency(path: &Path, hasher: &mut RspackHash) -> Result<()> {
+  let unreadable =
+    |e: std::io::Error| error!("Failed to read build dependency {}: {e}", path.display());
+  path.hash(hasher);
+  if fs::metadata(path).map_err(unreadable)?.is_dir() {
+    let mut entries = fs::read_dir(path)
+      .and_then(|entries| {
+        entries
+          .map(|entry| entry.map(|entry| entry.path()))
+          .collect::<std::io::Result<Vec<_>>>()
+      })
+      .map_err(unreadable)?;
+    entries.sort();
+    for entry in entries {
+      hash_build_dependency(&entry, hasher)?;
+    }
+  } else {
+    fs::read(path).map_err(unreadable)?.hash(hasher);
+  }
+  Ok(())
+}
+
+/// Remove the data of other versions which are not used for `max_age`.
+fn prune_versions(root: &Path, current: &Path, max_age: Duration) {
+  let Ok(versions) = fs::read_dir(root) else {
+    return;
+  };
+  for version in versions.flatten() {
+    let path = version.path();
+    if path != current && is_expired(&path, max_age) {
+      let _ = fs::remove_dir_all(path);
+    }
+  }
+}
+
+fn is_expired(path: &Path, max_age: Duration) -> bool {
+  fs::metadata(path)
+    .and_then(|meta| meta.modified())
+    .ok()
+    .and_then(|modified| modified.elapsed().ok())
+    .map(|elapsed| elapsed > max_age)
+    .unwrap_or(false)
+}
+
+fn remove_pack(dir: &Path, index: &mut PackIndex, name: &str) {
+  let _ = fs::remove_file(dir.join(name));
+  index.packs.remove(name);
+  index.entries.retain(|_, pack| pack != name);
+}
+
+fn write_atomic(path: &Path, bytes: &[u8]) -> bool {
+  let temp = path.with_extension("tmp");
+  fs::write(&temp, bytes)
+    .and_then(|_| fs::rename(&temp, path))
+    .is_ok()
+}
+
+fn now() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|d| d.as_millis() as u64)
+    .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[derive(Debug, Clone, PartialEq)]
+  struct Item(String);
+
+  impl Persistable for Item {
+    fn to_bytes(&self) -> Option<Vec<u8>> {
+      Some(self.0.as_bytes().to_vec())
+    }
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+      String::from_utf8(bytes.to_vec()).ok().map(Self)
+    }
+  }
+
+  fn options(location: &Path, version: &str) -> FileSystemCacheOptions {
+    FileSystemCacheOptions {
+      cache_location: location.to_string_lossy().to_string(),
+      version: version.to_string(),
+      compression: CacheCompression::Gzip,
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn should_persist_and_invalidate_by_version() {
+    let location =
+      std::env::temp_dir().join(format!("rspack_fs_storage_test_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&location);
+    let id = Identifier::from("a.js");
+
+    let storage = FileSystemStorage::<Item>::new(&options(&location, "1"), "test")
+      .expect("should create storage");
+    storage.set(id, Item("a".to_string()));
+    drop(storage);
+
+    let storage = FileSystemStorage::<Item>::new(&options(&location, "1"), "test")
+      .expect("should create storage");
+    assert_eq!(storage.get(&id), Some(Item("a".to_string())));
+    storage.remove(&id);
+    drop(storage);
+
+    let storage = FileSystemStorage::<Item>::new(&options(&location, "1"), "test")
+      .expect("should create storage");
+    assert_eq!(storage.get(&id), None);
+    storage.set(id, Item("a".to_string()));
+    drop(storage);
+
+    let storage = FileSystemStorage::<Item>::new(&options(&location, "2"), "test")
+      .expect("should create storage");
+    assert_eq!(storage.get(&id), None);
+
+    let _ = fs::remove_dir_all(&location);
+  }
+
+  #[test]
+  fn should_store_after_idle_timeout_unless_cancelled() {
+    let location = std::env::temp_dir().join(format!(
+      "rspack_fs_storage_idle_test_{}",
+      std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&location);
+    let options = FileSystemCacheOptions {
+      idle_timeout: 10,
+      ..options(&location, "1")
+    };
+    let stored = |id: &str| {
+      FileSystemStorage::<Item>::new(&options, "test")
+        .expect("should create storage")
+        .get(&Identifier::from(id))
+        .is_some()
+    };
+
+    let storage = FileSystemStorage::<Item>::new(&options, "test").expect("should create storage");
+    storage.set(Identifier::from("a.js"), Item("a".to_string()));
+    storage.begin_idle();
+    storage.end_idle();
+    std::thread::sleep(Duration::from_millis(100));
+    assert!(!stored("a.js"));
+
+    storage.begin_idle();
+    std::thread::sleep(Duration::from_millis(100));
+    assert!(stored("a.js"));
+
+    drop(storage);
+    let _ = fs::remove_dir_all(&location);
+  }
+
+  #[test]
+  fn should_evict_least_recently_used_packs() {
+    let location =
+      std::env::temp_dir().join(format!("rspack_fs_storage_lru_test_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&location);
+    // every pack is a little larger than 1KB without compression
+    let options = FileSystemCacheOptions {
+      max_size: 1500,
+      compression: CacheCompression::None,
+      ..options(&location, "1")
+    };
+    let content = |i: usize| Item(format!("{i}").repeat(1000));
+
+    for i in 0..3 {
+      let storage =
+        FileSystemStorage::<Item>::new(&options, "test").expect("should create storage");
+      storage.set(Identifier::from(format!("{i}.js")), content(i));
+      drop(storage);
+      // make sure packs have different last used time
+      std::thread::sleep(Duration::from_millis(2));
+    }
+
+    let storage = FileSystemStorage::<Item>::new(&options, "test").expect("should create storage");
+    assert_eq!(storage.get(&Identifier::from("0.js")), None);
+    assert_eq!(storage.get(&Identifier::from("1.js")), None);
+    assert_eq!(storage.get(&Identifier::from("2.js")), Some(content(2)));
+
+    let _ = fs::remove_dir_all(&location);
+  }
+
+  #[test]
+  fn should_limit_the_total_size_of_all_namespaces() {
+    let location = std::env::temp_dir().join(format!(
+      "rspack_fs_storage_namespaces_test_{}",
+      std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&location);
+    // every pack is a little larger than 1KB without compression
+    let options = FileSystemCacheOptions {
+      max_size: 2500,
+      compression: CacheCompression::None,
+      ..options(&location, "1")
+    };
+    let content = |i: usize| Item(format!("{i}").repeat(1000));
+
+    for (i, namespace) in ["a", "b", "c"].into_iter().enumerate() {
+      let storage =
+        FileSystemStorage::<Item>::new(&options, namespace).expect("should create storage");
+      storage.set(Identifier::from("index.js"), content(i));
+      drop(storage);
+      // make sure packs have different last used time
+      std::thread::sleep(Duration::from_millis(2));
+    }
+
+    let get = |namespace: &str| {
+      FileSystemStorage::<Item>::new(&options, namespace)
+        .expect("should create storage")
+        .get(&Identifier::from("index.js"))
+    };
+    assert_eq!(get("a"), None);
+    assert_eq!(get("b"), Some(content(1)));
+    assert_eq!(get("c"), Some(content(2)));
+
+    let _ = fs::remove_dir_all(&location);
+  }
+
+  #[test]
+  fn should_compact_packs_with_mostly_outdated_entries() {
+    let location = std::env::temp_dir().join(format!(
+      "rspack_fs_storage_compact_test_{}",
+      std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&location);
+    let options = options(&location, "1");
+    let ids = ["a.js", "b.js", "c.js"].map(Identifier::from);
+
+    let storage = FileSystemStorage::<Item>::new(&options, "test").expect("should create storage");
+    for id in ids {
+      storage.set(id, Item(format!("{id} 1")));
+    }
+    drop(storage);
+    let storage = FileSystemStorage::<Item>::new(&options, "test").expect("should create storage");
+    storage.set(ids[0], Item("a.js 2".to_string()));
+    storage.set(ids[1], Item("b.js 2".to_string()));
+    let dir = storage.inner.dir.clone();
+    drop(storage);
+
+    // only the latest data of every entry is left on disk
+    let mut stored: Vec<String> = fs::read_dir(dir)
+      .expect("should read dir")
+      .flatten()
+      .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "pack"))
+      .flat_map(|entry| decode_pack(&fs::read(entry.path()).expect("should read pack")))
+      .flatten()
+      .map(|(_, payload)| String::from_utf8(payload).expect("should be utf8"))
+      .collect();
+    stored.sort();
+    assert_eq!(stored, ["a.js 2", "b.js 2", "c.js 1"]);
+
+    let storage = FileSystemStorage::<Item>::new(&options, "test").expect("should create storage");
+    assert_eq!(storage.get(&ids[2]), Some(Item("c.js 1".to_string())));
+
+    let _ = fs::remove_dir_all(&location);
+  }
+
+  #[test]
+  fn should_hash_the_files_in_build_dependency_directories() {
+    let dir = std::env::temp_dir().join(format!(
+      "rspack_fs_storage_build_dependencies_test_{}",
+      std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("config")).expect("should create dir");
+    fs::write(dir.join("config/a.js"), "a").expect("should write file");
+    let options = FileSystemCacheOptions {
+      build_dependencies: vec![dir.join("config").to_string_lossy().to_string()],
+      ..options(&dir.join("cache"), "1")
+    };
+
+    let version = cache_version(&options).expect("should hash build dependencies");
+    fs::write(dir.join("config/a.js"), "b").expect("should write file");
+    assert_ne!(
+      cache_version(&options).expect("should hash build dependencies"),
+      version
+    );
+
+    let missing = FileSystemCacheOptions {
+      build_dependencies: vec![dir.join("missing.js").to_string_lossy().to_string()],
+      ..options
+    };
+    assert!(cache_version(&missing).is_err());
+
+    let _ = fs::remove_dir_all(&dir);
+  }
+}
@@ -1,22 +1,54 @@
 use std::fmt::Debug;
 
+use rspack_error::Result;
 use rspack_identifier::Identifier;
 
 use crate::CacheOptions;
 
+mod filesystem;
 mod memory;
+mod pack;
+use filesystem::FileSystemStorage;
 use memory::MemoryStorage;
 
 pub trait Storage<Item>: Debug + Send + Sync {
   fn get(&self, id: &Identifier) -> Option<Item>;
   fn set(&self, id: Identifier, data: Item);
   fn remove(&self, id: &Identifier);
-  // fn begin_idle(&self);
-  // fn end_idle(&self);
+  fn begin_idle(&self) {}
+  fn end_idle(&self) {}
   // fn clear(&self);
 }
 
-pub fn new_storage<Item>(options: &CacheOptions) -> Option<Box<dyn Storage<Item>>>
+/// Cache items which can be written to the persistent cache.
+///
+/// Items returning `None` from `to_bytes` are only kept in memory.
+pub trait Persistable: Sized {
+  fn to_bytes(&self) -> Option<Vec<u8>>;
+  fn from_bytes(bytes: &[u8]) -> Option<Self>;
+}
+
+/// Create a storage for the given cache options.
+///
+/// `namespace` separates the data of different occasions in the persistent cache.
+pub fn new_storage<Item>(
+  options: &CacheOptions,
+  namespace: &str,
+) -> Result<Option<Box<dyn Storage<Item>>>>
+where
+  Item: Persistable + Debug + Clone + Send + Sync + 'static,
+{
+  Ok(match options {
+    CacheOptions::Disabled => None,
+    CacheOptions::FileSystem(options) => {
+      Some(Box::new(FileSystemStorage::new(options, namespace)?))
+    }
+    _ => Some(Box::new(MemoryStorage::new())),
+  })
+}
+
+/// Create a storage which is never persisted, for items which can not be serialized.
+pub fn new_memory_storage<Item>(options: &CacheOptions) -> Option<Box<dyn Storage<Item>>>
 where
   Item: Debug + Clone + Send + Sync + 'static,
 {
@@ -25,3 +57,19 @@ where
     _ => Some(Box::new(MemoryStorage::new())),
   }
 }
+
+/// Create a storage which only exists with the persistent cache.
+pub fn new_persistent_storage<Item>(
+  options: &CacheOptions,
+  namespace: &str,
+) -> Result<Option<Box<dyn Storage<Item>>>>
+where
+  Item: Persistable + Debug + Clone + Send + Sync + 'static,
+{
+  Ok(match options {
+    CacheOptions::FileSystem(options) => {
+      Some(Box::new(FileSystemStorage::new(options, namespace)?))
+    }
+    _ => None,
+  })
+}
@@ -0,0 +1,147 @@
+use std::io::{Read, Write};
+
+use rspack_identifier::Identifier;
+
+use crate::CacheCompression;
+
+const MAGIC: &[u8; 4] = b"RSPK";
+const PACK_VERSION: u8 = 1;
+
+/// A pack file is `[magic][pack version][compression][body]`, and the (compressed) body is
+/// a list of `[identifier length (u32 le)][identifier][payload length (u32 le)][payload]`.
+///
+/// The compression is recorded in each pack, so packs written with other `cache.compression`
+/// can still be read.
+pub fn encode_pack(entries: &[(Identifier, Vec<u8>)], compression: CacheCompression) -> Vec<u8> {
+  let mut body = Vec::with_capacity(
+    entries
+      .iter()
+      .map(|(id, payload)| 8 + id.len() + payload.len())
+      .sum(),
+  );
+  for (id, payload) in entries {
+    body.extend_from_slice(&(id.len() as u32).to_le_bytes());
+    body.extend_from_slice(id.as_bytes());
+    body.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    body.extend_from_slice(payload);
+  }
+
+  let mut bytes = Vec::with_capacity(body.len() + 6);
+  bytes.extend_from_slice(MAGIC);
+  bytes.push(PACK_VERSION);
+  bytes.push(compression_to_byte(compression));
+  compress(&body, compression, &mut bytes);
+  bytes
+}
+
+pub fn decode_pack(bytes: &[u8]) -> Option<Vec<(Identifier, Vec<u8>)>> {
+  if bytes.get(0..4)? != MAGIC || *bytes.get(4)? != PACK_VERSION {
+    return None;
+  }
+  let compression = compression_from_byte(*bytes.get(5)?)?;
+  let body = decompress(bytes.get(6..)?, compression)?;
+
+  let mut entries = vec![];
+  let mut rest = body.as_slice();
+  while !rest.is_empty() {
+    let (id, next) = read_chunk(rest)?;
+    let (payload, next) = read_chunk(next)?;
+    entries.push((
+      Identifier::from(std::str::from_utf8(id).ok()?),
+      payload.to_vec(),
+    ));
+    rest = next;
+  }
+  Some(entries)
+}
+
+fn read_chunk(bytes: &[u8]) -> Option<(&[u8], &[u8])> {
+  let len = u32::from_le_bytes(bytes.get(0..4)?.try_into().ok()?) as usize;
+  Some((bytes.get(4..4 + len)?, bytes.get(4 + len..)?))
+}
+
+fn compression_to_byte(compression: CacheCompression) -> u8 {
+  match compression {
+    CacheCompression::None => 0,
+    CacheCompression::Gzip => 1,
+    CacheCompression::Brotli => 2,
+    CacheCompression::Zstd => 3,
+  }
+}
+
+fn compression_from_byte(byte: u8) -> Option<CacheCompression> {
+  match byte {
+    0 => Some(CacheCompression::None),
+    1 => Some(CacheCompression::Gzip),
+    2 => Some(CacheCompression::Brotli),
+    3 => Some(CacheCompression::Zstd),
+    _ => None,
+  }
+}
+
+fn compress(body: &[u8], compression: CacheCompression, out: &mut Vec<u8>) {
+  match compression {
+    CacheCompression::None => out.extend_from_slice(body),
+    CacheCompression::Gzip => {
+      let mut encoder = flate2::write::GzEncoder::new(out, flate2::Compression::fast());
+      encoder
+        .write_all(body)
+        .and_then(|_| encoder.try_finish())
+        .expect("write to vec should not fail");
+    }
+    CacheCompression::Brotli => {
+      let mut encoder = brotli::CompressorWriter::new(out, 4096, 4, 22);
+      encoder
+        .write_all(body)
+        .expect("write to vec should not fail");
+      // finish the stream
+      encoder.into_inner();
+    }
+    CacheCompression::Zstd => {
+      zstd::stream::copy_encode(body, out, 3).expect("write to vec should not fail");
+    }
+  }
+}
+
+fn decompress(bytes: &[u8], compression: CacheCompression) -> Option<Vec<u8>> {
+  let mut body = vec![];
+  match compression {
+    CacheCompression::None => body.extend_from_slice(bytes),
+    CacheCompression::Gzip => {
+      flate2::read::GzDecoder::new(bytes)
+        .read_to_end(&mut body)
+        .ok()?;
+    }
+    CacheCompression::Brotli => {
+      brotli::Decompressor::new(bytes, 4096)
+        .read_to_end(&mut body)
+        .ok()?;
+    }
+    CacheCompression::Zstd => {
+      zstd::stream::copy_decode(bytes, &mut body).ok()?;
+    }
+  }
+  Some(body)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn should_roundtrip_with_all_compressions() {
+    let entries = vec![
+      (Identifier::from("a.js"), b"console.log('a')".repeat(100)),
+      (Identifier::from("b.js"), vec![]),
+    ];
+    for compression in [
+      CacheCompression::None,
+      CacheCompression::Gzip,
+      CacheCompression::Brotli,
+      CacheCompression::Zstd,
+    ] {
+      let bytes = encode_pack(&entries, compression);
+      assert_eq!(decode_pack(&bytes), Some(entries.clone()));
+    }
+  }
+}
@@ -8,9 +8,9 @@ use rspack_util::ext::DynHash;
 use rustc_hash::FxHashSet as HashSet;
 
 use crate::{
-  get_chunk_group_from_ukey, AsyncDependenciesBlockIdentifier, BoxModule, ChunkByUkey, ChunkGroup,
-  ChunkGroupByUkey, ChunkGroupUkey, ChunkUkey, ExportsHash, ModuleIdentifier, RuntimeGlobals,
-  RuntimeSpec, RuntimeSpecMap, RuntimeSpecSet,
+  get_chunk_group_from_ukey, get_runtime_key, AsyncDependenciesBlockIdentifier, BoxModule,
+  ChunkByUkey, ChunkGroup, ChunkGroupByUkey, ChunkGroupUkey, ChunkUkey, ExportsHash,
+  ModuleIdentifier, RuntimeGlobals, RuntimeSpec, RuntimeSpecMap, RuntimeSpecSet,
 };
 use crate::{ChunkGraph, ModuleGraph};
 
@@ -87,6 +87,11 @@ impl ChunkGraph {
     cgm.chunks.len()
   }
 
+  pub fn is_entry_module(&self, module_identifier: &ModuleIdentifier) -> bool {
+    let cgm = self.get_chunk_graph_module(*module_identifier);
+    !cgm.entry_in_chunks.is_empty()
+  }
+
   pub fn add_module_runtime_requirements(
     &mut self,
     module_identifier: ModuleIdentifier,
@@ -169,18 +174,23 @@ impl ChunkGraph {
     &self,
     module: &BoxModule,
     module_graph: &ModuleGraph,
+    runtimes: &RuntimeSpecSet,
     with_connections: bool,
   ) -> String {
     let mut hasher = DefaultHasher::new();
     let mut connection_hash_cache: HashMap<Identifier, u64> = HashMap::new();
 
     fn process_module_graph_module(
+      chunk_graph: &ChunkGraph,
       module: &BoxModule,
       module_graph: &ModuleGraph,
       strict: bool,
     ) -> u64 {
       let mut hasher = DefaultHasher::new();
       module.identifier().dyn_hash(&mut hasher);
+      chunk_graph
+        .get_module_id(module.identifier())
+        .dyn_hash(&mut hasher);
       module.source_types().dyn_hash(&mut hasher);
       module_graph
         .is_async(&module.identifier())
@@ -203,7 +213,15 @@ impl ChunkGraph {
       .get_module_hash(&module.identifier())
       .dyn_hash(&mut hasher);
     // hash module graph module
-    process_module_graph_module(module, module_graph, false).dyn_hash(&mut hasher);
+    process_module_graph_module(self, module, module_graph, false).dyn_hash(&mut hasher);
+    // hash runtimes, sorted by runtime key
+    let mut runtime_keys = runtimes
+      .values()
+      .into_iter()
+      .map(|runtime| get_runtime_key(runtime.clone()))
+      .collect::<Vec<_>>();
+    runtime_keys.sort_unstable();
+    runtime_keys.dyn_hash(&mut hasher);
 
     let strict: bool = module_graph
       .module_graph_module_by_identifier(&module.identifier())
@@ -229,6 +247,7 @@ impl ChunkGraph {
           connection_hash.dyn_hash(&mut hasher)
         } else {
           let connection_hash = process_module_graph_module(
+            self,
             module_graph
               .module_by_identifier(&connection.module_identifier)
               .unwrap_or_else(|| {
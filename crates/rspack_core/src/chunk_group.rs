@@ -34,6 +34,8 @@ pub struct ChunkGroup {
   // Entrypoint
   pub(crate) runtime_chunk: Option<ChunkUkey>,
   pub(crate) entry_point_chunk: Option<ChunkUkey>,
+  /// whether the entrypoint exceeds the size limit of `performance`
+  pub is_over_size_limit: Option<bool>,
 }
 
 impl ChunkGroup {
@@ -53,6 +55,7 @@ impl ChunkGroup {
       runtime_chunk: None,
       entry_point_chunk: None,
       index: None,
+      is_over_size_limit: None,
     }
   }
 
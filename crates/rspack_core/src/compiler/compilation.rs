@@ -7,6 +7,7 @@ use std::{
     atomic::{AtomicBool, Ordering},
     Arc,
   },
+  time::{SystemTime, UNIX_EPOCH},
 };
 
 use dashmap::DashSet;
@@ -16,6 +17,7 @@ use rayon::prelude::{
   IntoParallelIterator, IntoParallelRefIterator, ParallelBridge, ParallelIterator,
 };
 use rspack_error::{error, Diagnostic, Result, Severity, TWithDiagnosticArray};
+use rspack_fs::AsyncReadableFileSystem;
 use rspack_futures::FuturesResults;
 use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_identifier::{Identifiable, IdentifierMap, IdentifierSet};
@@ -26,7 +28,7 @@ use tokio::sync::mpsc::error::TryRecvError;
 use tracing::instrument;
 
 use super::{
-  hmr::CompilationRecords,
+  hmr::{CompilationRecords, HotUpdateManifest},
   make::{MakeParam, RebuildDepsBuilder},
 };
 use crate::{
@@ -39,14 +41,14 @@ use crate::{
   BuildTask, BuildTaskResult, CacheCount, CacheOptions, Chunk, ChunkByUkey, ChunkContentHash,
   ChunkGraph, ChunkGroupByUkey, ChunkGroupUkey, ChunkHashArgs, ChunkKind, ChunkUkey, CleanQueue,
   CleanTask, CleanTaskResult, CodeGenerationResult, CodeGenerationResults, CompilationLogger,
-  CompilationLogging, CompilerOptions, ContentHashArgs, ContextDependency, DependencyId,
-  DependencyParents, DependencyType, Entry, EntryData, EntryOptions, Entrypoint, ErrorSpan,
-  FactorizeQueue, FactorizeTask, FactorizeTaskResult, Filename, Logger, Module, ModuleFactory,
-  ModuleGraph, ModuleGraphModule, ModuleIdentifier, ModuleProfile, NormalModuleSource, PathData,
-  ProcessAssetsArgs, ProcessDependenciesQueue, ProcessDependenciesResult, ProcessDependenciesTask,
-  RenderManifestArgs, Resolve, ResolverFactory, RuntimeGlobals, RuntimeModule,
-  RuntimeRequirementsInTreeArgs, RuntimeSpec, SharedPluginDriver, SourceType, Stats, TaskResult,
-  WorkerTask,
+  CompilationLogging, CompilerOptions, ConcatenatedModule, ContentHashArgs, ContextDependency,
+  DependencyId, DependencyParents, DependencyType, Entry, EntryData, EntryOptions, Entrypoint,
+  ErrorSpan, FactorizeQueue, FactorizeTask, FactorizeTaskResult, Filename, Logger, Module,
+  ModuleFactory, ModuleGraph, ModuleGraphModule, ModuleIdentifier, ModuleProfile,
+  NormalModuleSource, PathData, ProcessAssetsArgs, ProcessDependenciesQueue,
+  ProcessDependenciesResult, ProcessDependenciesTask, RenderManifestArgs, Resolve, ResolverFactory,
+  RuntimeGlobals, RuntimeModule, RuntimeRequirementsInTreeArgs, RuntimeSpec, RuntimeSpecSet,
+  SharedPluginDriver, SourceType, Stats, TaskResult, WorkerTask,
 };
 use crate::{tree_shaking::visitor::OptimizeAnalyzeResult, Context};
 
@@ -64,6 +66,8 @@ pub struct Compilation {
   // So use compilation hash update `hot_index` to fix it.
   pub hot_index: u32,
   pub records: Option<CompilationRecords>,
+  /// Hot updates emitted by this compilation, one for each runtime of the previous compilation.
+  pub hot_update_manifests: Vec<HotUpdateManifest>,
   pub options: Arc<CompilerOptions>,
   pub entries: Entry,
   pub global_entry: EntryData,
@@ -81,6 +85,8 @@ pub struct Compilation {
   pub async_entrypoints: Vec<ChunkGroupUkey>,
   assets: CompilationAssets,
   pub emitted_assets: DashSet<String, BuildHasherDefault<FxHasher>>,
+  /// Assets which are not written because the same content is already on disk.
+  pub compared_for_emit_assets: DashSet<String, BuildHasherDefault<FxHasher>>,
   diagnostics: Vec<Diagnostic>,
   logging: CompilationLogging,
   pub plugin_driver: SharedPluginDriver,
@@ -98,6 +104,7 @@ pub struct Compilation {
   pub code_generation_results: CodeGenerationResults,
   pub code_generated_modules: IdentifierSet,
   pub cache: Arc<Cache>,
+  pub input_filesystem: Arc<dyn AsyncReadableFileSystem>,
   pub code_splitting_cache: CodeSplittingCache,
   pub hash: Option<RspackHashDigest>,
   // lazy compilation visit module
@@ -109,8 +116,18 @@ pub struct Compilation {
   pub context_dependencies: IndexSet<PathBuf, BuildHasherDefault<FxHasher>>,
   pub missing_dependencies: IndexSet<PathBuf, BuildHasherDefault<FxHasher>>,
   pub build_dependencies: IndexSet<PathBuf, BuildHasherDefault<FxHasher>>,
+  /// Files reported as changed by the rebuild which creates this compilation.
+  pub modified_files: HashSet<PathBuf>,
+  /// Files reported as removed by the rebuild which creates this compilation.
+  pub removed_files: HashSet<PathBuf>,
   pub side_effects_free_modules: IdentifierSet,
   pub module_item_map: IdentifierMap<Vec<ModuleItem>>,
+  /// Modules rendered with the modules they concatenate, keyed by the root module.
+  pub concatenated_modules: IdentifierMap<ConcatenatedModule>,
+  /// Milliseconds since the unix epoch when the compilation starts.
+  pub start_time: u64,
+  /// Milliseconds since the unix epoch when the compilation is done.
+  pub end_time: Option<u64>,
 }
 
 impl Compilation {
@@ -123,10 +140,12 @@ impl Compilation {
     loader_resolver_factory: Arc<ResolverFactory>,
     records: Option<CompilationRecords>,
     cache: Arc<Cache>,
+    input_filesystem: Arc<dyn AsyncReadableFileSystem>,
   ) -> Self {
     Self {
       hot_index: 0,
       records,
+      hot_update_manifests: Default::default(),
       options,
       module_graph,
       dependency_factories: Default::default(),
@@ -144,6 +163,7 @@ impl Compilation {
       async_entrypoints: Default::default(),
       assets: Default::default(),
       emitted_assets: Default::default(),
+      compared_for_emit_assets: Default::default(),
       diagnostics: Default::default(),
       logging: Default::default(),
       plugin_driver,
@@ -160,6 +180,7 @@ impl Compilation {
       code_generated_modules: Default::default(),
 
       cache,
+      input_filesystem,
       code_splitting_cache: Default::default(),
       hash: None,
       lazy_visit_modules: Default::default(),
@@ -169,9 +190,14 @@ impl Compilation {
       context_dependencies: Default::default(),
       missing_dependencies: Default::default(),
       build_dependencies: Default::default(),
+      modified_files: Default::default(),
+      removed_files: Default::default(),
       side_effects_free_modules: IdentifierSet::default(),
       module_item_map: IdentifierMap::default(),
+      concatenated_modules: IdentifierMap::default(),
       include_module_ids: IdentifierSet::default(),
+      start_time: now_millis(),
+      end_time: None,
     }
   }
 
@@ -539,39 +565,45 @@ impl Compilation {
     let mut make_failed_module = HashSet::default();
     let mut errored = None;
 
-    deps_builder
-      .revoke_modules(&mut self.module_graph)
-      .into_iter()
-      .for_each(|(id, parent_module_identifier)| {
-        let dependency = self
-          .module_graph
-          .dependency_by_id(&id)
-          .expect("dependency not found");
-        if dependency.as_module_dependency().is_none()
-          && dependency.as_context_dependency().is_none()
-        {
-          return;
-        }
-
-        let parent_module =
-          parent_module_identifier.and_then(|id| self.module_graph.module_by_identifier(&id));
-        if parent_module_identifier.is_some() && parent_module.is_none() {
-          return;
-        }
-
-        self.handle_module_creation(
-          &mut factorize_queue,
-          parent_module_identifier,
-          parent_module.and_then(|m| m.get_context()),
-          vec![id],
-          parent_module_identifier.is_none(),
-          parent_module.and_then(|module| module.get_resolve_options()),
-          self.lazy_visit_modules.clone(),
-          parent_module
-            .and_then(|m| m.as_normal_module())
-            .and_then(|module| module.name_for_condition()),
-        );
-      });
+    // Dependencies of a module requesting the same resource are factorized together, like the
+    // ones processed after building, so a failed request is reported once.
+    let mut revoked_dependencies: IndexMap<_, Vec<DependencyId>> = IndexMap::default();
+    for (id, parent_module_identifier) in deps_builder.revoke_modules(&mut self.module_graph) {
+      let dependency = self
+        .module_graph
+        .dependency_by_id(&id)
+        .expect("dependency not found");
+      let Some(resource_identifier) = get_resource_identifier(dependency) else {
+        continue;
+      };
+      // entries are never grouped
+      let entry = parent_module_identifier.is_none().then_some(id);
+      revoked_dependencies
+        .entry((parent_module_identifier, resource_identifier, entry))
+        .or_default()
+        .push(id);
+    }
+    for ((parent_module_identifier, ..), mut dependencies) in revoked_dependencies {
+      let parent_module =
+        parent_module_identifier.and_then(|id| self.module_graph.module_by_identifier(&id));
+      if parent_module_identifier.is_some() && parent_module.is_none() {
+        continue;
+      }
+      dependencies.sort_unstable();
+
+      self.handle_module_creation(
+        &mut factorize_queue,
+        parent_module_identifier,
+        parent_module.and_then(|m| m.get_context()),
+        dependencies,
+        parent_module_identifier.is_none(),
+        parent_module.and_then(|module| module.get_resolve_options()),
+        self.lazy_visit_modules.clone(),
+        parent_module
+          .and_then(|m| m.as_normal_module())
+          .and_then(|module| module.name_for_condition()),
+      );
+    }
 
     let mut add_time = logger.time_aggregate("module add task");
     let mut process_deps_time = logger.time_aggregate("module process dependencies task");
@@ -647,29 +679,7 @@ impl Compilation {
 
         task.dependencies.into_iter().for_each(|dependency_id| {
           let dependency = dependency_id.get_dependency(&self.module_graph);
-          // FIXME: now only module/context dependency can put into resolve queue.
-          // FIXME: should align webpack
-          let resource_identifier =
-            if let Some(module_dependency) = dependency.as_module_dependency() {
-              // TODO need implement more dependency `resource_identifier()`
-              // https://github.com/webpack/webpack/blob/main/lib/Compilation.js#L1621
-              let id = if let Some(resource_identifier) = module_dependency.resource_identifier() {
-                resource_identifier.to_string()
-              } else {
-                format!(
-                  "{}|{}",
-                  module_dependency.dependency_type(),
-                  module_dependency.request()
-                )
-              };
-              Some(id)
-            } else {
-              dependency
-                .as_context_dependency()
-                .map(|d| ContextDependency::resource_identifier(d).to_string())
-            };
-
-          if let Some(resource_identifier) = resource_identifier {
+          if let Some(resource_identifier) = get_resource_identifier(dependency) {
             sorted_dependencies
               .entry(resource_identifier)
               .or_insert(vec![])
@@ -807,6 +817,7 @@ impl Compilation {
                   compiler_options: self.options.clone(),
                   plugin_driver: self.plugin_driver.clone(),
                   cache: self.cache.clone(),
+                  input_filesystem: self.input_filesystem.clone(),
                   current_profile,
                 });
               }
@@ -1176,26 +1187,36 @@ impl Compilation {
             return None;
           }
 
-          let res = compilation
-            .cache
-            .code_generate_occasion
-            .use_cache(module, runtimes, compilation, |module, runtimes| {
-              let take_length = if used_exports_optimization {
-                runtimes.len()
-              } else {
-                // Only codegen once
-                1
-              };
-              let mut codegen_list = vec![];
-              for runtime in runtimes.into_values().take(take_length) {
-                codegen_list.push((
-                  module.code_generation(compilation, Some(&runtime))?,
-                  runtime,
-                ));
-              }
-              Ok(codegen_list)
-            })
-            .map(|(result, from_cache)| (*module_identifier, result, from_cache));
+          let generate = |module: &dyn Module, runtimes: RuntimeSpecSet| {
+            let take_length = if used_exports_optimization {
+              runtimes.len()
+            } else {
+              // Only codegen once
+              1
+            };
+            let mut codegen_list = vec![];
+            for runtime in runtimes.into_values().take(take_length) {
+              codegen_list.push((
+                module.code_generation(compilation, Some(&runtime))?,
+                runtime,
+              ));
+            }
+            Ok(codegen_list)
+          };
+          // The concatenated modules depend on the code of several modules, skip the cache.
+          let res = if let Some(concatenated_module) =
+            compilation.concatenated_modules.get(module_identifier)
+          {
+            generate(concatenated_module, runtimes).map(|result| (result, false))
+          } else {
+            compilation.cache.code_generate_occasion.use_cache(
+              module,
+              runtimes,
+              compilation,
+              |module, runtimes| generate(module.as_ref(), runtimes),
+            )
+          }
+          .map(|(result, from_cache)| (*module_identifier, result, from_cache));
           Some(res)
         })
         .collect::<Result<
@@ -1364,6 +1385,7 @@ impl Compilation {
   }
 
   pub async fn done(&mut self, plugin_driver: SharedPluginDriver) -> Result<()> {
+    self.end_time = Some(now_millis());
     let stats = &mut Stats::new(self);
     plugin_driver.done(stats).await?;
     Ok(())
@@ -2019,6 +2041,8 @@ pub struct AssetInfo {
   /// An empty string means no version, it will always emit
   pub version: String,
   pub source_filename: Option<String>,
+  /// whether the asset exceeds the size limit of `performance`
+  pub is_over_size_limit: Option<bool>,
 }
 
 impl AssetInfo {
@@ -2077,3 +2101,34 @@ impl AssetInfo {
 pub struct AssetInfoRelated {
   pub source_map: Option<String>,
 }
+
+/// Dependencies with the same resource identifier are resolved to the same module.
+fn get_resource_identifier(dependency: &BoxDependency) -> Option<String> {
+  // FIXME: now only module/context dependency can put into resolve queue.
+  // FIXME: should align webpack
+  if let Some(module_dependency) = dependency.as_module_dependency() {
+    // TODO need implement more dependency `resource_identifier()`
+    // https://github.com/webpack/webpack/blob/main/lib/Compilation.js#L1621
+    let id = if let Some(resource_identifier) = module_dependency.resource_identifier() {
+      resource_identifier.to_string()
+    } else {
+      format!(
+        "{}|{}",
+        module_dependency.dependency_type(),
+        module_dependency.request()
+      )
+    };
+    Some(id)
+  } else {
+    dependency
+      .as_context_dependency()
+      .map(|d| ContextDependency::resource_identifier(d).to_string())
+  }
+}
+
+fn now_millis() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|d| d.as_millis() as u64)
+    .unwrap_or_default()
+}
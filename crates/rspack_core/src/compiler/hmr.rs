@@ -2,10 +2,11 @@ use std::path::PathBuf;
 
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use rspack_error::Result;
-use rspack_fs::AsyncWritableFileSystem;
+use rspack_fs::AsyncFileSystem;
 use rspack_hash::RspackHashDigest;
-use rspack_identifier::IdentifierMap;
-use rustc_hash::FxHashSet as HashSet;
+use rspack_identifier::{Identifier, IdentifierMap, IdentifierSet};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use serde::Serialize;
 
 use super::MakeParam;
 use crate::{
@@ -14,7 +15,7 @@ use crate::{
 
 impl<T> Compiler<T>
 where
-  T: AsyncWritableFileSystem + Send + Sync,
+  T: AsyncFileSystem + Send + Sync,
 {
   pub async fn rebuild(
     &mut self,
@@ -38,14 +39,24 @@ where
     }
 
     let mut old_chunks: Vec<(String, RuntimeSpec)> = vec![];
+    let mut old_chunk_modules: HashMap<String, IdentifierSet> = Default::default();
     for (_, chunk) in old.compilation.chunk_by_ukey.iter() {
       if chunk.kind != ChunkKind::HotUpdate {
         old_chunks.push((chunk.expect_id().to_string(), chunk.runtime.clone()));
+        old_chunk_modules.insert(
+          chunk.expect_id().to_string(),
+          old
+            .compilation
+            .chunk_graph
+            .get_chunk_module_identifiers(&chunk.ukey)
+            .clone(),
+        );
       }
     }
 
     let records = CompilationRecords {
       old_chunks,
+      old_chunk_modules,
       all_old_runtime,
       old_all_modules,
       old_runtime_modules,
@@ -54,14 +65,17 @@ where
 
     // build without stats
     {
-      let mut modified_files = HashSet::default();
-      modified_files.extend(changed_files.iter().map(PathBuf::from));
-      modified_files.extend(removed_files.iter().map(PathBuf::from));
+      let modified_files: HashSet<PathBuf> = changed_files.iter().map(PathBuf::from).collect();
+      let removed_files: HashSet<PathBuf> = removed_files.iter().map(PathBuf::from).collect();
 
       self.cache.end_idle();
-      self
-        .cache
-        .set_modified_files(modified_files.iter().cloned().collect::<Vec<_>>());
+      self.cache.set_modified_files(
+        modified_files
+          .iter()
+          .chain(removed_files.iter())
+          .cloned()
+          .collect::<Vec<_>>(),
+      );
       self.plugin_driver.resolver_factory.clear_cache();
 
       let mut new_compilation = Compilation::new(
@@ -72,6 +86,7 @@ where
         self.loader_resolver_factory.clone(),
         Some(records),
         self.cache.clone(),
+        self.input_filesystem.clone(),
       );
 
       if let Some(state) = self.options.get_incremental_rebuild_make_state() {
@@ -100,6 +115,18 @@ where
           std::mem::take(&mut self.compilation.missing_dependencies);
         new_compilation.build_dependencies =
           std::mem::take(&mut self.compilation.build_dependencies);
+        // the removed files are collected again as missing dependencies if they are still
+        // requested
+        new_compilation
+          .file_dependencies
+          .retain(|file| !removed_files.contains(file));
+        new_compilation
+          .build_dependencies
+          .retain(|file| !removed_files.contains(file));
+        // the created missing paths are collected again if the requests still fail
+        new_compilation
+          .missing_dependencies
+          .retain(|file| !modified_files.contains(file));
         // tree shaking usage start
         new_compilation.optimize_analyze_result_map =
           std::mem::take(&mut self.compilation.optimize_analyze_result_map);
@@ -116,12 +143,17 @@ where
         new_compilation.has_module_import_export_change = false;
       }
 
+      new_compilation.modified_files = modified_files.clone();
+      new_compilation.removed_files = removed_files.clone();
+
       fast_set(&mut self.compilation, new_compilation);
 
       self.compilation.lazy_visit_modules = changed_files.clone();
 
       let setup_make_params = if is_incremental_rebuild_make {
-        MakeParam::ModifiedFiles(modified_files)
+        // modules depending on the removed files are rebuilt as well, the importers of the
+        // removed modules get the resolve errors
+        MakeParam::ModifiedFiles(modified_files.union(&removed_files).cloned().collect())
       } else {
         MakeParam::ForceBuildDeps(Default::default())
       };
@@ -138,12 +170,42 @@ where
 #[derive(Debug)]
 pub struct CompilationRecords {
   pub old_chunks: Vec<(String, RuntimeSpec)>,
+  /// Modules of the chunks in `old_chunks`, keyed by chunk id.
+  pub old_chunk_modules: HashMap<String, IdentifierSet>,
   pub all_old_runtime: RuntimeSpec,
   pub old_all_modules: IdentifierMap<(RspackHashDigest, String)>,
   pub old_runtime_modules: IdentifierMap<String>,
   pub old_hash: Option<RspackHashDigest>,
 }
 
+/// The hot update of a runtime, serialized as the `[runtime].[fullhash].hot-update.json` manifest
+/// in the `c/r/m` shape of webpack.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct HotUpdateManifest {
+  /// Filename of the emitted manifest.
+  #[serde(skip)]
+  pub filename: String,
+  #[serde(skip)]
+  pub runtime: RuntimeSpec,
+  /// Ids of the chunks which have a hot update chunk.
+  #[serde(rename = "c")]
+  pub updated_chunk_ids: Vec<String>,
+  /// Ids of the chunks which are removed from the runtime.
+  #[serde(rename = "r")]
+  pub removed_chunk_ids: Vec<String>,
+  /// Ids of the modules which should be disposed from the runtime.
+  #[serde(rename = "m")]
+  pub removed_module_ids: Vec<String>,
+  /// Modules whose code is contained in the hot update chunks.
+  #[serde(skip)]
+  pub updated_modules: Vec<Identifier>,
+  /// Runtime modules whose code is contained in the hot update chunks. The runtime is patched
+  /// in place by them, so a dev server may prefer a full reload when this is not empty. The
+  /// runtime of webpack ignores this field, it's omitted when empty to keep the `c/r/m` shape.
+  #[serde(rename = "runtimeModules", skip_serializing_if = "Vec::is_empty")]
+  pub updated_runtime_modules: Vec<Identifier>,
+}
+
 pub fn collect_changed_modules(
   compilation: &Compilation,
 ) -> (
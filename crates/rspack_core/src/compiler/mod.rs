@@ -2,20 +2,25 @@ mod compilation;
 mod hmr;
 mod make;
 mod queue;
+mod watch;
 
 use std::collections::hash_map::Entry;
 use std::ops::Deref;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::{path::Path, sync::Arc};
 
 pub use compilation::*;
-pub use hmr::{collect_changed_modules, CompilationRecords};
+pub use hmr::{collect_changed_modules, CompilationRecords, HotUpdateManifest};
 pub use make::MakeParam;
 pub use queue::*;
 use rspack_error::Result;
-use rspack_fs::AsyncWritableFileSystem;
+use rspack_fs::{
+  AsyncFileSystem, AsyncNativeFileSystem, AsyncReadableFileSystem, ReadableFileSystem,
+};
 use rspack_futures::FuturesResults;
 use rspack_identifier::{IdentifierMap, IdentifierSet};
-use rustc_hash::FxHashMap as HashMap;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use swc_core::ecma::atoms::JsWord;
 use tracing::instrument;
 
@@ -23,8 +28,8 @@ use crate::cache::Cache;
 use crate::tree_shaking::symbol::{IndirectType, StarSymbolKind, DEFAULT_JS_WORD};
 use crate::tree_shaking::visitor::SymbolRef;
 use crate::{
-  fast_set, AssetEmittedArgs, CompilerOptions, Logger, ModuleGraph, PluginDriver, ResolverFactory,
-  SharedPluginDriver,
+  calc_hash, fast_set, AssetEmittedArgs, CompilerOptions, Logger, ModuleGraph, PluginDriver,
+  ResolverFactory, ResolverFileSystem, SharedPluginDriver,
 };
 use crate::{BoxPlugin, ExportInfo, UsageState};
 use crate::{CompilationParams, ContextModuleFactory, NormalModuleFactory};
@@ -32,10 +37,12 @@ use crate::{CompilationParams, ContextModuleFactory, NormalModuleFactory};
 #[derive(Debug)]
 pub struct Compiler<T>
 where
-  T: AsyncWritableFileSystem + Send + Sync,
+  T: AsyncFileSystem + Send + Sync,
 {
   pub options: Arc<CompilerOptions>,
   pub output_filesystem: T,
+  /// File system used to read module resources.
+  pub input_filesystem: Arc<dyn AsyncReadableFileSystem>,
   pub compilation: Compilation,
   pub plugin_driver: SharedPluginDriver,
   pub resolver_factory: Arc<ResolverFactory>,
@@ -44,28 +51,97 @@ where
   /// emitted asset versions
   /// the key of HashMap is filename, the value of HashMap is version
   pub emitted_asset_versions: HashMap<String, String>,
+  /// Files in the output directory written or confirmed by this compiler, by filename.
+  emitted_files: HashMap<String, EmittedFile>,
+  /// Whether the files left in the output directory by earlier runs are cleaned.
+  cleaned_earlier_output: bool,
+}
+
+/// What an emitted file looked like on disk right after it was written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct EmittedFile {
+  content_hash: u64,
+  size: u64,
+  mtime_ms: u64,
 }
 
 impl<T> Compiler<T>
 where
-  T: AsyncWritableFileSystem + Send + Sync,
+  T: AsyncFileSystem + Send + Sync,
 {
   #[instrument(skip_all)]
   pub fn new(options: CompilerOptions, plugins: Vec<BoxPlugin>, output_filesystem: T) -> Self {
-    #[cfg(debug_assertions)]
-    {
-      if let Ok(mut debug_info) = crate::debug_info::DEBUG_INFO.lock() {
-        debug_info.with_context(options.context.to_string());
-      }
-    }
     let new_resolver = options.experiments.rspack_future.new_resolver;
     let resolver_factory = Arc::new(ResolverFactory::new(new_resolver, options.resolve.clone()));
     let loader_resolver_factory = Arc::new(ResolverFactory::new(
       new_resolver,
       options.resolve_loader.clone(),
     ));
+    Self::new_with_resolver_factories(
+      options,
+      plugins,
+      output_filesystem,
+      Arc::new(AsyncNativeFileSystem),
+      resolver_factory,
+      loader_resolver_factory,
+    )
+  }
+
+  /// Create a compiler which reads module resources from `input_filesystem` instead of the
+  /// native file system.
+  ///
+  /// Requests are resolved through it as well, except by the old resolver, which only reads
+  /// the native file system.
+  #[instrument(skip_all)]
+  pub fn new_with_input_filesystem<F>(
+    options: CompilerOptions,
+    plugins: Vec<BoxPlugin>,
+    output_filesystem: T,
+    input_filesystem: F,
+  ) -> Self
+  where
+    F: AsyncReadableFileSystem + ReadableFileSystem + Send + Sync + 'static,
+  {
+    let input_filesystem = Arc::new(input_filesystem);
+    let new_resolver = options.experiments.rspack_future.new_resolver;
+    let resolver_filesystem = ResolverFileSystem::new(input_filesystem.clone());
+    let resolver_factory = Arc::new(ResolverFactory::new_with_file_system(
+      new_resolver,
+      options.resolve.clone(),
+      resolver_filesystem.clone(),
+    ));
+    let loader_resolver_factory = Arc::new(ResolverFactory::new_with_file_system(
+      new_resolver,
+      options.resolve_loader.clone(),
+      resolver_filesystem,
+    ));
+    Self::new_with_resolver_factories(
+      options,
+      plugins,
+      output_filesystem,
+      input_filesystem,
+      resolver_factory,
+      loader_resolver_factory,
+    )
+  }
+
+  fn new_with_resolver_factories(
+    options: CompilerOptions,
+    plugins: Vec<BoxPlugin>,
+    output_filesystem: T,
+    input_filesystem: Arc<dyn AsyncReadableFileSystem>,
+    resolver_factory: Arc<ResolverFactory>,
+    loader_resolver_factory: Arc<ResolverFactory>,
+  ) -> Self {
+    #[cfg(debug_assertions)]
+    {
+      if let Ok(mut debug_info) = crate::debug_info::DEBUG_INFO.lock() {
+        debug_info.with_context(options.context.to_string());
+      }
+    }
     let (plugin_driver, options) = PluginDriver::new(options, plugins, resolver_factory.clone());
-    let cache = Arc::new(Cache::new(options.clone()));
+    // snapshots are read from the input file system as well
+    let cache = Arc::new(Cache::new(options.clone(), input_filesystem.clone()));
     let is_new_treeshaking = options.is_new_tree_shaking();
     assert!(!(options.is_new_tree_shaking() && options.builtins.tree_shaking.enable()), "Can't enable builtins.tree_shaking and `experiments.rspack_future.new_treeshaking` at the same time");
     Self {
@@ -78,13 +154,17 @@ where
         loader_resolver_factory.clone(),
         None,
         cache.clone(),
+        input_filesystem.clone(),
       ),
       output_filesystem,
+      input_filesystem,
       plugin_driver,
       resolver_factory,
       loader_resolver_factory,
       cache,
       emitted_asset_versions: Default::default(),
+      emitted_files: Default::default(),
+      cleaned_earlier_output: false,
     }
   }
 
@@ -95,6 +175,7 @@ where
 
   #[instrument(name = "build", skip_all)]
   pub async fn build(&mut self) -> Result<()> {
+    self.cache.check_persistent_cache()?;
     self.cache.end_idle();
     // TODO: clear the outdated cache entries in resolver,
     // TODO: maybe it's better to use external entries.
@@ -110,6 +191,7 @@ where
         self.loader_resolver_factory.clone(),
         None,
         self.cache.clone(),
+        self.input_filesystem.clone(),
       ),
     );
 
@@ -303,30 +385,6 @@ where
 
   #[instrument(name = "emit_assets", skip_all)]
   pub async fn emit_assets(&mut self) -> Result<()> {
-    if self.options.output.clean {
-      if self.emitted_asset_versions.is_empty() {
-        self
-          .output_filesystem
-          .remove_dir_all(&self.options.output.path)
-          .await?;
-      } else {
-        // clean unused file
-        let assets = self.compilation.assets();
-        let _ = self
-          .emitted_asset_versions
-          .iter()
-          .filter_map(|(filename, _version)| {
-            if !assets.contains_key(filename) {
-              let file_path = Path::new(&self.options.output.path).join(filename);
-              Some(self.output_filesystem.remove_file(file_path))
-            } else {
-              None
-            }
-          })
-          .collect::<FuturesResults<_>>();
-      }
-    }
-
     self.plugin_driver.emit(&mut self.compilation).await?;
 
     let mut new_emitted_asset_versions = HashMap::default();
@@ -353,24 +411,143 @@ where
     self.emitted_asset_versions = new_emitted_asset_versions;
     // return first error
     for item in results.into_inner() {
-      item?;
+      if let Some((filename, emitted_file)) = item? {
+        self.emitted_files.insert(filename, emitted_file);
+      }
+    }
+
+    let current_files = self
+      .compilation
+      .assets()
+      .keys()
+      .map(|filename| strip_query(filename).to_string())
+      .collect::<HashSet<_>>();
+    if self.options.output.clean {
+      self.clean_stale_files(&current_files).await?;
+      self.cleaned_earlier_output = true;
     }
+    self
+      .emitted_files
+      .retain(|filename, _| current_files.contains(filename));
 
     self.plugin_driver.after_emit(&mut self.compilation).await
   }
 
+  /// Remove the files which are not emitted by the current compilation, unless they match
+  /// `output.clean.keep`.
+  ///
+  /// The first emission looks at every file in the output directory, since the files of earlier
+  /// runs are unknown, and removes the directories it empties. Later ones only look at the files
+  /// emitted by previous compilations of this compiler.
+  async fn clean_stale_files(&self, current_files: &HashSet<String>) -> Result<()> {
+    let options = &self.options;
+    let output_path = &options.output.path;
+    let output_filesystem = &self.output_filesystem;
+    let (files, dirs) = if self.cleaned_earlier_output {
+      (self.emitted_files.keys().cloned().collect(), vec![])
+    } else {
+      self.output_files().await
+    };
+    let is_kept = |path: &str| {
+      options
+        .output
+        .clean_keep
+        .as_ref()
+        .is_some_and(|keep| keep.matches(Path::new(path)))
+    };
+    let results = files
+      .iter()
+      .filter(|filename| !current_files.contains(*filename) && !is_kept(filename))
+      .map(|filename| async move {
+        let file_path = output_path.join(filename);
+        // the file may be removed by others already
+        match output_filesystem.symlink_metadata(&file_path).await {
+          Ok(metadata) if !metadata.is_directory => {
+            output_filesystem.remove_file(&file_path).await?;
+          }
+          _ => {}
+        }
+        Ok(())
+      })
+      .collect::<FuturesResults<Result<()>>>();
+    for item in results.into_inner() {
+      item?;
+    }
+    // nested directories come after their parents
+    for dir in dirs.iter().rev().filter(|dir| !is_kept(dir)) {
+      let dir_path = output_path.join(dir);
+      if output_filesystem
+        .read_dir(&dir_path)
+        .await
+        .is_ok_and(|entries| entries.is_empty())
+      {
+        output_filesystem.remove_dir_all(&dir_path).await?;
+      }
+    }
+    Ok(())
+  }
+
+  /// The files and directories in the output directory, relative to it.
+  async fn output_files(&self) -> (Vec<String>, Vec<String>) {
+    let output_path = &self.options.output.path;
+    let mut files = vec![];
+    let mut dirs = vec![];
+    let mut pending = vec![String::new()];
+    while let Some(dir) = pending.pop() {
+      let Ok(names) = self
+        .output_filesystem
+        .read_dir(&output_path.join(&dir))
+        .await
+      else {
+        continue;
+      };
+      for name in names {
+        let relative = if dir.is_empty() {
+          name
+        } else {
+          format!("{dir}/{name}")
+        };
+        match self
+          .output_filesystem
+          .symlink_metadata(&output_path.join(&relative))
+          .await
+        {
+          Ok(metadata) if metadata.is_directory => {
+            dirs.push(relative.clone());
+            pending.push(relative);
+          }
+          Ok(_) => files.push(relative),
+          Err(_) => {}
+        }
+      }
+    }
+    (files, dirs)
+  }
+
+  /// Write the asset unless its content on disk is unchanged, and return how it looks on disk.
   async fn emit_asset(
     &self,
     output_path: &Path,
     filename: &str,
     asset: &CompilationAsset,
-  ) -> Result<()> {
+  ) -> Result<Option<(String, EmittedFile)>> {
     if let Some(source) = asset.get_source() {
-      let filename = filename
-        .split_once('?')
-        .map(|(filename, _query)| filename)
-        .unwrap_or(filename);
+      let filename = strip_query(filename);
       let file_path = Path::new(&output_path).join(filename);
+      let content = source.buffer();
+      let content_hash = calc_hash(&content.as_ref());
+
+      if let Some(emitted_file) = self
+        .unchanged_on_disk(filename, &file_path, content_hash, &content)
+        .await
+      {
+        self
+          .compilation
+          .compared_for_emit_assets
+          .insert(filename.to_string());
+        return Ok(Some((filename.to_string(), emitted_file)));
+      }
+
       self
         .output_filesystem
         .create_dir_all(
@@ -379,10 +556,19 @@ where
             .unwrap_or_else(|| panic!("The parent of {} can't found", file_path.display())),
         )
         .await?;
-      self
-        .output_filesystem
-        .write(&file_path, source.buffer())
-        .await?;
+      // write to a temporary file first, so nobody sees a half-written file
+      let temp_path = temp_file_path(&file_path);
+      self.output_filesystem.write(&temp_path, &content).await?;
+      if let Err(e) = self.output_filesystem.rename(&temp_path, &file_path).await {
+        let _ = self.output_filesystem.remove_file(&temp_path).await;
+        return Err(e.into());
+      }
+      let metadata = self.output_filesystem.metadata(&file_path).await?;
+      let emitted_file = EmittedFile {
+        content_hash,
+        size: metadata.size,
+        mtime_ms: metadata.mtime_ms,
+      };
 
       self.compilation.emitted_assets.insert(filename.to_string());
 
@@ -397,8 +583,36 @@ where
         .plugin_driver
         .asset_emitted(&asset_emitted_args)
         .await?;
+      return Ok(Some((filename.to_string(), emitted_file)));
+    }
+    Ok(None)
+  }
+
+  /// A file written by this compiler is unchanged when its content hash is the same and nobody
+  /// touched it since, which only needs its metadata. Other files have to be read once.
+  async fn unchanged_on_disk(
+    &self,
+    filename: &str,
+    file_path: &Path,
+    content_hash: u64,
+    content: &[u8],
+  ) -> Option<EmittedFile> {
+    let metadata = self.output_filesystem.metadata(file_path).await.ok()?;
+    if !metadata.is_file || metadata.size != content.len() as u64 {
+      return None;
+    }
+    let on_disk = EmittedFile {
+      content_hash,
+      size: metadata.size,
+      mtime_ms: metadata.mtime_ms,
+    };
+    match self.emitted_files.get(filename) {
+      Some(emitted_file) => (emitted_file == &on_disk).then_some(on_disk),
+      None => {
+        let existing = self.output_filesystem.read(file_path).await.ok()?;
+        (calc_hash(&existing.as_slice()) == content_hash).then_some(on_disk)
+      }
     }
-    Ok(())
   }
 
   fn new_compilation_params(&self) -> CompilationParams {
@@ -416,3 +630,25 @@ where
     }
   }
 }
+
+fn strip_query(filename: &str) -> &str {
+  filename
+    .split_once('?')
+    .map(|(filename, _query)| filename)
+    .unwrap_or(filename)
+}
+
+static TEMP_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// A hidden file next to `file_path`, so it can be renamed to `file_path` atomically.
+fn temp_file_path(file_path: &Path) -> PathBuf {
+  let file_name = file_path
+    .file_name()
+    .map(|name| name.to_string_lossy())
+    .unwrap_or_default();
+  file_path.with_file_name(format!(
+    ".{file_name}.{}-{}.tmp",
+    std::process::id(),
+    TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+  ))
+}
@@ -2,6 +2,7 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result};
+use rspack_fs::AsyncReadableFileSystem;
 use rspack_sources::BoxSource;
 use rustc_hash::FxHashSet as HashSet;
 
@@ -163,7 +164,7 @@ impl WorkerTask for FactorizeTask {
             .with_diagnostics(diagnostics)
             .with_file_dependencies(create_data.file_dependencies.drain())
             .with_missing_dependencies(create_data.missing_dependencies.drain())
-            .with_context_dependencies(create_data.missing_dependencies.drain()),
+            .with_context_dependencies(create_data.context_dependencies.drain()),
         )))
       }
       Err(mut e) => {
@@ -188,7 +189,7 @@ impl WorkerTask for FactorizeTask {
             .with_diagnostics(diagnostics)
             .with_file_dependencies(create_data.file_dependencies.drain())
             .with_missing_dependencies(create_data.missing_dependencies.drain())
-            .with_context_dependencies(create_data.missing_dependencies.drain()),
+            .with_context_dependencies(create_data.context_dependencies.drain()),
         )))
       }
     }
@@ -289,6 +290,7 @@ pub struct BuildTask {
   pub compiler_options: Arc<CompilerOptions>,
   pub plugin_driver: SharedPluginDriver,
   pub cache: Arc<Cache>,
+  pub input_filesystem: Arc<dyn AsyncReadableFileSystem>,
   pub current_profile: Option<Box<ModuleProfile>>,
 }
 
@@ -313,6 +315,7 @@ impl WorkerTask for BuildTask {
     let resolver_factory = self.resolver_factory;
     let cache = self.cache;
     let plugin_driver = self.plugin_driver;
+    let input_filesystem = self.input_filesystem;
 
     let (build_result, is_cache_valid) = match cache
       .build_module_occasion
@@ -332,6 +335,7 @@ impl WorkerTask for BuildTask {
             },
             plugin_driver: plugin_driver.clone(),
             compiler_options: &compiler_options,
+            input_filesystem: input_filesystem.clone(),
           })
           .await;
 
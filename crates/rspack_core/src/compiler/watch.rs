@@ -0,0 +1,321 @@
+use std::{
+  ops::ControlFlow,
+  path::{Path, PathBuf},
+  time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use futures::future::join_all;
+use notify::{Config, Event, EventKind, PollWatcher, RecommendedWatcher, RecursiveMode, Watcher};
+use rspack_error::{error, Result};
+use rspack_fs::{AsyncFileSystem, AsyncReadableFileSystem};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+
+use crate::{Compilation, Compiler, WatchOptions};
+
+/// File systems may record modification times coarser than the clock, a change right after
+/// a build starts can look earlier than it.
+const FS_ACCURACY: Duration = Duration::from_millis(10);
+
+type Changes = (
+  std::collections::HashSet<String>,
+  std::collections::HashSet<String>,
+);
+
+impl<T> Compiler<T>
+where
+  T: AsyncFileSystem + Send + Sync,
+{
+  /// Build, then rebuild whenever the files, contexts or missing paths the compilation depends on
+  /// are changed or removed.
+  ///
+  /// `handler` is called after every build with its result, return `ControlFlow::Break` to stop
+  /// watching.
+  ///
+  /// Paths are only watched after a build finishes, like `startTime` of webpack, the ones changed
+  /// since the build started are rebuilt right away.
+  pub async fn watch<F>(&mut self, options: WatchOptions, mut handler: F) -> Result<()>
+  where
+    F: FnMut(Result<&Compilation>) -> ControlFlow<()>,
+  {
+    let (sender, mut receiver) = unbounded_channel();
+    let event_handler = move |event: notify::Result<Event>| {
+      // the receiver is only dropped after the watcher
+      let _ = sender.send(event);
+    };
+    let mut watcher: Box<dyn Watcher + Send> = match options.poll {
+      Some(interval) => Box::new(
+        PollWatcher::new(
+          event_handler,
+          Config::default().with_poll_interval(interval),
+        )
+        .map_err(|e| error!("Failed to create the file watcher: {e}"))?,
+      ),
+      None => Box::new(
+        RecommendedWatcher::new(event_handler, Config::default())
+          .map_err(|e| error!("Failed to create the file watcher: {e}"))?,
+      ),
+    };
+
+    let mut start_time = SystemTime::now();
+    let mut result = self.build().await;
+    let mut watch_set = WatchSet::default();
+    loop {
+      watch_set.update(&self.compilation, &options, watcher.as_mut());
+      let changes_during_build = watch_set
+        .changes_since(
+          start_time,
+          self.input_filesystem.as_ref(),
+          &mut receiver,
+          &options,
+        )
+        .await;
+      if handler(result.map(|_| &self.compilation)).is_break() {
+        return Ok(());
+      }
+
+      let (changed_files, removed_files) = match changes_during_build {
+        Some(changes) => changes,
+        None => {
+          let Some(changes) = watch_set
+            .wait_for_changes(&mut receiver, &options, watcher.as_mut())
+            .await
+          else {
+            return Ok(());
+          };
+          changes
+        }
+      };
+      start_time = SystemTime::now();
+      result = self.rebuild(changed_files, removed_files).await;
+    }
+  }
+}
+
+/// Paths the last compilation depends on, and the directories watched for them.
+#[derive(Debug, Default)]
+struct WatchSet {
+  files: HashSet<PathBuf>,
+  missing: HashSet<PathBuf>,
+  contexts: Vec<PathBuf>,
+  directories: HashMap<PathBuf, RecursiveMode>,
+}
+
+impl WatchSet {
+  fn update(
+    &mut self,
+    compilation: &Compilation,
+    options: &WatchOptions,
+    watcher: &mut dyn Watcher,
+  ) {
+    let not_ignored = |path: &&PathBuf| !options.is_ignored(path);
+    self.files = compilation
+      .file_dependencies
+      .iter()
+      .filter(not_ignored)
+      .cloned()
+      .collect();
+    self.missing = compilation
+      .missing_dependencies
+      .iter()
+      .filter(not_ignored)
+      .cloned()
+      .collect();
+    self.contexts = compilation
+      .context_dependencies
+      .iter()
+      .filter(not_ignored)
+      .cloned()
+      .collect();
+
+    self.watch_directories(watcher);
+  }
+
+  fn watch_directories(&mut self, watcher: &mut dyn Watcher) {
+    // Files are watched by their directories, so removing and recreating them is noticed.
+    let mut directories: HashMap<PathBuf, RecursiveMode> = HashMap::default();
+    for path in self.contexts.iter() {
+      match nearest_existing(path) {
+        Some(directory) if directory == path.as_path() => {
+          directories.insert(directory.to_path_buf(), RecursiveMode::Recursive);
+        }
+        Some(directory) => {
+          directories
+            .entry(directory.to_path_buf())
+            .or_insert(RecursiveMode::NonRecursive);
+        }
+        None => {}
+      }
+    }
+    for path in self.files.iter().chain(self.missing.iter()) {
+      if let Some(directory) = path.parent().and_then(nearest_existing) {
+        directories
+          .entry(directory.to_path_buf())
+          .or_insert(RecursiveMode::NonRecursive);
+      }
+    }
+
+    for (directory, mode) in self.directories.iter() {
+      if directories.get(directory) != Some(mode) {
+        let _ = watcher.unwatch(directory);
+      }
+    }
+    for (directory, mode) in directories.iter() {
+      if self.directories.get(directory) != Some(mode) {
+        if let Err(e) = watcher.watch(directory, *mode) {
+          tracing::debug!("Failed to watch {}: {e}", directory.display());
+        }
+      }
+    }
+    self.directories = directories;
+  }
+
+  /// Finds the watched paths modified or created since `start_time`, which were not watched yet
+  /// when they changed.
+  ///
+  /// Only the modification times of the paths themselves are compared, a context is changed
+  /// when an entry is created or removed in it. Files in a context changed during the build are
+  /// left to the watcher.
+  ///
+  /// Returns `None` when nothing changed, otherwise the pending events are taken as well, as
+  /// they are included in the same rebuild.
+  async fn changes_since(
+    &self,
+    start_time: SystemTime,
+    fs: &dyn AsyncReadableFileSystem,
+    receiver: &mut UnboundedReceiver<notify::Result<Event>>,
+    options: &WatchOptions,
+  ) -> Option<Changes> {
+    let start_time = start_time.checked_sub(FS_ACCURACY).unwrap_or(start_time);
+    let start_time_ms = start_time
+      .duration_since(UNIX_EPOCH)
+      .map_or(0, |duration| duration.as_millis() as u64);
+    let modified = join_all(
+      self
+        .files
+        .iter()
+        .chain(self.missing.iter())
+        .chain(self.contexts.iter())
+        .map(|path| async move {
+          fs.metadata(path)
+            .await
+            .is_ok_and(|metadata| metadata.mtime_ms >= start_time_ms)
+            .then(|| path.clone())
+        }),
+    )
+    .await;
+    let mut paths: HashSet<PathBuf> = modified.into_iter().flatten().collect();
+    if paths.is_empty() {
+      return None;
+    }
+
+    while let Ok(event) = receiver.try_recv() {
+      self.add_event_paths(event, options, &mut paths);
+    }
+    Some(into_changes(paths))
+  }
+
+  /// Wait for changes of the watched paths, until no more change comes in `aggregate_timeout`.
+  ///
+  /// Returns the changed and removed paths, or `None` when the watcher is gone.
+  async fn wait_for_changes(
+    &mut self,
+    receiver: &mut UnboundedReceiver<notify::Result<Event>>,
+    options: &WatchOptions,
+    watcher: &mut dyn Watcher,
+  ) -> Option<Changes> {
+    let mut paths: HashSet<PathBuf> = HashSet::default();
+    let mut has_created_directory = false;
+    loop {
+      let event = if paths.is_empty() && !has_created_directory {
+        receiver.recv().await?
+      } else {
+        match tokio::time::timeout(options.aggregate_timeout, receiver.recv()).await {
+          Ok(event) => event?,
+          Err(_) => {
+            if has_created_directory {
+              // The directories of missing paths may be created with the missing paths together,
+              // events of the paths inside them are not received as they are not watched yet.
+              self.watch_directories(watcher);
+              paths.extend(self.missing.iter().filter(|path| path.exists()).cloned());
+            }
+            if paths.is_empty() {
+              has_created_directory = false;
+              continue;
+            }
+            break;
+          }
+        }
+      };
+      has_created_directory |= self.add_event_paths(event, options, &mut paths);
+    }
+
+    Some(into_changes(paths))
+  }
+
+  /// Collects the watched paths of `event` into `paths`.
+  ///
+  /// Returns whether a directory of the missing paths is created.
+  fn add_event_paths(
+    &self,
+    event: notify::Result<Event>,
+    options: &WatchOptions,
+    paths: &mut HashSet<PathBuf>,
+  ) -> bool {
+    let event = match event {
+      Ok(event) => event,
+      Err(e) => {
+        tracing::debug!("Failed to receive file system events: {e}");
+        return false;
+      }
+    };
+    if matches!(event.kind, EventKind::Access(_)) {
+      return false;
+    }
+    let mut has_created_directory = false;
+    for path in event.paths {
+      if options.is_ignored(&path) {
+        continue;
+      }
+      if self.is_watched(&path) {
+        paths.insert(path);
+      } else if self
+        .missing
+        .iter()
+        .any(|missing| missing.starts_with(&path))
+      {
+        has_created_directory = true;
+      }
+    }
+    has_created_directory
+  }
+
+  fn is_watched(&self, path: &Path) -> bool {
+    self.files.contains(path)
+      || self.missing.contains(path)
+      || self
+        .contexts
+        .iter()
+        .any(|context| path.starts_with(context))
+  }
+}
+
+fn nearest_existing(path: &Path) -> Option<&Path> {
+  path.ancestors().find(|path| path.is_dir())
+}
+
+/// Splits `paths` into the changed and the removed ones, in the form `Compiler::rebuild` takes.
+fn into_changes(paths: HashSet<PathBuf>) -> Changes {
+  let mut changed_files = std::collections::HashSet::new();
+  let mut removed_files = std::collections::HashSet::new();
+  for path in paths {
+    let exists = path.exists();
+    let path = path.to_string_lossy().into_owned();
+    if exists {
+      changed_files.insert(path);
+    } else {
+      removed_files.insert(path);
+    }
+  }
+  (changed_files, removed_files)
+}
@@ -0,0 +1,838 @@
+use std::{borrow::Cow, hash::Hash, sync::Arc};
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rspack_error::{error, impl_empty_diagnosable_trait, Result};
+use rspack_identifier::{Identifiable, IdentifierMap};
+use rspack_sources::{BoxSource, ConcatSource, RawSource, ReplaceSource, Source, SourceExt};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use swc_core::{
+  common::{sync::Lrc, FileName, Globals, Mark, SourceMap, Span, SyntaxContext, GLOBALS},
+  ecma::{
+    ast::{
+      BreakStmt, ContinueStmt, EsVersion, Ident, LabeledStmt, MemberProp, ObjectPatProp,
+      PrivateName, Prop, PropName, SuperProp,
+    },
+    atoms::JsWord,
+    parser::{parse_file_as_module, EsConfig, Syntax},
+    transforms::base::resolver,
+    visit::{noop_visit_type, Visit, VisitMutWith, VisitWith},
+  },
+};
+
+use crate::{
+  dependencies_block::AsyncDependenciesBlockIdentifier, property_access, property_name,
+  render_init_fragments, to_identifier, CodeGenerationResult, Compilation, ConcatenationScope,
+  Context, DependenciesBlock, DependencyId, GenerateContext, HarmonyExportInitFragment,
+  InitFragmentKey, InitFragmentStage, Module, ModuleIdentifier, ModuleInitFragments, ModuleType,
+  NormalInitFragment, RuntimeGlobals, RuntimeSpec, SourceType, UsedName, DEFAULT_EXPORT,
+  NAMESPACE_OBJECT_EXPORT,
+};
+
+static RESERVED_NAMES: Lazy<HashSet<&'static str>> = Lazy::new(|| {
+  [
+    // internal names (should always be renamed)
+    DEFAULT_EXPORT,
+    NAMESPACE_OBJECT_EXPORT,
+    // keywords
+    "abstract,arguments,async,await,boolean,break,byte,case,catch,char,class,const,continue",
+    "debugger,default,delete,do,double,else,enum,eval,export,extends,false,final,finally,float",
+    "for,function,goto,if,implements,import,in,instanceof,int,interface,let,long,native,new,null",
+    "package,private,protected,public,return,short,static,super,switch,synchronized,this,throw",
+    "throws,transient,true,try,typeof,var,void,volatile,while,with,yield",
+    // commonjs/amd
+    "module,__dirname,__filename,exports,require,define",
+    // js globals
+    "Array,Date,eval,function,hasOwnProperty,Infinity,isFinite,isNaN,isPrototypeOf,length,Math",
+    "NaN,name,Number,Object,prototype,String,toString,undefined,valueOf",
+    // browser globals
+    "alert,all,anchor,anchors,area,assign,blur,button,checkbox,clearInterval,clearTimeout",
+    "clientInformation,close,closed,confirm,constructor,crypto,decodeURI,decodeURIComponent",
+    "defaultStatus,document,element,elements,embed,embeds,encodeURI,encodeURIComponent,escape",
+    "event,fileUpload,focus,frame,innerHeight,innerWidth,layer,layers,link,location,mimeTypes",
+    "navigate,navigator,frames,frameRate,hidden,history,image,images,offscreenBuffering,open",
+    "opener,option,outerHeight,outerWidth,packages,pageXOffset,pageYOffset,parent,parseFloat",
+    "parseInt,password,pkcs11,plugin,prompt,propertyIsEnum,radio,reset,screenX,screenY,scroll",
+    "secure,select,self,setInterval,setTimeout,status,submit,taint,text,textarea,top,unescape",
+    "untaint,window",
+    // window events
+    "onblur,onclick,onerror,onfocus,onkeydown,onkeypress,onkeyup,onmouseover,onload,onmouseup,onmousedown,onsubmit",
+  ]
+  .into_iter()
+  .flat_map(|names| names.split(','))
+  .collect()
+});
+
+static EXTRA_INFO_REGEXP: Lazy<Regex> = Lazy::new(|| {
+  Regex::new(r"\.+/|(/index)?\.([a-zA-Z0-9]{1,4})($|\s|\?)|\s*\+\s*\d+\s*modules")
+    .expect("should init regex")
+});
+
+static CONCATENATED_MODULE_SOURCE_TYPES: &[SourceType] = &[SourceType::JavaScript];
+
+/// The target of a reexport, resolved when the concatenation is created.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ConcatenatedReexport {
+  pub module: ModuleIdentifier,
+  /// `None` when the namespace of `module` is reexported.
+  pub export: Option<Vec<JsWord>>,
+}
+
+/// ECMAScript modules rendered into one scope, i.e. scope hoisting. The root module is the only
+/// one which keeps its module wrapper, the others are only referenced by the root or by each other.
+///
+/// The modules stay in the module graph, the concatenation only changes how the root module is
+/// rendered, see `Compilation::concatenated_modules`.
+///
+/// https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/optimize/ConcatenatedModule.js
+#[derive(Debug)]
+pub struct ConcatenatedModule {
+  identifier: ModuleIdentifier,
+  root_module: ModuleIdentifier,
+  /// In the order of evaluation, the root module is the last one.
+  modules: Vec<ModuleIdentifier>,
+  reexports: IdentifierMap<HashMap<JsWord, ConcatenatedReexport>>,
+  module_type: ModuleType,
+  readable_identifier: String,
+  size: f64,
+  blocks: Vec<AsyncDependenciesBlockIdentifier>,
+  dependencies: Vec<DependencyId>,
+}
+
+impl ConcatenatedModule {
+  pub fn new(
+    root_module: ModuleIdentifier,
+    modules: Vec<ModuleIdentifier>,
+    reexports: IdentifierMap<HashMap<JsWord, ConcatenatedReexport>>,
+    compilation: &Compilation,
+  ) -> Self {
+    let root = compilation
+      .module_graph
+      .module_by_identifier(&root_module)
+      .expect("should have root module");
+    let size = modules
+      .iter()
+      .filter_map(|m| compilation.module_graph.module_by_identifier(m))
+      .map(|m| m.size(&SourceType::JavaScript))
+      .sum();
+    Self {
+      identifier: format!("{root_module}|concatenated").into(),
+      readable_identifier: format!(
+        "{} + {} modules",
+        root.readable_identifier(&compilation.options.context),
+        modules.len() - 1
+      ),
+      module_type: *root.module_type(),
+      root_module,
+      modules,
+      reexports,
+      size,
+      blocks: Default::default(),
+      dependencies: Default::default(),
+    }
+  }
+
+  pub fn root_module(&self) -> ModuleIdentifier {
+    self.root_module
+  }
+
+  pub fn modules(&self) -> &[ModuleIdentifier] {
+    &self.modules
+  }
+}
+
+impl Identifiable for ConcatenatedModule {
+  fn identifier(&self) -> ModuleIdentifier {
+    self.identifier
+  }
+}
+
+impl DependenciesBlock for ConcatenatedModule {
+  fn add_block_id(&mut self, block: AsyncDependenciesBlockIdentifier) {
+    self.blocks.push(block)
+  }
+
+  fn get_blocks(&self) -> &[AsyncDependenciesBlockIdentifier] {
+    &self.blocks
+  }
+
+  fn add_dependency_id(&mut self, dependency: DependencyId) {
+    self.dependencies.push(dependency)
+  }
+
+  fn get_dependencies(&self) -> &[DependencyId] {
+    &self.dependencies
+  }
+}
+
+#[async_trait::async_trait]
+impl Module for ConcatenatedModule {
+  fn module_type(&self) -> &ModuleType {
+    &self.module_type
+  }
+
+  fn source_types(&self) -> &[SourceType] {
+    CONCATENATED_MODULE_SOURCE_TYPES
+  }
+
+  fn original_source(&self) -> Option<&dyn Source> {
+    None
+  }
+
+  fn readable_identifier(&self, _context: &Context) -> Cow<str> {
+    Cow::Borrowed(&self.readable_identifier)
+  }
+
+  fn size(&self, _source_type: &SourceType) -> f64 {
+    self.size
+  }
+
+  fn code_generation(
+    &self,
+    compilation: &Compilation,
+    runtime: Option<&RuntimeSpec>,
+  ) -> Result<CodeGenerationResult> {
+    let mut code_generation_result = CodeGenerationResult::default();
+    let module_indices: Arc<IdentifierMap<usize>> = Arc::new(
+      self
+        .modules
+        .iter()
+        .enumerate()
+        .map(|(index, module)| (*module, index))
+        .collect(),
+    );
+
+    let mut infos = Vec::with_capacity(self.modules.len());
+    for module_identifier in &self.modules {
+      let module = compilation
+        .module_graph
+        .module_by_identifier(module_identifier)
+        .and_then(|module| module.as_normal_module())
+        .ok_or_else(|| {
+          error!("Concatenated module {module_identifier} should be a NormalModule")
+        })?;
+      let mut scope = ConcatenationScope::new(module_indices.clone(), *module_identifier);
+      let mut result =
+        module.code_generation_in_concatenation_scope(compilation, runtime, &mut scope)?;
+      code_generation_result
+        .runtime_requirements
+        .insert(result.runtime_requirements);
+      code_generation_result
+        .chunk_init_fragments
+        .extend(std::mem::take(&mut result.chunk_init_fragments));
+      let source = result
+        .get(&SourceType::JavaScript)
+        .cloned()
+        .ok_or_else(|| error!("Concatenated module {module_identifier} should have javascript"))?;
+      let readable_identifier = module
+        .readable_identifier(&compilation.options.context)
+        .into_owned();
+      let analysis = ScopeAnalysis::analyze(&source.source(), &readable_identifier)?;
+      infos.push(ConcatenatedModuleInfo {
+        identifier: *module_identifier,
+        readable_identifier,
+        source,
+        exports_map: scope.into_exports_map(),
+        analysis,
+        internal_names: Default::default(),
+        namespace_object_name: None,
+      });
+    }
+
+    let root_mgm = compilation
+      .module_graph
+      .module_graph_module_by_identifier(&self.root_module)
+      .expect("should have root module graph module");
+    let exports_argument = root_mgm.get_exports_argument();
+
+    // The names which can't be used by the top level bindings, the globals can't be shadowed.
+    let mut used_names: HashSet<JsWord> = RESERVED_NAMES
+      .iter()
+      .map(|name| JsWord::from(*name))
+      .collect();
+    used_names.insert(exports_argument.to_string().into());
+    used_names.insert(RuntimeGlobals::REQUIRE.to_string().into());
+    for info in &infos {
+      used_names.extend(info.analysis.unresolved.iter().cloned());
+    }
+    // Every name used in a module, keeping a name of another module could shadow it.
+    let all_names: HashSet<JsWord> = infos
+      .iter()
+      .flat_map(|info| info.analysis.names.iter().cloned())
+      .collect();
+
+    for index in 0..infos.len() {
+      let mut top_level_names = infos[index]
+        .analysis
+        .top_level
+        .keys()
+        .cloned()
+        .collect::<Vec<_>>();
+      top_level_names.sort_unstable();
+      for name in top_level_names {
+        let conflicts = used_names.contains(&name)
+          || infos
+            .iter()
+            .enumerate()
+            .any(|(other, info)| other != index && info.analysis.names.contains(&name));
+        let new_name = if conflicts {
+          find_new_name(&name, &infos[index].readable_identifier, |n| {
+            used_names.contains(n) || all_names.contains(n)
+          })
+        } else {
+          name.clone()
+        };
+        used_names.insert(new_name.clone());
+        infos[index].internal_names.insert(name, new_name);
+      }
+    }
+
+    let resolver = BindingResolver {
+      infos: &infos,
+      module_indices: &module_indices,
+      reexports: &self.reexports,
+    };
+
+    // The namespace objects can reference other namespace objects, e.g. `export * as ns from`.
+    let mut namespace_objects = Vec::new();
+    let mut needs_namespace_object = infos
+      .iter()
+      .flat_map(|info| &info.analysis.references)
+      .filter_map(|(_, reference)| resolver.resolve_namespace_requirement(reference))
+      .collect::<Vec<_>>();
+    let root_exports = compilation.module_graph.get_exports_info(&self.root_module);
+    let mut root_exports_map = vec![];
+    for export_info_id in root_exports.get_ordered_exports() {
+      let export_info = export_info_id.get_export_info(&compilation.module_graph);
+      let Some(name) = export_info.name.clone() else {
+        continue;
+      };
+      if matches!(export_info.provided, Some(crate::ExportInfoProvided::False)) {
+        continue;
+      }
+      let used_name = if compilation.options.is_new_tree_shaking() {
+        match root_exports.id.get_used_name(
+          &compilation.module_graph,
+          runtime,
+          UsedName::Str(name.clone()),
+        ) {
+          Some(UsedName::Str(used_name)) => used_name,
+          _ => continue,
+        }
+      } else {
+        name.clone()
+      };
+      let binding = resolver.resolve(infos.len() - 1, &[name]);
+      if let Binding::Namespace(index, _) = &binding {
+        needs_namespace_object.push(*index);
+      }
+      root_exports_map.push((used_name, binding));
+    }
+    let mut visited_namespace_objects = HashSet::default();
+    while let Some(index) = needs_namespace_object.pop() {
+      if !visited_namespace_objects.insert(index) {
+        continue;
+      }
+      let exports_info = compilation
+        .module_graph
+        .get_exports_info(&infos[index].identifier);
+      let mut exports = vec![];
+      for export_info_id in exports_info.get_ordered_exports() {
+        let export_info = export_info_id.get_export_info(&compilation.module_graph);
+        let Some(name) = export_info.name.clone() else {
+          continue;
+        };
+        if matches!(export_info.provided, Some(crate::ExportInfoProvided::False)) {
+          continue;
+        }
+        let used_name = match exports_info.id.get_used_name(
+          &compilation.module_graph,
+          runtime,
+          UsedName::Str(name.clone()),
+        ) {
+          Some(UsedName::Str(used_name)) => used_name,
+          _ => continue,
+        };
+        let binding = resolver.resolve(index, &[name]);
+        if let Binding::Namespace(index, _) = &binding {
+          needs_namespace_object.push(*index);
+        }
+        exports.push((used_name, binding));
+      }
+      namespace_objects.push((index, exports));
+    }
+    namespace_objects.sort_unstable_by_key(|(index, _)| *index);
+    let mut namespace_object_names = HashMap::default();
+    for (index, _) in &namespace_objects {
+      let name = find_new_name(
+        NAMESPACE_OBJECT_EXPORT,
+        &infos[*index].readable_identifier,
+        |n| used_names.contains(n) || all_names.contains(n),
+      );
+      used_names.insert(name.clone());
+      namespace_object_names.insert(*index, name);
+    }
+    for (index, name) in &namespace_object_names {
+      infos[*index].namespace_object_name = Some(name.clone());
+    }
+    let resolver = BindingResolver {
+      infos: &infos,
+      module_indices: &module_indices,
+      reexports: &self.reexports,
+    };
+
+    let mut source = ConcatSource::default();
+    for (index, exports) in namespace_objects {
+      let name = resolver.infos[index]
+        .namespace_object_name
+        .as_ref()
+        .expect("should have namespace object name");
+      code_generation_result
+        .runtime_requirements
+        .insert(RuntimeGlobals::MAKE_NAMESPACE_OBJECT);
+      let mut definitions = exports
+        .into_iter()
+        .map(|(used_name, binding)| {
+          Ok(format!(
+            "{}: function() {{ return {}; }}",
+            property_name(&used_name)?,
+            resolver.render(&binding, false)
+          ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+      definitions.sort_unstable();
+      let mut content = format!(
+        "\n// NAMESPACE OBJECT: {}\nvar {name} = {{}};\n{}({name});\n",
+        resolver.infos[index].readable_identifier,
+        RuntimeGlobals::MAKE_NAMESPACE_OBJECT,
+      );
+      if !definitions.is_empty() {
+        code_generation_result
+          .runtime_requirements
+          .insert(RuntimeGlobals::DEFINE_PROPERTY_GETTERS);
+        content.push_str(&format!(
+          "{}({name}, {{\n  {}\n}});\n",
+          RuntimeGlobals::DEFINE_PROPERTY_GETTERS,
+          definitions.join(",\n  ")
+        ));
+      }
+      source.add(RawSource::from(content));
+    }
+
+    for info in resolver.infos {
+      source.add(RawSource::from(format!(
+        "\n;// CONCATENATED MODULE: {}\n",
+        info.readable_identifier
+      )));
+      let mut module_source = ReplaceSource::new(info.source.clone());
+      for (name, occurrences) in &info.analysis.top_level {
+        let new_name = &info.internal_names[name];
+        if new_name == name {
+          continue;
+        }
+        for occurrence in occurrences {
+          let content = if occurrence.shorthand {
+            format!("{name}: {new_name}")
+          } else {
+            new_name.to_string()
+          };
+          module_source.replace(occurrence.start, occurrence.end, &content, None);
+        }
+      }
+      for (occurrence, reference) in &info.analysis.references {
+        let binding = resolver.resolve(reference.index, &reference.ids);
+        module_source.replace(
+          occurrence.start,
+          occurrence.end,
+          &resolver.render(&binding, reference.call),
+          None,
+        );
+      }
+      source.add(module_source);
+    }
+
+    let mut init_fragments: ModuleInitFragments = vec![Box::new(NormalInitFragment::new(
+      format!(
+        "{}({});\n",
+        RuntimeGlobals::MAKE_NAMESPACE_OBJECT,
+        exports_argument
+      ),
+      InitFragmentStage::StageHarmonyExports,
+      0,
+      InitFragmentKey::HarmonyCompatibility,
+      None,
+    ))];
+    code_generation_result
+      .runtime_requirements
+      .insert(RuntimeGlobals::MAKE_NAMESPACE_OBJECT | RuntimeGlobals::EXPORTS);
+    if !root_exports_map.is_empty() {
+      init_fragments.push(Box::new(HarmonyExportInitFragment::new(
+        exports_argument,
+        root_exports_map
+          .into_iter()
+          .map(|(used_name, binding)| (used_name, resolver.render(&binding, false).into()))
+          .collect(),
+      )));
+    }
+
+    let mut data = Default::default();
+    let source = render_init_fragments(
+      source.boxed(),
+      init_fragments,
+      &mut GenerateContext {
+        compilation,
+        module_generator_options: None,
+        runtime_requirements: &mut code_generation_result.runtime_requirements,
+        data: &mut data,
+        requested_source_type: SourceType::JavaScript,
+        runtime,
+        concatenation_scope: None,
+      },
+    )?;
+    code_generation_result.data = data;
+    code_generation_result.add(SourceType::JavaScript, source);
+    code_generation_result.set_hash(
+      &compilation.options.output.hash_function,
+      &compilation.options.output.hash_digest,
+      &compilation.options.output.hash_salt,
+    );
+    Ok(code_generation_result)
+  }
+}
+
+impl_empty_diagnosable_trait!(ConcatenatedModule);
+
+impl Hash for ConcatenatedModule {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    "__rspack_internal__ConcatenatedModule".hash(state);
+    self.identifier.hash(state);
+    self.modules.hash(state);
+  }
+}
+
+impl PartialEq for ConcatenatedModule {
+  fn eq(&self, other: &Self) -> bool {
+    self.identifier == other.identifier
+  }
+}
+
+impl Eq for ConcatenatedModule {}
+
+#[derive(Debug)]
+struct ConcatenatedModuleInfo {
+  identifier: ModuleIdentifier,
+  readable_identifier: String,
+  source: BoxSource,
+  exports_map: HashMap<JsWord, JsWord>,
+  analysis: ScopeAnalysis,
+  /// The final names of the top level bindings.
+  internal_names: HashMap<JsWord, JsWord>,
+  namespace_object_name: Option<JsWord>,
+}
+
+#[derive(Debug)]
+enum Binding {
+  /// A top level binding of a module, with the properties accessed on it.
+  Symbol(JsWord, Vec<JsWord>),
+  /// The namespace object of a module, with the properties accessed on it.
+  Namespace(usize, Vec<JsWord>),
+  Missing,
+}
+
+struct BindingResolver<'a> {
+  infos: &'a [ConcatenatedModuleInfo],
+  module_indices: &'a IdentifierMap<usize>,
+  reexports: &'a IdentifierMap<HashMap<JsWord, ConcatenatedReexport>>,
+}
+
+impl<'a> BindingResolver<'a> {
+  fn resolve(&self, index: usize, ids: &[JsWord]) -> Binding {
+    let mut visited = HashSet::default();
+    self.resolve_inner(index, ids.to_vec(), &mut visited)
+  }
+
+  fn resolve_inner(
+    &self,
+    index: usize,
+    ids: Vec<JsWord>,
+    visited: &mut HashSet<(usize, JsWord)>,
+  ) -> Binding {
+    let Some(name) = ids.first() else {
+      return Binding::Namespace(index, vec![]);
+    };
+    let info = &self.infos[index];
+    if let Some(local) = info.exports_map.get(name) {
+      let local = info.internal_names.get(local).unwrap_or(local);
+      return Binding::Symbol(local.clone(), ids[1..].to_vec());
+    }
+    if let Some(target) = self
+      .reexports
+      .get(&info.identifier)
+      .and_then(|reexports| reexports.get(name))
+      && let Some(target_index) = self.module_indices.get(&target.module)
+      && visited.insert((index, name.clone()))
+    {
+      let ids = match &target.export {
+        Some(export) => export.iter().chain(&ids[1..]).cloned().collect(),
+        None => ids[1..].to_vec(),
+      };
+      return match self.resolve_inner(*target_index, ids, visited) {
+        Binding::Namespace(index, properties) if properties.is_empty() => {
+          Binding::Namespace(index, properties)
+        }
+        binding => binding,
+      };
+    }
+    Binding::Missing
+  }
+
+  /// The module whose namespace object is needed by the reference.
+  fn resolve_namespace_requirement(&self, reference: &crate::ModuleReference) -> Option<usize> {
+    match self.resolve(reference.index, &reference.ids) {
+      Binding::Namespace(index, _) => Some(index),
+      _ => None,
+    }
+  }
+
+  fn render(&self, binding: &Binding, call: bool) -> String {
+    let (name, properties) = match binding {
+      Binding::Symbol(name, properties) => (name.as_ref(), properties),
+      Binding::Namespace(index, properties) => (
+        self.infos[*index]
+          .namespace_object_name
+          .as_deref()
+          .unwrap_or(NAMESPACE_OBJECT_EXPORT),
+        properties,
+      ),
+      Binding::Missing => return "/* unused export */ undefined".to_string(),
+    };
+    if properties.is_empty() {
+      return name.to_string();
+    }
+    let expr = format!("{name}{}", property_access(properties, 0));
+    if call {
+      format!("(0, {expr})")
+    } else {
+      expr
+    }
+  }
+}
+
+/// Finds a name which isn't used, prefixed by the path of the module.
+///
+/// https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/optimize/ConcatenatedModule.js#L1844-L1878
+fn find_new_name(old_name: &str, extra_info: &str, is_used: impl Fn(&JsWord) -> bool) -> JsWord {
+  let mut name = match old_name {
+    DEFAULT_EXPORT => String::new(),
+    NAMESPACE_OBJECT_EXPORT => "namespaceObject".to_string(),
+    _ => old_name.to_string(),
+  };
+  let extra_info = EXTRA_INFO_REGEXP.replace_all(extra_info, "");
+  for part in extra_info.split('/').rev() {
+    name = if name.is_empty() {
+      part.to_string()
+    } else {
+      format!("{part}_{name}")
+    };
+    let identifier = JsWord::from(to_identifier(&name));
+    if !is_used(&identifier) {
+      return identifier;
+    }
+  }
+  let mut i = 0;
+  loop {
+    let identifier = JsWord::from(to_identifier(&format!("{name}_{i}")));
+    if !is_used(&identifier) {
+      return identifier;
+    }
+    i += 1;
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Occurrence {
+  start: u32,
+  end: u32,
+  /// `{ a }` in an object literal or pattern, which should be renamed as `{ a: b }`.
+  shorthand: bool,
+}
+
+#[derive(Debug, Default)]
+struct ScopeAnalysis {
+  /// The occurrences of the top level bindings.
+  top_level: HashMap<JsWord, Vec<Occurrence>>,
+  /// The references to the other modules of the concatenation.
+  references: Vec<(Occurrence, crate::ModuleReference)>,
+  /// The globals referenced by the module.
+  unresolved: HashSet<JsWord>,
+  /// Every name used by the module, in any scope.
+  names: HashSet<JsWord>,
+}
+
+impl ScopeAnalysis {
+  fn analyze(code: &str, readable_identifier: &str) -> Result<Self> {
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(
+      FileName::Custom(readable_identifier.to_string()),
+      code.to_string(),
+    );
+    let mut errors = vec![];
+    let mut module = parse_file_as_module(
+      &fm,
+      Syntax::Es(EsConfig::default()),
+      EsVersion::EsNext,
+      None,
+      &mut errors,
+    )
+    .map_err(|e| {
+      error!(
+        "Failed to parse the generated code of {readable_identifier} for module concatenation: {}",
+        e.kind().msg()
+      )
+    })?;
+    GLOBALS.set(&Globals::default(), || {
+      let unresolved_mark = Mark::new();
+      let top_level_mark = Mark::new();
+      module.visit_mut_with(&mut resolver(unresolved_mark, top_level_mark, false));
+      let mut collector = IdentCollector {
+        analysis: Default::default(),
+        unresolved_ctxt: SyntaxContext::empty().apply_mark(unresolved_mark),
+        top_level_ctxt: SyntaxContext::empty().apply_mark(top_level_mark),
+        start_pos: fm.start_pos.0,
+      };
+      module.visit_with(&mut collector);
+      Ok(collector.analysis)
+    })
+  }
+}
+
+struct IdentCollector {
+  analysis: ScopeAnalysis,
+  unresolved_ctxt: SyntaxContext,
+  top_level_ctxt: SyntaxContext,
+  start_pos: u32,
+}
+
+impl IdentCollector {
+  fn add(&mut self, ident: &Ident, shorthand: bool) {
+    let occurrence = Occurrence {
+      start: self.occurrence_pos(ident.span),
+      end: self.occurrence_pos(ident.span) + (ident.span.hi.0 - ident.span.lo.0),
+      shorthand,
+    };
+    if ident.span.ctxt == self.unresolved_ctxt {
+      if let Some(reference) = ConcatenationScope::match_module_reference(&ident.sym) {
+        self.analysis.references.push((occurrence, reference));
+        return;
+      }
+      self.analysis.unresolved.insert(ident.sym.clone());
+    } else if ident.span.ctxt == self.top_level_ctxt {
+      self
+        .analysis
+        .top_level
+        .entry(ident.sym.clone())
+        .or_default()
+        .push(occurrence);
+    }
+    self.analysis.names.insert(ident.sym.clone());
+  }
+
+  fn occurrence_pos(&self, span: Span) -> u32 {
+    span.lo.0 - self.start_pos
+  }
+}
+
+impl Visit for IdentCollector {
+  noop_visit_type!();
+
+  fn visit_ident(&mut self, ident: &Ident) {
+    self.add(ident, false);
+  }
+
+  fn visit_prop(&mut self, prop: &Prop) {
+    if let Prop::Shorthand(ident) = prop {
+      self.add(ident, true);
+    } else {
+      prop.visit_children_with(self);
+    }
+  }
+
+  fn visit_object_pat_prop(&mut self, prop: &ObjectPatProp) {
+    if let ObjectPatProp::Assign(assign) = prop {
+      self.add(&assign.key, true);
+      assign.value.visit_with(self);
+    } else {
+      prop.visit_children_with(self);
+    }
+  }
+
+  fn visit_prop_name(&mut self, name: &PropName) {
+    if let PropName::Computed(computed) = name {
+      computed.visit_with(self);
+    }
+  }
+
+  fn visit_member_prop(&mut self, prop: &MemberProp) {
+    if let MemberProp::Computed(computed) = prop {
+      computed.visit_with(self);
+    }
+  }
+
+  fn visit_super_prop(&mut self, prop: &SuperProp) {
+    if let SuperProp::Computed(computed) = prop {
+      computed.visit_with(self);
+    }
+  }
+
+  fn visit_private_name(&mut self, _name: &PrivateName) {}
+
+  fn visit_labeled_stmt(&mut self, stmt: &LabeledStmt) {
+    stmt.body.visit_with(self);
+  }
+
+  fn visit_break_stmt(&mut self, _stmt: &BreakStmt) {}
+
+  fn visit_continue_stmt(&mut self, _stmt: &ContinueStmt) {}
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn should_find_new_name_from_module_path() {
+    let used = HashSet::from_iter(["foo_x"]);
+    let is_used = |name: &JsWord| used.contains(&**name);
+    assert_eq!(find_new_name("x", "./src/bar.js", is_used), "bar_x");
+    assert_eq!(find_new_name("x", "./src/foo.js", is_used), "src_foo_x");
+    assert_eq!(
+      find_new_name(DEFAULT_EXPORT, "./src/foo/index.js", is_used),
+      "foo"
+    );
+    assert_eq!(
+      find_new_name(NAMESPACE_OBJECT_EXPORT, "./lib.js", is_used),
+      "lib_namespaceObject"
+    );
+  }
+
+  #[test]
+  fn should_collect_top_level_bindings_and_references() {
+    let code = "var a = 1;\nfunction f(b) { return { a, b, c: __WEBPACK_MODULE_REFERENCE__0_ns__ }; }\nobj.a; label: for (;;) break label;";
+    let analysis = ScopeAnalysis::analyze(code, "./a.js").expect("should analyze");
+    let mut top_level = analysis
+      .top_level
+      .keys()
+      .map(|n| n.to_string())
+      .collect::<Vec<_>>();
+    top_level.sort();
+    assert_eq!(top_level, vec!["a", "f"]);
+    let a = &analysis.top_level[&JsWord::from("a")];
+    assert_eq!(a.len(), 2);
+    assert!(!a[0].shorthand && a[1].shorthand);
+    assert_eq!(&code[a[1].start as usize..a[1].end as usize], "a");
+    assert_eq!(analysis.references.len(), 1);
+    assert!(analysis.unresolved.contains(&JsWord::from("obj")));
+    assert!(analysis.names.contains(&JsWord::from("b")));
+    assert!(!analysis.names.contains(&JsWord::from("label")));
+  }
+}
@@ -0,0 +1,158 @@
+use std::{fmt::Write, sync::Arc};
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rspack_identifier::IdentifierMap;
+use rustc_hash::FxHashMap as HashMap;
+use swc_core::ecma::atoms::JsWord;
+
+use crate::ModuleIdentifier;
+
+pub const DEFAULT_EXPORT: &str = "__WEBPACK_DEFAULT_EXPORT__";
+pub const NAMESPACE_OBJECT_EXPORT: &str = "__WEBPACK_NAMESPACE_OBJECT__";
+
+const MODULE_REFERENCE_PREFIX: &str = "__WEBPACK_MODULE_REFERENCE__";
+
+static MODULE_REFERENCE_REGEXP: Lazy<Regex> = Lazy::new(|| {
+  Regex::new(r"^__WEBPACK_MODULE_REFERENCE__(\d+)_([\da-f]+|ns)(_call)?__$")
+    .expect("should init regex")
+});
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleReference {
+  /// Index of the referenced module in the concatenation.
+  pub index: usize,
+  pub ids: Vec<JsWord>,
+  pub call: bool,
+}
+
+/// Collects the exports of a module which is rendered into a [crate::ConcatenatedModule], and
+/// renders the references to the other modules of the concatenation as placeholders, which are
+/// replaced by the final names of the bindings once all the modules are rendered.
+///
+/// https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/ConcatenationScope.js
+#[derive(Debug)]
+pub struct ConcatenationScope {
+  modules: Arc<IdentifierMap<usize>>,
+  current_module: ModuleIdentifier,
+  exports_map: HashMap<JsWord, JsWord>,
+}
+
+impl ConcatenationScope {
+  pub fn new(modules: Arc<IdentifierMap<usize>>, current_module: ModuleIdentifier) -> Self {
+    Self {
+      modules,
+      current_module,
+      exports_map: Default::default(),
+    }
+  }
+
+  pub fn current_module(&self) -> ModuleIdentifier {
+    self.current_module
+  }
+
+  pub fn is_module_in_scope(&self, module: &ModuleIdentifier) -> bool {
+    self.modules.contains_key(module)
+  }
+
+  /// Registers the local `symbol` exported as `export_name`, the first registration wins.
+  pub fn register_export(&mut self, export_name: JsWord, symbol: JsWord) {
+    self.exports_map.entry(export_name).or_insert(symbol);
+  }
+
+  pub fn exports_map(&self) -> &HashMap<JsWord, JsWord> {
+    &self.exports_map
+  }
+
+  pub fn into_exports_map(self) -> HashMap<JsWord, JsWord> {
+    self.exports_map
+  }
+
+  pub fn create_module_reference(
+    &self,
+    module: &ModuleIdentifier,
+    ids: &[JsWord],
+    call: bool,
+  ) -> String {
+    let index = self
+      .modules
+      .get(module)
+      .expect("should only reference modules in scope");
+    let export_data = if ids.is_empty() {
+      "ns".to_string()
+    } else {
+      serde_json::to_string(ids)
+        .expect("should serialize ids")
+        .bytes()
+        .fold(String::new(), |mut hex, b| {
+          let _ = write!(hex, "{b:02x}");
+          hex
+        })
+    };
+    let call_flag = if call { "_call" } else { "" };
+    format!("{MODULE_REFERENCE_PREFIX}{index}_{export_data}{call_flag}__")
+  }
+
+  pub fn is_module_reference(name: &str) -> bool {
+    name.starts_with(MODULE_REFERENCE_PREFIX)
+  }
+
+  pub fn match_module_reference(name: &str) -> Option<ModuleReference> {
+    let captures = MODULE_REFERENCE_REGEXP.captures(name)?;
+    let index = captures[1].parse().ok()?;
+    let ids = if &captures[2] == "ns" {
+      vec![]
+    } else {
+      let bytes = (0..captures[2].len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&captures[2][i..i + 2], 16))
+        .collect::<Result<Vec<_>, _>>()
+        .ok()?;
+      serde_json::from_slice::<Vec<String>>(&bytes)
+        .ok()?
+        .into_iter()
+        .map(JsWord::from)
+        .collect()
+    };
+    Some(ModuleReference {
+      index,
+      ids,
+      call: captures.get(3).is_some(),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn module_reference_roundtrip() {
+    let root = ModuleIdentifier::from("./index.js");
+    let lib = ModuleIdentifier::from("./lib.js");
+    let scope = ConcatenationScope::new(
+      Arc::new(IdentifierMap::from_iter([(lib, 0), (root, 1)])),
+      root,
+    );
+    let reference = scope.create_module_reference(&lib, &["default".into(), "a b".into()], true);
+    assert_eq!(
+      ConcatenationScope::match_module_reference(&reference),
+      Some(ModuleReference {
+        index: 0,
+        ids: vec!["default".into(), "a b".into()],
+        call: true,
+      })
+    );
+    let namespace = scope.create_module_reference(&lib, &[], false);
+    assert_eq!(namespace, "__WEBPACK_MODULE_REFERENCE__0_ns__");
+    assert_eq!(
+      ConcatenationScope::match_module_reference(&namespace),
+      Some(ModuleReference {
+        index: 0,
+        ids: vec![],
+        call: false,
+      })
+    );
+    assert_eq!(ConcatenationScope::match_module_reference("foo"), None);
+  }
+}
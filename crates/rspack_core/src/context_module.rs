@@ -1,16 +1,17 @@
 use std::{
   borrow::Cow,
   fmt::{self, Display},
-  fs,
   hash::Hash,
   path::{Path, PathBuf},
   sync::Arc,
 };
 
+use async_recursion::async_recursion;
 use itertools::Itertools;
 use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
-use rspack_error::{impl_empty_diagnosable_trait, miette::IntoDiagnostic, Result};
+use rspack_error::{impl_empty_diagnosable_trait, Result};
+use rspack_fs::AsyncReadableFileSystem;
 use rspack_hash::RspackHash;
 use rspack_identifier::{Identifiable, Identifier};
 use rspack_regex::RspackRegex;
@@ -589,7 +590,7 @@ impl Module for ContextModule {
   }
 
   async fn build(&mut self, build_context: BuildContext<'_>) -> Result<BuildResult> {
-    self.resolve_dependencies(build_context)
+    self.resolve_dependencies(build_context).await
   }
 
   fn code_generation(
@@ -680,21 +681,23 @@ static WEBPACK_CHUNK_NAME_REQUEST_PLACEHOLDER: Lazy<Regex> =
   Lazy::new(|| Regex::new(r"\[request\]").expect("regexp init failed"));
 
 impl ContextModule {
-  fn visit_dirs(
+  #[async_recursion]
+  async fn visit_dirs(
+    fs: &dyn AsyncReadableFileSystem,
     ctx: &str,
     dir: &Path,
     dependencies: &mut Vec<ContextElementDependency>,
     options: &ContextModuleOptions,
-    resolve_options: &ResolveInnerOptions,
+    resolve_options: &ResolveInnerOptions<'_>,
   ) -> Result<()> {
-    if !dir.is_dir() {
+    if !fs.metadata(dir).await.map_or(false, |m| m.is_directory) {
       return Ok(());
     }
-    for entry in fs::read_dir(dir).into_diagnostic()? {
-      let path = entry.into_diagnostic()?.path();
-      if path.is_dir() {
+    for name in fs.read_dir(dir).await? {
+      let path = dir.join(name);
+      if fs.metadata(&path).await?.is_directory {
         if options.context_options.recursive {
-          Self::visit_dirs(ctx, &path, dependencies, options, resolve_options)?;
+          Self::visit_dirs(fs, ctx, &path, dependencies, options, resolve_options).await?;
         }
       } else if path
         .file_name()
@@ -751,7 +754,7 @@ impl ContextModule {
     Ok(())
   }
 
-  fn resolve_dependencies(&self, build_context: BuildContext<'_>) -> Result<BuildResult> {
+  async fn resolve_dependencies(&self, build_context: BuildContext<'_>) -> Result<BuildResult> {
     tracing::trace!("resolving context module path {}", self.options.resource);
 
     let resolver = &self.resolve_factory.get(ResolveOptionsWithDependencyType {
@@ -762,12 +765,14 @@ impl ContextModule {
 
     let mut context_element_dependencies = vec![];
     Self::visit_dirs(
+      build_context.input_filesystem.as_ref(),
       &self.options.resource,
       Path::new(&self.options.resource),
       &mut context_element_dependencies,
       &self.options,
       &resolver.options(),
-    )?;
+    )
+    .await?;
     context_element_dependencies.sort_by_cached_key(|d| d.user_request.to_string());
 
     tracing::trace!(
@@ -88,7 +88,10 @@ impl ContextModuleFactory {
     let (resource_data, from_cache) = match self
       .cache
       .resolve_module_occasion
-      .use_cache(resolve_args, |args| resolve(args, plugin_driver))
+      .use_cache(resolve_args, |args| {
+        let plugin_driver = plugin_driver.clone();
+        Box::pin(async move { resolve(args, &plugin_driver).await })
+      })
       .await
     {
       Ok(result) => result,
@@ -119,6 +122,8 @@ impl ContextModuleFactory {
         return Ok(ModuleFactoryResult::new_with_module(raw_module));
       }
       Err(err) => {
+        data.add_file_dependencies(file_dependencies);
+        data.add_missing_dependencies(missing_dependencies);
         return Err(err);
       }
     };
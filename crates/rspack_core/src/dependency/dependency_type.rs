@@ -48,6 +48,8 @@ pub enum DependencyType {
   CssImport,
   // css modules compose
   CssCompose,
+  // css :import or @value from
+  CssIcssImport,
   // context element
   ContextElement,
   // import context
@@ -111,6 +113,7 @@ impl DependencyType {
       DependencyType::CssUrl => Cow::Borrowed("css url"),
       DependencyType::CssImport => Cow::Borrowed("css import"),
       DependencyType::CssCompose => Cow::Borrowed("css compose"),
+      DependencyType::CssIcssImport => Cow::Borrowed("css icss import"),
       DependencyType::ContextElement => Cow::Borrowed("context element"),
       // TODO: mode
       DependencyType::ImportContext => Cow::Borrowed("import context"),
@@ -599,6 +599,115 @@ impl ExportsInfo {
       }
     }
   }
+
+  /// Names of the provided exports, sorted.
+  ///
+  /// https://github.com/webpack/webpack/blob/ac7e531436b0d47cd88451f497cdfd0dad4153d/lib/ExportsInfo.js#L606-L638
+  pub fn get_provided_exports(&self, mg: &ModuleGraph) -> ProvidedExports {
+    if self.redirect_to.is_none() {
+      match mg.get_export_info_by_id(&self.other_exports_info).provided {
+        None => return ProvidedExports::Unknown,
+        Some(ExportInfoProvided::Null | ExportInfoProvided::True) => return ProvidedExports::True,
+        Some(ExportInfoProvided::False) => {}
+      }
+    }
+    let mut names = self
+      .exports
+      .values()
+      .map(|id| mg.get_export_info_by_id(id))
+      .filter(|info| !matches!(info.provided, Some(ExportInfoProvided::False)))
+      .filter_map(|info| info.name.clone())
+      .collect::<Vec<_>>();
+    if let Some(redirect_to) = self.redirect_to {
+      match redirect_to.get_exports_info(mg).get_provided_exports(mg) {
+        ProvidedExports::Vec(redirected) => {
+          for name in redirected {
+            if !names.contains(&name) {
+              names.push(name);
+            }
+          }
+        }
+        provided => return provided,
+      }
+    }
+    names.sort_unstable();
+    ProvidedExports::Vec(names)
+  }
+
+  /// Names of the used exports in `runtime`, sorted.
+  ///
+  /// https://github.com/webpack/webpack/blob/ac7e531436b0d47cd88451f497cdfd0dad4153d/lib/ExportsInfo.js#L640-L684
+  pub fn get_used_exports(&self, runtime: Option<&RuntimeSpec>, mg: &ModuleGraph) -> UsedExports {
+    if self.redirect_to.is_none() {
+      match mg
+        .get_export_info_by_id(&self.other_exports_info)
+        .get_used(runtime)
+      {
+        UsageState::NoInfo => return UsedExports::Unknown,
+        UsageState::Unknown | UsageState::OnlyPropertiesUsed | UsageState::Used => {
+          return UsedExports::Bool(true)
+        }
+        UsageState::Unused => {}
+      }
+    }
+    let mut names = Vec::new();
+    for id in self.exports.values() {
+      let info = mg.get_export_info_by_id(id);
+      match info.get_used(runtime) {
+        UsageState::NoInfo => return UsedExports::Unknown,
+        UsageState::Unknown => return UsedExports::Bool(true),
+        UsageState::OnlyPropertiesUsed | UsageState::Used => {
+          if let Some(name) = &info.name {
+            names.push(name.clone());
+          }
+        }
+        UsageState::Unused => {}
+      }
+    }
+    if let Some(redirect_to) = self.redirect_to {
+      match redirect_to
+        .get_exports_info(mg)
+        .get_used_exports(runtime, mg)
+      {
+        UsedExports::Vec(redirected) => {
+          for name in redirected {
+            if !names.contains(&name) {
+              names.push(name);
+            }
+          }
+        }
+        used => return used,
+      }
+    }
+    if names.is_empty() {
+      match mg
+        .get_export_info_by_id(&self._side_effects_only_info)
+        .get_used(runtime)
+      {
+        UsageState::NoInfo => return UsedExports::Unknown,
+        UsageState::Unused => return UsedExports::Bool(false),
+        _ => {}
+      }
+    }
+    names.sort_unstable();
+    UsedExports::Vec(names)
+  }
+}
+
+/// `null`, `true` or `string[]` in webpack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProvidedExports {
+  Unknown,
+  True,
+  Vec(Vec<JsWord>),
+}
+
+/// `null`, `boolean` or `SortableSet<string>` in webpack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UsedExports {
+  Unknown,
+  Bool(bool),
+  Vec(Vec<JsWord>),
 }
 
 #[derive(Debug, Clone)]
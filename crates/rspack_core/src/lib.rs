@@ -4,6 +4,7 @@
 #![feature(box_patterns)]
 #![feature(anonymous_lifetime_in_impl_trait)]
 #![feature(hash_raw_entry)]
+#![feature(file_set_times)]
 
 use std::{fmt, sync::Arc};
 mod dependencies_block;
@@ -18,6 +19,7 @@ pub use template::Template;
 mod module_profile;
 pub use module_profile::*;
 use rspack_database::Database;
+use serde::{Deserialize, Serialize};
 pub mod external_module;
 pub use external_module::*;
 mod logger;
@@ -77,6 +79,10 @@ pub use runtime::*;
 pub use runtime_module::*;
 mod code_generation_results;
 pub use code_generation_results::*;
+mod concatenation_scope;
+pub use concatenation_scope::*;
+mod concatenated_module;
+pub use concatenated_module::*;
 mod entrypoint;
 pub use entrypoint::*;
 mod loader;
@@ -99,7 +105,7 @@ pub use rspack_sources;
 #[cfg(debug_assertions)]
 pub mod debug_info;
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SourceType {
   JavaScript,
   Css,
@@ -1,15 +1,25 @@
-use rspack_error::Result;
+use std::sync::Arc;
+
+use rspack_error::{error, Result};
+use rspack_fs::AsyncReadableFileSystem;
 use rspack_loader_runner::{Content, LoaderRunnerPlugin, ResourceData};
 
 use crate::SharedPluginDriver;
 
 pub struct LoaderRunnerPluginProcessResource {
   pub plugin_driver: SharedPluginDriver,
+  pub input_filesystem: Arc<dyn AsyncReadableFileSystem>,
 }
 
 impl LoaderRunnerPluginProcessResource {
-  pub fn new(plugin_driver: SharedPluginDriver) -> Self {
-    Self { plugin_driver }
+  pub fn new(
+    plugin_driver: SharedPluginDriver,
+    input_filesystem: Arc<dyn AsyncReadableFileSystem>,
+  ) -> Self {
+    Self {
+      plugin_driver,
+      input_filesystem,
+    }
   }
 }
 
@@ -25,6 +35,16 @@ impl LoaderRunnerPlugin for LoaderRunnerPluginProcessResource {
       return Ok(result);
     }
 
-    Ok(None)
+    let content = self
+      .input_filesystem
+      .read(&resource_data.resource_path)
+      .await
+      .map_err(|rspack_fs::Error::Io(e)| {
+        error!(
+          "{e}, failed to read {}",
+          resource_data.resource_path.display()
+        )
+      })?;
+    Ok(Some(Content::from(content)))
   }
 }
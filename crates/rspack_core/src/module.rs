@@ -1,11 +1,13 @@
 use std::fmt::Display;
 use std::hash::Hash;
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::{any::Any, borrow::Cow, fmt::Debug};
 
 use async_trait::async_trait;
 use json::JsonValue;
 use rspack_error::{Diagnosable, Result};
+use rspack_fs::AsyncReadableFileSystem;
 use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_identifier::{Identifiable, Identifier};
 use rspack_sources::Source;
@@ -25,6 +27,7 @@ pub struct BuildContext<'a> {
   pub compiler_context: CompilerContext,
   pub plugin_driver: SharedPluginDriver,
   pub compiler_options: &'a CompilerOptions,
+  pub input_filesystem: Arc<dyn AsyncReadableFileSystem>,
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -49,6 +52,8 @@ pub struct BuildInfo {
   pub all_star_exports: Vec<DependencyId>,
   pub need_create_require: bool,
   pub json_data: Option<JsonValue>,
+  /// The syntax which prevents the module from being concatenated, e.g. `eval()`.
+  pub module_concatenation_bailout: Option<String>,
 }
 
 #[derive(Debug, Default, Clone, Hash, PartialEq, Eq)]
@@ -11,10 +11,10 @@ use std::{
 use bitflags::bitflags;
 use dashmap::DashMap;
 use derivative::Derivative;
-use rspack_error::{error, Diagnosable, Diagnostic, Result, Severity};
+use rspack_error::{error, Diagnosable, Diagnostic, IntoTWithDiagnosticArray, Result, Severity};
 use rspack_hash::RspackHash;
 use rspack_identifier::Identifiable;
-use rspack_loader_runner::{run_loaders, Content, ResourceData};
+use rspack_loader_runner::{run_loaders, Content, LoaderResult, ResourceData};
 use rspack_sources::{
   BoxSource, CachedSource, OriginalSource, RawSource, Source, SourceExt, SourceMap,
   SourceMapSource, WithoutOriginalOptions,
@@ -25,11 +25,12 @@ use serde_json::json;
 
 use crate::{
   add_connection_states, contextify, get_context, AsyncDependenciesBlockIdentifier, BoxLoader,
-  BoxModule, BuildContext, BuildInfo, BuildMeta, BuildResult, CodeGenerationResult, Compilation,
-  CompilerOptions, ConnectionState, Context, DependenciesBlock, DependencyId, DependencyTemplate,
-  GenerateContext, GeneratorOptions, LibIdentOptions, LoaderRunnerPluginProcessResource, Module,
-  ModuleDependency, ModuleGraph, ModuleIdentifier, ModuleType, ParseContext, ParseResult,
-  ParserAndGenerator, ParserOptions, Resolve, RuntimeSpec, SourceType,
+  BoxModule, BuildContext, BuildInfo, BuildMeta, BuildResult, CacheOptions, CodeGenerationResult,
+  Compilation, CompilerOptions, ConcatenationScope, ConnectionState, Context, DependenciesBlock,
+  DependencyId, DependencyTemplate, GenerateContext, GeneratorOptions, LibIdentOptions,
+  LoaderRunnerPluginProcessResource, Module, ModuleDependency, ModuleGraph, ModuleIdentifier,
+  ModuleType, ParseContext, ParseResult, ParserAndGenerator, ParserOptions, Resolve, RuntimeSpec,
+  SourceType,
 };
 
 bitflags! {
@@ -123,6 +124,11 @@ pub struct NormalModule {
 
   code_generation_dependencies: Option<Vec<Box<dyn ModuleDependency>>>,
   presentational_dependencies: Option<Vec<Box<dyn DependencyTemplate>>>,
+
+  /// Result of the loaders read from the persistent cache, the loaders are skipped when it's set.
+  /// After a build it's the result to write to the persistent cache, if it can be persisted.
+  #[derivative(Debug = "ignore")]
+  loader_result: Option<LoaderResult>,
 }
 
 #[derive(Debug, Clone)]
@@ -200,6 +206,7 @@ impl NormalModule {
       diagnostics: Mutex::new(Default::default()),
       code_generation_dependencies: None,
       presentational_dependencies: None,
+      loader_result: None,
     }
   }
 
@@ -207,6 +214,12 @@ impl NormalModule {
     self.id
   }
 
+  /// Tell the module apart from other modules of the same request, like the modules created for
+  /// each distinct set of `@import` conditions in CSS.
+  pub fn extend_identifier(&mut self, suffix: &str) {
+    self.id = ModuleIdentifier::from(format!("{}{suffix}", self.id));
+  }
+
   pub fn match_resource(&self) -> Option<&ResourceData> {
     self.match_resource.as_ref()
   }
@@ -235,6 +248,14 @@ impl NormalModule {
     &mut self.source
   }
 
+  pub fn set_loader_result(&mut self, loader_result: LoaderResult) {
+    self.loader_result = Some(loader_result);
+  }
+
+  pub fn take_loader_result(&mut self) -> Option<LoaderResult> {
+    self.loader_result.take()
+  }
+
   pub fn loaders(&self) -> &[BoxLoader] {
     &self.loaders
   }
@@ -337,15 +358,21 @@ impl Module for NormalModule {
 
     build_context.plugin_driver.before_loaders(self).await?;
 
-    let loader_result = run_loaders(
-      &self.loaders,
-      &self.resource_data,
-      &[Box::new(LoaderRunnerPluginProcessResource {
-        plugin_driver: build_context.plugin_driver.clone(),
-      })],
-      build_context.compiler_context,
-    )
-    .await;
+    let loader_result = match self.loader_result.take() {
+      Some(loader_result) => Ok(loader_result.with_empty_diagnostic()),
+      None => {
+        run_loaders(
+          &self.loaders,
+          &self.resource_data,
+          &[Box::new(LoaderRunnerPluginProcessResource {
+            plugin_driver: build_context.plugin_driver.clone(),
+            input_filesystem: build_context.input_filesystem.clone(),
+          })],
+          build_context.compiler_context,
+        )
+        .await
+      }
+    };
     let (loader_result, ds) = match loader_result {
       Ok(r) => r.split_into_parts(),
       Err(e) => {
@@ -365,6 +392,21 @@ impl Module for NormalModule {
         });
       }
     };
+    // only the output of loaders without diagnostics and additional data can be persisted,
+    // modules without loaders are cheap to read again
+    let persisted_content = (matches!(
+      build_context.compiler_options.cache,
+      CacheOptions::FileSystem(_)
+    ) && !self.loaders.is_empty()
+      && loader_result.cacheable
+      && ds.is_empty()
+      && loader_result.additional_data.is_empty())
+    .then(|| {
+      (
+        loader_result.content.clone(),
+        loader_result.source_map.clone(),
+      )
+    });
     self.add_diagnostics(ds);
 
     let content = if self.module_type().is_binary() {
@@ -420,6 +462,17 @@ impl Module for NormalModule {
     build_info.missing_dependencies = loader_result.missing_dependencies;
     build_info.build_dependencies = loader_result.build_dependencies;
     build_info.asset_filenames = loader_result.asset_filenames;
+    self.loader_result = persisted_content.map(|(content, source_map)| LoaderResult {
+      cacheable: true,
+      file_dependencies: build_info.file_dependencies.clone(),
+      context_dependencies: build_info.context_dependencies.clone(),
+      missing_dependencies: build_info.missing_dependencies.clone(),
+      build_dependencies: build_info.build_dependencies.clone(),
+      asset_filenames: build_info.asset_filenames.clone(),
+      content,
+      source_map,
+      additional_data: Default::default(),
+    });
 
     Ok(BuildResult {
       build_info,
@@ -435,53 +488,7 @@ impl Module for NormalModule {
     compilation: &Compilation,
     runtime: Option<&RuntimeSpec>,
   ) -> Result<CodeGenerationResult> {
-    if let NormalModuleSource::BuiltSucceed(source) = &self.source {
-      let mut code_generation_result = CodeGenerationResult::default();
-      for source_type in self.source_types() {
-        let generation_result = self.parser_and_generator.generate(
-          source,
-          self,
-          &mut GenerateContext {
-            compilation,
-            module_generator_options: self.generator_options.as_ref(),
-            runtime_requirements: &mut code_generation_result.runtime_requirements,
-            data: &mut code_generation_result.data,
-            requested_source_type: *source_type,
-            runtime,
-          },
-        )?;
-        code_generation_result.add(*source_type, CachedSource::new(generation_result).boxed());
-      }
-      code_generation_result.set_hash(
-        &compilation.options.output.hash_function,
-        &compilation.options.output.hash_digest,
-        &compilation.options.output.hash_salt,
-      );
-      Ok(code_generation_result)
-    } else if let NormalModuleSource::BuiltFailed(error_message) = &self.source {
-      let mut code_generation_result = CodeGenerationResult::default();
-
-      // If the module build failed and the module is able to emit JavaScript source,
-      // we should emit an error message to the runtime, otherwise we do nothing.
-      if self.source_types().contains(&SourceType::JavaScript) {
-        let error = error_message.render_report(compilation.options.stats.colors)?;
-        code_generation_result.add(
-          SourceType::JavaScript,
-          RawSource::from(format!("throw new Error({});\n", json!(error))).boxed(),
-        );
-      }
-      code_generation_result.set_hash(
-        &compilation.options.output.hash_function,
-        &compilation.options.output.hash_digest,
-        &compilation.options.output.hash_salt,
-      );
-      Ok(code_generation_result)
-    } else {
-      Err(error!(
-        "Failed to generate code because ast or source is not set for module {}",
-        self.request
-      ))
-    }
+    self.generate(compilation, runtime, None)
   }
 
   fn name_for_condition(&self) -> Option<Box<str>> {
@@ -605,6 +612,75 @@ impl PartialEq for NormalModule {
 impl Eq for NormalModule {}
 
 impl NormalModule {
+  /// Generates the code of the module as a part of a [crate::ConcatenatedModule], the exports are
+  /// registered to `concatenation_scope` instead of being defined on the exports object.
+  pub fn code_generation_in_concatenation_scope(
+    &self,
+    compilation: &Compilation,
+    runtime: Option<&RuntimeSpec>,
+    concatenation_scope: &mut ConcatenationScope,
+  ) -> Result<CodeGenerationResult> {
+    self.generate(compilation, runtime, Some(concatenation_scope))
+  }
+
+  // The scope is reborrowed for each source type
+  #[allow(clippy::needless_option_as_deref)]
+  fn generate(
+    &self,
+    compilation: &Compilation,
+    runtime: Option<&RuntimeSpec>,
+    mut concatenation_scope: Option<&mut ConcatenationScope>,
+  ) -> Result<CodeGenerationResult> {
+    if let NormalModuleSource::BuiltSucceed(source) = &self.source {
+      let mut code_generation_result = CodeGenerationResult::default();
+      for source_type in self.source_types() {
+        let generation_result = self.parser_and_generator.generate(
+          source,
+          self,
+          &mut GenerateContext {
+            compilation,
+            module_generator_options: self.generator_options.as_ref(),
+            runtime_requirements: &mut code_generation_result.runtime_requirements,
+            data: &mut code_generation_result.data,
+            requested_source_type: *source_type,
+            runtime,
+            concatenation_scope: concatenation_scope.as_deref_mut(),
+          },
+        )?;
+        code_generation_result.add(*source_type, CachedSource::new(generation_result).boxed());
+      }
+      code_generation_result.set_hash(
+        &compilation.options.output.hash_function,
+        &compilation.options.output.hash_digest,
+        &compilation.options.output.hash_salt,
+      );
+      Ok(code_generation_result)
+    } else if let NormalModuleSource::BuiltFailed(error_message) = &self.source {
+      let mut code_generation_result = CodeGenerationResult::default();
+
+      // If the module build failed and the module is able to emit JavaScript source,
+      // we should emit an error message to the runtime, otherwise we do nothing.
+      if self.source_types().contains(&SourceType::JavaScript) {
+        let error = error_message.render_report(compilation.options.stats.colors)?;
+        code_generation_result.add(
+          SourceType::JavaScript,
+          RawSource::from(format!("throw new Error({});\n", json!(error))).boxed(),
+        );
+      }
+      code_generation_result.set_hash(
+        &compilation.options.output.hash_function,
+        &compilation.options.output.hash_digest,
+        &compilation.options.output.hash_salt,
+      );
+      Ok(code_generation_result)
+    } else {
+      Err(error!(
+        "Failed to generate code because ast or source is not set for module {}",
+        self.request
+      ))
+    }
+  }
+
   fn create_source(&self, content: Content, source_map: Option<SourceMap>) -> Result<BoxSource> {
     if content.is_buffer() {
       return Ok(RawSource::Buffer(content.into_bytes()).boxed());
@@ -47,6 +47,9 @@ impl ModuleFactory for NormalModuleFactory {
 static MATCH_RESOURCE_REGEX: Lazy<Regex> =
   Lazy::new(|| Regex::new("^([^!]+)!=!").expect("Failed to initialize `MATCH_RESOURCE_REGEX`"));
 
+static RELATIVE_REQUEST_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^\.{0,2}/").expect("Failed to initialize `RELATIVE_REQUEST_REGEX`"));
+
 static MATCH_WEBPACK_EXT_REGEX: Lazy<Regex> = Lazy::new(|| {
   Regex::new(r#"\.webpack\[([^\]]+)\]$"#).expect("Failed to initialize `MATCH_WEBPACK_EXT_REGEX`")
 });
@@ -147,8 +150,24 @@ impl NormalModuleFactory {
     let mut file_dependencies = Default::default();
     let mut missing_dependencies = Default::default();
 
-    let scheme = get_scheme(request_without_match_resource);
     let context_scheme = get_scheme(data.context.as_ref());
+    // Relative requests of a module loaded from an http(s) url are resolved against its url.
+    // TODO: call resolveInScheme hook for other schemes
+    let request_in_scheme = if context_scheme.is_http()
+      && get_scheme(request_without_match_resource).is_none()
+      && RELATIVE_REQUEST_REGEX.is_match(request_without_match_resource)
+    {
+      url::Url::parse(&format!("{}/", data.context))
+        .and_then(|base| base.join(request_without_match_resource))
+        .ok()
+        .map(|url| url.to_string())
+    } else {
+      None
+    };
+    if let Some(request_in_scheme) = &request_in_scheme {
+      request_without_match_resource = request_in_scheme;
+    }
+    let scheme = get_scheme(request_without_match_resource);
     let context = data.context.as_path();
     let plugin_driver = &self.plugin_driver;
     let loader_resolver = self.get_loader_resolver();
@@ -296,15 +315,25 @@ impl NormalModuleFactory {
         missing_dependencies: &mut missing_dependencies,
       };
 
-      // default resolve
-      let (resource_data, from_cache) = match self
-        .cache
-        .resolve_module_occasion
-        .use_cache(resolve_args, |args| resolve(args, plugin_driver))
-        .await
+      let (resource_data, from_cache) = if let Some(resolved) = plugin_driver
+        .normal_module_factory_resolve(&resolve_args)
+        .await?
       {
-        Ok(result) => result,
-        Err(err) => (Err(err), false),
+        (Ok(resolved), false)
+      } else {
+        // default resolve
+        match self
+          .cache
+          .resolve_module_occasion
+          .use_cache(resolve_args, |args| {
+            let plugin_driver = plugin_driver.clone();
+            Box::pin(async move { resolve(args, &plugin_driver).await })
+          })
+          .await
+        {
+          Ok(result) => result,
+          Err(err) => (Err(err), false),
+        }
       };
       match resource_data {
         Ok(ResolveResult::Resource(resource)) => {
@@ -386,6 +415,9 @@ impl NormalModuleFactory {
           //     }
           //   }
           // }
+          // the failed request is retried when one of the missing paths is created
+          data.add_file_dependencies(file_dependencies);
+          data.add_missing_dependencies(missing_dependencies);
           return Err(err);
         }
       }
@@ -608,7 +640,9 @@ impl NormalModuleFactory {
       })?();
 
     let mut create_data = NormalModuleCreateData {
+      dependency: &data.dependency,
       dependency_type: data.dependency.dependency_type().clone(),
+      issuer_identifier: data.issuer_identifier,
       resolve_data_request: dependency.request(),
       resource_resolve_data: resource_data.clone(),
       context: data.context.clone(),
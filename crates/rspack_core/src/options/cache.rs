@@ -1,9 +1,15 @@
+use rspack_error::{error_bail, Result};
+
 #[derive(Debug, Clone, Default)]
 pub struct MemoryCacheOptions {
   /// Define the lifespan of unused cache entries in the memory cache.
   pub max_generations: u32,
 }
 
+/// Options of the persistent cache.
+///
+/// Resolve results, loader results, code generation results and chunk assets are persisted.
+/// Built modules are only kept in memory, as their dependencies can not be serialized yet.
 #[derive(Debug, Clone, Default)]
 pub struct FileSystemCacheOptions {
   // Collect unused memory allocated during deserialization
@@ -14,7 +20,8 @@ pub struct FileSystemCacheOptions {
   pub max_age: u32,
   /// Track and log detailed timing information for individual cache items of type 'filesystem'.
   pub profile: bool,
-  /// An object of arrays of additional code dependencies for the build.
+  /// Additional code dependencies of the build, directories include all files in them; the build
+  /// fails if one of them can not be read.
   pub build_dependencies: Vec<String>,
   /// Base directory for the cache. Defaults to node_modules/.cache/rspack.
   pub cache_directory: String,
@@ -22,12 +29,14 @@ pub struct FileSystemCacheOptions {
   pub cache_location: String,
   // Algorithm used the hash generation.
   // hash_algorithm: "md4" | "md5" | ...,
-  // Compression type used for the cache files.
-  // compression: false | "gzip" | "brotli",
-  // Dnotes the time period after which the cache storing should happen.
-  // idle_timeout: number,
+  /// Compression type used for the cache files.
+  pub compression: CacheCompression,
+  /// The time period in milliseconds after the compilation goes idle and before the cache storing happens.
+  pub idle_timeout: u32,
   // idle_timeout_for_initial_store: number,
   // idle_timeout_after_large_changes": number,
+  /// The maximum total size in bytes of the cache files, least recently used packs are removed first; 0 means unlimited.
+  pub max_size: u64,
   /// Name for the cache
   pub name: String,
   // Store strategy
@@ -36,6 +45,30 @@ pub struct FileSystemCacheOptions {
   pub version: String,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CacheCompression {
+  #[default]
+  None,
+  Gzip,
+  Brotli,
+  Zstd,
+}
+
+impl TryFrom<&str> for CacheCompression {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self> {
+    Ok(match value {
+      "gzip" => Self::Gzip,
+      "brotli" => Self::Brotli,
+      "zstd" => Self::Zstd,
+      _ => error_bail!(
+        "Unknown cache.compression \"{value}\", expected false, \"gzip\", \"brotli\" or \"zstd\""
+      ),
+    })
+  }
+}
+
 #[derive(Debug, Default, Clone)]
 pub enum CacheOptions {
   #[default]
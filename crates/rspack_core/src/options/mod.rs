@@ -37,3 +37,7 @@ mod experiments;
 pub use experiments::*;
 mod node;
 pub use node::*;
+mod performance;
+pub use performance::*;
+mod watch;
+pub use watch::*;
@@ -13,13 +13,15 @@ use sugar_path::SugarPath;
 
 use crate::{
   parse_resource, AssetInfo, Chunk, ChunkGraph, ChunkGroupByUkey, ChunkKind, Compilation, Module,
-  ResourceParsedData, RuntimeSpec,
+  PathMatcher, ResourceParsedData, RuntimeSpec,
 };
 
 #[derive(Debug)]
 pub struct OutputOptions {
   pub path: PathBuf,
   pub clean: bool,
+  /// Files matching it are never removed by `clean`.
+  pub clean_keep: Option<PathMatcher>,
   pub public_path: PublicPath,
   pub asset_module_filename: Filename,
   pub wasm_loading: WasmLoading,
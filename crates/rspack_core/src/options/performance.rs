@@ -0,0 +1,87 @@
+use rspack_error::Result;
+
+use crate::{AssetInfo, RuleSetCondition};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PerformanceHints {
+  Warning,
+  Error,
+}
+
+impl From<&str> for PerformanceHints {
+  fn from(value: &str) -> Self {
+    match value {
+      "error" => Self::Error,
+      _ => Self::Warning,
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct PerformanceOptions {
+  /// `None` turns off the hints, like `performance.hints: false` of webpack.
+  pub hints: Option<PerformanceHints>,
+  pub max_asset_size: f64,
+  pub max_entrypoint_size: f64,
+  /// Only matched assets are counted, source maps are excluded when it's not set. Development
+  /// assets are always excluded.
+  pub asset_filter: Option<RuleSetCondition>,
+}
+
+impl Default for PerformanceOptions {
+  fn default() -> Self {
+    Self {
+      hints: None,
+      max_asset_size: 250000.0,
+      max_entrypoint_size: 250000.0,
+      asset_filter: None,
+    }
+  }
+}
+
+impl PerformanceOptions {
+  pub async fn is_asset_included(&self, name: &str, info: &AssetInfo) -> Result<bool> {
+    if info.development {
+      return Ok(false);
+    }
+    match &self.asset_filter {
+      Some(asset_filter) => asset_filter.try_match(name).await,
+      None => Ok(!name.ends_with(".map")),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use rspack_regex::RspackRegex;
+
+  use super::*;
+
+  #[tokio::test]
+  async fn should_exclude_development_assets_whatever_the_filter_is() {
+    let options = PerformanceOptions::default();
+    assert!(options
+      .is_asset_included("main.js", &AssetInfo::default())
+      .await
+      .unwrap());
+    assert!(!options
+      .is_asset_included("main.js.map", &AssetInfo::default())
+      .await
+      .unwrap());
+
+    let options = PerformanceOptions {
+      asset_filter: Some(RuleSetCondition::Regexp(
+        RspackRegex::new("^main\\.js").unwrap(),
+      )),
+      ..Default::default()
+    };
+    assert!(options
+      .is_asset_included("main.js.map", &AssetInfo::default())
+      .await
+      .unwrap());
+    assert!(!options
+      .is_asset_included("main.js.map", &AssetInfo::default().with_development(true))
+      .await
+      .unwrap());
+  }
+}
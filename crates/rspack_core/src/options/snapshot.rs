@@ -1,9 +1,29 @@
+use std::path::Path;
+
+use rspack_regex::RspackRegex;
+
 #[derive(Debug, Clone, Default)]
 pub struct SnapshotStrategy {
   pub hash: bool,
   pub timestamp: bool,
 }
 
+#[derive(Debug, Clone)]
+pub enum PathMatcher {
+  /// Matches the path and everything inside it.
+  String(String),
+  Regexp(RspackRegex),
+}
+
+impl PathMatcher {
+  pub fn matches(&self, path: &Path) -> bool {
+    match self {
+      Self::String(prefix) => path.starts_with(prefix),
+      Self::Regexp(regexp) => regexp.test(&path.to_string_lossy()),
+    }
+  }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct SnapshotOptions {
   // Snapshots for resolving of build dependencies when using the persistent cache.
@@ -14,8 +34,29 @@ pub struct SnapshotOptions {
   pub resolve: SnapshotStrategy,
   /// Snapshots for building modules.
   pub module: SnapshotStrategy,
-  // An array of paths that are managed by a package manager and contain a version or a hash in their paths.
-  // immutable_paths: Vec<String>,
-  // An array of paths that are managed by a package manager.
-  // managed_paths: Vec<String>,
+  /// An array of paths that are managed by a package manager and contain a version or a hash in their paths.
+  /// Files in them are never checked again.
+  pub immutable_paths: Vec<PathMatcher>,
+  /// An array of paths that are managed by a package manager.
+  /// Files in them are checked by content hash only, because installing packages touches timestamps.
+  pub managed_paths: Vec<PathMatcher>,
+}
+
+impl SnapshotOptions {
+  /// Get the strategy for a path, `immutable_paths` and `managed_paths` take precedence over `default`.
+  pub fn strategy_for(&self, path: &Path, default: &SnapshotStrategy) -> SnapshotStrategy {
+    if self.immutable_paths.iter().any(|m| m.matches(path)) {
+      return SnapshotStrategy {
+        hash: false,
+        timestamp: false,
+      };
+    }
+    if self.managed_paths.iter().any(|m| m.matches(path)) {
+      return SnapshotStrategy {
+        hash: true,
+        timestamp: false,
+      };
+    }
+    default.clone()
+  }
 }
@@ -1,4 +1,184 @@
-#[derive(Debug, Default)]
+use rspack_error::{error_bail, Result};
+
+/// Presets of `stats`, ordered from the least to the most verbose.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum StatsPreset {
+  None,
+  ErrorsOnly,
+  ErrorsWarnings,
+  #[default]
+  Normal,
+  Detailed,
+  Verbose,
+}
+
+impl TryFrom<&str> for StatsPreset {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self> {
+    Ok(match value {
+      "none" => Self::None,
+      "errors-only" => Self::ErrorsOnly,
+      "errors-warnings" => Self::ErrorsWarnings,
+      "normal" => Self::Normal,
+      "detailed" => Self::Detailed,
+      "verbose" => Self::Verbose,
+      _ => error_bail!(
+        "Unknown stats preset \"{value}\", expected \"none\", \"errors-only\", \"errors-warnings\", \"normal\", \"detailed\" or \"verbose\""
+      ),
+    })
+  }
+}
+
+/// A field is included when it is set explicitly, otherwise `all` decides, and at last the
+/// `preset`.
+#[derive(Debug, Default, Clone)]
 pub struct StatsOptions {
   pub colors: bool,
+  pub preset: StatsPreset,
+  pub all: Option<bool>,
+  pub assets: Option<bool>,
+  pub chunks: Option<bool>,
+  pub chunk_modules: Option<bool>,
+  pub chunk_relations: Option<bool>,
+  pub modules: Option<bool>,
+  pub nested_modules: Option<bool>,
+  pub runtime_modules: Option<bool>,
+  pub reasons: Option<bool>,
+  pub module_assets: Option<bool>,
+  pub source: Option<bool>,
+  pub optimization_bailout: Option<bool>,
+  pub used_exports: Option<bool>,
+  pub provided_exports: Option<bool>,
+  pub entrypoints: Option<bool>,
+  pub chunk_groups: Option<bool>,
+  pub errors: Option<bool>,
+  pub errors_count: Option<bool>,
+  pub warnings: Option<bool>,
+  pub warnings_count: Option<bool>,
+  pub hash: Option<bool>,
+  pub timings: Option<bool>,
+  pub built_at: Option<bool>,
+  pub public_path: Option<bool>,
+  pub output_path: Option<bool>,
+}
+
+impl StatsOptions {
+  pub fn from_preset(preset: StatsPreset) -> Self {
+    Self {
+      preset,
+      ..Default::default()
+    }
+  }
+
+  /// The text output hides details which are included in the json output by default, like
+  /// `stats.toString()` of webpack.
+  pub fn for_to_string(&self) -> Self {
+    let hidden_until =
+      |value: Option<bool>, enabled_from: StatsPreset| Some(self.resolve(value, enabled_from));
+    Self {
+      chunks: hidden_until(self.chunks, StatsPreset::Detailed),
+      chunk_relations: hidden_until(self.chunk_relations, StatsPreset::Detailed),
+      chunk_modules: hidden_until(self.chunk_modules, StatsPreset::Verbose),
+      nested_modules: hidden_until(self.nested_modules, StatsPreset::Verbose),
+      runtime_modules: hidden_until(self.runtime_modules, StatsPreset::Detailed),
+      reasons: hidden_until(self.reasons, StatsPreset::Detailed),
+      built_at: hidden_until(self.built_at, StatsPreset::Detailed),
+      output_path: hidden_until(self.output_path, StatsPreset::Verbose),
+      ..self.clone()
+    }
+  }
+
+  fn resolve(&self, value: Option<bool>, enabled_from: StatsPreset) -> bool {
+    value.or(self.all).unwrap_or(self.preset >= enabled_from)
+  }
+}
+
+macro_rules! stats_fields {
+  ($($field:ident: $enabled_from:ident),* $(,)?) => {
+    impl StatsOptions {
+      $(
+        pub fn $field(&self) -> bool {
+          self.resolve(self.$field, StatsPreset::$enabled_from)
+        }
+      )*
+    }
+  };
+}
+
+stats_fields! {
+  assets: Normal,
+  chunks: Normal,
+  chunk_modules: Normal,
+  chunk_relations: Normal,
+  modules: Normal,
+  nested_modules: Normal,
+  runtime_modules: Normal,
+  reasons: Normal,
+  module_assets: Normal,
+  source: Verbose,
+  optimization_bailout: Detailed,
+  used_exports: Detailed,
+  provided_exports: Detailed,
+  entrypoints: Normal,
+  chunk_groups: Normal,
+  errors: ErrorsOnly,
+  errors_count: ErrorsOnly,
+  warnings: ErrorsWarnings,
+  warnings_count: ErrorsWarnings,
+  hash: Normal,
+  timings: Normal,
+  built_at: Normal,
+  public_path: Normal,
+  output_path: Normal,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn should_resolve_fields_by_preset_and_all() {
+    let options = StatsOptions::from_preset(StatsPreset::ErrorsOnly);
+    assert!(options.errors());
+    assert!(!options.warnings());
+    assert!(!options.modules());
+
+    let options = StatsOptions::default();
+    assert!(options.modules());
+    assert!(!options.used_exports());
+    assert!(!options.source());
+
+    let options = StatsOptions {
+      all: Some(false),
+      modules: Some(true),
+      ..StatsOptions::from_preset(StatsPreset::Verbose)
+    };
+    assert!(options.modules());
+    assert!(!options.assets());
+    assert!(!options.source());
+
+    let options = StatsOptions::default().for_to_string();
+    assert!(options.modules());
+    assert!(!options.chunk_modules());
+    assert!(!options.reasons());
+
+    let options = StatsOptions::from_preset(StatsPreset::Detailed);
+    assert!(options.used_exports());
+    assert!(options.provided_exports());
+    assert!(!options.source());
+  }
+
+  #[test]
+  fn should_reject_unknown_presets() {
+    assert_eq!(
+      StatsPreset::try_from("errors-warnings").ok(),
+      Some(StatsPreset::ErrorsWarnings)
+    );
+    assert_eq!(
+      StatsPreset::try_from("normal").ok(),
+      Some(StatsPreset::Normal)
+    );
+    assert!(StatsPreset::try_from("minimal").is_err());
+  }
 }
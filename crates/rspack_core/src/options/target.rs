@@ -1,6 +1,12 @@
+use std::path::Path;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
 use rspack_error::{error, Result};
 pub use swc_core::ecma::ast::EsVersion;
 
+use crate::{ChunkLoading, ChunkLoadingType, Context, WasmLoading, WasmLoadingType};
+
 // TODO(swc-loader): Target still coupled with javascript downgrade, it should only affect runtime
 #[derive(Debug, Clone)]
 pub enum TargetEsVersion {
@@ -18,24 +24,231 @@ impl TargetEsVersion {
   }
 }
 
+/// Capabilities of the environment that the output runs in, like the target properties of webpack.
+/// `None` means it's unknown, or the targets disagree with each other.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TargetProperties {
+  /// web platform, importing of http(s) and std: is available
+  pub web: Option<bool>,
+  /// browser platform, running in a normal web browser
+  pub browser: Option<bool>,
+  /// (Web)Worker platform, running in a web/shared/service worker
+  pub webworker: Option<bool>,
+  /// node platform, require of node built-in modules is available
+  pub node: Option<bool>,
+  /// nwjs platform, require of legacy nw.gui is available
+  pub nwjs: Option<bool>,
+  /// electron platform, require of some electron built-in modules is available
+  pub electron: Option<bool>,
+  /// in electron main context
+  pub electron_main: Option<bool>,
+  /// in electron preload context
+  pub electron_preload: Option<bool>,
+  /// in electron renderer context with node integration
+  pub electron_renderer: Option<bool>,
+
+  /// has require function available
+  pub require: Option<bool>,
+  /// has node.js built-in modules available
+  pub node_builtins: Option<bool>,
+  /// has document available (allows script tags)
+  pub document: Option<bool>,
+  /// has importScripts available
+  pub import_scripts: Option<bool>,
+  /// has importScripts available when creating a worker
+  pub import_scripts_in_worker: Option<bool>,
+  /// has fetch function available for WebAssembly
+  pub fetch_wasm: Option<bool>,
+  /// has global variable available
+  pub global: Option<bool>,
+
+  /// has globalThis variable available
+  pub global_this: Option<bool>,
+  /// big int literal syntax is available
+  pub big_int_literal: Option<bool>,
+  /// const and let variable declarations are available
+  pub r#const: Option<bool>,
+  /// arrow functions are available
+  pub arrow_function: Option<bool>,
+  /// for of iteration is available
+  pub for_of: Option<bool>,
+  /// destructuring is available
+  pub destructuring: Option<bool>,
+  /// async import() is available
+  pub dynamic_import: Option<bool>,
+  /// async import() is available when creating a worker
+  pub dynamic_import_in_worker: Option<bool>,
+  /// ESM syntax is available (when in module)
+  pub module: Option<bool>,
+  /// optional chaining is available
+  pub optional_chaining: Option<bool>,
+  /// template literal is available
+  pub template_literal: Option<bool>,
+}
+
+macro_rules! merge_target_properties {
+  ($properties:expr, $($field:ident),+) => {{
+    let mut merged = TargetProperties::default();
+    $(
+      let has_true = $properties.iter().any(|p| p.$field == Some(true));
+      let has_false = $properties.iter().any(|p| p.$field == Some(false));
+      merged.$field = match (has_true, has_false) {
+        (true, false) => Some(true),
+        (false, true) => Some(false),
+        _ => None,
+      };
+    )+
+    merged
+  }};
+}
+
+impl TargetProperties {
+  /// Merge the properties of multiple targets, a property is only known when all the targets
+  /// that know it agree.
+  pub fn merge(properties: &[TargetProperties]) -> Self {
+    merge_target_properties!(
+      properties,
+      web,
+      browser,
+      webworker,
+      node,
+      nwjs,
+      electron,
+      electron_main,
+      electron_preload,
+      electron_renderer,
+      require,
+      node_builtins,
+      document,
+      import_scripts,
+      import_scripts_in_worker,
+      fetch_wasm,
+      global,
+      global_this,
+      big_int_literal,
+      r#const,
+      arrow_function,
+      for_of,
+      destructuring,
+      dynamic_import,
+      dynamic_import_in_worker,
+      module,
+      optional_chaining,
+      template_literal
+    )
+  }
+
+  /// Default of `output.chunkLoading`, `output.module` selects the ESM chunk format.
+  pub fn chunk_loading(&self, output_module: bool) -> ChunkLoading {
+    let chunk_loading = match self.chunk_format(output_module) {
+      Some(ChunkFormat::ArrayPush) if self.document == Some(true) => Some(ChunkLoadingType::Jsonp),
+      Some(ChunkFormat::ArrayPush) if self.import_scripts == Some(true) => {
+        Some(ChunkLoadingType::ImportScripts)
+      }
+      Some(ChunkFormat::CommonJs) if self.require == Some(true) => Some(ChunkLoadingType::Require),
+      Some(ChunkFormat::CommonJs) if self.node_builtins == Some(true) => {
+        Some(ChunkLoadingType::AsyncNode)
+      }
+      Some(ChunkFormat::Module) if self.dynamic_import == Some(true) => {
+        Some(ChunkLoadingType::Import)
+      }
+      _ => None,
+    };
+    chunk_loading.map_or(ChunkLoading::Disable, ChunkLoading::Enable)
+  }
+
+  /// Default of `output.workerChunkLoading`.
+  pub fn worker_chunk_loading(&self, output_module: bool) -> ChunkLoading {
+    let chunk_loading = match self.chunk_format(output_module) {
+      Some(ChunkFormat::ArrayPush) if self.import_scripts_in_worker == Some(true) => {
+        Some(ChunkLoadingType::ImportScripts)
+      }
+      Some(ChunkFormat::CommonJs) if self.require == Some(true) => Some(ChunkLoadingType::Require),
+      Some(ChunkFormat::CommonJs) if self.node_builtins == Some(true) => {
+        Some(ChunkLoadingType::AsyncNode)
+      }
+      Some(ChunkFormat::Module) if self.dynamic_import_in_worker == Some(true) => {
+        Some(ChunkLoadingType::Import)
+      }
+      _ => None,
+    };
+    chunk_loading.map_or(ChunkLoading::Disable, ChunkLoading::Enable)
+  }
+
+  /// Default of `output.wasmLoading` and `output.workerWasmLoading`.
+  pub fn wasm_loading(&self, output_module: bool) -> WasmLoading {
+    if self.fetch_wasm == Some(true) {
+      WasmLoading::Enable(WasmLoadingType::Fetch)
+    } else if self.node_builtins == Some(true) {
+      if output_module {
+        WasmLoading::Enable(WasmLoadingType::AsyncNodeModule)
+      } else {
+        WasmLoading::Enable(WasmLoadingType::AsyncNode)
+      }
+    } else {
+      WasmLoading::Disable
+    }
+  }
+
+  /// Default of `output.globalObject`.
+  pub fn global_object(&self) -> &'static str {
+    if self.global == Some(true) {
+      "global"
+    } else if self.global_this == Some(true) {
+      "globalThis"
+    } else {
+      "self"
+    }
+  }
+
+  /// Default of `output.chunkFormat`, `None` when the environment has no chunk format available.
+  pub fn chunk_format(&self, output_module: bool) -> Option<ChunkFormat> {
+    if output_module {
+      if self.dynamic_import == Some(true) {
+        Some(ChunkFormat::Module)
+      } else if self.document == Some(true) {
+        Some(ChunkFormat::ArrayPush)
+      } else {
+        None
+      }
+    } else if self.document == Some(true) {
+      Some(ChunkFormat::ArrayPush)
+    } else if self.require == Some(true) || self.node_builtins == Some(true) {
+      Some(ChunkFormat::CommonJs)
+    } else if self.import_scripts == Some(true) {
+      Some(ChunkFormat::ArrayPush)
+    } else {
+      None
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkFormat {
+  ArrayPush,
+  CommonJs,
+  Module,
+}
+
 #[derive(Debug, Clone)]
 pub struct Target {
   pub es_version: TargetEsVersion,
+  pub properties: TargetProperties,
 }
 
 impl Target {
-  pub fn new(args: &Vec<String>) -> Result<Target> {
+  pub fn new(args: &Vec<String>, context: &Context) -> Result<Target> {
     let mut es_version = TargetEsVersion::None;
+    let mut properties = vec![];
 
     for item in args {
       let item = item.as_str();
-      if item.starts_with("es") || item == "browserslist" {
+      if item.starts_with("es") || item == "browserslist" || item.starts_with("browserslist:") {
         // es version
         if !es_version.is_none() {
           return Err(error!("Target es version conflict"));
         }
         let version = match item {
-          "browserslist" => TargetEsVersion::BrowsersList,
           "es3" => TargetEsVersion::Esx(EsVersion::Es3),
           "es5" => TargetEsVersion::Esx(EsVersion::Es5),
           "es2015" => TargetEsVersion::Esx(EsVersion::Es2015),
@@ -46,15 +259,657 @@ impl Target {
           "es2020" => TargetEsVersion::Esx(EsVersion::Es2020),
           "es2021" => TargetEsVersion::Esx(EsVersion::Es2021),
           "es2022" => TargetEsVersion::Esx(EsVersion::Es2022),
+          _ if item.starts_with("browserslist") => TargetEsVersion::BrowsersList,
           _ => {
             return Err(error!("Unknown target es version {}", item));
           }
         };
         es_version = version;
-        continue;
       }
+      properties.push(get_target_properties(item, context)?);
     }
 
-    Ok(Target { es_version })
+    Ok(Target {
+      es_version,
+      properties: TargetProperties::merge(&properties),
+    })
+  }
+}
+
+static BROWSERSLIST_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^browserslist(?::(.+))?$").expect("invalid regexp"));
+static NODE_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^(async-)?node(?:(\d+)(?:\.(\d+))?)?$").expect("invalid regexp"));
+static ELECTRON_REGEX: Lazy<Regex> = Lazy::new(|| {
+  Regex::new(r"^electron(?:(\d+)(?:\.(\d+))?)?-(main|preload|renderer)$").expect("invalid regexp")
+});
+static NWJS_REGEX: Lazy<Regex> = Lazy::new(|| {
+  Regex::new(r"^(?:nwjs|node-webkit)(?:(\d+)(?:\.(\d+))?)?$").expect("invalid regexp")
+});
+static ES_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^es(\d+)$").expect("invalid regexp"));
+
+const SUPPORTED_TARGETS: &str = "* browserslist / browserslist:env / browserslist:query / browserslist:path-to-config / browserslist:path-to-config:env: Resolve features from browserslist.
+* web: Web browser.
+* webworker: Web Worker, SharedWorker or Service Worker.
+* [async-]node[X[.Y]]: Node.js in version X.Y. The 'async-' prefix will load chunks asynchronously via 'fs' and 'vm' instead of 'require()'. Examples: node14.5, async-node10.
+* electron[X[.Y]]-main/preload/renderer: Electron in version X.Y. Script is running in main, preload resp. renderer context.
+* nwjs[X[.Y]] / node-webkit[X[.Y]]: NW.js in version X.Y.
+* esX: EcmaScript in this version. Examples: es2020, es5.";
+
+/// Returns a checker of whether the version of the target is at least `v_major.v_minor`, the
+/// checker returns `None` when the target has no version.
+fn version_dependent(
+  major: Option<&str>,
+  minor: Option<&str>,
+) -> impl Fn(u32, u32) -> Option<bool> {
+  let major = major.and_then(|major| major.parse::<u32>().ok());
+  let minor = minor
+    .and_then(|minor| minor.parse::<u32>().ok())
+    .unwrap_or(0);
+  move |v_major, v_minor| {
+    major.map(|major| major > v_major || (major == v_major && minor >= v_minor))
+  }
+}
+
+fn get_target_properties(target: &str, context: &Context) -> Result<TargetProperties> {
+  if let Some(caps) = BROWSERSLIST_REGEX.captures(target) {
+    let browsers = load_browserslist(caps.get(1).map(|m| m.as_str().trim()), context)?;
+    return Ok(resolve_browserslist(&browsers));
+  }
+  if target == "web" {
+    return Ok(TargetProperties {
+      web: Some(true),
+      browser: Some(true),
+      webworker: None,
+      node: Some(false),
+      electron: Some(false),
+      nwjs: Some(false),
+
+      document: Some(true),
+      import_scripts_in_worker: Some(true),
+      fetch_wasm: Some(true),
+      node_builtins: Some(false),
+      import_scripts: Some(false),
+      require: Some(false),
+      global: Some(false),
+      ..Default::default()
+    });
+  }
+  if target == "webworker" {
+    return Ok(TargetProperties {
+      web: Some(true),
+      browser: Some(true),
+      webworker: Some(true),
+      node: Some(false),
+      electron: Some(false),
+      nwjs: Some(false),
+
+      import_scripts: Some(true),
+      import_scripts_in_worker: Some(true),
+      fetch_wasm: Some(true),
+      node_builtins: Some(false),
+      require: Some(false),
+      document: Some(false),
+      global: Some(false),
+      ..Default::default()
+    });
+  }
+  if let Some(caps) = NODE_REGEX.captures(target) {
+    let major = caps.get(2).map(|m| m.as_str());
+    let v = version_dependent(major, caps.get(3).map(|m| m.as_str()));
+    // see https://node.green/
+    return Ok(TargetProperties {
+      node: Some(true),
+      electron: Some(false),
+      nwjs: Some(false),
+      web: Some(false),
+      webworker: Some(false),
+      browser: Some(false),
+
+      require: Some(caps.get(1).is_none()),
+      node_builtins: Some(true),
+      global: Some(true),
+      document: Some(false),
+      fetch_wasm: Some(false),
+      import_scripts: Some(false),
+      import_scripts_in_worker: Some(false),
+
+      global_this: v(12, 0),
+      r#const: v(6, 0),
+      template_literal: v(4, 0),
+      optional_chaining: v(14, 0),
+      arrow_function: v(6, 0),
+      for_of: v(5, 0),
+      destructuring: v(6, 0),
+      big_int_literal: v(10, 4),
+      dynamic_import: v(12, 17),
+      dynamic_import_in_worker: major.map(|_| false),
+      module: v(12, 17),
+      ..Default::default()
+    });
+  }
+  if let Some(caps) = ELECTRON_REGEX.captures(target) {
+    let major = caps.get(1).map(|m| m.as_str());
+    let v = version_dependent(major, caps.get(2).map(|m| m.as_str()));
+    let context = &caps[3];
+    // see https://node.green/ + https://github.com/electron/releases
+    return Ok(TargetProperties {
+      node: Some(true),
+      electron: Some(true),
+      web: Some(context != "main"),
+      webworker: Some(false),
+      browser: Some(false),
+      nwjs: Some(false),
+
+      electron_main: Some(context == "main"),
+      electron_preload: Some(context == "preload"),
+      electron_renderer: Some(context == "renderer"),
+
+      global: Some(true),
+      node_builtins: Some(true),
+      require: Some(true),
+      document: Some(context == "renderer"),
+      fetch_wasm: Some(context == "renderer"),
+      import_scripts: Some(false),
+      import_scripts_in_worker: Some(true),
+
+      global_this: v(5, 0),
+      r#const: v(1, 1),
+      template_literal: v(1, 1),
+      optional_chaining: v(8, 0),
+      arrow_function: v(1, 1),
+      for_of: v(0, 36),
+      destructuring: v(1, 1),
+      big_int_literal: v(4, 0),
+      dynamic_import: v(11, 0),
+      dynamic_import_in_worker: major.map(|_| false),
+      module: v(11, 0),
+    });
+  }
+  if let Some(caps) = NWJS_REGEX.captures(target) {
+    let major = caps.get(1).map(|m| m.as_str());
+    let v = version_dependent(major, caps.get(2).map(|m| m.as_str()));
+    // see https://node.green/ + https://github.com/nwjs/nw.js/blob/nw48/CHANGELOG.md
+    return Ok(TargetProperties {
+      node: Some(true),
+      web: Some(true),
+      nwjs: Some(true),
+      webworker: None,
+      browser: Some(false),
+      electron: Some(false),
+
+      global: Some(true),
+      node_builtins: Some(true),
+      document: Some(false),
+      import_scripts_in_worker: Some(false),
+      fetch_wasm: Some(false),
+      import_scripts: Some(false),
+      require: Some(false),
+
+      global_this: v(0, 43),
+      r#const: v(0, 15),
+      template_literal: v(0, 13),
+      optional_chaining: v(0, 44),
+      arrow_function: v(0, 15),
+      for_of: v(0, 13),
+      destructuring: v(0, 15),
+      big_int_literal: v(0, 32),
+      dynamic_import: v(0, 43),
+      dynamic_import_in_worker: major.map(|_| false),
+      module: v(0, 43),
+      ..Default::default()
+    });
+  }
+  if let Some(caps) = ES_REGEX.captures(target) {
+    let mut version = caps[1]
+      .parse::<u32>()
+      .map_err(|_| error!("Unknown target es version {}", target))?;
+    if version < 1000 {
+      version += 2009;
+    }
+    return Ok(TargetProperties {
+      r#const: Some(version >= 2015),
+      template_literal: Some(version >= 2015),
+      optional_chaining: Some(version >= 2020),
+      arrow_function: Some(version >= 2015),
+      for_of: Some(version >= 2015),
+      destructuring: Some(version >= 2015),
+      module: Some(version >= 2015),
+      global_this: Some(version >= 2020),
+      big_int_literal: Some(version >= 2020),
+      dynamic_import: Some(version >= 2020),
+      dynamic_import_in_worker: Some(version >= 2020),
+      ..Default::default()
+    });
+  }
+  Err(error!(
+    "Unknown target '{target}'. The following targets are supported:\n{SUPPORTED_TARGETS}"
+  ))
+}
+
+/// Load the browsers of `browserslist`, `browserslist:env`, `browserslist:query`,
+/// `browserslist:path-to-config` or `browserslist:path-to-config:env`.
+fn load_browserslist(input: Option<&str>, context: &Context) -> Result<Vec<String>> {
+  let mut opts = browserslist::Opts::new();
+  opts.path(context.as_str());
+  let distribs = match input {
+    None => browserslist::execute(&opts),
+    Some(input) if Path::new(input).is_absolute() => {
+      // [[C:]/path/to/config][:env]
+      let drive_len = if input.as_bytes().get(1) == Some(&b':') {
+        2
+      } else {
+        0
+      };
+      let (config_path, env) = match input[drive_len..].split_once(':') {
+        Some((path, env)) => (&input[..drive_len + path.len()], Some(env)),
+        None => (input, None),
+      };
+      opts.config(config_path);
+      if let Some(env) = env {
+        opts.env(env);
+      }
+      browserslist::execute(&opts)
+    }
+    Some(input) => {
+      // an environment of the nearest config takes precedence over a query
+      let is_env = !input.contains(|c: char| c.is_whitespace() || c == ',');
+      let from_env = is_env
+        .then(|| {
+          let mut opts = opts.clone();
+          opts.env(input).throw_on_missing(true);
+          browserslist::execute(&opts).ok()
+        })
+        .flatten();
+      match from_env {
+        Some(distribs) => Ok(distribs),
+        None => browserslist::resolve([input], &opts),
+      }
+    }
+  };
+  let distribs = distribs.map_err(|e| {
+    error!(
+      "Failed to resolve browserslist for the 'browserslist' target: {e}.
+See https://github.com/browserslist/browserslist#queries for possible ways to provide a config.
+You can also more options via the 'target' option: 'browserslist' / 'browserslist:env' / 'browserslist:query' / 'browserslist:path-to-config' / 'browserslist:path-to-config:env'"
+    )
+  })?;
+  Ok(
+    distribs
+      .into_iter()
+      .map(|d| format!("{} {}", d.name(), d.version()))
+      .collect(),
+  )
+}
+
+enum RequiredVersion {
+  Major(u32),
+  MajorMinor(u32, u32),
+}
+use RequiredVersion::{Major as V, MajorMinor as VV};
+
+/// Resolve target properties from browsers like `chrome 100` and `node 16.0.0`.
+fn resolve_browserslist(browsers: &[String]) -> TargetProperties {
+  // Checks all against a version number
+  let raw_checker = |versions: &[(&str, RequiredVersion)]| {
+    browsers.iter().all(|browser| {
+      let Some((name, parsed_version)) = browser.split_once(' ') else {
+        return false;
+      };
+      let Some((_, required_version)) = versions.iter().find(|(n, _)| *n == name) else {
+        return false;
+      };
+      // safari TP supports all features for normal safari
+      let (parsed_major, parsed_minor) = if parsed_version == "TP" {
+        (u32::MAX, u32::MAX)
+      } else {
+        // ranges like `15.2-15.3` are checked by the lower bound
+        let mut parts = parsed_version
+          .split('-')
+          .next()
+          .unwrap_or(parsed_version)
+          .split('.');
+        let major = parts.next().and_then(|v| v.parse().ok()).unwrap_or(0);
+        let minor = parts.next().and_then(|v| v.parse().ok()).unwrap_or(0);
+        (major, minor)
+      };
+      match required_version {
+        V(major) => parsed_major >= *major,
+        VV(major, minor) => {
+          if *major == parsed_major {
+            parsed_minor >= *minor
+          } else {
+            parsed_major > *major
+          }
+        }
+      }
+    })
+  };
+  let any_node = browsers.iter().any(|b| b.starts_with("node "));
+  let any_browser = browsers.iter().any(|b| !b.starts_with("node"));
+  let browser_property = if !any_browser {
+    Some(false)
+  } else if any_node {
+    None
+  } else {
+    Some(true)
+  };
+  let node_property = if !any_node {
+    Some(false)
+  } else if any_browser {
+    None
+  } else {
+    Some(true)
+  };
+  // Internet Explorer Mobile, Blackberry browser and Opera Mini are very old browsers, they do not support new features
+  let es6_dynamic_import = raw_checker(&[
+    ("chrome", V(63)),
+    ("and_chr", V(63)),
+    ("edge", V(79)),
+    ("firefox", V(67)),
+    ("and_ff", V(67)),
+    // ie: Not supported
+    ("opera", V(50)),
+    ("op_mob", V(46)),
+    ("safari", VV(11, 1)),
+    ("ios_saf", VV(11, 3)),
+    ("samsung", VV(8, 2)),
+    ("android", V(63)),
+    ("and_qq", VV(10, 4)),
+    // baidu: Not supported
+    // and_uc: Not supported
+    // kaios: Not supported
+    ("node", VV(12, 17)),
+  ]);
+
+  TargetProperties {
+    r#const: Some(raw_checker(&[
+      ("chrome", V(49)),
+      ("and_chr", V(49)),
+      ("edge", V(12)),
+      // Prior to Firefox 13, const is implemented, but re-assignment is not failing.
+      // Prior to Firefox 46, a TypeError was thrown on redeclaration instead of a SyntaxError.
+      ("firefox", V(36)),
+      ("and_ff", V(36)),
+      // Not supported in for-in and for-of loops
+      // ie: Not supported
+      ("opera", V(36)),
+      ("op_mob", V(36)),
+      ("safari", VV(10, 0)),
+      ("ios_saf", VV(10, 0)),
+      // Before 5.0 supported correctly in strict mode, otherwise supported without block scope
+      ("samsung", VV(5, 0)),
+      ("android", V(37)),
+      ("and_qq", VV(10, 4)),
+      // Supported correctly in strict mode, otherwise supported without block scope
+      // baidu: Not supported
+      ("and_uc", VV(12, 12)),
+      ("kaios", VV(2, 5)),
+      ("node", VV(6, 0)),
+    ])),
+    arrow_function: Some(raw_checker(&[
+      ("chrome", V(45)),
+      ("and_chr", V(45)),
+      ("edge", V(12)),
+      // The initial implementation of arrow functions in Firefox made them automatically strict.
+      // Prior to Firefox 39, a line terminator was incorrectly allowed after arrow function arguments.
+      ("firefox", V(39)),
+      ("and_ff", V(39)),
+      // ie: Not supported,
+      ("opera", V(32)),
+      ("op_mob", V(32)),
+      ("safari", V(10)),
+      ("ios_saf", V(10)),
+      ("samsung", VV(5, 0)),
+      ("android", V(45)),
+      ("and_qq", VV(10, 4)),
+      ("baidu", VV(7, 12)),
+      ("and_uc", VV(12, 12)),
+      ("kaios", VV(2, 5)),
+      ("node", VV(6, 0)),
+    ])),
+    for_of: Some(raw_checker(&[
+      ("chrome", V(38)),
+      ("and_chr", V(38)),
+      ("edge", V(12)),
+      // Prior to Firefox 51, using the for...of loop construct with the const keyword threw a SyntaxError.
+      ("firefox", V(51)),
+      ("and_ff", V(51)),
+      // ie: Not supported,
+      ("opera", V(25)),
+      ("op_mob", V(25)),
+      ("safari", V(7)),
+      ("ios_saf", V(7)),
+      ("samsung", VV(3, 0)),
+      ("android", V(38)),
+      // and_qq: Unknown support
+      // baidu: Unknown support
+      // and_uc: Unknown support
+      // kaios: Unknown support
+      ("node", VV(0, 12)),
+    ])),
+    destructuring: Some(raw_checker(&[
+      ("chrome", V(49)),
+      ("and_chr", V(49)),
+      ("edge", V(14)),
+      ("firefox", V(41)),
+      ("and_ff", V(41)),
+      // ie: Not supported,
+      ("opera", V(36)),
+      ("op_mob", V(36)),
+      ("safari", V(8)),
+      ("ios_saf", V(8)),
+      ("samsung", VV(5, 0)),
+      ("android", V(49)),
+      // and_qq: Unknown support
+      // baidu: Unknown support
+      // and_uc: Unknown support
+      // kaios: Unknown support
+      ("node", VV(6, 0)),
+    ])),
+    big_int_literal: Some(raw_checker(&[
+      ("chrome", V(67)),
+      ("and_chr", V(67)),
+      ("edge", V(79)),
+      ("firefox", V(68)),
+      ("and_ff", V(68)),
+      // ie: Not supported,
+      ("opera", V(54)),
+      ("op_mob", V(48)),
+      ("safari", V(14)),
+      ("ios_saf", V(14)),
+      ("samsung", VV(9, 2)),
+      ("android", V(67)),
+      // and_qq: Not supported
+      // baidu: Not supported
+      // and_uc: Not supported
+      // kaios: Not supported
+      ("node", VV(10, 4)),
+    ])),
+    // Support syntax `import` and `export` and no limitations and bugs on Node.js
+    // Not include `export * as namespace`
+    module: Some(raw_checker(&[
+      ("chrome", V(61)),
+      ("and_chr", V(61)),
+      ("edge", V(16)),
+      ("firefox", V(60)),
+      ("and_ff", V(60)),
+      // ie: Not supported,
+      ("opera", V(48)),
+      ("op_mob", V(45)),
+      ("safari", VV(10, 1)),
+      ("ios_saf", VV(10, 3)),
+      ("samsung", VV(8, 0)),
+      ("android", V(61)),
+      ("and_qq", VV(10, 4)),
+      // baidu: Not supported
+      // and_uc: Not supported
+      // kaios: Not supported
+      ("node", VV(12, 17)),
+    ])),
+    dynamic_import: Some(es6_dynamic_import),
+    dynamic_import_in_worker: Some(es6_dynamic_import && !any_node),
+    // browserslist does not have info about globalThis
+    // so this is based on mdn-browser-compat-data
+    global_this: Some(raw_checker(&[
+      ("chrome", V(71)),
+      ("and_chr", V(71)),
+      ("edge", V(79)),
+      ("firefox", V(65)),
+      ("and_ff", V(65)),
+      // ie: Not supported,
+      ("opera", V(58)),
+      ("op_mob", V(50)),
+      ("safari", VV(12, 1)),
+      ("ios_saf", VV(12, 2)),
+      ("samsung", VV(10, 1)),
+      ("android", V(71)),
+      // and_qq: Unknown support
+      // baidu: Unknown support
+      // and_uc: Unknown support
+      // kaios: Unknown support
+      ("node", V(12)),
+    ])),
+    optional_chaining: Some(raw_checker(&[
+      ("chrome", V(80)),
+      ("and_chr", V(80)),
+      ("edge", V(80)),
+      ("firefox", V(74)),
+      ("and_ff", V(79)),
+      // ie: Not supported,
+      ("opera", V(67)),
+      ("op_mob", V(64)),
+      ("safari", VV(13, 1)),
+      ("ios_saf", VV(13, 4)),
+      ("samsung", V(13)),
+      ("android", V(80)),
+      // and_qq: Not supported
+      // baidu: Not supported
+      // and_uc: Not supported
+      // kaios: Not supported
+      ("node", V(14)),
+    ])),
+    template_literal: Some(raw_checker(&[
+      ("chrome", V(41)),
+      ("and_chr", V(41)),
+      ("edge", V(13)),
+      ("firefox", V(34)),
+      ("and_ff", V(34)),
+      // ie: Not supported,
+      ("opera", V(29)),
+      ("op_mob", V(64)),
+      ("safari", VV(9, 1)),
+      ("ios_saf", V(9)),
+      ("samsung", V(4)),
+      ("android", V(41)),
+      ("and_qq", VV(10, 4)),
+      ("baidu", VV(7, 12)),
+      ("and_uc", VV(12, 12)),
+      ("kaios", VV(2, 5)),
+      ("node", V(4)),
+    ])),
+    browser: browser_property,
+    electron: Some(false),
+    node: node_property,
+    nwjs: Some(false),
+    web: browser_property,
+    webworker: Some(false),
+
+    document: browser_property,
+    fetch_wasm: browser_property,
+    global: node_property,
+    import_scripts: Some(false),
+    import_scripts_in_worker: Some(true),
+    node_builtins: node_property,
+    require: node_property,
+    ..Default::default()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn target(args: &[&str]) -> Target {
+    Target::new(
+      &args.iter().map(|arg| arg.to_string()).collect(),
+      &Context::default(),
+    )
+    .expect("should be a valid target")
+  }
+
+  #[test]
+  fn should_resolve_web_defaults() {
+    let properties = target(&["web"]).properties;
+    assert_eq!(
+      properties.chunk_loading(false),
+      ChunkLoading::Enable(ChunkLoadingType::Jsonp)
+    );
+    assert_eq!(
+      properties.worker_chunk_loading(false),
+      ChunkLoading::Enable(ChunkLoadingType::ImportScripts)
+    );
+    assert_eq!(properties.global_object(), "self");
+    assert_eq!(
+      target(&["web", "es2020"]).properties.global_object(),
+      "globalThis"
+    );
+  }
+
+  #[test]
+  fn should_resolve_node_and_electron_defaults() {
+    let node = target(&["node14.5"]).properties;
+    assert_eq!(node.dynamic_import, Some(true));
+    assert_eq!(node.optional_chaining, Some(true));
+    assert_eq!(
+      node.chunk_loading(false),
+      ChunkLoading::Enable(ChunkLoadingType::Require)
+    );
+    assert_eq!(node.global_object(), "global");
+    assert_eq!(
+      target(&["async-node"]).properties.chunk_loading(false),
+      ChunkLoading::Enable(ChunkLoadingType::AsyncNode)
+    );
+    assert_eq!(target(&["node10"]).properties.dynamic_import, Some(false));
+    assert_eq!(target(&["node"]).properties.dynamic_import, None);
+
+    let renderer = target(&["electron20-renderer"]).properties;
+    assert_eq!(renderer.electron_renderer, Some(true));
+    assert_eq!(
+      renderer.chunk_loading(false),
+      ChunkLoading::Enable(ChunkLoadingType::Jsonp)
+    );
+    let main = target(&["electron20-main"]).properties;
+    assert_eq!(
+      main.chunk_loading(false),
+      ChunkLoading::Enable(ChunkLoadingType::Require)
+    );
+  }
+
+  #[test]
+  fn should_merge_conflicting_targets() {
+    let properties = target(&["web", "node"]).properties;
+    assert_eq!(properties.node, None);
+    assert_eq!(properties.node_builtins, None);
+    assert_eq!(properties.fetch_wasm, None);
+  }
+
+  #[test]
+  fn should_resolve_browserslist_query() {
+    let properties = target(&["browserslist:chrome 100"]).properties;
+    assert!(target(&["browserslist:chrome 100"])
+      .es_version
+      .is_browsers_list());
+    assert_eq!(properties.browser, Some(true));
+    assert_eq!(properties.global_this, Some(true));
+    assert_eq!(properties.dynamic_import, Some(true));
+    let properties = target(&["browserslist:ie 11"]).properties;
+    assert_eq!(properties.arrow_function, Some(false));
+    assert_eq!(properties.global_object(), "self");
+  }
+
+  #[test]
+  fn should_reject_unknown_target() {
+    assert!(Target::new(&vec!["unknown".to_string()], &Context::default()).is_err());
   }
 }
@@ -0,0 +1,56 @@
+use std::{path::Path, time::Duration};
+
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+  /// Changes in this period after the last change are aggregated into one rebuild.
+  pub aggregate_timeout: Duration,
+  /// Glob patterns of paths which are not watched, a path is ignored when itself or any of its
+  /// parent directories matches.
+  pub ignored: Vec<String>,
+  /// Poll the file system with the interval instead of relying on native file system events,
+  /// which are not available on some network file systems.
+  pub poll: Option<Duration>,
+}
+
+impl Default for WatchOptions {
+  fn default() -> Self {
+    Self {
+      aggregate_timeout: Duration::from_millis(20),
+      ignored: vec![],
+      poll: None,
+    }
+  }
+}
+
+impl WatchOptions {
+  pub fn is_ignored(&self, path: &Path) -> bool {
+    if self.ignored.is_empty() {
+      return false;
+    }
+    path.ancestors().any(|path| {
+      let path = path.to_string_lossy().replace('\\', "/");
+      self
+        .ignored
+        .iter()
+        .any(|pattern| glob_match::glob_match(pattern, &path))
+    })
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn should_ignore_paths_inside_matched_directories() {
+    let options = WatchOptions {
+      ignored: vec!["**/node_modules".to_string(), "**/*.log".to_string()],
+      ..Default::default()
+    };
+    assert!(options.is_ignored(Path::new("/project/node_modules")));
+    assert!(options.is_ignored(Path::new("/project/node_modules/react/index.js")));
+    assert!(options.is_ignored(Path::new("/project/logs/build.log")));
+    assert!(!options.is_ignored(Path::new("/project/src/index.js")));
+    assert!(!WatchOptions::default().is_ignored(Path::new("/project/node_modules")));
+  }
+}
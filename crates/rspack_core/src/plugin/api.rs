@@ -14,7 +14,8 @@ use crate::{
   ModuleType, NormalModule, NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs,
   NormalModuleCreateData, OptimizeChunksArgs, ParserAndGenerator, PluginContext, ProcessAssetsArgs,
   RenderArgs, RenderChunkArgs, RenderManifestArgs, RenderModuleContentArgs, RenderStartupArgs,
-  Resolver, RuntimeRequirementsInTreeArgs, SourceType, ThisCompilationArgs,
+  ResolveArgs, ResolveResult, Resolver, RuntimeRequirementsInTreeArgs, SourceType,
+  ThisCompilationArgs,
 };
 
 // use anyhow::{Context, Result};
@@ -28,6 +29,7 @@ pub type PluginFactorizeHookOutput = Result<Option<ModuleFactoryResult>>;
 pub type PluginNormalModuleFactoryCreateModuleHookOutput = Result<Option<BoxModule>>;
 pub type PluginNormalModuleFactoryModuleHookOutput = Result<BoxModule>;
 pub type PluginNormalModuleFactoryResolveForSchemeOutput = Result<(ResourceData, bool)>;
+pub type PluginNormalModuleFactoryResolveOutput = Result<Option<ResolveResult>>;
 pub type PluginNormalModuleFactoryBeforeResolveOutput = Result<Option<bool>>;
 pub type PluginNormalModuleFactoryAfterResolveOutput = Result<Option<bool>>;
 pub type PluginContentHashHookOutput = Result<Option<(SourceType, RspackHashDigest)>>;
@@ -158,6 +160,16 @@ pub trait Plugin: Debug + Send + Sync {
     Ok((args, false))
   }
 
+  /// Resolve a request without scheme before the resolver, e.g. a module which doesn't exist on disk.
+  /// It behaves like a BailHook hook.
+  async fn normal_module_factory_resolve(
+    &self,
+    _ctx: PluginContext,
+    _args: &ResolveArgs<'_>,
+  ) -> PluginNormalModuleFactoryResolveOutput {
+    Ok(None)
+  }
+
   async fn content_hash(
     &self,
     _ctx: PluginContext,
@@ -392,6 +404,15 @@ pub trait Plugin: Debug + Send + Sync {
     Ok(())
   }
 
+  /// Called after all stages of `process_assets`, like `afterProcessAssets` of webpack.
+  async fn after_process_assets(
+    &self,
+    _ctx: PluginContext,
+    _args: ProcessAssetsArgs<'_>,
+  ) -> PluginProcessAssetsOutput {
+    Ok(())
+  }
+
   async fn optimize_chunks(
     &self,
     _ctx: PluginContext,
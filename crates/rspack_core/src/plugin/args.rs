@@ -9,7 +9,7 @@ use rspack_sources::BoxSource;
 use rustc_hash::FxHashSet as HashSet;
 
 use crate::{
-  Chunk, ChunkInitFragments, ChunkUkey, Compilation, Context, ContextModuleFactory,
+  BoxDependency, Chunk, ChunkInitFragments, ChunkUkey, Compilation, Context, ContextModuleFactory,
   DependencyCategory, DependencyType, ErrorSpan, FactoryMeta, ModuleDependency, ModuleGraphModule,
   ModuleIdentifier, NormalModuleFactory, Resolve, RuntimeGlobals, SharedPluginDriver, Stats,
 };
@@ -75,7 +75,9 @@ pub struct FactorizeArgs<'me> {
 
 #[derive(Debug)]
 pub struct NormalModuleCreateData<'a> {
+  pub dependency: &'a BoxDependency,
   pub dependency_type: DependencyType,
+  pub issuer_identifier: Option<ModuleIdentifier>,
   pub resolve_data_request: &'a str,
   pub resource_resolve_data: ResourceData,
   pub context: Context,
@@ -20,12 +20,13 @@ use crate::{
   PluginCompilationHookOutput, PluginContext, PluginFactorizeHookOutput,
   PluginJsChunkHashHookOutput, PluginMakeHookOutput, PluginNormalModuleFactoryAfterResolveOutput,
   PluginNormalModuleFactoryBeforeResolveOutput, PluginNormalModuleFactoryCreateModuleHookOutput,
-  PluginNormalModuleFactoryModuleHookOutput, PluginProcessAssetsOutput,
-  PluginRenderChunkHookOutput, PluginRenderHookOutput, PluginRenderManifestHookOutput,
-  PluginRenderModuleContentOutput, PluginRenderStartupHookOutput,
+  PluginNormalModuleFactoryModuleHookOutput, PluginNormalModuleFactoryResolveOutput,
+  PluginProcessAssetsOutput, PluginRenderChunkHookOutput, PluginRenderHookOutput,
+  PluginRenderManifestHookOutput, PluginRenderModuleContentOutput, PluginRenderStartupHookOutput,
   PluginRuntimeRequirementsInTreeOutput, PluginThisCompilationHookOutput, ProcessAssetsArgs,
   RenderArgs, RenderChunkArgs, RenderManifestArgs, RenderModuleContentArgs, RenderStartupArgs,
-  Resolver, ResolverFactory, RuntimeRequirementsInTreeArgs, Stats, ThisCompilationArgs,
+  ResolveArgs, Resolver, ResolverFactory, RuntimeRequirementsInTreeArgs, Stats,
+  ThisCompilationArgs,
 };
 
 pub struct PluginDriver {
@@ -425,6 +426,22 @@ impl PluginDriver {
     Ok(args)
   }
 
+  pub async fn normal_module_factory_resolve(
+    &self,
+    args: &ResolveArgs<'_>,
+  ) -> PluginNormalModuleFactoryResolveOutput {
+    for plugin in &self.plugins {
+      tracing::trace!("running normal_module_factory_resolve:{}", plugin.name());
+      if let Some(result) = plugin
+        .normal_module_factory_resolve(PluginContext::new(), args)
+        .await?
+      {
+        return Ok(Some(result));
+      }
+    }
+    Ok(None)
+  }
+
   #[instrument(name = "plugin:additional_chunk_runtime_requirements", skip_all)]
   pub fn additional_chunk_runtime_requirements(
     &self,
@@ -500,6 +517,7 @@ impl PluginDriver {
     run_stage!(process_assets_stage_optimize_transfer);
     run_stage!(process_assets_stage_analyse);
     run_stage!(process_assets_stage_report);
+    run_stage!(after_process_assets);
     Ok(())
   }
 
@@ -3,7 +3,7 @@ use std::{hash::BuildHasherDefault, sync::Arc};
 use dashmap::DashMap;
 use rustc_hash::FxHasher;
 
-use super::resolver_impl::Resolver;
+use super::resolver_impl::{Resolver, ResolverFileSystem};
 use crate::{DependencyCategory, Resolve};
 
 #[derive(Debug, Hash, Eq, PartialEq, Clone)]
@@ -35,9 +35,17 @@ impl ResolverFactory {
   }
 
   pub fn new(new_resolver: bool, options: Resolve) -> Self {
+    Self::new_with_file_system(new_resolver, options, ResolverFileSystem::default())
+  }
+
+  pub fn new_with_file_system(
+    new_resolver: bool,
+    options: Resolve,
+    fs: ResolverFileSystem,
+  ) -> Self {
     Self {
       base_options: options.clone(),
-      resolver: Resolver::new(new_resolver, options),
+      resolver: Resolver::new_with_file_system(new_resolver, options, fs),
       resolvers: Default::default(),
     }
   }
@@ -1,13 +1,18 @@
 mod factory;
 mod resolver_impl;
 
-use std::{fmt, path::PathBuf};
+use std::{
+  fmt,
+  path::{Path, PathBuf},
+};
 
+use indexmap::IndexSet;
 use rspack_error::Error;
 use rspack_loader_runner::DescriptionData;
+use sugar_path::SugarPath;
 
 pub use self::factory::{ResolveOptionsWithDependencyType, ResolverFactory};
-pub use self::resolver_impl::{ResolveInnerOptions, Resolver};
+pub use self::resolver_impl::{ResolveInnerOptions, Resolver, ResolverFileSystem};
 use crate::{ResolveArgs, SharedPluginDriver};
 
 /// A successful path resolution or an ignored path.
@@ -72,13 +77,121 @@ pub async fn resolve(
   let base_dir = base_dir.as_ref();
 
   let resolver = plugin_driver.resolver_factory.get(dep);
-  let result = resolver
-    .resolve(base_dir, args.specifier)
-    .map_err(|error| error.into_resolve_error(&args));
+  let result = resolver.resolve(base_dir, args.specifier);
 
   let (file_dependencies, missing_dependencies) = resolver.dependencies();
   args.file_dependencies.extend(file_dependencies);
   args.missing_dependencies.extend(missing_dependencies);
+  match &result {
+    Ok(ResolveResult::Resource(resource)) if is_path_request(args.specifier) => {
+      // a relative or absolute request is only looked up in one place, the candidates tried
+      // before the resolved one would take precedence when they are created
+      args.missing_dependencies.extend(
+        missing_paths(&resolver, base_dir, args.specifier, args.resolve_to_context)
+          .into_iter()
+          .take_while(|path| !resource.path.starts_with(path)),
+      );
+    }
+    Ok(_) => {}
+    Err(_) => {
+      args.missing_dependencies.extend(missing_paths(
+        &resolver,
+        base_dir,
+        args.specifier,
+        args.resolve_to_context,
+      ));
+    }
+  }
+
+  result.map_err(|error| error.into_resolve_error(&args))
+}
+
+/// Whether the request is a relative or absolute path, rather than a module looked up in the
+/// `modules` directories.
+fn is_path_request(specifier: &str) -> bool {
+  let request = specifier
+    .split_once('?')
+    .map_or(specifier, |(request, _query)| request);
+  request == "."
+    || request == ".."
+    || request.starts_with("./")
+    || request.starts_with("../")
+    || Path::new(request).is_absolute()
+}
 
-  result
+/// Paths which may make a failed request resolvable when they are created, with closer `modules`
+/// directories and earlier extensions first.
+///
+/// The resolvers don't report the paths they tried, so the paths are guessed from the request
+/// and the `extensions`, `main_files` and `modules` options. Targets of aliases and exports
+/// fields are not covered.
+fn missing_paths(
+  resolver: &Resolver,
+  context: &Path,
+  specifier: &str,
+  resolve_to_context: bool,
+) -> Vec<PathBuf> {
+  let request = specifier
+    .split_once('?')
+    .map_or(specifier, |(request, _query)| request);
+  let options = resolver.options();
+  let is_relative =
+    request == "." || request == ".." || request.starts_with("./") || request.starts_with("../");
+  let is_absolute = Path::new(request).is_absolute();
+  let bases: IndexSet<PathBuf> = if is_absolute {
+    IndexSet::from([Path::new(request).normalize().into_owned()])
+  } else if is_relative {
+    IndexSet::from([context.join(request).normalize().into_owned()])
+  } else if request.is_empty() || request.starts_with('#') || request.contains(':') {
+    // package imports and requests with schemes are not resolved from the file system
+    IndexSet::new()
+  } else {
+    // absolute `modules` entries are looked up once, relative ones in every ancestor
+    let (absolute_modules, relative_modules): (Vec<_>, Vec<_>) = options
+      .modules()
+      .partition(|modules| Path::new(modules).is_absolute());
+    context
+      .ancestors()
+      .flat_map(|directory| {
+        relative_modules
+          .iter()
+          .map(move |modules| directory.join(modules).join(request))
+      })
+      .chain(
+        absolute_modules
+          .iter()
+          .map(|modules| Path::new(modules).join(request)),
+      )
+      .collect()
+  };
+  if resolve_to_context {
+    return bases.into_iter().collect();
+  }
+
+  let with_extension = |path: &Path, extension: &str| {
+    let mut path = path.as_os_str().to_owned();
+    path.push(extension);
+    PathBuf::from(path)
+  };
+  let mut paths = IndexSet::new();
+  for base in bases {
+    paths.extend(
+      options
+        .extensions()
+        .map(|extension| with_extension(&base, extension)),
+    );
+    for main_file in options.main_files() {
+      let main_file = base.join(main_file);
+      paths.extend(
+        options
+          .extensions()
+          .map(|extension| with_extension(&main_file, extension)),
+      );
+    }
+    if !is_relative && !is_absolute {
+      paths.insert(base.join("package.json"));
+    }
+    paths.insert(base);
+  }
+  paths.into_iter().collect()
 }
@@ -1,5 +1,5 @@
 use std::{
-  fmt,
+  fmt, io,
   path::{Path, PathBuf},
   sync::Arc,
 };
@@ -7,6 +7,7 @@ use std::{
 use rspack_error::{
   error, miette::miette, DiagnosticError, Error, ErrorExt, Severity, TraceableError,
 };
+use rspack_fs::{NativeFileSystem, ReadableFileSystem};
 use rspack_loader_runner::DescriptionData;
 
 use super::{ResolveResult, Resource};
@@ -76,19 +77,81 @@ impl<'a> ResolveInnerOptions<'a> {
   }
 }
 
+/// Lets [oxc_resolver] read through a [ReadableFileSystem], the native file system by default.
+#[derive(Clone)]
+pub struct ResolverFileSystem(Arc<dyn ReadableFileSystem + Send + Sync>);
+
+impl ResolverFileSystem {
+  pub fn new(fs: Arc<dyn ReadableFileSystem + Send + Sync>) -> Self {
+    Self(fs)
+  }
+}
+
+impl Default for ResolverFileSystem {
+  fn default() -> Self {
+    Self(Arc::new(NativeFileSystem))
+  }
+}
+
+fn to_io_error(error: rspack_fs::Error) -> io::Error {
+  match error {
+    rspack_fs::Error::Io(e) => e,
+  }
+}
+
+fn to_oxc_metadata(metadata: rspack_fs::FileMetadata) -> oxc_resolver::FileMetadata {
+  oxc_resolver::FileMetadata::new(metadata.is_file, metadata.is_directory, metadata.is_symlink)
+}
+
+impl oxc_resolver::FileSystem for ResolverFileSystem {
+  fn read_to_string(&self, path: &Path) -> io::Result<String> {
+    let content = self.0.read(path).map_err(to_io_error)?;
+    String::from_utf8(content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+  }
+
+  fn metadata(&self, path: &Path) -> io::Result<oxc_resolver::FileMetadata> {
+    self
+      .0
+      .metadata(path)
+      .map(to_oxc_metadata)
+      .map_err(to_io_error)
+  }
+
+  fn symlink_metadata(&self, path: &Path) -> io::Result<oxc_resolver::FileMetadata> {
+    self
+      .0
+      .symlink_metadata(path)
+      .map(to_oxc_metadata)
+      .map_err(to_io_error)
+  }
+
+  fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+    self.0.canonicalize(path).map_err(to_io_error)
+  }
+}
+
 /// Proxy to [nodejs_resolver::Resolver] or [oxc_resolver::Resolver]
 ///
 /// Internal caches are shared.
 #[derive(Debug)]
 pub enum Resolver {
   NodejsResolver(nodejs_resolver::Resolver, Arc<nodejs_resolver::Cache>),
-  OxcResolver(oxc_resolver::Resolver),
+  OxcResolver(oxc_resolver::ResolverGeneric<ResolverFileSystem>),
 }
 
 impl Resolver {
   pub fn new(new_resolver: bool, options: Resolve) -> Self {
+    Self::new_with_file_system(new_resolver, options, ResolverFileSystem::default())
+  }
+
+  /// Only the new resolver reads through `fs`, the old one always uses the native file system.
+  pub fn new_with_file_system(
+    new_resolver: bool,
+    options: Resolve,
+    fs: ResolverFileSystem,
+  ) -> Self {
     if new_resolver {
-      Self::new_oxc_resolver(options)
+      Self::new_oxc_resolver(options, fs)
     } else {
       Self::new_nodejs_resolver(options)
     }
@@ -102,9 +165,9 @@ impl Resolver {
     Self::NodejsResolver(resolver, cache)
   }
 
-  fn new_oxc_resolver(options: Resolve) -> Self {
+  fn new_oxc_resolver(options: Resolve, fs: ResolverFileSystem) -> Self {
     let options = to_oxc_resolver_options(options, false, DependencyCategory::Unknown);
-    let resolver = oxc_resolver::Resolver::new(options);
+    let resolver = oxc_resolver::ResolverGeneric::new_with_file_system(fs, options);
     Self::OxcResolver(resolver)
   }
 
@@ -477,3 +540,34 @@ fn map_resolver_error(is_recursion: bool, args: &ResolveArgs<'_>) -> Error {
   )
   .into()
 }
+
+#[cfg(test)]
+mod test {
+  use std::{path::Path, sync::Arc};
+
+  use rspack_fs::{MemoryFileSystem, WritableFileSystem};
+
+  use super::{Resolver, ResolverFileSystem};
+  use crate::{Resolve, ResolveResult};
+
+  #[test]
+  fn should_resolve_through_custom_file_system() {
+    let fs = MemoryFileSystem::new();
+    fs.create_dir_all("/src").expect("should create dir");
+    fs.write("/src/a.js", "").expect("should write file");
+
+    let resolver = Resolver::new_with_file_system(
+      true,
+      Resolve {
+        extensions: Some(vec![".js".to_string()]),
+        ..Default::default()
+      },
+      ResolverFileSystem::new(Arc::new(fs)),
+    );
+    let Ok(ResolveResult::Resource(resource)) = resolver.resolve(Path::new("/src"), "./a") else {
+      panic!("should resolve ./a");
+    };
+    assert_eq!(resource.path, Path::new("/src/a.js"));
+    assert!(resolver.resolve(Path::new("/src"), "./b").is_err());
+  }
+}
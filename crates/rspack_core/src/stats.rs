@@ -3,13 +3,20 @@ use itertools::Itertools;
 use rspack_error::emitter::{DiagnosticDisplay, DiagnosticDisplayer};
 use rspack_error::emitter::{StdioDiagnosticDisplay, StringDiagnosticDisplay};
 use rspack_error::Result;
+use rspack_identifier::IdentifierSet;
 use rspack_sources::Source;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use serde::{ser::SerializeMap, Serialize, Serializer};
 
-use crate::{get_chunk_from_ukey, get_chunk_group_from_ukey};
-use crate::{BoxModule, BoxRuntimeModule, Chunk};
+use crate::tree_shaking::BailoutFlag;
+use crate::Module;
+use crate::{get_chunk_from_ukey, get_chunk_group_from_ukey, PublicPath};
+use crate::{BoxModule, BoxRuntimeModule, Chunk, ProvidedExports, StatsOptions, UsedExports};
 use crate::{ChunkGroupUkey, Compilation, LogType, ModuleIdentifier, ModuleType, SourceType};
 
+mod printer;
+pub use printer::format_size;
+
 #[derive(Debug, Clone)]
 pub struct Stats<'compilation> {
   pub compilation: &'compilation Compilation,
@@ -32,9 +39,100 @@ impl<'compilation> Stats<'compilation> {
     let errors = displayer.emit_batch_diagnostic(self.compilation.get_errors())?;
     Ok(format!("{warnings}{errors}"))
   }
+
+  /// Collect the fields enabled by `options`, which serializes to the same shape as
+  /// `stats.toJson()` of webpack.
+  pub fn to_json(&self, options: &StatsOptions) -> Result<StatsCompilation<'compilation>> {
+    let fields = StatsModuleFields {
+      reasons: options.reasons(),
+      module_assets: options.module_assets(),
+      nested_modules: options.nested_modules(),
+      source: options.source(),
+      optimization_bailout: options.optimization_bailout(),
+      used_exports: options.used_exports(),
+      provided_exports: options.provided_exports(),
+    };
+    let (assets, assets_by_chunk_name) = if options.assets() {
+      let (assets, assets_by_chunk_name) = self.get_assets();
+      (Some(assets), Some(assets_by_chunk_name))
+    } else {
+      (None, None)
+    };
+    let modules = options
+      .modules()
+      .then(|| self.collect_modules(fields, options.runtime_modules()))
+      .transpose()?;
+    let chunks = options
+      .chunks()
+      .then(|| self.collect_chunks(options.chunk_modules(), options.chunk_relations(), fields))
+      .transpose()?;
+    let errors = options.errors().then(|| self.get_errors());
+    let warnings = options.warnings().then(|| self.get_warnings());
+
+    Ok(StatsCompilation {
+      hash: options
+        .hash()
+        .then(|| self.get_hash().map(ToString::to_string))
+        .flatten(),
+      time: options
+        .timings()
+        .then(|| {
+          self
+            .compilation
+            .end_time
+            .map(|end_time| end_time.saturating_sub(self.compilation.start_time))
+        })
+        .flatten(),
+      built_at: options
+        .built_at()
+        .then_some(self.compilation.end_time)
+        .flatten(),
+      public_path: options.public_path().then(|| {
+        match &self.compilation.options.output.public_path {
+          PublicPath::String(public_path) => public_path.clone(),
+          PublicPath::Auto => "auto".to_string(),
+        }
+      }),
+      output_path: options.output_path().then(|| {
+        self
+          .compilation
+          .options
+          .output
+          .path
+          .to_string_lossy()
+          .to_string()
+      }),
+      assets_by_chunk_name,
+      assets,
+      chunks,
+      modules,
+      entrypoints: options.entrypoints().then(|| self.get_entrypoints()),
+      named_chunk_groups: options
+        .chunk_groups()
+        .then(|| self.get_named_chunk_groups()),
+      errors_count: options
+        .errors_count()
+        .then(|| self.compilation.get_errors().count()),
+      errors,
+      warnings_count: options
+        .warnings_count()
+        .then(|| self.compilation.get_warnings().count()),
+      warnings,
+      children: Vec::new(),
+    })
+  }
+
+  /// Render the fields enabled by `options` as human-readable text, like `stats.toString()` of
+  /// webpack.
+  pub fn to_string(&self, options: &StatsOptions) -> Result<String> {
+    Ok(printer::print(
+      &self.to_json(&options.for_to_string())?,
+      options.colors,
+    ))
+  }
 }
 
-impl Stats<'_> {
+impl<'compilation> Stats<'compilation> {
   pub fn get_assets(&self) -> (Vec<StatsAsset>, Vec<StatsAssetsByChunkName>) {
     let mut compilation_file_to_chunks: HashMap<&String, Vec<&Chunk>> = HashMap::default();
     for chunk in self.compilation.chunk_by_ukey.values() {
@@ -70,6 +168,8 @@ impl Stats<'_> {
                   source_filename: asset.info.source_filename.clone(),
                 },
                 emitted: self.compilation.emitted_assets.contains(name),
+                compared_for_emit: self.compilation.compared_for_emit_assets.contains(name),
+                is_over_size_limit: asset.info.is_over_size_limit,
               },
             )
           })
@@ -125,33 +225,71 @@ impl Stats<'_> {
     (assets, assets_by_chunk_name)
   }
 
+  #[allow(clippy::too_many_arguments)]
   pub fn get_modules(
     &self,
     reasons: bool,
     module_assets: bool,
     nested_modules: bool,
     source: bool,
+    optimization_bailout: bool,
+    used_exports: bool,
+    provided_exports: bool,
   ) -> Result<Vec<StatsModule>> {
+    self.collect_modules(
+      StatsModuleFields {
+        reasons,
+        module_assets,
+        nested_modules,
+        source,
+        optimization_bailout,
+        used_exports,
+        provided_exports,
+      },
+      true,
+    )
+  }
+
+  fn collect_modules(
+    &self,
+    fields: StatsModuleFields,
+    runtime_modules: bool,
+  ) -> Result<Vec<StatsModule<'compilation>>> {
+    let runtime_modules = self
+      .compilation
+      .runtime_modules
+      .iter()
+      .filter(|_| runtime_modules)
+      .map(|(identifier, module)| {
+        self.get_runtime_module(identifier, module, fields.reasons, fields.module_assets)
+      });
+    // The modules concatenated into another module are listed as its nested modules
+    let concatenated_modules: IdentifierSet = self
+      .compilation
+      .concatenated_modules
+      .values()
+      .flat_map(|concatenated_module| {
+        concatenated_module
+          .modules()
+          .iter()
+          .filter(|m| **m != concatenated_module.root_module())
+          .copied()
+      })
+      .collect();
     let mut modules: Vec<StatsModule> = self
       .compilation
       .module_graph
       .modules()
       .values()
-      .map(|module| self.get_module(module, reasons, module_assets, nested_modules, source))
-      .chain(
-        self
-          .compilation
-          .runtime_modules
-          .iter()
-          .map(|(identifier, module)| {
-            self.get_runtime_module(identifier, module, reasons, module_assets)
-          }),
-      )
+      .filter(|module| !concatenated_modules.contains(&module.identifier()))
+      .map(|module| self.get_module(module, fields))
+      .chain(runtime_modules)
       .collect::<Result<_>>()?;
     Self::sort_modules(&mut modules);
     Ok(modules)
   }
 
+  #[allow(clippy::too_many_arguments)]
   pub fn get_chunks(
     &self,
     chunk_modules: bool,
@@ -160,7 +298,31 @@ impl Stats<'_> {
     module_assets: bool,
     nested_modules: bool,
     source: bool,
+    optimization_bailout: bool,
+    used_exports: bool,
+    provided_exports: bool,
   ) -> Result<Vec<StatsChunk>> {
+    self.collect_chunks(
+      chunk_modules,
+      chunk_relations,
+      StatsModuleFields {
+        reasons,
+        module_assets,
+        nested_modules,
+        source,
+        optimization_bailout,
+        used_exports,
+        provided_exports,
+      },
+    )
+  }
+
+  fn collect_chunks(
+    &self,
+    chunk_modules: bool,
+    chunk_relations: bool,
+    fields: StatsModuleFields,
+  ) -> Result<Vec<StatsChunk<'compilation>>> {
     let mut chunks: Vec<StatsChunk> = self
       .compilation
       .chunk_by_ukey
@@ -179,7 +341,7 @@ impl Stats<'_> {
             .get_chunk_modules(&c.ukey, &self.compilation.module_graph);
           let mut chunk_modules = chunk_modules
             .into_iter()
-            .map(|m| self.get_module(m, reasons, module_assets, nested_modules, source))
+            .map(|m| self.get_module(m, fields))
             .collect::<Result<Vec<_>>>()?;
           Self::sort_modules(&mut chunk_modules);
           Some(chunk_modules)
@@ -255,6 +417,7 @@ impl Stats<'_> {
       chunks,
       assets_size: assets.iter().map(|i| i.size).sum(),
       assets,
+      is_over_size_limit: cg.is_over_size_limit,
     }
   }
 
@@ -371,14 +534,40 @@ impl Stats<'_> {
     });
   }
 
-  fn get_module<'a>(
-    &'a self,
-    module: &'a BoxModule,
-    reasons: bool,
-    module_assets: bool,
-    nested_modules: bool,
-    source: bool,
-  ) -> Result<StatsModule<'a>> {
+  fn get_module(
+    &self,
+    module: &'compilation BoxModule,
+    fields: StatsModuleFields,
+  ) -> Result<StatsModule<'compilation>> {
+    let mut stats = self.get_plain_module(module, fields)?;
+    if let Some(concatenated_module) = self
+      .compilation
+      .concatenated_modules
+      .get(&module.identifier())
+    {
+      stats.name = concatenated_module
+        .readable_identifier(&self.compilation.options.context)
+        .into();
+      stats.size = concatenated_module.size(&SourceType::JavaScript);
+      if let Some(modules) = &mut stats.modules {
+        *modules = concatenated_module
+          .modules()
+          .iter()
+          .filter_map(|m| self.compilation.module_graph.module_by_identifier(m))
+          .map(|m| self.get_plain_module(m, fields))
+          .collect::<Result<_>>()?;
+        Self::sort_modules(modules);
+      }
+    }
+    Ok(stats)
+  }
+
+  /// The stats of a module regardless of the concatenation it belongs to.
+  fn get_plain_module(
+    &self,
+    module: &'compilation BoxModule,
+    fields: StatsModuleFields,
+  ) -> Result<StatsModule<'compilation>> {
     let identifier = module.identifier();
     let mgm = self
       .compilation
@@ -403,7 +592,8 @@ impl Stats<'_> {
     }
     issuer_path.reverse();
 
-    let reasons = reasons
+    let reasons = fields
+      .reasons
       .then(|| -> Result<_> {
         let mut reasons: Vec<StatsModuleReason> = mgm
           .incoming_connections_unordered(&self.compilation.module_graph)?
@@ -455,7 +645,7 @@ impl Stats<'_> {
       .collect();
     chunks.sort_unstable();
 
-    let assets = module_assets.then(|| {
+    let assets = fields.module_assets.then(|| {
       let mut assets: Vec<_> = mgm
         .build_info
         .as_ref()
@@ -465,8 +655,8 @@ impl Stats<'_> {
       assets
     });
 
-    // TODO: a placeholder for concatenation modules
-    let modules = nested_modules.then(Vec::new);
+    // Filled with the concatenated modules by `get_module`
+    let modules = fields.nested_modules.then(Vec::new);
     let profile = if let Some(p) = mgm.get_profile()
       && let Some(factory) = p.factory.duration()
       && let Some(integration) = p.integration.duration()
@@ -481,6 +671,30 @@ impl Stats<'_> {
       None
     };
 
+    let optimization_bailout = fields.optimization_bailout.then(|| {
+      let mut bailout = mgm.optimization_bailout.clone();
+      if let Some(flag) = self.compilation.bailout_module_identifiers.get(&identifier) {
+        bailout.extend(tree_shaking_bailout_reasons(*flag));
+      }
+      bailout
+    });
+    let exports_info = self.compilation.module_graph.get_exports_info(&identifier);
+    let used_exports = fields.used_exports.then(|| {
+      match exports_info.get_used_exports(None, &self.compilation.module_graph) {
+        UsedExports::Unknown => StatsUsedExports::Null,
+        UsedExports::Bool(used) => StatsUsedExports::Bool(used),
+        UsedExports::Vec(names) => {
+          StatsUsedExports::Names(names.into_iter().map(|n| n.to_string()).collect())
+        }
+      }
+    });
+    let provided_exports = fields.provided_exports.then(|| {
+      match exports_info.get_provided_exports(&self.compilation.module_graph) {
+        ProvidedExports::Vec(names) => Some(names.into_iter().map(|n| n.to_string()).collect()),
+        ProvidedExports::Unknown | ProvidedExports::True => None,
+      }
+    });
+
     Ok(StatsModule {
       r#type: "module",
       module_type: *module.module_type(),
@@ -503,18 +717,21 @@ impl Stats<'_> {
       reasons,
       assets,
       modules,
-      source: source.then(|| module.original_source()).flatten(),
+      source: fields.source.then(|| module.original_source()).flatten(),
       profile,
+      optimization_bailout,
+      used_exports,
+      provided_exports,
     })
   }
 
-  fn get_runtime_module<'a>(
-    &'a self,
+  fn get_runtime_module(
+    &self,
     identifier: &ModuleIdentifier,
-    module: &'a BoxRuntimeModule,
+    module: &'compilation BoxRuntimeModule,
     reasons: bool,
     module_assets: bool,
-  ) -> Result<StatsModule<'a>> {
+  ) -> Result<StatsModule<'compilation>> {
     let mut chunks: Vec<Option<String>> = self
       .compilation
       .chunk_graph
@@ -543,6 +760,9 @@ impl Stats<'_> {
       modules: None,
       source: None,
       profile: None,
+      optimization_bailout: None,
+      used_exports: None,
+      provided_exports: None,
     })
   }
   fn get_chunk_relations(&self, chunk: &Chunk) -> (Vec<String>, Vec<String>, Vec<String>) {
@@ -597,6 +817,31 @@ impl Stats<'_> {
   }
 }
 
+/// Optional fields of [StatsModule].
+#[derive(Debug, Default, Clone, Copy)]
+struct StatsModuleFields {
+  reasons: bool,
+  module_assets: bool,
+  nested_modules: bool,
+  source: bool,
+  optimization_bailout: bool,
+  used_exports: bool,
+  provided_exports: bool,
+}
+
+fn tree_shaking_bailout_reasons(flag: BailoutFlag) -> impl Iterator<Item = String> {
+  [
+    (BailoutFlag::COMMONJS_REQUIRE, "required by CommonJS"),
+    (BailoutFlag::COMMONJS_EXPORTS, "exports by CommonJS"),
+    (BailoutFlag::DYNAMIC_IMPORT, "imported dynamically"),
+    (BailoutFlag::CONTEXT_MODULE, "used by a context module"),
+    (BailoutFlag::CONTAINER_EXPOSED, "exposed by a container"),
+  ]
+  .into_iter()
+  .filter(move |(f, _)| flag.contains(*f))
+  .map(|(_, reason)| format!("Tree shaking bailout: {reason}"))
+}
+
 fn get_stats_module_name_and_id(
   module: &BoxModule,
   compilation: &Compilation,
@@ -607,7 +852,80 @@ fn get_stats_module_name_and_id(
   (name.to_string(), id)
 }
 
-#[derive(Debug)]
+/// The result of [Stats::to_json].
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsCompilation<'a> {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub hash: Option<String>,
+  /// Milliseconds the compilation takes.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub time: Option<u64>,
+  /// Milliseconds since the unix epoch when the compilation is done.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub built_at: Option<u64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub public_path: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub output_path: Option<String>,
+  #[serde(
+    skip_serializing_if = "Option::is_none",
+    serialize_with = "serialize_assets_by_chunk_name"
+  )]
+  pub assets_by_chunk_name: Option<Vec<StatsAssetsByChunkName>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub assets: Option<Vec<StatsAsset>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub chunks: Option<Vec<StatsChunk<'a>>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub modules: Option<Vec<StatsModule<'a>>>,
+  #[serde(
+    skip_serializing_if = "Option::is_none",
+    serialize_with = "serialize_chunk_groups"
+  )]
+  pub entrypoints: Option<Vec<StatsChunkGroup>>,
+  #[serde(
+    skip_serializing_if = "Option::is_none",
+    serialize_with = "serialize_chunk_groups"
+  )]
+  pub named_chunk_groups: Option<Vec<StatsChunkGroup>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub errors: Option<Vec<StatsError>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub errors_count: Option<usize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub warnings: Option<Vec<StatsWarning>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub warnings_count: Option<usize>,
+  pub children: Vec<StatsCompilation<'a>>,
+}
+
+fn serialize_assets_by_chunk_name<S: Serializer>(
+  value: &Option<Vec<StatsAssetsByChunkName>>,
+  serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+  let value = value.as_deref().unwrap_or_default();
+  let mut map = serializer.serialize_map(Some(value.len()))?;
+  for item in value.iter().sorted_by(|a, b| a.name.cmp(&b.name)) {
+    map.serialize_entry(&item.name, &item.files)?;
+  }
+  map.end()
+}
+
+fn serialize_chunk_groups<S: Serializer>(
+  value: &Option<Vec<StatsChunkGroup>>,
+  serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+  let value = value.as_deref().unwrap_or_default();
+  let mut map = serializer.serialize_map(Some(value.len()))?;
+  for item in value {
+    map.serialize_entry(&item.name, item)?;
+  }
+  map.end()
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsError {
   pub message: String,
   pub formatted: String,
@@ -616,7 +934,8 @@ pub struct StatsError {
   pub module_id: Option<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsWarning {
   pub message: String,
   pub formatted: String,
@@ -625,7 +944,8 @@ pub struct StatsWarning {
   pub module_id: Option<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsAsset {
   pub r#type: &'static str,
   pub name: String,
@@ -634,6 +954,9 @@ pub struct StatsAsset {
   pub chunk_names: Vec<String>,
   pub info: StatsAssetInfo,
   pub emitted: bool,
+  pub compared_for_emit: bool,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub is_over_size_limit: Option<bool>,
 }
 
 #[derive(Debug)]
@@ -642,16 +965,20 @@ pub struct StatsAssetsByChunkName {
   pub files: Vec<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsAssetInfo {
   pub development: bool,
   pub hot_module_replacement: bool,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub source_filename: Option<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsModule<'a> {
   pub r#type: &'static str,
+  #[serde(serialize_with = "serialize_module_type")]
   pub module_type: ModuleType,
   pub identifier: ModuleIdentifier,
   pub name: String,
@@ -663,21 +990,64 @@ pub struct StatsModule<'a> {
   pub issuer_name: Option<String>,
   pub issuer_id: Option<String>,
   pub issuer_path: Vec<StatsModuleIssuer>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub reasons: Option<Vec<StatsModuleReason>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub assets: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub modules: Option<Vec<StatsModule<'a>>>,
+  #[serde(
+    skip_serializing_if = "Option::is_none",
+    serialize_with = "serialize_module_source"
+  )]
   pub source: Option<&'a dyn Source>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub profile: Option<StatsModuleProfile>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub optimization_bailout: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub used_exports: Option<StatsUsedExports>,
+  /// `Some(None)` is serialized as `null`, which means the provided exports are unknown.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub provided_exports: Option<Option<Vec<String>>>,
 }
 
-#[derive(Debug)]
+fn serialize_module_type<S: Serializer>(
+  value: &ModuleType,
+  serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+  serializer.serialize_str(value.as_str())
+}
+
+fn serialize_module_source<S: Serializer>(
+  value: &Option<&dyn Source>,
+  serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+  match value {
+    Some(source) => serializer.serialize_str(&source.source()),
+    None => serializer.serialize_none(),
+  }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum StatsUsedExports {
+  /// Unknown.
+  Null,
+  Bool(bool),
+  Names(Vec<String>),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsModuleProfile {
   pub factory: StatsMillisecond,
   pub integration: StatsMillisecond,
   pub building: StatsMillisecond,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsChunk<'a> {
   pub r#type: &'static str,
   pub files: Vec<String>,
@@ -687,34 +1057,44 @@ pub struct StatsChunk<'a> {
   pub initial: bool,
   pub names: Vec<String>,
   pub size: f64,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub modules: Option<Vec<StatsModule<'a>>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub parents: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub children: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub siblings: Option<Vec<String>>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsChunkGroupAsset {
   pub name: String,
   pub size: f64,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsChunkGroup {
   pub name: String,
   pub assets: Vec<StatsChunkGroupAsset>,
   pub chunks: Vec<Option<String>>,
   pub assets_size: f64,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub is_over_size_limit: Option<bool>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsModuleIssuer {
   pub identifier: String,
   pub name: String,
   pub id: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsModuleReason {
   pub module_identifier: Option<String>,
   pub module_name: Option<String>,
@@ -736,4 +1116,15 @@ impl StatsMillisecond {
       subsec_millis,
     }
   }
+
+  pub fn as_millis(&self) -> u64 {
+    self.secs * 1000 + self.subsec_millis as u64
+  }
+}
+
+/// Serialized as milliseconds like webpack.
+impl Serialize for StatsMillisecond {
+  fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_u64(self.as_millis())
+  }
 }
@@ -0,0 +1,467 @@
+use std::fmt::Write;
+
+use itertools::Itertools;
+
+use super::{
+  StatsAsset, StatsChunk, StatsChunkGroup, StatsCompilation, StatsModule, StatsUsedExports,
+};
+
+const BOLD: &str = "\u{1b}[1m";
+const RED: &str = "\u{1b}[31m";
+const GREEN: &str = "\u{1b}[32m";
+const YELLOW: &str = "\u{1b}[33m";
+const CYAN: &str = "\u{1b}[36m";
+const RESET: &str = "\u{1b}[39m\u{1b}[22m";
+
+/// Render `stats` like the `DefaultStatsPrinterPlugin` of webpack.
+pub(super) fn print(stats: &StatsCompilation, colors: bool) -> String {
+  let mut printer = StatsPrinter {
+    colors,
+    output: String::new(),
+  };
+  printer.print_compilation(stats);
+  printer.output
+}
+
+struct StatsPrinter {
+  colors: bool,
+  output: String,
+}
+
+impl StatsPrinter {
+  fn paint(&self, text: &str, style: &str) -> String {
+    if self.colors {
+      format!("{style}{text}{RESET}")
+    } else {
+      text.to_string()
+    }
+  }
+
+  fn line(&mut self, indent: usize, text: &str) {
+    let _ = writeln!(self.output, "{}{text}", "  ".repeat(indent));
+  }
+
+  fn print_compilation(&mut self, stats: &StatsCompilation) {
+    for asset in stats.assets.iter().flatten() {
+      self.print_asset(asset);
+    }
+    for entrypoint in stats.entrypoints.iter().flatten() {
+      self.print_chunk_group("Entrypoint", entrypoint);
+    }
+    for chunk in stats.chunks.iter().flatten() {
+      self.print_chunk(chunk);
+    }
+    if let Some(modules) = &stats.modules {
+      self.print_modules(0, modules);
+    }
+
+    for error in stats.errors.iter().flatten() {
+      self.line(0, "");
+      let text = format!("ERROR in {}", error.formatted.trim_end());
+      self.line(0, &self.paint(&text, RED));
+    }
+    for warning in stats.warnings.iter().flatten() {
+      self.line(0, "");
+      let text = format!("WARNING in {}", warning.formatted.trim_end());
+      self.line(0, &self.paint(&text, YELLOW));
+    }
+
+    if stats.errors_count.is_some() || stats.warnings_count.is_some() || stats.time.is_some() {
+      if !self.output.is_empty() {
+        self.line(0, "");
+      }
+      let summary = self.summary(stats);
+      self.line(0, &summary);
+    }
+  }
+
+  fn summary(&self, stats: &StatsCompilation) -> String {
+    let errors_count = stats.errors_count.unwrap_or_default();
+    let warnings_count = stats.warnings_count.unwrap_or_default();
+    let mut problems = Vec::new();
+    if errors_count > 0 {
+      problems.push(self.paint(&pluralize(errors_count, "error"), RED));
+    }
+    if warnings_count > 0 {
+      problems.push(self.paint(&pluralize(warnings_count, "warning"), YELLOW));
+    }
+    let mut summary = if problems.is_empty() {
+      format!("Rspack compiled {}", self.paint("successfully", GREEN))
+    } else {
+      format!("Rspack compiled with {}", problems.join(" and "))
+    };
+    if let Some(time) = stats.time {
+      let _ = write!(summary, " in {time} ms");
+    }
+    summary
+  }
+
+  fn print_asset(&mut self, asset: &StatsAsset) {
+    let is_big = asset.is_over_size_limit.unwrap_or_default();
+    let mut text = format!(
+      "asset {} {}",
+      self.paint(&asset.name, &format!("{BOLD}{GREEN}")),
+      if is_big {
+        self.paint(&format_size(asset.size), YELLOW)
+      } else {
+        format_size(asset.size)
+      }
+    );
+    if asset.emitted {
+      text.push_str(&self.paint(" [emitted]", GREEN));
+    }
+    if asset.compared_for_emit {
+      text.push_str(" [compared for emit]");
+    }
+    if is_big {
+      text.push_str(&self.paint(" [big]", YELLOW));
+    }
+    if asset.info.development {
+      text.push_str(" [dev]");
+    }
+    if asset.info.hot_module_replacement {
+      text.push_str(" [hmr]");
+    }
+    if !asset.chunk_names.is_empty() {
+      let _ = write!(text, " (name: {})", asset.chunk_names.join(", "));
+    }
+    self.line(0, &text);
+  }
+
+  fn print_chunk_group(&mut self, kind: &str, chunk_group: &StatsChunkGroup) {
+    let assets = chunk_group
+      .assets
+      .iter()
+      .map(|asset| {
+        format!(
+          "{} {}",
+          self.paint(&asset.name, BOLD),
+          format_size(asset.size)
+        )
+      })
+      .join(" ");
+    let mut text = format!("{kind} {}", self.paint(&chunk_group.name, BOLD));
+    if chunk_group.is_over_size_limit.unwrap_or_default() {
+      text.push_str(&self.paint(" [big]", YELLOW));
+    }
+    let _ = write!(text, " {} = {assets}", format_size(chunk_group.assets_size));
+    self.line(0, &text);
+  }
+
+  fn print_chunk(&mut self, chunk: &StatsChunk) {
+    let mut text = String::from("chunk");
+    if let Some(id) = &chunk.id {
+      let _ = write!(text, " ({})", self.paint(id, BOLD));
+    }
+    for file in &chunk.files {
+      let _ = write!(text, " {}", self.paint(file, GREEN));
+    }
+    if !chunk.names.is_empty() {
+      let _ = write!(text, " ({})", chunk.names.join(", "));
+    }
+    let _ = write!(text, " {}", format_size(chunk.size));
+    for parent in chunk.parents.iter().flatten() {
+      let _ = write!(text, " <{{{parent}}}>");
+    }
+    for child in chunk.children.iter().flatten() {
+      let _ = write!(text, " >{{{child}}}<");
+    }
+    for sibling in chunk.siblings.iter().flatten() {
+      let _ = write!(text, " ={{{sibling}}}=");
+    }
+    if chunk.entry {
+      text.push_str(&self.paint(" [entry]", YELLOW));
+    } else if chunk.initial {
+      text.push_str(&self.paint(" [initial]", YELLOW));
+    }
+    self.line(0, &text);
+    if let Some(modules) = &chunk.modules {
+      self.print_modules(1, modules);
+    }
+  }
+
+  /// Runtime modules are collapsed into one line, and the other modules are grouped by their
+  /// directories like `groupModulesByPath` of webpack.
+  fn print_modules(&mut self, indent: usize, modules: &[StatsModule]) {
+    let (runtime_modules, modules): (Vec<_>, Vec<_>) = modules
+      .iter()
+      .partition(|module| module.module_type.as_str() == "runtime");
+    if !runtime_modules.is_empty() {
+      let text = format!(
+        "runtime modules {} {}",
+        format_size(runtime_modules.iter().map(|module| module.size).sum()),
+        pluralize(runtime_modules.len(), "module")
+      );
+      self.line(indent, &text);
+    }
+    let modules = modules
+      .into_iter()
+      .map(|module| (module_dirs(&module.name), module))
+      .collect::<Vec<_>>();
+    self.print_modules_by_path(indent, 0, &modules);
+  }
+
+  /// Print the modules in the directory of `depth` components, where a sub-directory gets its own
+  /// group when it has more than one module, named by the deepest directory shared by them.
+  fn print_modules_by_path(
+    &mut self,
+    indent: usize,
+    depth: usize,
+    modules: &[(Vec<&str>, &StatsModule)],
+  ) {
+    let depth = common_depth(modules, depth);
+    let groups = modules
+      .iter()
+      .into_group_map_by(|(dirs, _)| dirs.get(depth).copied());
+    let mut printed_dirs = Vec::new();
+    for (dirs, module) in modules {
+      match dirs.get(depth) {
+        Some(dir) if groups[&Some(*dir)].len() > 1 => {
+          if printed_dirs.contains(dir) {
+            continue;
+          }
+          printed_dirs.push(*dir);
+          let children = groups[&Some(*dir)]
+            .iter()
+            .map(|(dirs, module)| (dirs.clone(), *module))
+            .collect::<Vec<_>>();
+          let children_depth = common_depth(&children, depth + 1);
+          let text = format!(
+            "modules by path {} {}",
+            self.paint(&format!("{}/", dirs[..children_depth].join("/")), BOLD),
+            format_size(children.iter().map(|(_, module)| module.size).sum())
+          );
+          self.line(indent, &text);
+          self.print_modules_by_path(indent + 1, children_depth, &children);
+        }
+        _ => self.print_module(indent, module),
+      }
+    }
+  }
+
+  fn print_module(&mut self, indent: usize, module: &StatsModule) {
+    let mut text = format!(
+      "{} {}",
+      self.paint(&module.name, BOLD),
+      format_size(module.size)
+    );
+    if module.module_type.as_str() == "runtime" {
+      text.push_str(&self.paint(" [runtime]", CYAN));
+    }
+    if let Some(id) = &module.id
+      && !id.is_empty()
+      && id != &module.name
+    {
+      let _ = write!(text, " [{id}]");
+    }
+    match &module.provided_exports {
+      Some(Some(provided)) if !provided.is_empty() => {
+        let _ = write!(text, " [exports: {}]", provided.join(", "));
+      }
+      _ => {}
+    }
+    match &module.used_exports {
+      Some(StatsUsedExports::Bool(false)) => text.push_str(" [no exports used]"),
+      Some(StatsUsedExports::Names(names)) if !names.is_empty() => {
+        let _ = write!(text, " [only some exports used: {}]", names.join(", "));
+      }
+      _ => {}
+    }
+    self.line(indent, &text);
+
+    for reason in module.reasons.iter().flatten() {
+      let text = [
+        reason.r#type.as_deref(),
+        reason.module_name.as_deref(),
+        reason.user_request.as_deref(),
+      ]
+      .into_iter()
+      .flatten()
+      .join(" ");
+      self.line(indent + 1, &text);
+    }
+    for bailout in module.optimization_bailout.iter().flatten() {
+      self.line(indent + 1, &self.paint(bailout, YELLOW));
+    }
+    for module in module.modules.iter().flatten() {
+      self.print_module(indent + 1, module);
+    }
+  }
+}
+
+/// The number of leading directories, at least `depth`, shared by all `modules`.
+fn common_depth(modules: &[(Vec<&str>, &StatsModule)], mut depth: usize) -> usize {
+  while let Some((dirs, _)) = modules.first()
+    && let Some(dir) = dirs.get(depth)
+    && modules
+      .iter()
+      .all(|(other, _)| other.get(depth) == Some(dir))
+  {
+    depth += 1;
+  }
+  depth
+}
+
+/// The directories of a module resource, e.g. `[".", "src"]` for `./src/index.js?query`.
+fn module_dirs(name: &str) -> Vec<&str> {
+  let resource = name.rsplit('!').next().unwrap_or(name);
+  let resource = resource.split('?').next().unwrap_or(resource);
+  if !resource.starts_with('.') {
+    return Vec::new();
+  }
+  let mut dirs = resource.split('/').collect::<Vec<_>>();
+  dirs.pop();
+  dirs
+}
+
+fn pluralize(count: usize, word: &str) -> String {
+  if count == 1 {
+    format!("{count} {word}")
+  } else {
+    format!("{count} {word}s")
+  }
+}
+
+/// Format like `formatSize` of webpack, with 3 significant digits.
+pub fn format_size(size: f64) -> String {
+  const UNITS: [&str; 4] = ["bytes", "KiB", "MiB", "GiB"];
+  if size <= 0.0 {
+    return "0 bytes".to_string();
+  }
+  let index = ((size.ln() / 1024f64.ln()).floor() as usize).min(UNITS.len() - 1);
+  let value = size / 1024f64.powi(index as i32);
+  let decimals = if value >= 100.0 {
+    0
+  } else if value >= 10.0 {
+    1
+  } else {
+    2
+  };
+  let value = format!("{value:.decimals$}");
+  let value = if value.contains('.') {
+    value.trim_end_matches('0').trim_end_matches('.')
+  } else {
+    &value
+  };
+  format!("{value} {}", UNITS[index])
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{ModuleType, StatsAssetInfo, StatsError};
+
+  fn module(name: &str, size: f64, module_type: ModuleType) -> StatsModule<'static> {
+    StatsModule {
+      r#type: "module",
+      module_type,
+      identifier: name.into(),
+      name: name.to_string(),
+      name_for_condition: None,
+      id: None,
+      chunks: Vec::new(),
+      size,
+      issuer: None,
+      issuer_name: None,
+      issuer_id: None,
+      issuer_path: Vec::new(),
+      reasons: None,
+      assets: None,
+      modules: None,
+      source: None,
+      profile: None,
+      optimization_bailout: None,
+      used_exports: None,
+      provided_exports: None,
+    }
+  }
+
+  #[test]
+  fn should_format_size() {
+    assert_eq!(format_size(0.0), "0 bytes");
+    assert_eq!(format_size(59.0), "59 bytes");
+    assert_eq!(format_size(1536.0), "1.5 KiB");
+    assert_eq!(format_size(300_000.0), "293 KiB");
+    assert_eq!(format_size(1024.0 * 1024.0 * 12.34), "12.3 MiB");
+  }
+
+  #[test]
+  fn should_print_assets_and_summary() {
+    let stats = StatsCompilation {
+      hash: None,
+      time: Some(42),
+      built_at: None,
+      public_path: None,
+      output_path: None,
+      assets_by_chunk_name: None,
+      assets: Some(vec![StatsAsset {
+        r#type: "asset",
+        name: "main.js".to_string(),
+        size: 300_000.0,
+        chunks: vec![Some("main".to_string())],
+        chunk_names: vec!["main".to_string()],
+        info: StatsAssetInfo {
+          development: false,
+          hot_module_replacement: false,
+          source_filename: None,
+        },
+        emitted: true,
+        compared_for_emit: false,
+        is_over_size_limit: Some(true),
+      }]),
+      chunks: None,
+      modules: None,
+      entrypoints: None,
+      named_chunk_groups: None,
+      errors: Some(vec![StatsError {
+        message: "failed".to_string(),
+        formatted: "failed\n".to_string(),
+        module_identifier: None,
+        module_name: None,
+        module_id: None,
+      }]),
+      errors_count: Some(1),
+      warnings: None,
+      warnings_count: Some(0),
+      children: Vec::new(),
+    };
+    assert_eq!(
+      print(&stats, false),
+      "asset main.js 293 KiB [emitted] [big] (name: main)\n\nERROR in failed\n\nRspack compiled with 1 error in 42 ms\n"
+    );
+  }
+
+  #[test]
+  fn should_group_modules_by_path() {
+    let modules = vec![
+      module("webpack/runtime/a", 10.0, ModuleType::Runtime),
+      module("webpack/runtime/b", 20.0, ModuleType::Runtime),
+      module("./src/index.js", 10.0, ModuleType::Js),
+      module("./src/components/a.js", 20.0, ModuleType::Js),
+      module(
+        "builtin:swc-loader!./src/components/b.js?raw",
+        20.0,
+        ModuleType::Js,
+      ),
+      module("./src/utils/c.js", 5.0, ModuleType::Js),
+      module("external \"react\"", 42.0, ModuleType::Js),
+    ];
+    let mut printer = StatsPrinter {
+      colors: false,
+      output: String::new(),
+    };
+    printer.print_modules(0, &modules);
+    assert_eq!(
+      printer.output,
+      r#"runtime modules 30 bytes 2 modules
+modules by path ./src/ 55 bytes
+  ./src/index.js 10 bytes
+  modules by path ./src/components/ 40 bytes
+    ./src/components/a.js 20 bytes
+    builtin:swc-loader!./src/components/b.js?raw 20 bytes
+  ./src/utils/c.js 5 bytes
+external "react" 42 bytes
+"#
+    );
+  }
+}
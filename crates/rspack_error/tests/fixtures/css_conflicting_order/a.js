@@ -0,0 +1,2 @@
+import './1.css'
+import './2.css'
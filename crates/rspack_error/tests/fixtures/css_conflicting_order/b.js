@@ -0,0 +1,2 @@
+import './2.css'
+import './1.css'
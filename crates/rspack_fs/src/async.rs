@@ -1,8 +1,11 @@
-use std::path::Path;
+use std::{
+  fmt::Debug,
+  path::{Path, PathBuf},
+};
 
 use futures::future::BoxFuture;
 
-use crate::Result;
+use crate::{FileMetadata, Result};
 
 pub trait AsyncWritableFileSystem {
   /// Creates a new, empty directory at the provided path.
@@ -29,13 +32,32 @@ pub trait AsyncWritableFileSystem {
 
   /// Removes a directory at this path, after removing all its contents. Use carefully.
   fn remove_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>>;
+
+  /// Rename a file or directory to a new name, replacing the original file if `to` already exists.
+  fn rename<P: AsRef<Path>, Q: AsRef<Path>>(&self, from: P, to: Q) -> BoxFuture<'_, Result<()>>;
 }
 
-pub trait AsyncReadableFileSystem {
+/// Readable file systems are object safe, so they can be shared as `dyn AsyncReadableFileSystem`.
+pub trait AsyncReadableFileSystem: Debug + Send + Sync {
   /// Read the entire contents of a file into a bytes vector.
   ///
   /// Error: This function will return an error if path does not already exist.
-  fn read<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<Vec<u8>>>;
+  fn read(&self, file: &Path) -> BoxFuture<'_, Result<Vec<u8>>>;
+
+  /// Query the metadata of a path, following symbolic links.
+  fn metadata(&self, path: &Path) -> BoxFuture<'_, Result<FileMetadata>>;
+
+  /// Query the metadata of a path without following symbolic links.
+  fn symlink_metadata(&self, path: &Path) -> BoxFuture<'_, Result<FileMetadata>>;
+
+  /// Read the names of the entries in a directory, without `.` and `..`.
+  fn read_dir(&self, dir: &Path) -> BoxFuture<'_, Result<Vec<String>>>;
+
+  /// Read the target of a symbolic link.
+  fn read_link(&self, path: &Path) -> BoxFuture<'_, Result<PathBuf>>;
+
+  /// Get the absolute path with all intermediate components normalized and symbolic links resolved.
+  fn canonicalize(&self, path: &Path) -> BoxFuture<'_, Result<PathBuf>>;
 }
 
 /// Async readable and writable file system representation.
@@ -10,6 +10,14 @@ pub use sync::{FileSystem, ReadableFileSystem, WritableFileSystem};
 mod error;
 pub use error::{Error, Result};
 
+mod metadata;
+pub use metadata::FileMetadata;
+
+mod memory;
+pub use memory::MemoryFileSystem;
+mod overlay;
+pub use overlay::OverlayFileSystem;
+
 cfg_native! {
   mod native;
   pub use native::{NativeFileSystem};
@@ -0,0 +1,485 @@
+use std::{
+  collections::HashMap,
+  io::ErrorKind,
+  path::{Path, PathBuf},
+  sync::{Arc, RwLock},
+  time::SystemTime,
+};
+
+use super::{
+  cfg_async,
+  metadata::system_time_to_ms,
+  sync::{ReadableFileSystem, WritableFileSystem},
+  Error, FileMetadata, Result,
+};
+
+#[derive(Debug, Clone)]
+enum MemoryEntry {
+  File { content: Vec<u8>, mtime_ms: u64 },
+  Dir { mtime_ms: u64 },
+}
+
+impl MemoryEntry {
+  fn metadata(&self) -> FileMetadata {
+    match self {
+      Self::File { content, mtime_ms } => FileMetadata {
+        is_file: true,
+        mtime_ms: *mtime_ms,
+        size: content.len() as u64,
+        ..Default::default()
+      },
+      Self::Dir { mtime_ms } => FileMetadata {
+        is_directory: true,
+        mtime_ms: *mtime_ms,
+        ..Default::default()
+      },
+    }
+  }
+}
+
+/// A file system which keeps everything in memory.
+///
+/// Cloned file systems share the same files, so a clone can be given to the compiler
+/// and the emitted files can be read from another one.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryFileSystem {
+  entries: Arc<RwLock<HashMap<PathBuf, MemoryEntry>>>,
+}
+
+fn io_error(kind: ErrorKind, path: &Path, message: &str) -> Error {
+  Error::Io(std::io::Error::new(
+    kind,
+    format!("{message}: {}", path.display()),
+  ))
+}
+
+/// Remove `.` and trailing separators, so the same path always has the same key.
+fn normalize(path: &Path) -> PathBuf {
+  path.components().collect()
+}
+
+fn is_root(path: &Path) -> bool {
+  path.parent().is_none() || path.as_os_str().is_empty()
+}
+
+fn now_ms() -> u64 {
+  system_time_to_ms(SystemTime::now())
+}
+
+impl MemoryFileSystem {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Whether the path exists as a file or a directory.
+  pub fn exists<P: AsRef<Path>>(&self, path: P) -> bool {
+    let path = normalize(path.as_ref());
+    is_root(&path)
+      || self
+        .entries
+        .read()
+        .expect("should get lock")
+        .contains_key(&path)
+  }
+
+  /// Paths of all files, in no particular order.
+  pub fn files(&self) -> Vec<PathBuf> {
+    self
+      .entries
+      .read()
+      .expect("should get lock")
+      .iter()
+      .filter(|(_, entry)| matches!(entry, MemoryEntry::File { .. }))
+      .map(|(path, _)| path.clone())
+      .collect()
+  }
+
+  fn is_dir(entries: &HashMap<PathBuf, MemoryEntry>, path: &Path) -> bool {
+    is_root(path) || matches!(entries.get(path), Some(MemoryEntry::Dir { .. }))
+  }
+
+  fn mkdir(&self, dir: &Path) -> Result<()> {
+    let dir = normalize(dir);
+    let mut entries = self.entries.write().expect("should get lock");
+    if is_root(&dir) || entries.contains_key(&dir) {
+      return Err(io_error(
+        ErrorKind::AlreadyExists,
+        &dir,
+        "path already exists",
+      ));
+    }
+    if let Some(parent) = dir.parent().filter(|p| !Self::is_dir(&entries, p)) {
+      return Err(io_error(ErrorKind::NotFound, parent, "no such directory"));
+    }
+    entries.insert(dir, MemoryEntry::Dir { mtime_ms: now_ms() });
+    Ok(())
+  }
+
+  fn mkdirp(&self, dir: &Path) -> Result<()> {
+    let dir = normalize(dir);
+    let mut entries = self.entries.write().expect("should get lock");
+    let mut ancestors: Vec<&Path> = dir.ancestors().filter(|p| !is_root(p)).collect();
+    ancestors.reverse();
+    for ancestor in ancestors {
+      match entries.get(ancestor) {
+        Some(MemoryEntry::Dir { .. }) => {}
+        Some(MemoryEntry::File { .. }) => {
+          return Err(io_error(
+            ErrorKind::AlreadyExists,
+            ancestor,
+            "not a directory",
+          ))
+        }
+        None => {
+          entries.insert(
+            ancestor.to_path_buf(),
+            MemoryEntry::Dir { mtime_ms: now_ms() },
+          );
+        }
+      }
+    }
+    Ok(())
+  }
+
+  fn write_file(&self, file: &Path, data: &[u8]) -> Result<()> {
+    let file = normalize(file);
+    let mut entries = self.entries.write().expect("should get lock");
+    if let Some(parent) = file.parent().filter(|p| !Self::is_dir(&entries, p)) {
+      return Err(io_error(ErrorKind::NotFound, parent, "no such directory"));
+    }
+    if Self::is_dir(&entries, &file) {
+      return Err(io_error(ErrorKind::Other, &file, "is a directory"));
+    }
+    entries.insert(
+      file,
+      MemoryEntry::File {
+        content: data.to_vec(),
+        mtime_ms: now_ms(),
+      },
+    );
+    Ok(())
+  }
+
+  fn read_file(&self, file: &Path) -> Result<Vec<u8>> {
+    let file = normalize(file);
+    match self.entries.read().expect("should get lock").get(&file) {
+      Some(MemoryEntry::File { content, .. }) => Ok(content.clone()),
+      Some(MemoryEntry::Dir { .. }) => Err(io_error(ErrorKind::Other, &file, "is a directory")),
+      None => Err(io_error(ErrorKind::NotFound, &file, "no such file")),
+    }
+  }
+
+  fn remove(&self, file: &Path) -> Result<()> {
+    let file = normalize(file);
+    let mut entries = self.entries.write().expect("should get lock");
+    match entries.get(&file) {
+      Some(MemoryEntry::File { .. }) => {
+        entries.remove(&file);
+        Ok(())
+      }
+      Some(MemoryEntry::Dir { .. }) => Err(io_error(ErrorKind::Other, &file, "is a directory")),
+      None => Err(io_error(ErrorKind::NotFound, &file, "no such file")),
+    }
+  }
+
+  fn remove_dir(&self, dir: &Path) -> Result<()> {
+    let dir = normalize(dir);
+    let mut entries = self.entries.write().expect("should get lock");
+    if !Self::is_dir(&entries, &dir) {
+      return Err(io_error(ErrorKind::NotFound, &dir, "no such directory"));
+    }
+    entries.retain(|path, _| !path.starts_with(&dir));
+    Ok(())
+  }
+
+  fn move_entry(&self, from: &Path, to: &Path) -> Result<()> {
+    let from = normalize(from);
+    let to = normalize(to);
+    let mut entries = self.entries.write().expect("should get lock");
+    if !entries.contains_key(&from) {
+      return Err(io_error(
+        ErrorKind::NotFound,
+        &from,
+        "no such file or directory",
+      ));
+    }
+    if let Some(parent) = to.parent().filter(|p| !Self::is_dir(&entries, p)) {
+      return Err(io_error(ErrorKind::NotFound, parent, "no such directory"));
+    }
+    if to == from {
+      return Ok(());
+    }
+    if to.starts_with(&from) {
+      return Err(io_error(
+        ErrorKind::InvalidInput,
+        &to,
+        "cannot move a directory into itself",
+      ));
+    }
+    // like rename(2), an existing destination is only replaced by the same kind of entry,
+    // and a directory only when it's empty
+    let from_is_dir = Self::is_dir(&entries, &from);
+    if Self::is_dir(&entries, &to) {
+      if !from_is_dir {
+        return Err(io_error(ErrorKind::Other, &to, "is a directory"));
+      }
+      if is_root(&to)
+        || entries
+          .keys()
+          .any(|path| path.parent() == Some(to.as_path()))
+      {
+        return Err(io_error(ErrorKind::Other, &to, "directory not empty"));
+      }
+    } else if entries.contains_key(&to) && from_is_dir {
+      return Err(io_error(ErrorKind::Other, &to, "not a directory"));
+    }
+    let moved = entries
+      .keys()
+      .filter(|path| path.starts_with(&from))
+      .cloned()
+      .collect::<Vec<_>>();
+    entries.remove(&to);
+    for path in moved {
+      let entry = entries.remove(&path).expect("should have entry");
+      let relative = path.strip_prefix(&from).expect("should be a child");
+      entries.insert(to.join(relative), entry);
+    }
+    Ok(())
+  }
+
+  fn stat(&self, path: &Path) -> Result<FileMetadata> {
+    let path = normalize(path);
+    if is_root(&path) {
+      return Ok(MemoryEntry::Dir { mtime_ms: 0 }.metadata());
+    }
+    match self.entries.read().expect("should get lock").get(&path) {
+      Some(entry) => Ok(entry.metadata()),
+      None => Err(io_error(
+        ErrorKind::NotFound,
+        &path,
+        "no such file or directory",
+      )),
+    }
+  }
+
+  fn list_dir(&self, dir: &Path) -> Result<Vec<String>> {
+    let dir = normalize(dir);
+    let entries = self.entries.read().expect("should get lock");
+    if !Self::is_dir(&entries, &dir) {
+      return Err(io_error(ErrorKind::NotFound, &dir, "no such directory"));
+    }
+    let mut names = entries
+      .keys()
+      .filter(|path| path.parent() == Some(dir.as_path()))
+      .filter_map(|path| path.file_name())
+      .map(|name| name.to_string_lossy().to_string())
+      .collect::<Vec<_>>();
+    names.sort();
+    Ok(names)
+  }
+
+  fn link(&self, path: &Path) -> Result<PathBuf> {
+    // symbolic links are not supported
+    self.stat(path)?;
+    Err(io_error(
+      ErrorKind::InvalidInput,
+      path,
+      "not a symbolic link",
+    ))
+  }
+
+  fn real_path(&self, path: &Path) -> Result<PathBuf> {
+    self.stat(path)?;
+    Ok(normalize(path))
+  }
+}
+
+impl WritableFileSystem for MemoryFileSystem {
+  fn create_dir<P: AsRef<Path>>(&self, dir: P) -> Result<()> {
+    self.mkdir(dir.as_ref())
+  }
+
+  fn create_dir_all<P: AsRef<Path>>(&self, dir: P) -> Result<()> {
+    self.mkdirp(dir.as_ref())
+  }
+
+  fn write<P: AsRef<Path>, D: AsRef<[u8]>>(&self, file: P, data: D) -> Result<()> {
+    self.write_file(file.as_ref(), data.as_ref())
+  }
+
+  fn rename<P: AsRef<Path>, Q: AsRef<Path>>(&self, from: P, to: Q) -> Result<()> {
+    self.move_entry(from.as_ref(), to.as_ref())
+  }
+}
+
+impl ReadableFileSystem for MemoryFileSystem {
+  fn read(&self, file: &Path) -> Result<Vec<u8>> {
+    self.read_file(file)
+  }
+
+  fn metadata(&self, path: &Path) -> Result<FileMetadata> {
+    self.stat(path)
+  }
+
+  fn symlink_metadata(&self, path: &Path) -> Result<FileMetadata> {
+    self.stat(path)
+  }
+
+  fn read_dir(&self, dir: &Path) -> Result<Vec<String>> {
+    self.list_dir(dir)
+  }
+
+  fn read_link(&self, path: &Path) -> Result<PathBuf> {
+    self.link(path)
+  }
+
+  fn canonicalize(&self, path: &Path) -> Result<PathBuf> {
+    self.real_path(path)
+  }
+}
+
+cfg_async! {
+  use futures::future::BoxFuture;
+
+  use crate::{AsyncReadableFileSystem, AsyncWritableFileSystem};
+
+  impl AsyncWritableFileSystem for MemoryFileSystem {
+    fn create_dir<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+      let res = self.mkdir(dir.as_ref());
+      Box::pin(async move { res })
+    }
+
+    fn create_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+      let res = self.mkdirp(dir.as_ref());
+      Box::pin(async move { res })
+    }
+
+    fn write<P: AsRef<Path>, D: AsRef<[u8]>>(&self, file: P, data: D) -> BoxFuture<'_, Result<()>> {
+      let res = self.write_file(file.as_ref(), data.as_ref());
+      Box::pin(async move { res })
+    }
+
+    fn remove_file<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<()>> {
+      let res = self.remove(file.as_ref());
+      Box::pin(async move { res })
+    }
+
+    fn remove_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+      let res = self.remove_dir(dir.as_ref());
+      Box::pin(async move { res })
+    }
+
+    fn rename<P: AsRef<Path>, Q: AsRef<Path>>(&self, from: P, to: Q) -> BoxFuture<'_, Result<()>> {
+      let res = self.move_entry(from.as_ref(), to.as_ref());
+      Box::pin(async move { res })
+    }
+  }
+
+  impl AsyncReadableFileSystem for MemoryFileSystem {
+    fn read(&self, file: &Path) -> BoxFuture<'_, Result<Vec<u8>>> {
+      let res = self.read_file(file);
+      Box::pin(async move { res })
+    }
+
+    fn metadata(&self, path: &Path) -> BoxFuture<'_, Result<FileMetadata>> {
+      let res = self.stat(path);
+      Box::pin(async move { res })
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> BoxFuture<'_, Result<FileMetadata>> {
+      let res = self.stat(path);
+      Box::pin(async move { res })
+    }
+
+    fn read_dir(&self, dir: &Path) -> BoxFuture<'_, Result<Vec<String>>> {
+      let res = self.list_dir(dir);
+      Box::pin(async move { res })
+    }
+
+    fn read_link(&self, path: &Path) -> BoxFuture<'_, Result<PathBuf>> {
+      let res = self.link(path);
+      Box::pin(async move { res })
+    }
+
+    fn canonicalize(&self, path: &Path) -> BoxFuture<'_, Result<PathBuf>> {
+      let res = self.real_path(path);
+      Box::pin(async move { res })
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::path::Path;
+
+  use super::MemoryFileSystem;
+  use crate::sync::{ReadableFileSystem, WritableFileSystem};
+
+  #[test]
+  fn should_read_and_write() {
+    let fs = MemoryFileSystem::new();
+    assert!(fs.write("/dist/main.js", "a").is_err());
+    assert!(fs.create_dir("/dist/js").is_err());
+
+    fs.create_dir_all("/dist/js").unwrap();
+    assert!(fs.create_dir("/dist/js").is_err());
+    fs.write("/dist/js/main.js", "a").unwrap();
+    fs.write("/dist/./js/main.js", "b").unwrap();
+    assert_eq!(fs.read(Path::new("/dist/js/main.js")).unwrap(), b"b");
+    assert!(fs.read(Path::new("/dist/js")).is_err());
+    assert!(fs.write("/dist/js", "a").is_err());
+
+    // shared with clones
+    let clone = fs.clone();
+    clone.remove_dir("/dist".as_ref()).unwrap();
+    assert!(!fs.exists("/dist/js/main.js"));
+    assert!(fs.files().is_empty());
+  }
+
+  #[test]
+  fn should_stat_list_and_rename() {
+    let fs = MemoryFileSystem::new();
+    fs.create_dir_all("/dist/js").unwrap();
+    fs.write("/dist/js/main.js", "main").unwrap();
+    fs.write("/dist/index.html", "").unwrap();
+
+    let metadata = fs.metadata(Path::new("/dist/js/main.js")).unwrap();
+    assert!(metadata.is_file && !metadata.is_directory);
+    assert_eq!(metadata.size, 4);
+    assert!(fs.metadata(Path::new("/dist")).unwrap().is_directory);
+    assert!(fs.metadata(Path::new("/dist/css")).is_err());
+    assert_eq!(
+      fs.read_dir(Path::new("/dist")).unwrap(),
+      vec!["index.html", "js"]
+    );
+
+    fs.rename("/dist/js", "/dist/scripts").unwrap();
+    assert_eq!(
+      fs.read(Path::new("/dist/scripts/main.js")).unwrap(),
+      b"main"
+    );
+    assert!(!fs.exists("/dist/js"));
+    assert!(fs.rename("/dist/scripts", "/dist/scripts/nested").is_err());
+    // a non-empty directory is never replaced, including the parent of the moved entry
+    assert!(fs.rename("/dist/scripts", "/dist").is_err());
+    assert!(fs.rename("/dist/scripts/main.js", "/dist/scripts").is_err());
+    fs.create_dir("/dist/js").unwrap();
+    fs.write("/dist/js/other.js", "other").unwrap();
+    assert!(fs.rename("/dist/scripts", "/dist/js").is_err());
+    assert!(fs.rename("/dist/scripts", "/dist/index.html").is_err());
+    assert_eq!(
+      fs.read(Path::new("/dist/scripts/main.js")).unwrap(),
+      b"main"
+    );
+    // an empty directory or a file is replaced
+    fs.remove(Path::new("/dist/js/other.js")).unwrap();
+    fs.rename("/dist/scripts", "/dist/js").unwrap();
+    fs.rename("/dist/js/main.js", "/dist/index.html").unwrap();
+    assert_eq!(fs.read(Path::new("/dist/index.html")).unwrap(), b"main");
+    assert_eq!(
+      fs.canonicalize(Path::new("/dist/./js")).unwrap(),
+      Path::new("/dist/js")
+    );
+    assert!(fs.read_link(Path::new("/dist/js")).is_err());
+  }
+}
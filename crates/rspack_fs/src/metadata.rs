@@ -0,0 +1,35 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Metadata of a file or directory, similar to `fs.Stats` of Node.js.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileMetadata {
+  pub is_file: bool,
+  pub is_directory: bool,
+  /// Only set by `symlink_metadata`, `metadata` follows symbolic links.
+  pub is_symlink: bool,
+  /// Last modification time, in milliseconds since the unix epoch.
+  pub mtime_ms: u64,
+  pub size: u64,
+}
+
+impl From<std::fs::Metadata> for FileMetadata {
+  fn from(metadata: std::fs::Metadata) -> Self {
+    Self {
+      is_file: metadata.is_file(),
+      is_directory: metadata.is_dir(),
+      is_symlink: metadata.is_symlink(),
+      mtime_ms: metadata
+        .modified()
+        .map(system_time_to_ms)
+        .unwrap_or_default(),
+      size: metadata.len(),
+    }
+  }
+}
+
+pub(crate) fn system_time_to_ms(time: SystemTime) -> u64 {
+  time
+    .duration_since(UNIX_EPOCH)
+    .map(|d| d.as_millis() as u64)
+    .unwrap_or_default()
+}
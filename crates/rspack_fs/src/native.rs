@@ -1,11 +1,15 @@
-use std::{fs, path::Path};
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
 
 use super::{
   cfg_async,
   sync::{ReadableFileSystem, WritableFileSystem},
-  Error, Result,
+  Error, FileMetadata, Result,
 };
 
+#[derive(Debug)]
 pub struct NativeFileSystem;
 
 impl WritableFileSystem for NativeFileSystem {
@@ -20,11 +24,42 @@ impl WritableFileSystem for NativeFileSystem {
   fn write<P: AsRef<std::path::Path>, D: AsRef<[u8]>>(&self, file: P, data: D) -> Result<()> {
     fs::write(file.as_ref(), data.as_ref()).map_err(Error::from)
   }
+
+  fn rename<P: AsRef<Path>, Q: AsRef<Path>>(&self, from: P, to: Q) -> Result<()> {
+    fs::rename(from.as_ref(), to.as_ref()).map_err(Error::from)
+  }
 }
 
 impl ReadableFileSystem for NativeFileSystem {
-  fn read<P: AsRef<Path>>(&self, file: P) -> Result<Vec<u8>> {
-    fs::read(file.as_ref()).map_err(Error::from)
+  fn read(&self, file: &Path) -> Result<Vec<u8>> {
+    fs::read(file).map_err(Error::from)
+  }
+
+  fn metadata(&self, path: &Path) -> Result<FileMetadata> {
+    fs::metadata(path)
+      .map(FileMetadata::from)
+      .map_err(Error::from)
+  }
+
+  fn symlink_metadata(&self, path: &Path) -> Result<FileMetadata> {
+    fs::symlink_metadata(path)
+      .map(FileMetadata::from)
+      .map_err(Error::from)
+  }
+
+  fn read_dir(&self, dir: &Path) -> Result<Vec<String>> {
+    fs::read_dir(dir)?
+      .map(|entry| Ok(entry?.file_name().to_string_lossy().to_string()))
+      .collect()
+  }
+
+  fn read_link(&self, path: &Path) -> Result<PathBuf> {
+    fs::read_link(path).map_err(Error::from)
+  }
+
+  fn canonicalize(&self, path: &Path) -> Result<PathBuf> {
+    // avoid the `\\?\` prefix of verbatim paths on windows
+    dunce::canonicalize(path).map_err(Error::from)
   }
 }
 
@@ -32,6 +67,7 @@ cfg_async! {
   use futures::future::BoxFuture;
 
   use crate::{AsyncReadableFileSystem, AsyncWritableFileSystem};
+  #[derive(Debug)]
   pub struct AsyncNativeFileSystem;
 
   impl AsyncWritableFileSystem for AsyncNativeFileSystem {
@@ -69,13 +105,100 @@ cfg_async! {
       let fut = async move { tokio::fs::remove_dir_all(dir).await.map_err(Error::from) };
       Box::pin(fut)
     }
+
+    fn rename<P: AsRef<Path>, Q: AsRef<Path>>(&self, from: P, to: Q) -> BoxFuture<'_, Result<()>> {
+      let from = from.as_ref().to_path_buf();
+      let to = to.as_ref().to_path_buf();
+      let fut = async move { tokio::fs::rename(from, to).await.map_err(Error::from) };
+      Box::pin(fut)
+    }
   }
 
   impl AsyncReadableFileSystem for AsyncNativeFileSystem {
-    fn read<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<Vec<u8>>> {
-      let file = file.as_ref().to_string_lossy().to_string();
+    fn read(&self, file: &Path) -> BoxFuture<'_, Result<Vec<u8>>> {
+      let file = file.to_string_lossy().to_string();
       let fut = async move { tokio::fs::read(file).await.map_err(Error::from) };
       Box::pin(fut)
     }
+
+    fn metadata(&self, path: &Path) -> BoxFuture<'_, Result<FileMetadata>> {
+      let path = path.to_path_buf();
+      let fut = async move {
+        tokio::fs::metadata(path)
+          .await
+          .map(FileMetadata::from)
+          .map_err(Error::from)
+      };
+      Box::pin(fut)
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> BoxFuture<'_, Result<FileMetadata>> {
+      let path = path.to_path_buf();
+      let fut = async move {
+        tokio::fs::symlink_metadata(path)
+          .await
+          .map(FileMetadata::from)
+          .map_err(Error::from)
+      };
+      Box::pin(fut)
+    }
+
+    fn read_dir(&self, dir: &Path) -> BoxFuture<'_, Result<Vec<String>>> {
+      let dir = dir.to_path_buf();
+      let fut = async move {
+        let mut entries = tokio::fs::read_dir(dir).await?;
+        let mut names = vec![];
+        while let Some(entry) = entries.next_entry().await? {
+          names.push(entry.file_name().to_string_lossy().to_string());
+        }
+        Ok(names)
+      };
+      Box::pin(fut)
+    }
+
+    fn read_link(&self, path: &Path) -> BoxFuture<'_, Result<PathBuf>> {
+      let path = path.to_path_buf();
+      let fut = async move { tokio::fs::read_link(path).await.map_err(Error::from) };
+      Box::pin(fut)
+    }
+
+    fn canonicalize(&self, path: &Path) -> BoxFuture<'_, Result<PathBuf>> {
+      let path = path.to_path_buf();
+      let fut = async move {
+        // avoid the `\\?\` prefix of verbatim paths on windows, like `dunce::canonicalize`
+        tokio::fs::canonicalize(path)
+          .await
+          .map(|path| dunce::simplified(&path).to_path_buf())
+          .map_err(Error::from)
+      };
+      Box::pin(fut)
+    }
+  }
+
+  /// Blocking reads, for the consumers which can't await like the resolver.
+  impl ReadableFileSystem for AsyncNativeFileSystem {
+    fn read(&self, file: &Path) -> Result<Vec<u8>> {
+      ReadableFileSystem::read(&NativeFileSystem, file)
+    }
+
+    fn metadata(&self, path: &Path) -> Result<FileMetadata> {
+      ReadableFileSystem::metadata(&NativeFileSystem, path)
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> Result<FileMetadata> {
+      ReadableFileSystem::symlink_metadata(&NativeFileSystem, path)
+    }
+
+    fn read_dir(&self, dir: &Path) -> Result<Vec<String>> {
+      ReadableFileSystem::read_dir(&NativeFileSystem, dir)
+    }
+
+    fn read_link(&self, path: &Path) -> Result<PathBuf> {
+      ReadableFileSystem::read_link(&NativeFileSystem, path)
+    }
+
+    fn canonicalize(&self, path: &Path) -> Result<PathBuf> {
+      ReadableFileSystem::canonicalize(&NativeFileSystem, path)
+    }
   }
 }
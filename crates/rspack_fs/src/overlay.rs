@@ -0,0 +1,275 @@
+use std::path::{Path, PathBuf};
+
+use super::{
+  cfg_async,
+  sync::{ReadableFileSystem, WritableFileSystem},
+  FileMetadata, MemoryFileSystem, Result,
+};
+
+/// A file system which puts a [`MemoryFileSystem`] on top of another file system.
+///
+/// Reads look at the memory layer first and fall back to the lower file system, writes only
+/// go to the memory layer, so the lower file system is never touched. Renaming or removing an
+/// entry that only exists in the lower file system is an error, both in sync and async.
+#[derive(Debug, Clone)]
+pub struct OverlayFileSystem<T> {
+  upper: MemoryFileSystem,
+  lower: T,
+}
+
+impl<T> OverlayFileSystem<T> {
+  pub fn new(lower: T) -> Self {
+    Self::with_upper(MemoryFileSystem::new(), lower)
+  }
+
+  pub fn with_upper(upper: MemoryFileSystem, lower: T) -> Self {
+    Self { upper, lower }
+  }
+
+  /// The memory layer, which contains every file written through this file system.
+  pub fn upper(&self) -> &MemoryFileSystem {
+    &self.upper
+  }
+
+  pub fn lower(&self) -> &T {
+    &self.lower
+  }
+
+  fn write_upper(&self, file: &Path, data: &[u8]) -> Result<()> {
+    // writing into a directory that only exists in the lower layer should work as well
+    if let Some(parent) = file.parent() {
+      WritableFileSystem::create_dir_all(&self.upper, parent)?;
+    }
+    WritableFileSystem::write(&self.upper, file, data)
+  }
+}
+
+/// Entries of a directory in both layers, it's only an error if the directory is in neither of them.
+fn merge_dir(upper: Result<Vec<String>>, lower: Result<Vec<String>>) -> Result<Vec<String>> {
+  let mut names = match (upper, lower) {
+    (Err(_), Err(e)) => return Err(e),
+    (upper, lower) => [upper.unwrap_or_default(), lower.unwrap_or_default()].concat(),
+  };
+  names.sort();
+  names.dedup();
+  Ok(names)
+}
+
+impl<T> WritableFileSystem for OverlayFileSystem<T> {
+  fn create_dir<P: AsRef<Path>>(&self, dir: P) -> Result<()> {
+    WritableFileSystem::create_dir(&self.upper, dir)
+  }
+
+  fn create_dir_all<P: AsRef<Path>>(&self, dir: P) -> Result<()> {
+    WritableFileSystem::create_dir_all(&self.upper, dir)
+  }
+
+  fn write<P: AsRef<Path>, D: AsRef<[u8]>>(&self, file: P, data: D) -> Result<()> {
+    self.write_upper(file.as_ref(), data.as_ref())
+  }
+
+  fn rename<P: AsRef<Path>, Q: AsRef<Path>>(&self, from: P, to: Q) -> Result<()> {
+    WritableFileSystem::rename(&self.upper, from, to)
+  }
+}
+
+impl<T: ReadableFileSystem> ReadableFileSystem for OverlayFileSystem<T> {
+  fn read(&self, file: &Path) -> Result<Vec<u8>> {
+    if self.upper.exists(file) {
+      ReadableFileSystem::read(&self.upper, file)
+    } else {
+      self.lower.read(file)
+    }
+  }
+
+  fn metadata(&self, path: &Path) -> Result<FileMetadata> {
+    if self.upper.exists(path) {
+      ReadableFileSystem::metadata(&self.upper, path)
+    } else {
+      self.lower.metadata(path)
+    }
+  }
+
+  fn symlink_metadata(&self, path: &Path) -> Result<FileMetadata> {
+    if self.upper.exists(path) {
+      ReadableFileSystem::symlink_metadata(&self.upper, path)
+    } else {
+      self.lower.symlink_metadata(path)
+    }
+  }
+
+  fn read_dir(&self, dir: &Path) -> Result<Vec<String>> {
+    merge_dir(
+      ReadableFileSystem::read_dir(&self.upper, dir),
+      self.lower.read_dir(dir),
+    )
+  }
+
+  fn read_link(&self, path: &Path) -> Result<PathBuf> {
+    if self.upper.exists(path) {
+      ReadableFileSystem::read_link(&self.upper, path)
+    } else {
+      self.lower.read_link(path)
+    }
+  }
+
+  fn canonicalize(&self, path: &Path) -> Result<PathBuf> {
+    if self.upper.exists(path) {
+      ReadableFileSystem::canonicalize(&self.upper, path)
+    } else {
+      self.lower.canonicalize(path)
+    }
+  }
+}
+
+cfg_async! {
+  use futures::future::BoxFuture;
+
+  use crate::{AsyncReadableFileSystem, AsyncWritableFileSystem};
+
+  impl<T> AsyncWritableFileSystem for OverlayFileSystem<T> {
+    fn create_dir<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+      AsyncWritableFileSystem::create_dir(&self.upper, dir)
+    }
+
+    fn create_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+      AsyncWritableFileSystem::create_dir_all(&self.upper, dir)
+    }
+
+    fn write<P: AsRef<Path>, D: AsRef<[u8]>>(&self, file: P, data: D) -> BoxFuture<'_, Result<()>> {
+      let res = self.write_upper(file.as_ref(), data.as_ref());
+      Box::pin(async move { res })
+    }
+
+    fn remove_file<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<()>> {
+      AsyncWritableFileSystem::remove_file(&self.upper, file)
+    }
+
+    fn remove_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+      AsyncWritableFileSystem::remove_dir_all(&self.upper, dir)
+    }
+
+    fn rename<P: AsRef<Path>, Q: AsRef<Path>>(&self, from: P, to: Q) -> BoxFuture<'_, Result<()>> {
+      AsyncWritableFileSystem::rename(&self.upper, from, to)
+    }
+  }
+
+  impl<T: AsyncReadableFileSystem> AsyncReadableFileSystem for OverlayFileSystem<T> {
+    fn read(&self, file: &Path) -> BoxFuture<'_, Result<Vec<u8>>> {
+      if self.upper.exists(file) {
+        AsyncReadableFileSystem::read(&self.upper, file)
+      } else {
+        self.lower.read(file)
+      }
+    }
+
+    fn metadata(&self, path: &Path) -> BoxFuture<'_, Result<FileMetadata>> {
+      if self.upper.exists(path) {
+        AsyncReadableFileSystem::metadata(&self.upper, path)
+      } else {
+        self.lower.metadata(path)
+      }
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> BoxFuture<'_, Result<FileMetadata>> {
+      if self.upper.exists(path) {
+        AsyncReadableFileSystem::symlink_metadata(&self.upper, path)
+      } else {
+        self.lower.symlink_metadata(path)
+      }
+    }
+
+    fn read_dir(&self, dir: &Path) -> BoxFuture<'_, Result<Vec<String>>> {
+      let upper = ReadableFileSystem::read_dir(&self.upper, dir);
+      let lower = self.lower.read_dir(dir);
+      Box::pin(async move { merge_dir(upper, lower.await) })
+    }
+
+    fn read_link(&self, path: &Path) -> BoxFuture<'_, Result<PathBuf>> {
+      if self.upper.exists(path) {
+        AsyncReadableFileSystem::read_link(&self.upper, path)
+      } else {
+        self.lower.read_link(path)
+      }
+    }
+
+    fn canonicalize(&self, path: &Path) -> BoxFuture<'_, Result<PathBuf>> {
+      if self.upper.exists(path) {
+        AsyncReadableFileSystem::canonicalize(&self.upper, path)
+      } else {
+        self.lower.canonicalize(path)
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::path::Path;
+
+  use super::OverlayFileSystem;
+  use crate::{
+    sync::{ReadableFileSystem, WritableFileSystem},
+    MemoryFileSystem,
+  };
+
+  #[test]
+  fn should_read_upper_before_lower() {
+    let lower = MemoryFileSystem::new();
+    lower.create_dir_all("/src").unwrap();
+    lower.write("/src/a.js", "lower a").unwrap();
+    lower.write("/src/b.js", "lower b").unwrap();
+
+    let fs = OverlayFileSystem::new(lower.clone());
+    fs.write("/src/a.js", "upper a").unwrap();
+    assert_eq!(fs.read(Path::new("/src/a.js")).unwrap(), b"upper a");
+    assert_eq!(fs.read(Path::new("/src/b.js")).unwrap(), b"lower b");
+    assert!(fs.read(Path::new("/src/c.js")).is_err());
+    // the lower file system is left untouched
+    assert_eq!(lower.read(Path::new("/src/a.js")).unwrap(), b"lower a");
+
+    fs.write("/src/c.js", "upper c").unwrap();
+    assert_eq!(
+      fs.read_dir(Path::new("/src")).unwrap(),
+      vec!["a.js", "b.js", "c.js"]
+    );
+    assert_eq!(fs.metadata(Path::new("/src/b.js")).unwrap().size, 7);
+  }
+
+  #[cfg(feature = "async")]
+  #[test]
+  fn should_only_rename_in_upper() {
+    use futures::executor::block_on;
+
+    let lower = MemoryFileSystem::new();
+    lower.create_dir_all("/src").unwrap();
+    lower.write("/src/a.js", "lower a").unwrap();
+
+    let fs = OverlayFileSystem::new(lower.clone());
+    fs.write("/src/b.js", "upper b").unwrap();
+    WritableFileSystem::rename(&fs, "/src/b.js", "/src/c.js").unwrap();
+    block_on(crate::AsyncWritableFileSystem::rename(
+      &fs,
+      "/src/c.js",
+      "/src/d.js",
+    ))
+    .unwrap();
+    assert_eq!(fs.read(Path::new("/src/d.js")).unwrap(), b"upper b");
+
+    // entries of the lower file system can't be moved by either of them
+    assert!(WritableFileSystem::rename(&fs, "/src/a.js", "/src/e.js").is_err());
+    assert!(block_on(crate::AsyncWritableFileSystem::rename(
+      &fs,
+      "/src/a.js",
+      "/src/e.js"
+    ))
+    .is_err());
+    assert!(block_on(crate::AsyncWritableFileSystem::remove_file(
+      &fs,
+      "/src/a.js"
+    ))
+    .is_err());
+    assert_eq!(lower.read(Path::new("/src/a.js")).unwrap(), b"lower a");
+    assert_eq!(fs.read(Path::new("/src/a.js")).unwrap(), b"lower a");
+  }
+}
@@ -1,6 +1,6 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use super::Result;
+use super::{FileMetadata, Result};
 
 pub trait WritableFileSystem {
   /// Creates a new, empty directory at the provided path.
@@ -21,13 +21,32 @@ pub trait WritableFileSystem {
   /// Write a slice as the entire contents of a file.
   /// This function will create a file if it does not exist, and will entirely replace its contents if it does.
   fn write<P: AsRef<Path>, D: AsRef<[u8]>>(&self, file: P, data: D) -> Result<()>;
+
+  /// Rename a file or directory to a new name, replacing the original file if `to` already exists.
+  fn rename<P: AsRef<Path>, Q: AsRef<Path>>(&self, from: P, to: Q) -> Result<()>;
 }
 
+/// Readable file systems are object safe, so they can be shared as `dyn ReadableFileSystem`.
 pub trait ReadableFileSystem {
   /// Read the entire contents of a file into a bytes vector.
   ///
   /// Error: This function will return an error if path does not already exist.
-  fn read<P: AsRef<Path>>(&self, file: P) -> Result<Vec<u8>>;
+  fn read(&self, file: &Path) -> Result<Vec<u8>>;
+
+  /// Query the metadata of a path, following symbolic links.
+  fn metadata(&self, path: &Path) -> Result<FileMetadata>;
+
+  /// Query the metadata of a path without following symbolic links.
+  fn symlink_metadata(&self, path: &Path) -> Result<FileMetadata>;
+
+  /// Read the names of the entries in a directory, without `.` and `..`.
+  fn read_dir(&self, dir: &Path) -> Result<Vec<String>>;
+
+  /// Read the target of a symbolic link.
+  fn read_link(&self, path: &Path) -> Result<PathBuf>;
+
+  /// Get the absolute path with all intermediate components normalized and symbolic links resolved.
+  fn canonicalize(&self, path: &Path) -> Result<PathBuf>;
 }
 
 /// Readable and writable file system representation.
@@ -1,7 +1,12 @@
+use std::path::{Path, PathBuf};
+
 use futures::future::BoxFuture;
 use napi::Env;
-use rspack_fs::r#async::AsyncWritableFileSystem;
-use rspack_napi_shared::threadsafe_function::ThreadsafeFunctionCallMode;
+use rspack_fs::{
+  r#async::{AsyncReadableFileSystem, AsyncWritableFileSystem},
+  FileMetadata,
+};
+use rspack_napi_shared::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
 
 use crate::node::{ThreadsafeFunctionRef, ThreadsafeNodeFS, TryIntoThreadsafeFunctionRef};
 
@@ -16,6 +21,38 @@ impl AsyncNodeWritableFileSystem {
   }
 }
 
+impl std::fmt::Debug for AsyncNodeWritableFileSystem {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("AsyncNodeWritableFileSystem")
+      .finish_non_exhaustive()
+  }
+}
+
+/// Call a function of the node file system, and convert the error to [rspack_fs::Error].
+fn call_fs<T: Send + 'static, R: Send + 'static>(
+  tsfn: &ThreadsafeFunction<T, R>,
+  value: T,
+) -> BoxFuture<'_, rspack_fs::Result<R>> {
+  let fut = async move {
+    tsfn
+      .call(value, ThreadsafeFunctionCallMode::NonBlocking)
+      .expect("Failed to call tsfn")
+      .await
+      .expect("Failed to poll")
+      .map_err(|e| {
+        rspack_fs::Error::Io(std::io::Error::new(
+          std::io::ErrorKind::Other,
+          e.to_string(),
+        ))
+      })
+  };
+  Box::pin(fut)
+}
+
+fn path_to_string(path: &Path) -> String {
+  path.to_string_lossy().to_string()
+}
+
 impl AsyncWritableFileSystem for AsyncNodeWritableFileSystem {
   fn create_dir<P: AsRef<std::path::Path>>(&self, dir: P) -> BoxFuture<'_, rspack_fs::Result<()>> {
     let dir = dir.as_ref().to_string_lossy().to_string();
@@ -134,4 +171,46 @@ impl AsyncWritableFileSystem for AsyncNodeWritableFileSystem {
     };
     Box::pin(fut)
   }
+
+  fn rename<P: AsRef<Path>, Q: AsRef<Path>>(
+    &self,
+    from: P,
+    to: Q,
+  ) -> BoxFuture<'_, rspack_fs::Result<()>> {
+    call_fs(
+      &self.fs_ts.rename,
+      (path_to_string(from.as_ref()), path_to_string(to.as_ref())),
+    )
+  }
+}
+
+impl AsyncReadableFileSystem for AsyncNodeWritableFileSystem {
+  fn read(&self, file: &Path) -> BoxFuture<'_, rspack_fs::Result<Vec<u8>>> {
+    let fut = call_fs(&self.fs_ts.read_file, path_to_string(file));
+    Box::pin(async move { fut.await.map(Vec::from) })
+  }
+
+  fn metadata(&self, path: &Path) -> BoxFuture<'_, rspack_fs::Result<FileMetadata>> {
+    let fut = call_fs(&self.fs_ts.stat, path_to_string(path));
+    Box::pin(async move { fut.await.map(FileMetadata::from) })
+  }
+
+  fn symlink_metadata(&self, path: &Path) -> BoxFuture<'_, rspack_fs::Result<FileMetadata>> {
+    let fut = call_fs(&self.fs_ts.lstat, path_to_string(path));
+    Box::pin(async move { fut.await.map(FileMetadata::from) })
+  }
+
+  fn read_dir(&self, dir: &Path) -> BoxFuture<'_, rspack_fs::Result<Vec<String>>> {
+    call_fs(&self.fs_ts.readdir, path_to_string(dir))
+  }
+
+  fn read_link(&self, path: &Path) -> BoxFuture<'_, rspack_fs::Result<PathBuf>> {
+    let fut = call_fs(&self.fs_ts.readlink, path_to_string(path));
+    Box::pin(async move { fut.await.map(PathBuf::from) })
+  }
+
+  fn canonicalize(&self, path: &Path) -> BoxFuture<'_, rspack_fs::Result<PathBuf>> {
+    let fut = call_fs(&self.fs_ts.realpath, path_to_string(path));
+    Box::pin(async move { fut.await.map(PathBuf::from) })
+  }
 }
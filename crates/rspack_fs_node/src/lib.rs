@@ -10,7 +10,7 @@ mod sync;
 pub use sync::NodeWritableFileSystem;
 
 mod node;
-pub use node::NodeFS;
+pub use node::{NodeFS, NodeFsStats};
 
 cfg_async! {
   pub use node::ThreadsafeNodeFS;
@@ -1,6 +1,6 @@
 use napi::{Env, JsFunction, NapiRaw, Ref};
 use napi_derive::napi;
-use rspack_fs::cfg_async;
+use rspack_fs::{cfg_async, FileMetadata};
 
 pub(crate) struct JsFunctionRef {
   env: Env,
@@ -27,12 +27,40 @@ impl Drop for JsFunctionRef {
   }
 }
 
+#[napi(object, js_name = "NodeFsStats")]
+pub struct NodeFsStats {
+  pub is_file: bool,
+  pub is_directory: bool,
+  pub is_symlink: bool,
+  pub mtime_ms: f64,
+  pub size: f64,
+}
+
+impl From<NodeFsStats> for FileMetadata {
+  fn from(value: NodeFsStats) -> Self {
+    Self {
+      is_file: value.is_file,
+      is_directory: value.is_directory,
+      is_symlink: value.is_symlink,
+      mtime_ms: value.mtime_ms as u64,
+      size: value.size as u64,
+    }
+  }
+}
+
 #[napi(object, js_name = "NodeFS")]
 pub struct NodeFS {
   pub write_file: JsFunction,
   pub remove_file: JsFunction,
   pub mkdir: JsFunction,
   pub mkdirp: JsFunction,
+  pub rename: JsFunction,
+  pub read_file: JsFunction,
+  pub stat: JsFunction,
+  pub lstat: JsFunction,
+  pub readdir: JsFunction,
+  pub readlink: JsFunction,
+  pub realpath: JsFunction,
 }
 
 pub(crate) trait TryIntoNodeFSRef {
@@ -45,6 +73,13 @@ impl TryIntoNodeFSRef for NodeFS {
       write_file: JsFunctionRef::new(*env, self.write_file)?,
       mkdir: JsFunctionRef::new(*env, self.mkdir)?,
       mkdirp: JsFunctionRef::new(*env, self.mkdirp)?,
+      rename: JsFunctionRef::new(*env, self.rename)?,
+      read_file: JsFunctionRef::new(*env, self.read_file)?,
+      stat: JsFunctionRef::new(*env, self.stat)?,
+      lstat: JsFunctionRef::new(*env, self.lstat)?,
+      readdir: JsFunctionRef::new(*env, self.readdir)?,
+      readlink: JsFunctionRef::new(*env, self.readlink)?,
+      realpath: JsFunctionRef::new(*env, self.realpath)?,
     })
   }
 }
@@ -53,11 +88,18 @@ pub(crate) struct NodeFSRef {
   pub(crate) write_file: JsFunctionRef,
   pub(crate) mkdir: JsFunctionRef,
   pub(crate) mkdirp: JsFunctionRef,
+  pub(crate) rename: JsFunctionRef,
+  pub(crate) read_file: JsFunctionRef,
+  pub(crate) stat: JsFunctionRef,
+  pub(crate) lstat: JsFunctionRef,
+  pub(crate) readdir: JsFunctionRef,
+  pub(crate) readlink: JsFunctionRef,
+  pub(crate) realpath: JsFunctionRef,
 }
 
 cfg_async! {
   use napi::{
-    bindgen_prelude::FromNapiValue,
+    bindgen_prelude::{Buffer, FromNapiValue},
     JsUnknown,
     Either,
   };
@@ -70,6 +112,13 @@ cfg_async! {
     pub mkdir: JsFunction,
     pub mkdirp: JsFunction,
     pub remove_dir_all: JsFunction,
+    pub rename: JsFunction,
+    pub read_file: JsFunction,
+    pub stat: JsFunction,
+    pub lstat: JsFunction,
+    pub readdir: JsFunction,
+    pub readlink: JsFunction,
+    pub realpath: JsFunction,
   }
 
   trait TryIntoJsUnknown {
@@ -154,6 +203,13 @@ cfg_async! {
         mkdir: self.mkdir.try_into_tsfn(env)?,
         mkdirp: self.mkdirp.try_into_tsfn(env)?,
         remove_dir_all: self.remove_dir_all.try_into_tsfn(env)?,
+        rename: self.rename.try_into_tsfn(env)?,
+        read_file: self.read_file.try_into_tsfn(env)?,
+        stat: self.stat.try_into_tsfn(env)?,
+        lstat: self.lstat.try_into_tsfn(env)?,
+        readdir: self.readdir.try_into_tsfn(env)?,
+        readlink: self.readlink.try_into_tsfn(env)?,
+        realpath: self.realpath.try_into_tsfn(env)?,
       })
     }
   }
@@ -164,5 +220,12 @@ cfg_async! {
     pub(crate) mkdir: ThreadsafeFunction<String, ()>,
     pub(crate) mkdirp: ThreadsafeFunction<String, Either<String, ()>>,
     pub(crate) remove_dir_all: ThreadsafeFunction<String, Either<String, ()>>,
+    pub(crate) rename: ThreadsafeFunction<(String, String), ()>,
+    pub(crate) read_file: ThreadsafeFunction<String, Buffer>,
+    pub(crate) stat: ThreadsafeFunction<String, NodeFsStats>,
+    pub(crate) lstat: ThreadsafeFunction<String, NodeFsStats>,
+    pub(crate) readdir: ThreadsafeFunction<String, Vec<String>>,
+    pub(crate) readlink: ThreadsafeFunction<String, String>,
+    pub(crate) realpath: ThreadsafeFunction<String, String>,
   }
 }
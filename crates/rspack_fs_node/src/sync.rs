@@ -1,9 +1,18 @@
-use std::{marker::PhantomData, path::Path};
+use std::{
+  marker::PhantomData,
+  path::{Path, PathBuf},
+};
 
-use napi::Env;
-use rspack_fs::{sync::WritableFileSystem, Error, Result};
+use napi::{
+  bindgen_prelude::{Buffer, FromNapiValue},
+  Env, NapiRaw,
+};
+use rspack_fs::{
+  sync::{ReadableFileSystem, WritableFileSystem},
+  Error, FileMetadata, Result,
+};
 
-use crate::node::{NodeFS, NodeFSRef, TryIntoNodeFSRef};
+use crate::node::{JsFunctionRef, NodeFS, NodeFSRef, NodeFsStats, TryIntoNodeFSRef};
 
 pub struct NodeWritableFileSystem {
   env: Env,
@@ -19,6 +28,29 @@ impl NodeWritableFileSystem {
       _data: PhantomData,
     })
   }
+
+  /// Call a function of the node file system with paths, and convert the returned value.
+  fn call<R: FromNapiValue>(&self, f: &JsFunctionRef, paths: &[&Path]) -> Result<R> {
+    let to_error = |err: napi::Error| {
+      Error::Io(std::io::Error::new(
+        std::io::ErrorKind::Other,
+        err.to_string(),
+      ))
+    };
+    let f = f.get().map_err(to_error)?;
+    let args = paths
+      .iter()
+      .map(|p| {
+        self
+          .env
+          .create_string(&p.to_string_lossy())
+          .map(|s| s.into_unknown())
+      })
+      .collect::<napi::Result<Vec<_>>>()
+      .map_err(to_error)?;
+    let result = f.call(None, &args).map_err(to_error)?;
+    unsafe { R::from_napi_value(self.env.raw(), result.raw()) }.map_err(to_error)
+  }
 }
 
 impl WritableFileSystem for NodeWritableFileSystem {
@@ -98,4 +130,44 @@ impl WritableFileSystem for NodeWritableFileSystem {
 
     Ok(())
   }
+
+  fn rename<P: AsRef<Path>, Q: AsRef<Path>>(&self, from: P, to: Q) -> Result<()> {
+    self.call::<()>(&self.fs_ref.rename, &[from.as_ref(), to.as_ref()])
+  }
+}
+
+impl ReadableFileSystem for NodeWritableFileSystem {
+  fn read(&self, file: &Path) -> Result<Vec<u8>> {
+    self
+      .call::<Buffer>(&self.fs_ref.read_file, &[file])
+      .map(Vec::from)
+  }
+
+  fn metadata(&self, path: &Path) -> Result<FileMetadata> {
+    self
+      .call::<NodeFsStats>(&self.fs_ref.stat, &[path])
+      .map(FileMetadata::from)
+  }
+
+  fn symlink_metadata(&self, path: &Path) -> Result<FileMetadata> {
+    self
+      .call::<NodeFsStats>(&self.fs_ref.lstat, &[path])
+      .map(FileMetadata::from)
+  }
+
+  fn read_dir(&self, dir: &Path) -> Result<Vec<String>> {
+    self.call(&self.fs_ref.readdir, &[dir])
+  }
+
+  fn read_link(&self, path: &Path) -> Result<PathBuf> {
+    self
+      .call::<String>(&self.fs_ref.readlink, &[path])
+      .map(PathBuf::from)
+  }
+
+  fn canonicalize(&self, path: &Path) -> Result<PathBuf> {
+    self
+      .call::<String>(&self.fs_ref.realpath, &[path])
+      .map(PathBuf::from)
+  }
 }
@@ -39,6 +39,7 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
         mode: rspack_core::Mode::None,
         output: rspack_core::OutputOptions {
           clean: false,
+          clean_keep: None,
           path: Default::default(),
           public_path: Default::default(),
           filename: rspack_core::Filename::from_str("").expect("TODO:"),
@@ -76,7 +77,11 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
           worker_public_path: String::new(),
           script_type: String::from("false"),
         },
-        target: rspack_core::Target::new(&vec![String::from("web")]).expect("TODO:"),
+        target: rspack_core::Target::new(
+          &vec![String::from("web")],
+          &rspack_core::Context::default(),
+        )
+        .expect("TODO:"),
         resolve: rspack_core::Resolve::default(),
         resolve_loader: rspack_core::Resolve::default(),
         builtins: Default::default(),
@@ -93,7 +98,9 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
           used_exports: Default::default(),
           inner_graph: Default::default(),
           mangle_exports: Default::default(),
+          concatenate_modules: Default::default(),
         },
+        performance: Default::default(),
         profile: false,
       }),
       resolver_factory: Default::default(),
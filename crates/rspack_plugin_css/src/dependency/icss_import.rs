@@ -0,0 +1,75 @@
+use rspack_core::{
+  AsContextDependency, Dependency, DependencyCategory, DependencyId, DependencyTemplate,
+  DependencyType, ErrorSpan, ModuleDependency, TemplateContext, TemplateReplaceSource,
+};
+
+/// `:import("./x.css") { a: b }` or `@value a from "./x.css"`, the rule itself is removed and
+/// the imported values are replaced by `CssIcssSymbolDependency`.
+#[derive(Debug, Clone)]
+pub struct CssIcssImportDependency {
+  id: DependencyId,
+  request: String,
+  span: Option<ErrorSpan>,
+  start: u32,
+  end: u32,
+}
+
+impl CssIcssImportDependency {
+  pub fn new(request: String, span: Option<ErrorSpan>, start: u32, end: u32) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      span,
+      start,
+      end,
+    }
+  }
+}
+
+impl Dependency for CssIcssImportDependency {
+  fn dependency_debug_name(&self) -> &'static str {
+    "CssIcssImportDependency"
+  }
+
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::CssImport
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::CssIcssImport
+  }
+
+  fn span(&self) -> Option<ErrorSpan> {
+    self.span
+  }
+}
+
+impl ModuleDependency for CssIcssImportDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request;
+  }
+}
+
+impl DependencyTemplate for CssIcssImportDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    _code_generatable_context: &mut TemplateContext,
+  ) {
+    source.replace(self.start, self.end, "", None);
+  }
+}
+
+impl AsContextDependency for CssIcssImportDependency {}
@@ -0,0 +1,41 @@
+use rspack_core::{DependencyTemplate, TemplateContext, TemplateReplaceSource};
+
+use crate::utils::resolve_icss_import;
+
+/// A usage of a value imported by `:import` or `@value ... from`, which is replaced by the
+/// value exported from the imported module.
+#[derive(Debug, Clone)]
+pub struct CssIcssSymbolDependency {
+  request: String,
+  name: String,
+  start: u32,
+  end: u32,
+}
+
+impl CssIcssSymbolDependency {
+  pub fn new(request: String, name: String, start: u32, end: u32) -> Self {
+    Self {
+      request,
+      name,
+      start,
+      end,
+    }
+  }
+}
+
+impl DependencyTemplate for CssIcssSymbolDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      compilation,
+      module,
+      ..
+    } = code_generatable_context;
+    if let Some(value) = resolve_icss_import(*module, compilation, &self.request, &self.name) {
+      source.replace(self.start, self.end, &value, None);
+    }
+  }
+}
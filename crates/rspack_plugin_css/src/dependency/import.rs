@@ -10,18 +10,47 @@ pub struct CssImportDependency {
   span: Option<ErrorSpan>,
   start: u32,
   end: u32,
+  layer: Option<String>,
+  supports: Option<String>,
+  media: Option<String>,
 }
 
 impl CssImportDependency {
-  pub fn new(request: String, span: Option<ErrorSpan>, start: u32, end: u32) -> Self {
+  pub fn new(
+    request: String,
+    span: Option<ErrorSpan>,
+    start: u32,
+    end: u32,
+    layer: Option<String>,
+    supports: Option<String>,
+    media: Option<String>,
+  ) -> Self {
     Self {
       id: DependencyId::new(),
       request,
       span,
       start,
       end,
+      layer,
+      supports,
+      media,
     }
   }
+
+  /// The cascade layer of `layer` or `layer(name)`, it's empty for an anonymous layer.
+  pub fn layer(&self) -> Option<&str> {
+    self.layer.as_deref()
+  }
+
+  /// The condition inside of `supports(...)`.
+  pub fn supports(&self) -> Option<&str> {
+    self.supports.as_deref()
+  }
+
+  /// The media query list after the url.
+  pub fn media(&self) -> Option<&str> {
+    self.media.as_deref()
+  }
 }
 
 impl Dependency for CssImportDependency {
@@ -1,7 +1,11 @@
 mod compose;
+mod icss_import;
+mod icss_symbol;
 mod import;
 mod url;
 
 pub use compose::*;
+pub use icss_import::*;
+pub use icss_symbol::*;
 pub use import::*;
 pub use url::*;
@@ -0,0 +1,9 @@
+var __rspack_css_locals__ = JSON.stringify(module.exports);
+if (module.hot.data && module.hot.data.cssLocals !== undefined && module.hot.data.cssLocals !== __rspack_css_locals__) {
+	module.hot.invalidate();
+} else {
+	module.hot.accept();
+}
+module.hot.dispose(function (data) {
+	data.cssLocals = __rspack_css_locals__;
+});
@@ -1,6 +1,6 @@
 #![allow(clippy::comparison_chain)]
 
-use std::collections::HashMap;
+use std::{collections::HashMap, path::Path};
 
 use indexmap::IndexMap;
 use once_cell::sync::Lazy;
@@ -16,22 +16,28 @@ use rspack_core::{
   ParseContext, ParseResult, ParserAndGenerator, SourceType, TemplateContext,
 };
 use rspack_core::{ModuleInitFragments, RuntimeGlobals};
-use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_error::{error, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rustc_hash::FxHashSet;
 use sugar_path::SugarPath;
-use swc_core::{css::parser::parser::ParserConfig, ecma::atoms::JsWord};
+use swc_core::{
+  css::{parser::parser::ParserConfig, visit::VisitMutWith},
+  ecma::atoms::JsWord,
+};
 
 use crate::{
   dependency::CssComposeDependency,
   swc_css_compiler::{SwcCssCompiler, SwcCssSourceMapGenConfig},
 };
 use crate::{
-  plugin::CssConfig,
+  plugin::{CssConfig, ModulesMode, ModulesModeGetter},
   utils::{css_modules_exports_to_string, ModulesTransformConfig},
 };
 use crate::{
   utils::{export_locals_convention, stringify_css_modules_exports_elements},
-  visitors::analyze_dependencies,
+  visitors::{
+    analyze_dependencies, analyze_icss, analyze_pure_selectors, collect_local_names, IcssAnalysis,
+    IcssNamePreserver, IcssValue,
+  },
 };
 
 static REGEX_IS_MODULES: Lazy<Regex> =
@@ -49,6 +55,27 @@ type CssExportsType = Option<IndexMap<Vec<String>, Vec<(String, Option<String>)>
 pub struct CssParserAndGenerator {
   pub config: CssConfig,
   pub exports: CssExportsType,
+  /// Values exported by `:export` and `@value`, which are read by the modules importing them.
+  pub icss_exports: IndexMap<String, IcssValue>,
+  /// The mode got from the `mode` function of css modules, which is resolved before the loaders
+  /// run as the function is async.
+  pub modules_mode: Option<ModulesMode>,
+}
+
+impl CssParserAndGenerator {
+  pub(crate) fn is_css_modules(&self, module_type: &ModuleType, resource_path: &Path) -> bool {
+    match module_type {
+      ModuleType::CssModule => true,
+      ModuleType::CssAuto => {
+        let resource_path = resource_path.to_string_lossy();
+        match &self.config.modules.auto {
+          Some(auto) => auto.test(&resource_path),
+          None => REGEX_IS_MODULES.is_match(&resource_path),
+        }
+      }
+      _ => false,
+    }
+  }
 }
 
 impl ParserAndGenerator for CssParserAndGenerator {
@@ -90,20 +117,13 @@ impl ParserAndGenerator for CssParserAndGenerator {
     let mut source_code = source.source().into_owned();
     let resource_path = &parse_context.resource_data.resource_path;
 
-    let is_enable_css_modules = match module_type {
-      ModuleType::CssModule => true,
-      ModuleType::CssAuto
-        if REGEX_IS_MODULES.is_match(resource_path.to_string_lossy().as_ref()) =>
-      {
-        true
-      }
-      _ => false,
-    };
+    let is_enable_css_modules = self.is_css_modules(module_type, resource_path);
 
     let devtool = &compiler_options.devtool;
     let mut source_map = None;
     let mut diagnostic_vec = vec![];
 
+    self.exports = None;
     if is_enable_css_modules {
       let mut stylesheet = swc_compiler.parse_file(
         &resource_path.to_string_lossy(),
@@ -115,17 +135,41 @@ impl ParserAndGenerator for CssParserAndGenerator {
         },
       )?;
 
+      stylesheet.visit_mut_with(&mut IcssNamePreserver);
+      let modules = &self.config.modules;
+      let mode = match &modules.mode {
+        ModulesModeGetter::Mode(mode) => *mode,
+        ModulesModeGetter::Fn(_) => self
+          .modules_mode
+          .ok_or_else(|| error!("The mode of css modules should be resolved before parsing"))?,
+      };
+      if mode == ModulesMode::Pure {
+        analyze_pure_selectors(&stylesheet, &source.source(), &mut diagnostic_vec);
+      }
+      let local_names = (mode == ModulesMode::Global).then(|| collect_local_names(&stylesheet));
+      let captures = modules
+        .local_ident_reg_exp
+        .as_ref()
+        .and_then(|regexp| regexp.exec(&resource_path.to_string_lossy()));
       let result = swc_core::css::modules::compile(
         &mut stylesheet,
         ModulesTransformConfig::new(
-          &resource_data
-            .resource_path
-            .relative(&compiler_options.context),
-          &self.config.modules.local_ident_name,
+          &resource_data.resource_path.relative(
+            modules
+              .local_ident_context
+              .as_deref()
+              .unwrap_or(compiler_options.context.as_ref()),
+          ),
+          modules,
           &compiler_options.output,
+          captures.as_deref(),
+          local_names.as_ref(),
         ),
       );
       let mut exports: IndexMap<JsWord, _> = result.renamed.into_iter().collect();
+      if let Some(local_names) = &local_names {
+        exports.retain(|name, _| local_names.contains(name));
+      }
       exports.sort_keys();
 
       self.exports = Some(IndexMap::from_iter(
@@ -160,11 +204,17 @@ impl ParserAndGenerator for CssParserAndGenerator {
 
     let mut dependencies = analyze_dependencies(
       &new_stylesheet_ast,
+      &source_code,
       code_generation_dependencies,
       &mut diagnostic_vec,
     );
+    let IcssAnalysis {
+      dependencies: icss_dependencies,
+      presentational_dependencies,
+      exports: icss_exports,
+    } = analyze_icss(&new_stylesheet_ast, &source_code);
 
-    let dependencies = if let Some(locals) = &self.exports
+    let mut dependencies = if let Some(locals) = &self.exports
       && !locals.is_empty()
     {
       let mut dep_set = FxHashSet::default();
@@ -188,6 +238,22 @@ impl ParserAndGenerator for CssParserAndGenerator {
     } else {
       dependencies
     };
+    dependencies.extend(icss_dependencies);
+
+    if !icss_exports.is_empty() {
+      let exports = self.exports.get_or_insert_with(Default::default);
+      for (name, (value, from)) in &icss_exports {
+        let mut names = export_locals_convention(
+          &name.as_str().into(),
+          &self.config.modules.locals_convention,
+        );
+        names.sort_unstable();
+        names.dedup();
+        let value = serde_json::to_string(value).expect("should stringify icss export");
+        exports.insert(names, vec![(value, from.clone())]);
+      }
+    }
+    self.icss_exports = icss_exports;
 
     let new_source = if devtool.source_map() {
       if let Some(source_map) = source_map {
@@ -214,7 +280,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
       ParseResult {
         dependencies,
         blocks: vec![],
-        presentational_dependencies: vec![],
+        presentational_dependencies,
         source: new_source,
         analyze_result: Default::default(),
       }
@@ -243,6 +309,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
           runtime_requirements: generate_context.runtime_requirements,
           runtime: generate_context.runtime,
           init_fragments: &mut init_fragments,
+          concatenation_scope: None,
         };
 
         module.get_dependencies().iter().for_each(|id| {
@@ -265,14 +332,21 @@ impl ParserAndGenerator for CssParserAndGenerator {
         Ok(source.boxed())
       }
       SourceType::JavaScript => {
+        let hot = generate_context.compilation.options.dev_server.hot;
         let locals = if let Some(exports) = &self.exports {
-          css_modules_exports_to_string(
+          let mut locals = css_modules_exports_to_string(
             exports,
             module,
             generate_context.compilation,
             generate_context.runtime_requirements,
-          )?
-        } else if generate_context.compilation.options.dev_server.hot {
+          )?;
+          if hot {
+            // Self-accept as long as the exported locals stay the same, otherwise invalidate and
+            // let the update bubble up to the importers.
+            locals += include_str!("./css_modules_hmr.js");
+          }
+          locals
+        } else if hot {
           "module.hot.accept();".to_string()
         } else {
           "".to_string()
@@ -291,7 +365,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
     result
   }
   fn store(&self, extra_data: &mut HashMap<BuildExtraDataType, AlignedVec>) {
-    let data = self.exports.to_owned();
+    let data = (self.exports.to_owned(), self.icss_exports.to_owned());
     extra_data.insert(
       BuildExtraDataType::CssParserAndGenerator,
       to_bytes::<_, 1024>(&data).expect("Failed to store extra data"),
@@ -299,9 +373,11 @@ impl ParserAndGenerator for CssParserAndGenerator {
   }
   fn resume(&mut self, extra_data: &HashMap<BuildExtraDataType, AlignedVec>) {
     if let Some(data) = extra_data.get(&BuildExtraDataType::CssParserAndGenerator) {
-      let data = from_bytes::<Option<IndexMap<Vec<String>, Vec<(String, Option<String>)>>>>(data)
-        .expect("Failed to resume extra data");
-      self.exports = data;
+      let (exports, icss_exports) =
+        from_bytes::<(CssExportsType, IndexMap<String, IcssValue>)>(data)
+          .expect("Failed to resume extra data");
+      self.exports = exports;
+      self.icss_exports = icss_exports;
     }
   }
 }
@@ -6,18 +6,22 @@ use rayon::prelude::*;
 use rspack_core::rspack_sources::ReplaceSource;
 use rspack_core::{
   get_css_chunk_filename_template,
-  rspack_sources::{ConcatSource, RawSource, Source, SourceExt},
-  Chunk, ChunkKind, Module, ModuleType, ParserAndGenerator, PathData, Plugin, RenderManifestEntry,
-  SourceType,
+  rspack_sources::{BoxSource, ConcatSource, RawSource, Source, SourceExt},
+  BoxModule, Chunk, ChunkKind, Module, ModuleType, NormalModule, NormalModuleCreateData,
+  ParserAndGenerator, PathData, Plugin, PluginContext, PluginNormalModuleFactoryModuleHookOutput,
+  RenderManifestEntry, SourceType,
 };
 use rspack_core::{
   Compilation, CompilationArgs, CompilationParams, CompilerOptions, DependencyType,
-  LibIdentOptions, PluginCompilationHookOutput, PublicPath,
+  LibIdentOptions, PluginCompilationHookOutput, PluginProcessAssetsOutput, ProcessAssetsArgs,
+  PublicPath,
 };
-use rspack_error::Result;
+use rspack_error::{Diagnostic, Result};
 use rspack_hash::RspackHash;
 
+use crate::dependency::CssImportDependency;
 use crate::parser_and_generator::CssParserAndGenerator;
+use crate::plugin::{CssOrderConflict, ImportConditions, ModulesModeGetter};
 use crate::utils::AUTO_PUBLIC_PATH_PLACEHOLDER_REGEX;
 use crate::CssPlugin;
 
@@ -27,6 +31,7 @@ struct CssModuleDebugInfo<'a> {
 
 impl CssPlugin {
   fn render_chunk_to_source(
+    &self,
     compilation: &Compilation,
     chunk: &Chunk,
     ordered_css_modules: &[&dyn Module],
@@ -39,11 +44,13 @@ impl CssPlugin {
           .code_generation_results
           .get(module_id, Some(&chunk.runtime));
 
-        Ok(
-          code_gen_result
-            .get(&SourceType::Css)
-            .map(|source| (CssModuleDebugInfo { module: *module }, source)),
-        )
+        Ok(code_gen_result.get(&SourceType::Css).map(|source| {
+          let source = match self.import_conditions.get(module_id) {
+            Some(conditions) => Self::wrap_with_import_conditions(source.clone(), &conditions),
+            None => source.clone(),
+          };
+          (CssModuleDebugInfo { module: *module }, source)
+        }))
       })
       .collect::<Result<Vec<_>>>()?;
 
@@ -58,7 +65,7 @@ impl CssPlugin {
         |mut acc, (debug_info, cur_source)| {
           let (start, end) = Self::render_module_debug_info(compilation, &debug_info);
           acc.add(start);
-          acc.add(cur_source.clone());
+          acc.add(cur_source);
           acc.add(RawSource::from("\n"));
           acc.add(end);
           acc
@@ -72,6 +79,65 @@ impl CssPlugin {
     Ok(source)
   }
 
+  /// Wrap the source with `@media`, `@supports` and `@layer` of the `@import`s like webpack,
+  /// `@media` is the innermost and `@layer` is the outermost.
+  fn wrap_with_import_conditions(source: BoxSource, conditions: &[ImportConditions]) -> BoxSource {
+    conditions.iter().fold(source, |source, conditions| {
+      let mut source = source;
+      if let Some(media) = &conditions.media {
+        source = Self::wrap_with_block(format!("@media {media}"), source);
+      }
+      if let Some(supports) = &conditions.supports {
+        source = Self::wrap_with_block(format!("@supports ({supports})"), source);
+      }
+      if let Some(layer) = &conditions.layer {
+        let layer = if layer.is_empty() {
+          String::new()
+        } else {
+          format!(" {layer}")
+        };
+        source = Self::wrap_with_block(format!("@layer{layer}"), source);
+      }
+      source
+    })
+  }
+
+  fn wrap_with_block(prelude: String, source: BoxSource) -> BoxSource {
+    ConcatSource::new([
+      RawSource::from(format!("{prelude} {{\n")).boxed(),
+      source,
+      RawSource::from("\n}").boxed(),
+    ])
+    .boxed()
+  }
+
+  /// Align with the warning of mini-css-extract-plugin.
+  fn create_order_conflict_warning(
+    compilation: &Compilation,
+    chunk: &Chunk,
+    conflict: &CssOrderConflict,
+  ) -> Diagnostic {
+    let context = &compilation.options.context;
+    let chunk_name = chunk
+      .name
+      .as_deref()
+      .or(chunk.id.as_deref())
+      .unwrap_or_default();
+    let mut message = format!(
+      "chunk {chunk_name} [css]\nConflicting order. Following module has been added:\n * {}\ndespite it was not able to fulfill desired ordering with these modules:\n * {}\n   - couldn't fulfill desired order of chunk group(s) {}",
+      conflict.added.readable_identifier(context),
+      conflict.conflicting.readable_identifier(context),
+      conflict.failed_chunk_groups.join(", "),
+    );
+    if !conflict.fulfilled_chunk_groups.is_empty() {
+      message.push_str(&format!(
+        "\n   - while fulfilling desired order of chunk group(s) {}",
+        conflict.fulfilled_chunk_groups.join(", ")
+      ));
+    }
+    Diagnostic::warn("Conflicting order".to_string(), message)
+  }
+
   fn render_module_debug_info(
     compilation: &Compilation,
     debug_info: &CssModuleDebugInfo,
@@ -124,6 +190,8 @@ impl Plugin for CssPlugin {
       Box::new(CssParserAndGenerator {
         config: config.clone(),
         exports: None,
+        icss_exports: Default::default(),
+        modules_mode: None,
       }) as Box<dyn ParserAndGenerator>
     };
 
@@ -156,6 +224,83 @@ impl Plugin for CssPlugin {
       DependencyType::CssCompose,
       params.normal_module_factory.clone(),
     );
+    args.compilation.set_dependency_factory(
+      DependencyType::CssIcssImport,
+      params.normal_module_factory.clone(),
+    );
+    Ok(())
+  }
+
+  async fn normal_module_factory_module(
+    &self,
+    _ctx: PluginContext,
+    mut module: BoxModule,
+    args: &mut NormalModuleCreateData<'_>,
+  ) -> PluginNormalModuleFactoryModuleHookOutput {
+    // Align with webpack, the conditions belong to the `@import` rather than the imported module,
+    // so a module is created for each distinct set of conditions, including the inherited ones.
+    let Some(dependency) = args.dependency.downcast_ref::<CssImportDependency>() else {
+      return Ok(module);
+    };
+    let mut conditions = ImportConditions::from_dependency(dependency)
+      .into_iter()
+      .collect::<Vec<_>>();
+    if let Some(issuer) = &args.issuer_identifier
+      && let Some(inherited) = self.import_conditions.get(issuer)
+    {
+      conditions.extend(inherited.iter().cloned());
+    }
+    if conditions.is_empty() {
+      return Ok(module);
+    }
+    if let Some(normal_module) = module.as_normal_module_mut() {
+      let suffix = conditions
+        .iter()
+        .map(ToString::to_string)
+        .collect::<String>();
+      normal_module.extend_identifier(&suffix);
+      self
+        .import_conditions
+        .insert(normal_module.id(), conditions);
+    }
+    Ok(module)
+  }
+
+  async fn finish_modules(&self, compilation: &mut Compilation) -> Result<()> {
+    // Modules kept from the previous compilation aren't created again, so the conditions are
+    // pruned to the modules in the graph instead of being cleared for each compilation.
+    self.import_conditions.retain(|module_identifier, _| {
+      compilation
+        .module_graph
+        .module_by_identifier(module_identifier)
+        .is_some()
+    });
+    Ok(())
+  }
+
+  async fn before_loaders(&self, module: &mut NormalModule) -> Result<()> {
+    if !matches!(self.config.modules.mode, ModulesModeGetter::Fn(_)) {
+      return Ok(());
+    }
+    let module_type = *module.module_type();
+    let resource_path = module.resource_resolved_data().resource_path.clone();
+    let Some(parser_and_generator) = module
+      .parser_and_generator_mut()
+      .downcast_mut::<CssParserAndGenerator>()
+    else {
+      return Ok(());
+    };
+    parser_and_generator.modules_mode = None;
+    if parser_and_generator.is_css_modules(&module_type, &resource_path) {
+      parser_and_generator.modules_mode = Some(
+        self
+          .config
+          .modules
+          .mode
+          .get(&resource_path.to_string_lossy())
+          .await?,
+      );
+    }
     Ok(())
   }
 
@@ -166,7 +311,7 @@ impl Plugin for CssPlugin {
   ) -> rspack_core::PluginContentHashHookOutput {
     let compilation = &args.compilation;
     let chunk = compilation.chunk_by_ukey.expect_get(&args.chunk_ukey);
-    let ordered_modules = Self::get_ordered_chunk_css_modules(
+    let (ordered_modules, _) = Self::get_ordered_chunk_css_modules(
       chunk,
       &compilation.chunk_graph,
       &compilation.module_graph,
@@ -182,12 +327,17 @@ impl Plugin for CssPlugin {
             .code_generation_results
             .get_hash(&m.identifier(), Some(&chunk.runtime)),
           compilation.chunk_graph.get_module_id(m.identifier()),
+          self
+            .import_conditions
+            .get(&m.identifier())
+            .map(|conditions| conditions.clone()),
         )
       })
-      .for_each(|(current, id)| {
+      .for_each(|(current, id, conditions)| {
         if let Some(current) = current {
           current.hash(&mut hasher);
           id.hash(&mut hasher);
+          conditions.hash(&mut hasher);
         }
       });
 
@@ -208,19 +358,28 @@ impl Plugin for CssPlugin {
       return Ok(vec![]);
     }
 
-    let ordered_css_modules = Self::get_ordered_chunk_css_modules(
+    let (ordered_css_modules, conflicts) = Self::get_ordered_chunk_css_modules(
       chunk,
       &compilation.chunk_graph,
       &compilation.module_graph,
       compilation,
     );
+    if !self.config.ignore_order && !conflicts.is_empty() {
+      self.order_conflict_warnings.insert(
+        chunk.ukey,
+        conflicts
+          .iter()
+          .map(|conflict| Self::create_order_conflict_warning(compilation, chunk, conflict))
+          .collect(),
+      );
+    }
 
     // Prevent generating css files for chunks which don't contain css modules.
     if ordered_css_modules.is_empty() {
       return Ok(Default::default());
     }
 
-    let source = Self::render_chunk_to_source(compilation, chunk, &ordered_css_modules)?;
+    let source = self.render_chunk_to_source(compilation, chunk, &ordered_css_modules)?;
 
     let filename_template = get_css_chunk_filename_template(
       chunk,
@@ -263,4 +422,25 @@ impl Plugin for CssPlugin {
       false,
     )])
   }
+
+  async fn process_assets_stage_additional(
+    &self,
+    _ctx: rspack_core::PluginContext,
+    args: ProcessAssetsArgs<'_>,
+  ) -> PluginProcessAssetsOutput {
+    // the warnings are collected while the chunks are rendered
+    let mut warnings = self
+      .order_conflict_warnings
+      .iter()
+      .map(|entry| (*entry.key(), entry.value().clone()))
+      .collect::<Vec<_>>();
+    self.order_conflict_warnings.clear();
+    warnings.sort_unstable_by_key(|(chunk, _)| *chunk);
+    let diagnostics = warnings
+      .into_iter()
+      .flat_map(|(_, warnings)| warnings)
+      .collect::<Vec<_>>();
+    args.compilation.push_batch_diagnostic(diagnostics);
+    Ok(())
+  }
 }
@@ -1,23 +1,81 @@
 #![allow(clippy::comparison_chain)]
 mod impl_plugin_for_css_plugin;
 use std::cmp::{self, Reverse};
+use std::fmt;
 use std::hash::Hash;
+use std::path::PathBuf;
 use std::str::FromStr;
+use std::sync::Arc;
 
 use bitflags::bitflags;
+use dashmap::DashMap;
+use futures::future::BoxFuture;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rspack_core::Filename;
-use rspack_core::{Chunk, ChunkGraph, Compilation, Module, ModuleGraph, PathData, SourceType};
-use rspack_error::error_bail;
+use rspack_core::{
+  Chunk, ChunkGraph, ChunkUkey, Compilation, Module, ModuleGraph, ModuleIdentifier, PathData,
+  SourceType,
+};
+use rspack_error::{error_bail, Diagnostic, Result};
 use rspack_identifier::IdentifierSet;
+use rspack_regex::RspackRegex;
 
 static ESCAPE_LOCAL_IDENT_REGEX: Lazy<Regex> =
   Lazy::new(|| Regex::new(r#"[<>:"/\\|?*\.]"#).expect("Invalid regex"));
+static CAPTURE_PLACEHOLDER_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"\[(\d+)\]").expect("Invalid regex"));
+
+use crate::dependency::CssImportDependency;
 
 #[derive(Debug)]
 pub struct CssPlugin {
   config: CssConfig,
+  /// `@import` conditions of the modules created for them, from the nearest `@import` to the
+  /// farthest.
+  import_conditions: DashMap<ModuleIdentifier, Vec<ImportConditions>>,
+  /// Warnings of the order conflicts found while rendering the chunks, reported when processing
+  /// assets.
+  order_conflict_warnings: DashMap<ChunkUkey, Vec<Diagnostic>>,
+}
+
+/// `layer`, `supports` and `media` of an `@import`.
+#[derive(Debug, Clone, Hash)]
+pub(crate) struct ImportConditions {
+  pub layer: Option<String>,
+  pub supports: Option<String>,
+  pub media: Option<String>,
+}
+
+impl ImportConditions {
+  pub fn from_dependency(dependency: &CssImportDependency) -> Option<Self> {
+    if dependency.layer().is_none()
+      && dependency.supports().is_none()
+      && dependency.media().is_none()
+    {
+      return None;
+    }
+    Some(Self {
+      layer: dependency.layer().map(ToOwned::to_owned),
+      supports: dependency.supports().map(ToOwned::to_owned),
+      media: dependency.media().map(ToOwned::to_owned),
+    })
+  }
+}
+
+impl fmt::Display for ImportConditions {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if let Some(layer) = &self.layer {
+      write!(f, "|layer({layer})")?;
+    }
+    if let Some(supports) = &self.supports {
+      write!(f, "|supports({supports})")?;
+    }
+    if let Some(media) = &self.media {
+      write!(f, "|{media}")?;
+    }
+    Ok(())
+  }
 }
 
 #[derive(Debug, Clone)]
@@ -25,6 +83,76 @@ pub struct ModulesConfig {
   pub locals_convention: LocalsConvention,
   pub local_ident_name: LocalIdentName,
   pub exports_only: bool,
+  pub mode: ModulesModeGetter,
+  /// Prepended to the content of the hash of local idents.
+  pub hash_prefix: String,
+  /// The directory that `[path]` and `[name]` of local idents are relative to, defaults to
+  /// the `context` of compiler.
+  pub local_ident_context: Option<PathBuf>,
+  /// Matched against the resource path, and `[1]`, `[2]`... of local idents are replaced with
+  /// the capture groups.
+  pub local_ident_reg_exp: Option<RspackRegex>,
+  /// Which files of `css/auto` are CSS modules, defaults to `/\.module(s)?\.[^.]+$/`.
+  pub auto: Option<RspackRegex>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ModulesMode {
+  /// Class names and ids are local unless they are wrapped in `:global`.
+  #[default]
+  Local,
+  /// Class names and ids are global unless they are wrapped in `:local`.
+  Global,
+  /// Same as `Local`, but every selector must contain at least one local class or id.
+  Pure,
+}
+
+impl FromStr for ModulesMode {
+  type Err = rspack_error::Error;
+
+  fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+    Ok(match s {
+      "local" => Self::Local,
+      "global" => Self::Global,
+      "pure" => Self::Pure,
+      _ => error_bail!(
+        "css modules mode error, expected \"local\", \"global\" or \"pure\", got \"{s}\""
+      ),
+    })
+  }
+}
+
+pub type ModulesModeFn = Arc<dyn Fn(&str) -> BoxFuture<'static, Result<ModulesMode>> + Send + Sync>;
+
+#[derive(Clone)]
+pub enum ModulesModeGetter {
+  Mode(ModulesMode),
+  /// Get the mode by the resource path.
+  Fn(ModulesModeFn),
+}
+
+impl fmt::Debug for ModulesModeGetter {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Mode(mode) => f.debug_tuple("Mode").field(mode).finish(),
+      Self::Fn(_) => f.debug_tuple("Fn").finish(),
+    }
+  }
+}
+
+impl Default for ModulesModeGetter {
+  fn default() -> Self {
+    Self::Mode(ModulesMode::default())
+  }
+}
+
+impl ModulesModeGetter {
+  pub async fn get(&self, resource_path: &str) -> Result<ModulesMode> {
+    match self {
+      Self::Mode(mode) => Ok(*mode),
+      Self::Fn(f) => f(resource_path).await,
+    }
+  }
 }
 
 #[derive(Debug, Clone)]
@@ -36,6 +164,17 @@ impl LocalIdentName {
     if let Some(local) = options.local {
       s = s.replace("[local]", local);
     }
+    if let Some(captures) = options.captures {
+      s = CAPTURE_PLACEHOLDER_REGEX
+        .replace_all(&s, |caps: &regex::Captures| {
+          caps[1]
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| captures.get(index))
+            .map_or_else(|| caps[0].to_string(), |capture| capture.clone())
+        })
+        .into_owned();
+    }
     s = ESCAPE_LOCAL_IDENT_REGEX.replace_all(&s, "-").into_owned();
     s
   }
@@ -50,6 +189,8 @@ impl From<String> for LocalIdentName {
 pub struct LocalIdentNameRenderOptions<'a> {
   pub path_data: PathData<'a>,
   pub local: Option<&'a str>,
+  /// Capture groups of `localIdentRegExp`, which replace `[1]`, `[2]`...
+  pub captures: Option<&'a [String]>,
 }
 
 bitflags! {
@@ -101,11 +242,27 @@ impl Default for LocalsConvention {
 #[derive(Debug, Clone)]
 pub struct CssConfig {
   pub modules: ModulesConfig,
+  /// Don't warn about the conflicting order of css modules between chunk groups.
+  pub ignore_order: bool,
+}
+
+/// A css module that is added to a chunk in spite of the order desired by some chunk groups.
+pub(crate) struct CssOrderConflict<'module> {
+  pub added: &'module dyn Module,
+  pub conflicting: &'module dyn Module,
+  /// Chunk groups desiring `conflicting` to be placed before `added`.
+  pub failed_chunk_groups: Vec<String>,
+  /// Chunk groups desiring `added` to be placed before `conflicting`.
+  pub fulfilled_chunk_groups: Vec<String>,
 }
 
 impl CssPlugin {
   pub fn new(config: CssConfig) -> Self {
-    Self { config }
+    Self {
+      config,
+      import_conditions: Default::default(),
+      order_conflict_warnings: Default::default(),
+    }
   }
 
   pub(crate) fn get_ordered_chunk_css_modules<'chunk_graph>(
@@ -113,26 +270,27 @@ impl CssPlugin {
     chunk_graph: &'chunk_graph ChunkGraph,
     module_graph: &'chunk_graph ModuleGraph,
     compilation: &Compilation,
-  ) -> Vec<&'chunk_graph dyn Module> {
+  ) -> (
+    Vec<&'chunk_graph dyn Module>,
+    Vec<CssOrderConflict<'chunk_graph>>,
+  ) {
     // Align with https://github.com/webpack/webpack/blob/8241da7f1e75c5581ba535d127fa66aeb9eb2ac8/lib/css/CssModulesPlugin.js#L368
     let mut css_modules = chunk_graph
       .get_chunk_modules_iterable_by_source_type(&chunk.ukey, SourceType::Css, module_graph)
       .collect::<Vec<_>>();
     css_modules.sort_unstable_by_key(|module| module.identifier());
 
-    let css_modules = Self::get_modules_in_order(chunk, css_modules, compilation);
-
-    css_modules
+    Self::get_modules_in_order(chunk, css_modules, compilation)
   }
 
   pub(crate) fn get_modules_in_order<'module>(
     chunk: &Chunk,
     modules: Vec<&'module dyn Module>,
     compilation: &Compilation,
-  ) -> Vec<&'module dyn Module> {
+  ) -> (Vec<&'module dyn Module>, Vec<CssOrderConflict<'module>>) {
     // Align with https://github.com/webpack/webpack/blob/8241da7f1e75c5581ba535d127fa66aeb9eb2ac8/lib/css/CssModulesPlugin.js#L269
     if modules.is_empty() {
-      return vec![];
+      return (vec![], vec![]);
     };
 
     let modules_list = modules.clone();
@@ -170,6 +328,10 @@ impl CssPlugin {
         SortedModules {
           set: sorted_modules.iter().map(|m| m.identifier()).collect(),
           list: sorted_modules,
+          chunk_group_name: chunk_group
+            .name()
+            .map(ToString::to_string)
+            .unwrap_or_else(|| chunk_group.id(compilation)),
         }
       })
       .collect::<Vec<_>>();
@@ -181,12 +343,13 @@ impl CssPlugin {
         .expect("must have one")
         .list;
       ret.reverse();
-      return ret;
+      return (ret, vec![]);
     };
 
     modules_by_chunk_group.sort_unstable_by(compare_module_lists);
 
     let mut final_modules: Vec<&'module dyn Module> = vec![];
+    let mut conflicts = vec![];
 
     loop {
       let mut failed_modules: IdentifierSet = Default::default();
@@ -198,7 +361,7 @@ impl CssPlugin {
       let mut selected_module = *list.last().expect("TODO:");
       let mut has_failed = None;
       'outer: loop {
-        for SortedModules { set, list } in &modules_by_chunk_group {
+        for SortedModules { set, list, .. } in &modules_by_chunk_group {
           if list.is_empty() {
             continue;
           }
@@ -223,29 +386,17 @@ impl CssPlugin {
       }
       if let Some(has_failed) = has_failed {
         // There is a not resolve-able conflict with the selectedModule
-        // TODO(hyf0): we should emit a warning here
-        tracing::warn!("Conflicting order between");
-        // 		if (compilation) {
-        // 			// TODO print better warning
-        // 			compilation.warnings.push(
-        // 				new Error(
-        // 					`chunk ${
-        // 						chunk.name || chunk.id
-        // 					}\nConflicting order between ${hasFailed.readableIdentifier(
-        // 						compilation.requestShortener
-        // 					)} and ${selectedModule.readableIdentifier(
-        // 						compilation.requestShortener
-        // 					)}`
-        // 				)
-        // 			);
-        // 		}
-
+        conflicts.push(Self::get_order_conflict(
+          &modules_by_chunk_group,
+          has_failed,
+          selected_module,
+        ));
         selected_module = has_failed;
       }
       // Insert the selected module into the final modules list
       final_modules.push(selected_module);
       // Remove the selected module from all lists
-      for SortedModules { set, list } in &mut modules_by_chunk_group {
+      for SortedModules { set, list, .. } in &mut modules_by_chunk_group {
         let last_module = list.last();
         if last_module.map_or(false, |last_module| last_module == &selected_module) {
           list.pop();
@@ -260,7 +411,41 @@ impl CssPlugin {
 
       modules_by_chunk_group.sort_unstable_by(compare_module_lists);
     }
-    final_modules
+    (final_modules, conflicts)
+  }
+
+  fn get_order_conflict<'module>(
+    modules_by_chunk_group: &[SortedModules<'module>],
+    added: &'module dyn Module,
+    conflicting: &'module dyn Module,
+  ) -> CssOrderConflict<'module> {
+    let mut failed_chunk_groups = vec![];
+    let mut fulfilled_chunk_groups = vec![];
+    for SortedModules {
+      list,
+      chunk_group_name,
+      ..
+    } in modules_by_chunk_group
+    {
+      let added_index = list.iter().position(|m| *m == added);
+      let conflicting_index = list.iter().position(|m| *m == conflicting);
+      // Lists are in reverse order, the one with the larger index is desired to be placed first
+      match (added_index, conflicting_index) {
+        (Some(added_index), Some(conflicting_index)) if added_index < conflicting_index => {
+          failed_chunk_groups.push(chunk_group_name.clone())
+        }
+        (Some(_), Some(_)) => fulfilled_chunk_groups.push(chunk_group_name.clone()),
+        _ => {}
+      }
+    }
+    failed_chunk_groups.sort_unstable();
+    fulfilled_chunk_groups.sort_unstable();
+    CssOrderConflict {
+      added,
+      conflicting,
+      failed_chunk_groups,
+      fulfilled_chunk_groups,
+    }
   }
 }
 
@@ -268,6 +453,7 @@ impl CssPlugin {
 struct SortedModules<'module> {
   pub list: Vec<&'module dyn Module>,
   pub set: IdentifierSet,
+  pub chunk_group_name: String,
 }
 
 fn compare_module_lists(a: &SortedModules, b: &SortedModules) -> cmp::Ordering {
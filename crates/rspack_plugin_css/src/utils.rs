@@ -4,13 +4,15 @@ use heck::{ToKebabCase, ToLowerCamelCase};
 use indexmap::IndexMap;
 use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
-use rspack_core::{Compilation, OutputOptions, PathData, RuntimeGlobals};
+use rspack_core::{Compilation, ModuleGraphModule, OutputOptions, PathData, RuntimeGlobals};
 use rspack_error::{error, Result};
 use rspack_hash::{HashDigest, HashFunction, HashSalt, RspackHash};
+use rustc_hash::FxHashSet;
 use swc_core::css::modules::CssClassName;
 use swc_core::ecma::atoms::JsWord;
 
-use crate::plugin::{LocalIdentName, LocalIdentNameRenderOptions, LocalsConvention};
+use crate::parser_and_generator::CssParserAndGenerator;
+use crate::plugin::{LocalIdentName, LocalIdentNameRenderOptions, LocalsConvention, ModulesConfig};
 
 pub const AUTO_PUBLIC_PATH_PLACEHOLDER: &str = "__RSPACK_PLUGIN_CSS_AUTO_PUBLIC_PATH__";
 pub static AUTO_PUBLIC_PATH_PLACEHOLDER_REGEX: Lazy<Regex> =
@@ -19,6 +21,10 @@ pub static AUTO_PUBLIC_PATH_PLACEHOLDER_REGEX: Lazy<Regex> =
 pub struct ModulesTransformConfig<'a> {
   filename: &'a Path,
   local_name_ident: &'a LocalIdentName,
+  hash_prefix: &'a str,
+  captures: Option<&'a [String]>,
+  /// Names of `:local` in global mode, other names are kept as they are.
+  local_names: Option<&'a FxHashSet<JsWord>>,
   hash_function: &'a HashFunction,
   hash_digest: &'a HashDigest,
   hash_digest_length: usize,
@@ -28,12 +34,17 @@ pub struct ModulesTransformConfig<'a> {
 impl<'a> ModulesTransformConfig<'a> {
   pub fn new(
     filename: &'a Path,
-    local_name_ident: &'a LocalIdentName,
+    modules: &'a ModulesConfig,
     output: &'a OutputOptions,
+    captures: Option<&'a [String]>,
+    local_names: Option<&'a FxHashSet<JsWord>>,
   ) -> Self {
     Self {
       filename,
-      local_name_ident,
+      local_name_ident: &modules.local_ident_name,
+      hash_prefix: &modules.hash_prefix,
+      captures,
+      local_names,
       hash_function: &output.hash_function,
       hash_digest: &output.hash_digest,
       hash_digest_length: output.hash_digest_length,
@@ -44,8 +55,16 @@ impl<'a> ModulesTransformConfig<'a> {
 
 impl swc_core::css::modules::TransformConfig for ModulesTransformConfig<'_> {
   fn new_name_for(&self, local: &JsWord) -> JsWord {
+    if let Some(local_names) = self.local_names
+      && !local_names.contains(local)
+    {
+      return local.clone();
+    }
     let hash = {
       let mut hasher = RspackHash::with_salt(self.hash_function, self.hash_salt);
+      if !self.hash_prefix.is_empty() {
+        self.hash_prefix.hash(&mut hasher);
+      }
       self.filename.hash(&mut hasher);
       local.hash(&mut hasher);
       let hash = hasher.digest(self.hash_digest);
@@ -63,6 +82,7 @@ impl swc_core::css::modules::TransformConfig for ModulesTransformConfig<'_> {
           .filename(&self.filename.to_string_lossy())
           .hash(&hash),
         local: Some(local),
+        captures: self.captures,
       })
       .into()
   }
@@ -125,27 +145,7 @@ pub fn css_modules_exports_to_string(
       .map(|(name, from)| match from {
         None => name.to_owned(),
         Some(from_name) => {
-          let from = module
-            .get_dependencies()
-            .iter()
-            .find_map(|id| {
-              let dependency = compilation.module_graph.dependency_by_id(id);
-              let request = if let Some(d) = dependency.and_then(|d| d.as_module_dependency()) {
-                Some(d.request())
-              } else {
-                dependency
-                  .and_then(|d| d.as_context_dependency())
-                  .map(|d| d.request())
-              };
-              if let Some(request) = request
-                && request == from_name
-              {
-                return compilation
-                  .module_graph
-                  .module_graph_module_by_dependency_id(id);
-              }
-              None
-            })
+          let from = get_module_by_request(module, compilation, from_name)
             .expect("should have css from module");
 
           let from = serde_json::to_string(from.id(&compilation.chunk_graph)).expect("TODO:");
@@ -163,6 +163,66 @@ pub fn css_modules_exports_to_string(
   Ok(code)
 }
 
+fn get_module_by_request<'a>(
+  module: &dyn rspack_core::Module,
+  compilation: &'a Compilation,
+  request: &str,
+) -> Option<&'a ModuleGraphModule> {
+  module.get_dependencies().iter().find_map(|id| {
+    let dependency = compilation.module_graph.dependency_by_id(id);
+    let dependency_request = if let Some(d) = dependency.and_then(|d| d.as_module_dependency()) {
+      Some(d.request())
+    } else {
+      dependency
+        .and_then(|d| d.as_context_dependency())
+        .map(|d| d.request())
+    };
+    if let Some(dependency_request) = dependency_request
+      && dependency_request == request
+    {
+      return compilation
+        .module_graph
+        .module_graph_module_by_dependency_id(id);
+    }
+    None
+  })
+}
+
+/// Resolve the value of `name` exported by ICSS from the module of `request`, following the
+/// values that are imported from other modules again.
+pub(crate) fn resolve_icss_import<'a>(
+  module: &'a dyn rspack_core::Module,
+  compilation: &'a Compilation,
+  request: &str,
+  name: &str,
+) -> Option<String> {
+  let mut module = module;
+  let mut request = request.to_string();
+  let mut name = name.to_string();
+  // Avoid infinite loop of circular imports.
+  for _ in 0..MAX_ICSS_IMPORT_DEPTH {
+    let mgm = get_module_by_request(module, compilation, &request)?;
+    module = compilation
+      .module_graph
+      .module_by_identifier(&mgm.module_identifier)?
+      .as_ref();
+    let parser_and_generator = module
+      .as_normal_module()?
+      .parser_and_generator()
+      .downcast_ref::<CssParserAndGenerator>()?;
+    match parser_and_generator.icss_exports.get(&name)? {
+      (value, None) => return Some(value.clone()),
+      (imported, Some(from)) => {
+        name = imported.clone();
+        request = from.clone();
+      }
+    }
+  }
+  None
+}
+
+const MAX_ICSS_IMPORT_DEPTH: usize = 32;
+
 static STRING_MULTILINE: Lazy<Regex> =
   Lazy::new(|| Regex::new(r"\\[\n\r\f]").expect("Invalid RegExp"));
 
@@ -0,0 +1,156 @@
+use rspack_core::SpanExt;
+use rspack_error::miette::{diagnostic, Diagnostic, Severity};
+use rspack_error::DiagnosticExt;
+use rustc_hash::FxHashSet as HashSet;
+use swc_core::css::ast::{
+  ComplexSelector, ComplexSelectorChildren, KeyframesName, PseudoClassSelectorChildren,
+  QualifiedRule, QualifiedRulePrelude, Stylesheet, SubclassSelector,
+};
+use swc_core::css::visit::{Visit, VisitWith};
+use swc_core::ecma::atoms::JsWord;
+
+use super::is_icss_selector;
+
+static LOCAL_KEYWORD: &str = "local";
+static GLOBAL_KEYWORD: &str = "global";
+
+/// Collect the class names, ids and keyframes names that are `:local` in global mode.
+pub fn collect_local_names(ss: &Stylesheet) -> HashSet<JsWord> {
+  let mut v = LocalNamesCollector {
+    names: Default::default(),
+    is_local: false,
+  };
+  ss.visit_with(&mut v);
+  v.names
+}
+
+struct LocalNamesCollector {
+  names: HashSet<JsWord>,
+  is_local: bool,
+}
+
+impl Visit for LocalNamesCollector {
+  fn visit_complex_selector(&mut self, n: &ComplexSelector) {
+    let old_is_local = self.is_local;
+    for child in &n.children {
+      let ComplexSelectorChildren::CompoundSelector(compound) = child else {
+        continue;
+      };
+      for selector in &compound.subclass_selectors {
+        match selector {
+          SubclassSelector::Class(class) if self.is_local => {
+            self.names.insert(class.text.value.clone());
+          }
+          SubclassSelector::Id(id) if self.is_local => {
+            self.names.insert(id.text.value.clone());
+          }
+          SubclassSelector::PseudoClass(pseudo) => match (&*pseudo.name.value, &pseudo.children) {
+            (name, None) if name == LOCAL_KEYWORD => self.is_local = true,
+            (name, None) if name == GLOBAL_KEYWORD => self.is_local = false,
+            (name, Some(children)) if name == LOCAL_KEYWORD => {
+              let is_local = self.is_local;
+              self.is_local = true;
+              children.visit_with(self);
+              self.is_local = is_local;
+            }
+            (name, Some(_)) if name == GLOBAL_KEYWORD => {}
+            _ => pseudo.visit_with(self),
+          },
+          _ => {}
+        }
+      }
+    }
+    self.is_local = old_is_local;
+  }
+
+  fn visit_keyframes_name(&mut self, n: &KeyframesName) {
+    let name = match n {
+      KeyframesName::PseudoPrefix(prefix) if prefix.pseudo.value == LOCAL_KEYWORD => &prefix.name,
+      KeyframesName::PseudoFunction(function) if function.pseudo.value == LOCAL_KEYWORD => {
+        &function.name
+      }
+      _ => return,
+    };
+    match name {
+      KeyframesName::CustomIdent(ident) => {
+        self.names.insert(ident.value.clone());
+      }
+      KeyframesName::Str(str) => {
+        self.names.insert(str.value.clone());
+      }
+      _ => {}
+    }
+  }
+}
+
+/// Report the selectors which contain no local class or id in pure mode.
+pub fn analyze_pure_selectors(
+  ss: &Stylesheet,
+  source_code: &str,
+  diagnostics: &mut Vec<Box<dyn Diagnostic + Send + Sync>>,
+) {
+  let mut v = PureSelectorsAnalyzer {
+    source_code,
+    diagnostics,
+  };
+  ss.visit_with(&mut v);
+}
+
+struct PureSelectorsAnalyzer<'a> {
+  source_code: &'a str,
+  diagnostics: &'a mut Vec<Box<dyn Diagnostic + Send + Sync>>,
+}
+
+impl Visit for PureSelectorsAnalyzer<'_> {
+  fn visit_qualified_rule(&mut self, n: &QualifiedRule) {
+    if let QualifiedRulePrelude::SelectorList(list) = &n.prelude
+      && !is_icss_selector(&n.prelude)
+    {
+      for selector in &list.children {
+        if !is_pure_selector(selector, false) {
+          let selector = self
+            .source_code
+            .get(selector.span.real_lo() as usize..selector.span.real_hi() as usize)
+            .unwrap_or_default()
+            .trim();
+          self.diagnostics.push(
+            diagnostic!(
+              severity = Severity::Error,
+              "css: Selector \"{selector}\" is not pure (pure selectors must contain at least one local class or id)",
+            )
+            .boxed(),
+          );
+        }
+      }
+    }
+    n.block.visit_with(self);
+  }
+}
+
+fn is_pure_selector(selector: &ComplexSelector, is_global: bool) -> bool {
+  let mut is_global = is_global;
+  for child in &selector.children {
+    let ComplexSelectorChildren::CompoundSelector(compound) = child else {
+      continue;
+    };
+    for selector in &compound.subclass_selectors {
+      match selector {
+        SubclassSelector::Class(_) | SubclassSelector::Id(_) if !is_global => return true,
+        SubclassSelector::PseudoClass(pseudo) => match (&*pseudo.name.value, &pseudo.children) {
+          (name, None) if name == LOCAL_KEYWORD => is_global = false,
+          (name, None) if name == GLOBAL_KEYWORD => is_global = true,
+          (name, Some(children)) if name == LOCAL_KEYWORD => {
+            if children.iter().any(|child| {
+              matches!(child, PseudoClassSelectorChildren::ComplexSelector(selector) if is_pure_selector(selector, false))
+            }) {
+              return true;
+            }
+          }
+          _ => {}
+        },
+        _ => {}
+      }
+    }
+  }
+  false
+}
@@ -0,0 +1,311 @@
+use indexmap::IndexMap;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rspack_core::{BoxDependency, ConstDependency, DependencyTemplate, SpanExt};
+use rustc_hash::FxHashMap as HashMap;
+use swc_core::common::Span;
+use swc_core::css::ast::{
+  AtRule, AtRuleName, ComplexSelectorChildren, ComponentValue, Declaration, DeclarationName,
+  Function, Ident, MediaQuery, QualifiedRule, QualifiedRulePrelude, Rule, Stylesheet,
+  SubclassSelector, Token, TokenAndSpan,
+};
+use swc_core::css::visit::{Visit, VisitMut, VisitMutWith, VisitWith};
+
+use crate::dependency::{CssIcssImportDependency, CssIcssSymbolDependency};
+
+static VALUE_IMPORT: Lazy<Regex> = Lazy::new(|| {
+  Regex::new(r#"^([\s\S]+?)\s+from\s+("[^"]*"|'[^']*'|[\w-]+)$"#).expect("Invalid regexp")
+});
+static VALUE_DEFINITION: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^([\w-]+)(?:\s*:\s*|\s+)([\s\S]*)$").expect("Invalid regexp"));
+static VALUE_IMPORT_ITEM: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^([\w-]+)(?:\s+as\s+([\w-]+))?$").expect("Invalid regexp"));
+
+static VALUE_KEYWORD: &str = "value";
+static EXPORT_PRELUDE: &str = ":export";
+static IMPORT_PRELUDE: &str = ":import(";
+
+/// A value of ICSS, it's either the value itself, or the name imported from a request.
+pub type IcssValue = (String, Option<String>);
+
+#[derive(Debug, Default)]
+pub struct IcssAnalysis {
+  pub dependencies: Vec<BoxDependency>,
+  pub presentational_dependencies: Vec<Box<dyn DependencyTemplate>>,
+  /// Names exported by `:export` and `@value`, in the order of the source.
+  pub exports: IndexMap<String, IcssValue>,
+}
+
+/// Analyze [Interoperable CSS](https://github.com/css-modules/icss), `:import`, `:export` and
+/// `@value` are removed from the output, and the usages of imported names and values are
+/// replaced.
+pub fn analyze_icss(ss: &Stylesheet, source_code: &str) -> IcssAnalysis {
+  let mut analysis = IcssAnalysis::default();
+  let mut symbols: HashMap<String, IcssValue> = HashMap::default();
+
+  for rule in &ss.rules {
+    match rule {
+      Rule::QualifiedRule(rule) => {
+        let Some(prelude) = icss_rule_prelude(rule, source_code) else {
+          continue;
+        };
+        let declarations = icss_declarations(rule, source_code);
+        if prelude == EXPORT_PRELUDE {
+          for (name, value) in declarations {
+            let value = resolve_symbol(&symbols, value);
+            analysis.exports.insert(name, value);
+          }
+          analysis
+            .presentational_dependencies
+            .push(remove_dependency(rule.span));
+        } else {
+          let request = unquote(
+            prelude[IMPORT_PRELUDE.len()..prelude.len() - 1].trim(),
+            &symbols,
+          );
+          for (alias, name) in declarations {
+            symbols.insert(alias, (name, Some(request.clone())));
+          }
+          analysis
+            .dependencies
+            .push(Box::new(CssIcssImportDependency::new(
+              request,
+              Some(rule.span.into()),
+              rule.span.real_lo(),
+              rule.span.real_hi(),
+            )));
+        }
+      }
+      Rule::AtRule(rule) if is_value_at_rule(rule) => {
+        let prelude = source_of(source_code, rule.span)
+          .trim_start_matches('@')
+          .trim_start_matches(VALUE_KEYWORD)
+          .trim_end_matches(';')
+          .trim();
+        if let Some(captures) = VALUE_IMPORT.captures(prelude) {
+          let request = unquote(&captures[2], &symbols);
+          for item in captures[1].split(',') {
+            let Some(item) = VALUE_IMPORT_ITEM.captures(item.trim()) else {
+              continue;
+            };
+            let name = item[1].to_string();
+            let alias = item.get(2).map_or(name.clone(), |m| m.as_str().to_string());
+            let value = (name, Some(request.clone()));
+            symbols.insert(alias.clone(), value.clone());
+            analysis.exports.insert(alias, value);
+          }
+          analysis
+            .dependencies
+            .push(Box::new(CssIcssImportDependency::new(
+              request,
+              Some(rule.span.into()),
+              rule.span.real_lo(),
+              rule.span.real_hi(),
+            )));
+        } else if let Some(captures) = VALUE_DEFINITION.captures(prelude) {
+          let value = resolve_symbol(&symbols, captures[2].trim().to_string());
+          symbols.insert(captures[1].to_string(), value.clone());
+          analysis.exports.insert(captures[1].to_string(), value);
+          analysis
+            .presentational_dependencies
+            .push(remove_dependency(rule.span));
+        }
+      }
+      _ => {}
+    }
+  }
+
+  if !symbols.is_empty() {
+    let mut v = IcssSymbolAnalyzer {
+      source_code,
+      symbols: &symbols,
+      presentational_dependencies: &mut analysis.presentational_dependencies,
+      in_value: false,
+    };
+    ss.visit_with(&mut v);
+  }
+
+  analysis
+}
+
+/// The prelude of `:export` or `:import("...")`.
+pub(crate) fn icss_rule_prelude<'a>(rule: &QualifiedRule, source_code: &'a str) -> Option<&'a str> {
+  let prelude = source_code
+    .get(rule.span.real_lo() as usize..rule.block.span.real_lo() as usize)
+    .unwrap_or_default()
+    .trim();
+  (prelude == EXPORT_PRELUDE || (prelude.starts_with(IMPORT_PRELUDE) && prelude.ends_with(')')))
+    .then_some(prelude)
+}
+
+pub(crate) fn is_value_at_rule(rule: &AtRule) -> bool {
+  matches!(&rule.name, AtRuleName::Ident(ident) if &*ident.value == VALUE_KEYWORD)
+}
+
+fn icss_declarations(rule: &QualifiedRule, source_code: &str) -> Vec<(String, String)> {
+  rule
+    .block
+    .value
+    .iter()
+    .filter_map(|value| match value {
+      ComponentValue::Declaration(declaration) => {
+        let source = source_of(source_code, declaration.span);
+        let (name, value) = source.split_once(':')?;
+        Some((
+          name.trim().to_string(),
+          value.trim().trim_end_matches(';').trim().to_string(),
+        ))
+      }
+      _ => None,
+    })
+    .collect()
+}
+
+fn source_of(source_code: &str, span: Span) -> &str {
+  source_code
+    .get(span.real_lo() as usize..span.real_hi() as usize)
+    .unwrap_or_default()
+    .trim()
+}
+
+fn unquote(request: &str, symbols: &HashMap<String, IcssValue>) -> String {
+  let request = match symbols.get(request) {
+    Some((value, None)) => value.as_str(),
+    _ => request,
+  };
+  request
+    .strip_prefix('"')
+    .and_then(|r| r.strip_suffix('"'))
+    .or_else(|| {
+      request
+        .strip_prefix('\'')
+        .and_then(|r| r.strip_suffix('\''))
+    })
+    .unwrap_or(request)
+    .to_string()
+}
+
+fn resolve_symbol(symbols: &HashMap<String, IcssValue>, value: String) -> IcssValue {
+  symbols.get(&value).cloned().unwrap_or((value, None))
+}
+
+fn remove_dependency(span: Span) -> Box<dyn DependencyTemplate> {
+  Box::new(ConstDependency::new(
+    span.real_lo(),
+    span.real_hi(),
+    "".into(),
+    None,
+  ))
+}
+
+/// `swc_css_parser` lowercases the names of declarations, restore the names in `:import` and
+/// `:export` before the code generation of CSS modules, since they are names of JS exports.
+pub struct IcssNamePreserver;
+
+impl VisitMut for IcssNamePreserver {
+  fn visit_mut_qualified_rule(&mut self, n: &mut QualifiedRule) {
+    if !is_icss_selector(&n.prelude) {
+      n.visit_mut_children_with(self);
+      return;
+    }
+    for value in &mut n.block.value {
+      if let ComponentValue::Declaration(declaration) = value
+        && let DeclarationName::Ident(ident) = &mut declaration.name
+        && let Some(raw) = &ident.raw
+      {
+        ident.value = (&**raw).into();
+      }
+    }
+  }
+}
+
+pub(crate) fn is_icss_selector(prelude: &QualifiedRulePrelude) -> bool {
+  let QualifiedRulePrelude::SelectorList(list) = prelude else {
+    return false;
+  };
+  let [selector] = list.children.as_slice() else {
+    return false;
+  };
+  let [ComplexSelectorChildren::CompoundSelector(compound)] = selector.children.as_slice() else {
+    return false;
+  };
+  matches!(
+    compound.subclass_selectors.as_slice(),
+    [SubclassSelector::PseudoClass(pseudo)] if &*pseudo.name.value == "export" || &*pseudo.name.value == "import"
+  ) && compound.type_selector.is_none()
+    && compound.nesting_selector.is_none()
+}
+
+struct IcssSymbolAnalyzer<'a> {
+  source_code: &'a str,
+  symbols: &'a HashMap<String, IcssValue>,
+  presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
+  in_value: bool,
+}
+
+impl IcssSymbolAnalyzer<'_> {
+  fn analyze_symbol(&mut self, name: &str, span: Span) {
+    if !self.in_value {
+      return;
+    }
+    let Some((value, request)) = self.symbols.get(name) else {
+      return;
+    };
+    let dependency: Box<dyn DependencyTemplate> = match request {
+      Some(request) => Box::new(CssIcssSymbolDependency::new(
+        request.clone(),
+        value.clone(),
+        span.real_lo(),
+        span.real_hi(),
+      )),
+      None => Box::new(ConstDependency::new(
+        span.real_lo(),
+        span.real_hi(),
+        value.as_str().into(),
+        None,
+      )),
+    };
+    self.presentational_dependencies.push(dependency);
+  }
+}
+
+impl Visit for IcssSymbolAnalyzer<'_> {
+  fn visit_qualified_rule(&mut self, n: &QualifiedRule) {
+    if icss_rule_prelude(n, self.source_code).is_none() {
+      n.visit_children_with(self);
+    }
+  }
+
+  fn visit_at_rule(&mut self, n: &AtRule) {
+    if !is_value_at_rule(n) {
+      n.visit_children_with(self);
+    }
+  }
+
+  fn visit_declaration(&mut self, n: &Declaration) {
+    self.in_value = true;
+    n.value.visit_with(self);
+    self.in_value = false;
+  }
+
+  fn visit_media_query(&mut self, n: &MediaQuery) {
+    self.in_value = true;
+    n.visit_children_with(self);
+    self.in_value = false;
+  }
+
+  fn visit_function(&mut self, f: &Function) {
+    // Names of functions are not values.
+    f.value.visit_with(self);
+  }
+
+  fn visit_ident(&mut self, n: &Ident) {
+    self.analyze_symbol(&n.value, n.span);
+  }
+
+  fn visit_token_and_span(&mut self, t: &TokenAndSpan) {
+    if let Token::Ident { value, .. } = &t.token {
+      self.analyze_symbol(value, t.span);
+    }
+  }
+}
@@ -1,3 +1,7 @@
+mod css_modules;
+mod icss;
+pub use css_modules::*;
+pub use icss::*;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rspack_core::{BoxDependency, ModuleDependency, SpanExt};
@@ -5,8 +9,8 @@ use rspack_error::miette::{diagnostic, Diagnostic, Severity};
 use rspack_error::DiagnosticExt;
 use swc_core::common::Span;
 use swc_core::css::ast::{
-  AtRule, AtRuleName, Function, ImportHref, ImportPrelude, Stylesheet, Token, TokenAndSpan, Url,
-  UrlValue,
+  AtRule, AtRuleName, Function, ImportHref, ImportLayerName, ImportPrelude, QualifiedRule,
+  Stylesheet, Token, TokenAndSpan, Url, UrlValue,
 };
 use swc_core::css::visit::{Visit, VisitWith};
 
@@ -19,10 +23,12 @@ static IS_MODULE_REQUEST: Lazy<Regex> = Lazy::new(|| Regex::new(r"^~").expect("T
 
 pub fn analyze_dependencies(
   ss: &Stylesheet,
+  source_code: &str,
   code_generation_dependencies: &mut Vec<Box<dyn ModuleDependency>>,
   diagnostics: &mut Vec<Box<dyn Diagnostic + Send + Sync>>,
 ) -> Vec<BoxDependency> {
   let mut v = Analyzer {
+    source_code,
     deps: Vec::new(),
     code_generation_dependencies,
     diagnostics,
@@ -37,6 +43,7 @@ pub fn analyze_dependencies(
 
 #[derive(Debug)]
 struct Analyzer<'a> {
+  source_code: &'a str,
   deps: Vec<BoxDependency>,
   code_generation_dependencies: &'a mut Vec<Box<dyn ModuleDependency>>,
   diagnostics: &'a mut Vec<Box<dyn Diagnostic + Send + Sync>>,
@@ -79,10 +86,37 @@ impl Analyzer<'_> {
     self.deps.push(dep.clone());
     self.code_generation_dependencies.push(dep);
   }
+
+  fn source_of(&self, span: Span) -> &str {
+    self
+      .source_code
+      .get(span.real_lo() as usize..span.real_hi() as usize)
+      .unwrap_or_default()
+      .trim()
+  }
+
+  /// Source between the parentheses of `layer(...)` or `supports(...)`.
+  fn source_of_function_args(&self, f: &Function) -> String {
+    let source = self.source_of(f.span);
+    match (source.find('('), source.rfind(')')) {
+      (Some(start), Some(end)) if start < end => source[start + 1..end].trim().to_string(),
+      _ => String::new(),
+    }
+  }
 }
 
 impl Visit for Analyzer<'_> {
+  fn visit_qualified_rule(&mut self, n: &QualifiedRule) {
+    // ICSS rules are removed by `analyze_icss`.
+    if icss_rule_prelude(n, self.source_code).is_none() {
+      n.visit_children_with(self);
+    }
+  }
+
   fn visit_at_rule(&mut self, n: &AtRule) {
+    if is_value_at_rule(n) {
+      return;
+    }
     if let AtRuleName::Ident(ident) = &n.name
       && &*ident.value == IMPORT_KEYWORD
     {
@@ -105,11 +139,29 @@ impl Visit for Analyzer<'_> {
     };
     if let Some(specifier) = specifier {
       let specifier = replace_module_request_prefix(specifier, self.diagnostics);
+      let layer = n.layer_name.as_ref().map(|box layer| match layer {
+        ImportLayerName::Ident(_) => String::new(),
+        ImportLayerName::Function(f) => self.source_of_function_args(f),
+      });
+      let supports = n
+        .import_conditions
+        .as_ref()
+        .and_then(|c| c.supports.as_ref())
+        .map(|box f| self.source_of_function_args(f));
+      let media = n
+        .import_conditions
+        .as_ref()
+        .and_then(|c| c.media.as_ref())
+        .map(|media| self.source_of(media.span).to_string())
+        .filter(|media| !media.is_empty());
       self.deps.push(Box::new(CssImportDependency::new(
         specifier,
         Some(span.into()),
         span.real_lo(),
         span.real_hi(),
+        layer,
+        supports,
+        media,
       )));
     }
   }
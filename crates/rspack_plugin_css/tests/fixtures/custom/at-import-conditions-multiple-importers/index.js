@@ -0,0 +1,2 @@
+import './style1.css'
+import './style2.css'
@@ -0,0 +1,4 @@
+import styles from './style.module.css'
+import theme from './theme.css'
+
+console.log(styles, theme)
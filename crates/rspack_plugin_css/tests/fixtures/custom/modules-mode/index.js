@@ -0,0 +1,4 @@
+import classes from './styles/button.css'
+import './plain.css'
+
+console.log(classes)
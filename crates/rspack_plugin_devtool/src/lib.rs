@@ -4,6 +4,7 @@ use std::collections::HashSet;
 use std::{hash::Hash, path::Path};
 
 use dashmap::DashMap;
+use futures::future::join_all;
 use once_cell::sync::Lazy;
 use pathdiff::diff_paths;
 use rayon::prelude::*;
@@ -17,11 +18,14 @@ use rspack_core::{
 };
 use rspack_core::{Filename, Logger};
 use rspack_error::miette::IntoDiagnostic;
-use rspack_error::{Error, Result};
+use rspack_error::Result;
 use rspack_util::swc::normalize_custom_filename;
 use rustc_hash::FxHashMap as HashMap;
 use serde_json::json;
 
+mod module_filename_helpers;
+pub use module_filename_helpers::*;
+
 static IS_CSS_FILE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\.css($|\?)").expect("TODO:"));
 
 #[derive(Debug)]
@@ -32,13 +36,16 @@ pub struct SourceMapDevToolPluginOptions {
   pub columns: bool,
   pub no_sources: bool,
   pub public_path: Option<String>,
+  pub module_filename_template: Option<ModuleFilenameTemplate>,
+  pub fallback_module_filename_template: Option<ModuleFilenameTemplate>,
 }
 
 #[derive(Debug)]
 pub struct SourceMapDevToolPlugin {
   filename: Option<Filename>,
   source_mapping_url_comment: Option<String>,
-  module_filename_template: String,
+  module_filename_template: ModuleFilenameTemplate,
+  fallback_module_filename_template: ModuleFilenameTemplate,
   namespace: String,
   columns: bool,
   no_sources: bool,
@@ -51,13 +58,93 @@ impl SourceMapDevToolPlugin {
       filename: options.filename.map(Filename::from),
       source_mapping_url_comment: (!matches!(options.append, Some(false)))
         .then(|| "# sourceMappingURL=[url]".to_string()),
-      module_filename_template: "[resourcePath]".to_string(),
+      module_filename_template: options
+        .module_filename_template
+        .unwrap_or_else(|| ModuleFilenameTemplate::String("[resourcePath]".to_string())),
+      fallback_module_filename_template: options
+        .fallback_module_filename_template
+        .unwrap_or_else(|| ModuleFilenameTemplate::String("[resourcePath]?[hash]".to_string())),
       namespace: options.namespace,
       columns: options.columns,
       no_sources: options.no_sources,
       public_path: options.public_path,
     }
   }
+
+  /// Render the name of every source in `sources` by `module_filename_template`, names which
+  /// are already taken fall back to `fallback_module_filename_template`, like webpack.
+  async fn create_source_names(
+    &self,
+    sources: Vec<String>,
+    compilation: &Compilation,
+  ) -> Result<HashMap<String, String>> {
+    let mut modules = compilation
+      .module_graph
+      .modules()
+      .values()
+      .collect::<Vec<_>>();
+    modules.sort_by_key(|module| module.identifier());
+    let mut module_by_source = HashMap::default();
+    for module in modules {
+      if let Some(normal_module) = module.as_normal_module() {
+        let resource_data = normal_module.resource_resolved_data();
+        for key in [
+          normal_module.request().to_string(),
+          resource_data.resource.clone(),
+          resource_data.resource_path.to_string_lossy().into_owned(),
+        ] {
+          module_by_source.entry(key).or_insert(module);
+        }
+      }
+      module_by_source
+        .entry(module.identifier().to_string())
+        .or_insert(module);
+    }
+
+    // sources of modules take precedence over the plain ones
+    let mut sources = sources
+      .into_iter()
+      .map(|source| {
+        let module = module_by_source.get(&source).copied();
+        (source, module)
+      })
+      .collect::<Vec<_>>();
+    sources.sort_by_key(|(_, module)| module.is_none());
+    let ctxs = sources
+      .iter()
+      .map(|(source, module)| match module {
+        Some(module) => {
+          ModuleFilenameTemplateFnCtx::from_module(module, compilation, &self.namespace)
+        }
+        None => ModuleFilenameTemplateFnCtx::from_source(source, compilation, &self.namespace),
+      })
+      .collect::<Vec<_>>();
+    let names = join_all(
+      ctxs
+        .iter()
+        .map(|ctx| self.module_filename_template.render(ctx.clone())),
+    )
+    .await
+    .into_iter()
+    .collect::<Result<Vec<_>>>()?;
+
+    let mut used_names = names.iter().cloned().collect::<HashSet<_>>();
+    let mut conflict_detection = HashSet::new();
+    let mut source_names = HashMap::default();
+    for (((source, _), ctx), mut name) in sources.into_iter().zip(ctxs).zip(names) {
+      if conflict_detection.insert(name.clone()) {
+        source_names.insert(source, name);
+        continue;
+      }
+      name = self.fallback_module_filename_template.render(ctx).await?;
+      while used_names.contains(&name) {
+        name.push('*');
+      }
+      used_names.insert(name.clone());
+      source_names.insert(source, name);
+    }
+    Ok(source_names)
+  }
 }
 
 #[async_trait::async_trait]
@@ -73,37 +160,16 @@ impl Plugin for SourceMapDevToolPlugin {
   ) -> PluginProcessAssetsOutput {
     let logger = args.compilation.get_logger(self.name());
     let start = logger.time("collect source maps");
-    let context = args.compilation.options.context.clone();
-    let maps: HashMap<String, (Vec<u8>, Option<Vec<u8>>)> = args
+    let maps: HashMap<String, (Vec<u8>, Option<SourceMap>)> = args
       .compilation
-      .assets_mut()
+      .assets()
       .par_iter()
       .filter_map(|(filename, asset)| asset.get_source().map(|s| (filename, s)))
       .map(|(filename, source)| {
-        let map = source
-          .map(&MapOptions::new(self.columns))
-          .map(|mut map| {
-            map.set_file(Some(filename.clone()));
-            for source in map.sources_mut() {
-              let resource_path = normalize_custom_filename(source);
-              let resource_path = contextify(&context, resource_path);
-              *source = self
-                .module_filename_template
-                .replace("[namespace]", &self.namespace)
-                .replace("[resourcePath]", &resource_path);
-            }
-            if self.no_sources {
-              for content in map.sources_content_mut() {
-                *content = String::default();
-              }
-            }
-            let mut map_buffer = Vec::new();
-            map
-              .to_writer(&mut map_buffer)
-              .unwrap_or_else(|e| panic!("{}", e.to_string()));
-            Ok::<Vec<u8>, Error>(map_buffer)
-          })
-          .transpose()?;
+        let map = source.map(&MapOptions::new(self.columns)).map(|mut map| {
+          map.set_file(Some(filename.clone()));
+          map
+        });
         let mut code_buffer = Vec::new();
         source.to_writer(&mut code_buffer).into_diagnostic()?;
         Ok((filename.to_owned(), (code_buffer, map)))
@@ -111,6 +177,41 @@ impl Plugin for SourceMapDevToolPlugin {
       .collect::<Result<_>>()?;
     logger.time_end(start);
 
+    let start = logger.time("resolve sources");
+    let sources = maps
+      .values()
+      .filter_map(|(_, map)| map.as_ref())
+      .flat_map(|map| map.sources())
+      .map(|source| normalize_custom_filename(source).to_string())
+      .collect::<HashSet<_>>();
+    let mut sources = sources.into_iter().collect::<Vec<_>>();
+    sources.sort();
+    let source_names = self.create_source_names(sources, args.compilation).await?;
+    let maps: HashMap<String, (Vec<u8>, Option<Vec<u8>>)> = maps
+      .into_par_iter()
+      .map(|(filename, (code_buffer, map))| {
+        let map = map.map(|mut map| {
+          for source in map.sources_mut() {
+            if let Some(name) = source_names.get(normalize_custom_filename(source)) {
+              *source = name.clone();
+            }
+          }
+          if self.no_sources {
+            for content in map.sources_content_mut() {
+              *content = String::default();
+            }
+          }
+          let mut map_buffer = Vec::new();
+          map
+            .to_writer(&mut map_buffer)
+            .unwrap_or_else(|e| panic!("{}", e.to_string()));
+          map_buffer
+        });
+        (filename, (code_buffer, map))
+      })
+      .collect();
+    logger.time_end(start);
+
     let start = logger.time("emit source map assets");
     for (filename, (code_buffer, map_buffer)) in maps {
       let mut asset = args
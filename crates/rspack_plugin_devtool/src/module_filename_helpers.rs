@@ -0,0 +1,212 @@
+use std::fmt;
+use std::hash::Hasher;
+
+use futures::future::BoxFuture;
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use rspack_core::{contextify, BoxModule, Compilation};
+use rspack_error::Result;
+use rspack_hash::{HashDigest, RspackHash};
+
+static PLACEHOLDER_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"(?i)\[\\*([\w-]+)\\*\]").expect("invalid regexp"));
+
+/// Information about a module, or a plain source name that has no module, which is used to
+/// render the `sources` of a source map.
+#[derive(Debug, Clone)]
+pub struct ModuleFilenameTemplateFnCtx {
+  pub identifier: String,
+  pub short_identifier: String,
+  pub resource: String,
+  pub resource_path: String,
+  pub absolute_resource_path: String,
+  pub loaders: String,
+  pub all_loaders: String,
+  pub query: String,
+  pub module_id: String,
+  pub hash: String,
+  pub namespace: String,
+}
+
+pub type ModuleFilenameTemplateFn =
+  Box<dyn Fn(ModuleFilenameTemplateFnCtx) -> BoxFuture<'static, Result<String>> + Sync + Send>;
+
+pub enum ModuleFilenameTemplate {
+  String(String),
+  Fn(ModuleFilenameTemplateFn),
+}
+
+impl fmt::Debug for ModuleFilenameTemplate {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::String(arg0) => f.debug_tuple("String").field(arg0).finish(),
+      Self::Fn(_) => f.debug_tuple("Fn").finish(),
+    }
+  }
+}
+
+impl ModuleFilenameTemplate {
+  pub async fn render(&self, ctx: ModuleFilenameTemplateFnCtx) -> Result<String> {
+    match self {
+      Self::String(template) => Ok(create_filename(template, &ctx)),
+      Self::Fn(f) => f(ctx).await,
+    }
+  }
+}
+
+impl ModuleFilenameTemplateFnCtx {
+  pub fn from_module(module: &BoxModule, compilation: &Compilation, namespace: &str) -> Self {
+    let context = &compilation.options.context;
+    let short_identifier = module.readable_identifier(context).to_string();
+    let identifier = context.shorten(&module.identifier());
+    let module_id = compilation
+      .chunk_graph
+      .get_module_id(module.identifier())
+      .clone()
+      .unwrap_or_default();
+    let absolute_resource_path = match module.as_normal_module() {
+      Some(normal_module) => normal_module
+        .resource_resolved_data()
+        .resource_path
+        .to_string_lossy()
+        .to_string(),
+      None => last_segment(&identifier).to_string(),
+    };
+    Self::new(
+      identifier,
+      short_identifier,
+      absolute_resource_path,
+      module_id,
+      compilation,
+      namespace,
+    )
+  }
+
+  pub fn from_source(source: &str, compilation: &Compilation, namespace: &str) -> Self {
+    let short_identifier = contextify(&compilation.options.context, source);
+    Self::new(
+      short_identifier.clone(),
+      short_identifier,
+      last_segment(source).to_string(),
+      String::new(),
+      compilation,
+      namespace,
+    )
+  }
+
+  fn new(
+    identifier: String,
+    short_identifier: String,
+    absolute_resource_path: String,
+    module_id: String,
+    compilation: &Compilation,
+    namespace: &str,
+  ) -> Self {
+    let mut hasher = RspackHash::new(&compilation.options.output.hash_function);
+    hasher.write(identifier.as_bytes());
+    let hash = hasher.digest(&HashDigest::Hex).rendered(4).to_string();
+
+    let resource = last_segment(&short_identifier).to_string();
+    let query = resource
+      .find('?')
+      .map(|index| resource[index..].to_string())
+      .unwrap_or_default();
+    let resource_path = resource[..resource.len() - query.len()].to_string();
+    Self {
+      loaders: before_last_segment(&short_identifier).to_string(),
+      all_loaders: before_last_segment(&identifier).to_string(),
+      identifier,
+      short_identifier,
+      resource,
+      resource_path,
+      absolute_resource_path,
+      query,
+      module_id,
+      hash,
+      namespace: namespace.to_string(),
+    }
+  }
+}
+
+fn last_segment(request: &str) -> &str {
+  request.rsplit('!').next().unwrap_or(request)
+}
+
+fn before_last_segment(request: &str) -> &str {
+  request
+    .rfind('!')
+    .map(|index| &request[..index])
+    .unwrap_or_default()
+}
+
+/// Render placeholders like `createFilename` of webpack, `[\id\]` escapes to `[id]`.
+pub fn create_filename(template: &str, ctx: &ModuleFilenameTemplateFnCtx) -> String {
+  PLACEHOLDER_REGEX
+    .replace_all(template, |caps: &Captures| {
+      let matched = &caps[0];
+      let content = &caps[1];
+      if content.len() + 2 == matched.len() {
+        let replacement = match content.to_lowercase().as_str() {
+          "identifier" => &ctx.identifier,
+          "short-identifier" => &ctx.short_identifier,
+          "resource" => &ctx.resource,
+          "resource-path" | "resourcepath" => &ctx.resource_path,
+          "absolute-resource-path"
+          | "abs-resource-path"
+          | "absoluteresource-path"
+          | "absresource-path"
+          | "absolute-resourcepath"
+          | "abs-resourcepath"
+          | "absoluteresourcepath"
+          | "absresourcepath" => &ctx.absolute_resource_path,
+          "all-loaders" | "allloaders" => &ctx.all_loaders,
+          "loaders" => &ctx.loaders,
+          "query" => &ctx.query,
+          "id" => &ctx.module_id,
+          "hash" => &ctx.hash,
+          "namespace" => &ctx.namespace,
+          _ => return matched.to_string(),
+        };
+        replacement.clone()
+      } else if matched.starts_with("[\\") && matched.ends_with("\\]") {
+        format!("[{}]", &matched[2..matched.len() - 2])
+      } else {
+        matched.to_string()
+      }
+    })
+    .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn should_create_filename_from_placeholders() {
+    let ctx = ModuleFilenameTemplateFnCtx {
+      identifier: "./loader.js!./src/a.js?raw".to_string(),
+      short_identifier: "./loader.js!./src/a.js?raw".to_string(),
+      resource: "./src/a.js?raw".to_string(),
+      resource_path: "./src/a.js".to_string(),
+      absolute_resource_path: "/project/src/a.js".to_string(),
+      loaders: "./loader.js".to_string(),
+      all_loaders: "./loader.js".to_string(),
+      query: "?raw".to_string(),
+      module_id: "42".to_string(),
+      hash: "abcd".to_string(),
+      namespace: "app".to_string(),
+    };
+    assert_eq!(
+      create_filename("webpack://[namespace]/[resourcePath]?[loaders]", &ctx),
+      "webpack://app/./src/a.js?./loader.js"
+    );
+    assert_eq!(
+      create_filename("[resource-path][query] [id] [hash]", &ctx),
+      "./src/a.js?raw 42 abcd"
+    );
+    assert_eq!(
+      create_filename("[absolute-resource-path] [\\id\\] [unknown]", &ctx),
+      "/project/src/a.js [id] [unknown]"
+    );
+  }
+}
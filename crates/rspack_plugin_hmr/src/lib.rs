@@ -7,13 +7,14 @@ use hot_module_replacement::HotModuleReplacementRuntimeModule;
 use rspack_core::{
   collect_changed_modules,
   rspack_sources::{RawSource, SourceExt},
-  AdditionalChunkRuntimeRequirementsArgs, AssetInfo, Chunk, ChunkKind, CompilationArgs,
-  CompilationAsset, CompilationParams, CompilationRecords, DependencyType, ModuleIdentifier,
-  PathData, Plugin, PluginAdditionalChunkRuntimeRequirementsOutput, PluginCompilationHookOutput,
-  PluginContext, PluginProcessAssetsOutput, ProcessAssetsArgs, RenderManifestArgs, RuntimeGlobals,
+  AdditionalChunkRuntimeRequirementsArgs, AssetInfo, Chunk, ChunkKind, Compilation,
+  CompilationArgs, CompilationAsset, CompilationParams, CompilationRecords, DependencyType,
+  HotUpdateManifest, ModuleIdentifier, PathData, Plugin,
+  PluginAdditionalChunkRuntimeRequirementsOutput, PluginCompilationHookOutput, PluginContext,
+  PluginProcessAssetsOutput, ProcessAssetsArgs, RenderManifestArgs, RuntimeGlobals,
   RuntimeModuleExt, RuntimeSpec, SourceType,
 };
-use rspack_hash::RspackHash;
+use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_identifier::IdentifierSet;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
@@ -78,6 +79,7 @@ impl Plugin for HotModuleReplacementPlugin {
     let compilation = args.compilation;
     let Some(CompilationRecords {
       old_chunks,
+      old_chunk_modules,
       all_old_runtime,
       old_all_modules,
       old_runtime_modules,
@@ -126,11 +128,6 @@ impl Plugin for HotModuleReplacementPlugin {
       }
     }
 
-    // println!(
-    //   "updated_modules: {:?}\n, remove modules {:?}",
-    //   updated_modules, completely_removed_modules
-    // );
-
     for (identifier, old_runtime_module_content) in &old_runtime_modules {
       if let Some(new_runtime_module_content) = now_runtime_modules.get(identifier) {
         // updated
@@ -199,11 +196,29 @@ impl Plugin for HotModuleReplacementPlugin {
       }
 
       for removed in removed_from_runtime {
-        if let Some(info) = hot_update_main_content_by_runtime.get_mut(removed.as_ref()) {
-          info.removed_chunk_ids.insert(chunk_id.to_string());
+        let Some(info) = hot_update_main_content_by_runtime.get_mut(removed.as_ref()) else {
+          continue;
+        };
+        info.removed_chunk_ids.insert(chunk_id.to_string());
+        // Dispose the modules of the chunk which are no longer used in this runtime. Modules still
+        // used by other chunks of the runtime are kept, otherwise they would lose further updates.
+        let Some(old_modules) = old_chunk_modules.get(&chunk_id) else {
+          continue;
+        };
+        for module_identifier in old_modules {
+          let Some((_, module_id)) = now_all_modules.get(module_identifier) else {
+            continue;
+          };
+          let still_in_runtime = compilation
+            .chunk_graph
+            .get_module_runtimes(*module_identifier, &compilation.chunk_by_ukey)
+            .values()
+            .into_iter()
+            .any(|runtime| runtime.contains(&removed));
+          if !still_in_runtime {
+            info.removed_modules.insert(module_id.to_string());
+          }
         }
-        // TODO:
-        // for (const module of remainingModules) {}
       }
 
       if !new_modules.is_empty() || !new_runtime_modules.is_empty() {
@@ -244,10 +259,10 @@ impl Plugin for HotModuleReplacementPlugin {
             .connect_chunk_and_module(ukey, *module_identifier);
         }
 
-        for runtime_module in new_runtime_modules {
+        for runtime_module in new_runtime_modules.iter() {
           compilation
             .chunk_graph
-            .connect_chunk_and_runtime_module(ukey, runtime_module);
+            .connect_chunk_and_runtime_module(ukey, *runtime_module);
         }
 
         let render_manifest = compilation
@@ -287,40 +302,28 @@ impl Plugin for HotModuleReplacementPlugin {
         new_runtime.iter().for_each(|runtime| {
           if let Some(info) = hot_update_main_content_by_runtime.get_mut(runtime.as_ref()) {
             info.updated_chunk_ids.insert(chunk_id.to_string());
+            info.updated_modules.extend(new_modules.iter().copied());
+            info
+              .updated_runtime_modules
+              .extend(new_runtime_modules.iter().copied());
           }
         });
       }
     }
 
-    let completely_removed_modules_array: Vec<String> =
-      completely_removed_modules.into_iter().collect();
+    let mut hot_update_manifests = hot_update_main_content_by_runtime
+      .into_values()
+      .map(|content| content.into_manifest(compilation, &completely_removed_modules, &old_hash))
+      .collect::<Vec<_>>();
+    hot_update_manifests.sort_unstable_by(|a, b| a.filename.cmp(&b.filename));
 
-    for (_, content) in hot_update_main_content_by_runtime {
-      let c: Vec<String> = content.updated_chunk_ids.into_iter().collect();
-      let r: Vec<String> = content.removed_chunk_ids.into_iter().collect();
-      let m: Vec<String> = completely_removed_modules_array
-        .iter()
-        .map(|x| x.to_owned())
-        .collect();
-      let filename = compilation.get_path(
-        &compilation.options.output.hot_update_main_filename,
-        PathData::default().runtime(&content.runtime).hash_optional(
-          old_hash
-            .as_ref()
-            .map(|hash| hash.rendered(compilation.options.output.hash_digest_length)),
-        ),
-      );
+    for manifest in &hot_update_manifests {
       compilation.emit_asset(
-        filename,
+        manifest.filename.clone(),
         CompilationAsset::new(
           Some(
             RawSource::Source(
-              serde_json::json!({
-                "c": c,
-                "r": r,
-                "m": m,
-              })
-              .to_string(),
+              serde_json::to_string(manifest).expect("should serialize hot update manifest"),
             )
             .boxed(),
           ),
@@ -328,6 +331,7 @@ impl Plugin for HotModuleReplacementPlugin {
         ),
       );
     }
+    compilation.hot_update_manifests = hot_update_manifests;
 
     Ok(())
   }
@@ -338,7 +342,9 @@ struct HotUpdateContent {
   runtime: RuntimeSpec,
   updated_chunk_ids: HashSet<String>,
   removed_chunk_ids: HashSet<String>,
-  _removed_modules: IdentifierSet,
+  removed_modules: HashSet<String>,
+  updated_modules: IdentifierSet,
+  updated_runtime_modules: IdentifierSet,
 }
 
 impl HotUpdateContent {
@@ -348,4 +354,40 @@ impl HotUpdateContent {
       ..Default::default()
     }
   }
+
+  fn into_manifest(
+    self,
+    compilation: &Compilation,
+    completely_removed_modules: &HashSet<String>,
+    old_hash: &Option<RspackHashDigest>,
+  ) -> HotUpdateManifest {
+    let filename = compilation.get_path(
+      &compilation.options.output.hot_update_main_filename,
+      PathData::default().runtime(&self.runtime).hash_optional(
+        old_hash
+          .as_ref()
+          .map(|hash| hash.rendered(compilation.options.output.hash_digest_length)),
+      ),
+    );
+    HotUpdateManifest {
+      filename,
+      runtime: self.runtime,
+      updated_chunk_ids: into_sorted_vec(self.updated_chunk_ids),
+      removed_chunk_ids: into_sorted_vec(self.removed_chunk_ids),
+      removed_module_ids: into_sorted_vec(
+        completely_removed_modules
+          .iter()
+          .cloned()
+          .chain(self.removed_modules),
+      ),
+      updated_modules: into_sorted_vec(self.updated_modules),
+      updated_runtime_modules: into_sorted_vec(self.updated_runtime_modules),
+    }
+  }
+}
+
+fn into_sorted_vec<T: Ord>(set: impl IntoIterator<Item = T>) -> Vec<T> {
+  let mut vec = set.into_iter().collect::<Vec<_>>();
+  vec.sort_unstable();
+  vec
 }
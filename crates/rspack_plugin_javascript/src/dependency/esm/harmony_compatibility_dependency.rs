@@ -19,8 +19,13 @@ impl DependencyTemplate for HarmonyCompatibilityDependency {
       init_fragments,
       compilation,
       module,
+      concatenation_scope,
       ..
     } = code_generatable_context;
+    // The concatenated module marks its exports object itself.
+    if concatenation_scope.is_some() {
+      return;
+    }
     let mgm = compilation
       .module_graph
       .module_graph_module_by_identifier(&module.identifier())
@@ -1,10 +1,8 @@
+pub use rspack_core::DEFAULT_EXPORT;
 use rspack_core::{AsContextDependency, AsModuleDependency, Dependency};
 use rspack_core::{DependencyId, DependencyTemplate};
 use rspack_core::{TemplateContext, TemplateReplaceSource};
 
-pub const DEFAULT_EXPORT: &str = "__WEBPACK_DEFAULT_EXPORT__";
-// pub const NAMESPACE_OBJECT_EXPORT: &'static str = "__WEBPACK_NAMESPACE_OBJECT__";
-
 #[derive(Debug, Clone)]
 pub struct AnonymousFunctionRangeInfo {
   pub is_async: bool,
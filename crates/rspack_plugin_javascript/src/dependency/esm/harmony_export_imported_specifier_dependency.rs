@@ -845,6 +845,11 @@ impl DependencyTemplate for HarmonyExportImportedSpecifierDependency {
     _source: &mut TemplateReplaceSource,
     code_generatable_context: &mut TemplateContext,
   ) {
+    // The reexports of a concatenated module are resolved to the final bindings by the concatenated
+    // module, and the reexported modules are always in the same concatenation.
+    if code_generatable_context.concatenation_scope.is_some() {
+      return;
+    }
     let compilation = &code_generatable_context.compilation;
     let module = &code_generatable_context.module;
     let runtime = code_generatable_context.runtime;
@@ -100,6 +100,13 @@ pub fn harmony_import_dependency_apply<T: ModuleDependency>(
   if is_target_active.is_some_and(|x| !x) {
     return;
   }
+  // The modules of a concatenation are rendered into the same scope, no need to import them.
+  if let Some(scope) = &code_generatable_context.concatenation_scope
+    && let Some(ref_mgm) = ref_mgm
+    && scope.is_module_in_scope(&ref_mgm.module_identifier)
+  {
+    return;
+  }
   if let Some(ref_mgm) = ref_mgm
     && module_dependency.is_export_all() == Some(false)
   {
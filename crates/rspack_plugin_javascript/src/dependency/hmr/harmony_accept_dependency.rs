@@ -1,6 +1,8 @@
 use rspack_core::{
-  import_statement, DependencyId, DependencyTemplate, TemplateContext, TemplateReplaceSource,
+  import_statement, DependencyId, DependencyTemplate, DependencyType, TemplateContext,
+  TemplateReplaceSource,
 };
+use rspack_identifier::IdentifierSet;
 
 #[derive(Debug, Clone)]
 pub struct HarmonyAcceptDependency {
@@ -27,11 +29,50 @@ impl DependencyTemplate for HarmonyAcceptDependency {
     source: &mut TemplateReplaceSource,
     code_generatable_context: &mut TemplateContext,
   ) {
-    let TemplateContext { compilation, .. } = code_generatable_context;
+    let TemplateContext {
+      compilation,
+      module,
+      ..
+    } = code_generatable_context;
+
+    // Only the bindings of harmony imports can be refreshed, modules that are accepted but not
+    // imported by this module don't have a variable to update.
+    let harmony_imported_modules = module
+      .get_dependencies()
+      .iter()
+      .filter(|id| {
+        matches!(
+          compilation
+            .module_graph
+            .dependency_by_id(id)
+            .map(|dependency| dependency.dependency_type()),
+          Some(DependencyType::EsmImport(_))
+        )
+      })
+      .filter_map(|id| {
+        compilation
+          .module_graph
+          .module_identifier_by_dependency_id(id)
+      })
+      .copied()
+      .collect::<IdentifierSet>();
+    let mut refreshed_modules = IdentifierSet::default();
 
     let mut content = String::default();
 
     self.dependency_ids.iter().for_each(|id| {
+      let Some(module_identifier) = compilation
+        .module_graph
+        .module_identifier_by_dependency_id(id)
+        .copied()
+      else {
+        return;
+      };
+      if !harmony_imported_modules.contains(&module_identifier)
+        || !refreshed_modules.insert(module_identifier)
+      {
+        return;
+      }
       let dependency = compilation.module_graph.dependency_by_id(id);
       let request = if let Some(dependency) = dependency.and_then(|d| d.as_module_dependency()) {
         Some(dependency.request())
@@ -58,12 +99,20 @@ impl DependencyTemplate for HarmonyAcceptDependency {
         ")(__WEBPACK_OUTDATED_DEPENDENCIES__); }.bind(this)",
         None,
       );
-    } else {
+    } else if self.start == self.end {
       source.insert(
         self.start,
         format!(", function(){{\n{content}\n}}").as_str(),
         None,
       );
+    } else {
+      // A `null` or `undefined` placeholder in front of an error handler.
+      source.replace(
+        self.start,
+        self.end,
+        format!("function(){{\n{content}\n}}").as_str(),
+        None,
+      );
     }
   }
 }
@@ -59,6 +59,10 @@ impl ModuleDependency for ModuleHotAcceptDependency {
   fn set_request(&mut self, request: String) {
     self.request = request.into();
   }
+
+  fn get_optional(&self) -> bool {
+    true
+  }
 }
 
 impl DependencyTemplate for ModuleHotAcceptDependency {
@@ -5,12 +5,14 @@ pub mod impl_plugin_for_js_plugin;
 pub mod infer_async_modules_plugin;
 pub mod inner_graph_plugin;
 mod mangle_exports_plugin;
+mod module_concatenation_plugin;
 mod side_effects_flag_plugin;
 use std::hash::Hash;
 
 pub use flag_dependency_exports_plugin::*;
 pub use flag_dependency_usage_plugin::*;
 pub use mangle_exports_plugin::*;
+pub use module_concatenation_plugin::*;
 use rspack_core::rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt};
 use rspack_core::{
   render_init_fragments, ChunkRenderContext, ChunkUkey, Compilation, JsChunkHashArgs,
@@ -0,0 +1,715 @@
+use std::collections::VecDeque;
+
+use rspack_core::{
+  BuildMetaExportsType, Compilation, ConcatenatedModule, ConcatenatedReexport, DependencyType,
+  ExportInfoId, ExportInfoProvided, ModuleIdentifier, OptimizeChunksArgs, Plugin, UsageState,
+};
+use rspack_error::Result;
+use rspack_identifier::{IdentifierLinkedSet, IdentifierMap, IdentifierSet};
+use rustc_hash::FxHashMap as HashMap;
+
+const BAILOUT_PREFIX: &str = "ModuleConcatenation bailout: ";
+
+fn format_bailout_reason(msg: &str) -> String {
+  format!("{BAILOUT_PREFIX}{msg}")
+}
+
+/// Why a module can't be added to a concatenation.
+#[derive(Debug, Clone)]
+enum Problem {
+  Reason(String),
+  /// The module itself can't be an inner module, or it depends on the given module which can't.
+  Module(ModuleIdentifier),
+}
+
+#[derive(Debug)]
+struct ConcatConfiguration {
+  root_module: ModuleIdentifier,
+  modules: Vec<ModuleIdentifier>,
+  module_set: IdentifierSet,
+  warnings: IdentifierMap<Problem>,
+}
+
+impl ConcatConfiguration {
+  fn new(root_module: ModuleIdentifier) -> Self {
+    Self {
+      root_module,
+      modules: vec![root_module],
+      module_set: IdentifierSet::from_iter([root_module]),
+      warnings: Default::default(),
+    }
+  }
+
+  fn add(&mut self, module: ModuleIdentifier) {
+    self.modules.push(module);
+    self.module_set.insert(module);
+  }
+
+  fn has(&self, module: &ModuleIdentifier) -> bool {
+    self.module_set.contains(module)
+  }
+
+  fn is_empty(&self) -> bool {
+    self.modules.len() == 1
+  }
+
+  fn add_warning(&mut self, module: ModuleIdentifier, problem: Problem) {
+    self.warnings.insert(module, problem);
+  }
+
+  fn snapshot(&self) -> usize {
+    self.modules.len()
+  }
+
+  fn rollback(&mut self, snapshot: usize) {
+    for module in self.modules.drain(snapshot..) {
+      self.module_set.remove(&module);
+    }
+  }
+}
+
+fn is_harmony_import_dependency(dependency_type: &DependencyType) -> bool {
+  matches!(
+    dependency_type,
+    DependencyType::EsmImport(_)
+      | DependencyType::EsmImportSpecifier
+      | DependencyType::EsmExport(_)
+      | DependencyType::EsmExportImportedSpecifier
+  )
+}
+
+/// The dependencies of the import statements, in the order of the statements.
+fn is_harmony_side_effect_dependency(dependency_type: &DependencyType) -> bool {
+  matches!(
+    dependency_type,
+    DependencyType::EsmImport(_) | DependencyType::EsmExport(_)
+  )
+}
+
+fn is_harmony_reexport_dependency(dependency_type: &DependencyType) -> bool {
+  matches!(
+    dependency_type,
+    DependencyType::EsmExport(_) | DependencyType::EsmExportImportedSpecifier
+  )
+}
+
+/// Concatenates ECMAScript modules into the scope of the module importing them, i.e. scope hoisting.
+///
+/// https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/optimize/ModuleConcatenationPlugin.js
+#[derive(Debug, Default)]
+pub struct ModuleConcatenationPlugin;
+
+impl ModuleConcatenationPlugin {
+  fn readable_identifier(compilation: &Compilation, module: &ModuleIdentifier) -> String {
+    compilation
+      .module_graph
+      .module_by_identifier(module)
+      .map(|m| {
+        m.readable_identifier(&compilation.options.context)
+          .into_owned()
+      })
+      .unwrap_or_else(|| module.to_string())
+  }
+
+  fn set_bailout_reason(compilation: &mut Compilation, module: &ModuleIdentifier, reason: &str) {
+    if let Some(mgm) = compilation
+      .module_graph
+      .module_graph_module_by_identifier_mut(module)
+    {
+      mgm.optimization_bailout.push(format_bailout_reason(reason));
+    }
+  }
+
+  fn format_bailout_warning(
+    compilation: &Compilation,
+    module: &ModuleIdentifier,
+    problem: &Problem,
+  ) -> String {
+    let readable_identifier = Self::readable_identifier(compilation, module);
+    match problem {
+      Problem::Reason(reason) => format_bailout_reason(&format!(
+        "Cannot concat with {readable_identifier}: {reason}"
+      )),
+      Problem::Module(problem_module) if problem_module == module => {
+        format_bailout_reason(&format!("Cannot concat with {readable_identifier}"))
+      }
+      Problem::Module(problem_module) => format_bailout_reason(&format!(
+        "Cannot concat with {readable_identifier} because of {}",
+        Self::readable_identifier(compilation, problem_module)
+      )),
+    }
+  }
+
+  /// The modules imported by `module` with harmony imports, in the order of the imports.
+  fn get_imports(
+    compilation: &Compilation,
+    module: &ModuleIdentifier,
+    filter: fn(&DependencyType) -> bool,
+  ) -> IdentifierLinkedSet {
+    let module_graph = &compilation.module_graph;
+    let Some(module) = module_graph.module_by_identifier(module) else {
+      return Default::default();
+    };
+    module
+      .get_dependencies()
+      .iter()
+      .filter(|dependency_id| {
+        module_graph
+          .dependency_by_id(dependency_id)
+          .is_some_and(|dependency| filter(dependency.dependency_type()))
+      })
+      .filter_map(|dependency_id| module_graph.connection_by_dependency(dependency_id))
+      .filter(|connection| connection.is_target_active(module_graph, None))
+      .map(|connection| connection.module_identifier)
+      .collect()
+  }
+
+  fn get_relevant_exports(
+    compilation: &Compilation,
+    module: &ModuleIdentifier,
+  ) -> Vec<ExportInfoId> {
+    let module_graph = &compilation.module_graph;
+    let exports_info = module_graph.get_exports_info(module);
+    let mut relevant_exports = exports_info
+      .exports
+      .values()
+      .filter(|export_info_id| {
+        let export_info = export_info_id.get_export_info(module_graph);
+        export_info.get_used(None) != UsageState::Unused
+          && !matches!(export_info.provided, Some(ExportInfoProvided::False))
+      })
+      .copied()
+      .collect::<Vec<_>>();
+    let other_exports_info = exports_info
+      .other_exports_info
+      .get_export_info(module_graph);
+    if !matches!(other_exports_info.provided, Some(ExportInfoProvided::False))
+      && other_exports_info.get_used(None) != UsageState::Unused
+    {
+      relevant_exports.push(exports_info.other_exports_info);
+    }
+    relevant_exports
+  }
+
+  fn export_names(compilation: &Compilation, exports: &[ExportInfoId]) -> String {
+    exports
+      .iter()
+      .map(|export_info_id| {
+        export_info_id
+          .get_export_info(&compilation.module_graph)
+          .name
+          .as_ref()
+          .map(|name| name.to_string())
+          .unwrap_or_else(|| "other exports".to_string())
+      })
+      .collect::<Vec<_>>()
+      .join(", ")
+  }
+
+  fn chunk_names<'a>(
+    compilation: &Compilation,
+    chunks: impl Iterator<Item = &'a rspack_core::ChunkUkey>,
+  ) -> String {
+    let mut names = chunks
+      .map(|chunk| {
+        compilation
+          .chunk_by_ukey
+          .expect_get(chunk)
+          .name
+          .clone()
+          .unwrap_or_else(|| "unnamed chunk(s)".to_string())
+      })
+      .collect::<Vec<_>>();
+    names.sort_unstable();
+    names.dedup();
+    names.join(", ")
+  }
+
+  #[allow(clippy::too_many_arguments)]
+  fn try_to_add(
+    compilation: &Compilation,
+    config: &mut ConcatConfiguration,
+    module: ModuleIdentifier,
+    possible_modules: &IdentifierSet,
+    candidates: &mut IdentifierLinkedSet,
+    failure_cache: &mut IdentifierMap<Problem>,
+    avoid_mutate_on_failure: bool,
+  ) -> Option<Problem> {
+    if let Some(problem) = failure_cache.get(&module) {
+      return Some(problem.clone());
+    }
+    if config.has(&module) {
+      return None;
+    }
+    if !possible_modules.contains(&module) {
+      let problem = Problem::Module(module);
+      failure_cache.insert(module, problem.clone());
+      return Some(problem);
+    }
+
+    let chunk_graph = &compilation.chunk_graph;
+    let module_graph = &compilation.module_graph;
+    let root_chunks = chunk_graph.get_module_chunks(config.root_module);
+    let missing_chunks = root_chunks
+      .iter()
+      .filter(|chunk| !chunk_graph.is_module_in_chunk(&module, **chunk))
+      .collect::<Vec<_>>();
+    if !missing_chunks.is_empty() {
+      let problem = Problem::Reason(format!(
+        "Module {} is not in the same chunk(s) (expected in chunk(s) {}, module is in chunk(s) {})",
+        Self::readable_identifier(compilation, &module),
+        Self::chunk_names(compilation, missing_chunks.into_iter()),
+        Self::chunk_names(compilation, chunk_graph.get_module_chunks(module).iter()),
+      ));
+      failure_cache.insert(module, problem.clone());
+      return Some(problem);
+    }
+
+    let Some(box_module) = module_graph.module_by_identifier(&module) else {
+      return Some(Problem::Module(module));
+    };
+    let incoming_connections = module_graph
+      .get_incoming_connections(box_module)
+      .into_iter()
+      .filter(|connection| connection.is_active(module_graph, None))
+      .collect::<Vec<_>>();
+    if incoming_connections
+      .iter()
+      .any(|connection| connection.original_module_identifier.is_none())
+    {
+      let problem = Problem::Reason(format!(
+        "Module {} is referenced in an unsupported way",
+        Self::readable_identifier(compilation, &module)
+      ));
+      failure_cache.insert(module, problem.clone());
+      return Some(problem);
+    }
+
+    // Connections from orphan modules are ignored.
+    let mut incoming_connections_from_modules: IdentifierMap<Vec<&DependencyType>> =
+      Default::default();
+    for connection in &incoming_connections {
+      let Some(origin_module) = connection.original_module_identifier else {
+        continue;
+      };
+      if chunk_graph.get_number_of_module_chunks(origin_module) == 0 {
+        continue;
+      }
+      let dependency_types = incoming_connections_from_modules
+        .entry(origin_module)
+        .or_default();
+      if let Some(dependency) = module_graph.dependency_by_id(&connection.dependency_id) {
+        dependency_types.push(dependency.dependency_type());
+      }
+    }
+    let mut incoming_modules = incoming_connections_from_modules
+      .keys()
+      .copied()
+      .collect::<Vec<_>>();
+    incoming_modules.sort_unstable();
+
+    // Module must be in the same chunks like the referencing module
+    let mut other_chunk_modules = incoming_modules
+      .iter()
+      .filter(|origin_module| {
+        root_chunks
+          .iter()
+          .any(|chunk| !chunk_graph.is_module_in_chunk(origin_module, *chunk))
+      })
+      .map(|origin_module| Self::readable_identifier(compilation, origin_module))
+      .collect::<Vec<_>>();
+    if !other_chunk_modules.is_empty() {
+      other_chunk_modules.sort_unstable();
+      let problem = Problem::Reason(format!(
+        "Module {} is referenced from different chunks by these modules: {}",
+        Self::readable_identifier(compilation, &module),
+        other_chunk_modules.join(", ")
+      ));
+      failure_cache.insert(module, problem.clone());
+      return Some(problem);
+    }
+
+    let mut non_harmony_modules = incoming_connections_from_modules
+      .iter()
+      .filter(|(_, dependency_types)| {
+        dependency_types
+          .iter()
+          .any(|dependency_type| !is_harmony_import_dependency(dependency_type))
+      })
+      .map(|(origin_module, dependency_types)| {
+        let mut dependency_types = dependency_types
+          .iter()
+          .map(|dependency_type| dependency_type.as_str().into_owned())
+          .collect::<Vec<_>>();
+        dependency_types.sort_unstable();
+        dependency_types.dedup();
+        format!(
+          "{} (referenced with {})",
+          Self::readable_identifier(compilation, origin_module),
+          dependency_types.join(", ")
+        )
+      })
+      .collect::<Vec<_>>();
+    if !non_harmony_modules.is_empty() {
+      non_harmony_modules.sort_unstable();
+      let problem = Problem::Reason(format!(
+        "Module {} is referenced from these modules with unsupported syntax: {}",
+        Self::readable_identifier(compilation, &module),
+        non_harmony_modules.join(", ")
+      ));
+      failure_cache.insert(module, problem.clone());
+      return Some(problem);
+    }
+
+    let backup = avoid_mutate_on_failure.then(|| config.snapshot());
+    config.add(module);
+
+    // Every module which depends on the added module must be in the configuration too, and so
+    // must every module it reexports from, as the reexports are resolved to their final bindings.
+    let reexported_modules =
+      Self::get_imports(compilation, &module, is_harmony_reexport_dependency);
+    for other_module in incoming_modules
+      .into_iter()
+      .chain(reexported_modules.into_iter())
+    {
+      if let Some(problem) = Self::try_to_add(
+        compilation,
+        config,
+        other_module,
+        possible_modules,
+        candidates,
+        failure_cache,
+        false,
+      ) {
+        if let Some(backup) = backup {
+          config.rollback(backup);
+        }
+        failure_cache.insert(module, problem.clone());
+        return Some(problem);
+      }
+    }
+
+    candidates.extend(Self::get_imports(
+      compilation,
+      &module,
+      is_harmony_import_dependency,
+    ));
+    None
+  }
+
+  /// The imported modules in the order of evaluation.
+  fn get_ordered_imports(
+    compilation: &Compilation,
+    module: &ModuleIdentifier,
+  ) -> IdentifierLinkedSet {
+    let mut imports = Self::get_imports(compilation, module, is_harmony_side_effect_dependency);
+    imports.extend(Self::get_imports(
+      compilation,
+      module,
+      is_harmony_import_dependency,
+    ));
+    imports
+  }
+
+  /// Orders the modules as they are evaluated, the root module is the last one.
+  fn get_concatenation_list(
+    compilation: &Compilation,
+    config: &ConcatConfiguration,
+  ) -> Vec<ModuleIdentifier> {
+    fn visit(
+      compilation: &Compilation,
+      config: &ConcatConfiguration,
+      module: ModuleIdentifier,
+      visited: &mut IdentifierSet,
+      list: &mut Vec<ModuleIdentifier>,
+    ) {
+      if !visited.insert(module) {
+        return;
+      }
+      for imported in ModuleConcatenationPlugin::get_ordered_imports(compilation, &module) {
+        if config.has(&imported) {
+          visit(compilation, config, imported, visited, list);
+        }
+      }
+      list.push(module);
+    }
+
+    let mut visited = IdentifierSet::default();
+    let mut list = vec![];
+    visited.insert(config.root_module);
+    for imported in Self::get_ordered_imports(compilation, &config.root_module) {
+      if config.has(&imported) {
+        visit(compilation, config, imported, &mut visited, &mut list);
+      }
+    }
+    for module in &config.modules {
+      if !visited.contains(module) {
+        visit(compilation, config, *module, &mut visited, &mut list);
+      }
+    }
+    list.push(config.root_module);
+    list
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for ModuleConcatenationPlugin {
+  fn name(&self) -> &'static str {
+    "ModuleConcatenationPlugin"
+  }
+
+  async fn optimize_chunk_modules(&self, args: OptimizeChunksArgs<'_>) -> Result<()> {
+    let compilation = args.compilation;
+    compilation.concatenated_modules.clear();
+    for mgm in compilation
+      .module_graph
+      .module_identifier_to_module_graph_module
+      .values_mut()
+    {
+      mgm
+        .optimization_bailout
+        .retain(|reason| !reason.starts_with(BAILOUT_PREFIX));
+    }
+
+    let mut module_identifiers = compilation
+      .module_graph
+      .modules()
+      .keys()
+      .copied()
+      .collect::<Vec<_>>();
+    module_identifiers.sort_unstable();
+
+    let mut relevant_modules = vec![];
+    let mut possible_inners = IdentifierSet::default();
+    for module in module_identifiers {
+      let Some(mgm) = compilation
+        .module_graph
+        .module_graph_module_by_identifier(&module)
+      else {
+        continue;
+      };
+      if let Some(bailout) = mgm
+        .build_info
+        .as_ref()
+        .and_then(|info| info.module_concatenation_bailout.clone())
+      {
+        Self::set_bailout_reason(compilation, &module, &format!("Module uses {bailout}"));
+        continue;
+      }
+      let is_esm = compilation
+        .module_graph
+        .module_by_identifier(&module)
+        .is_some_and(|m| m.as_normal_module().is_some())
+        && mgm
+          .build_meta
+          .as_ref()
+          .is_some_and(|meta| matches!(meta.exports_type, BuildMetaExportsType::Namespace));
+      if !is_esm {
+        Self::set_bailout_reason(compilation, &module, "Module is not an ECMAScript module");
+        continue;
+      }
+      if compilation.module_graph.is_async(&module) == Some(true) {
+        Self::set_bailout_reason(compilation, &module, "Module is async");
+        continue;
+      }
+      if compilation.chunk_graph.get_number_of_module_chunks(module) == 0 {
+        Self::set_bailout_reason(compilation, &module, "Module is not in any chunk");
+        continue;
+      }
+
+      let relevant_exports = Self::get_relevant_exports(compilation, &module);
+      let unknown_reexports = relevant_exports
+        .iter()
+        .filter(|export_info_id| {
+          let export_info = export_info_id.get_export_info(&compilation.module_graph);
+          !export_info.target.is_empty()
+            && export_info_id
+              .get_target(&mut compilation.module_graph, None)
+              .is_none()
+        })
+        .copied()
+        .collect::<Vec<_>>();
+      if !unknown_reexports.is_empty() {
+        let names = Self::export_names(compilation, &unknown_reexports);
+        Self::set_bailout_reason(
+          compilation,
+          &module,
+          &format!("Reexports in this module do not have a static target ({names})"),
+        );
+        continue;
+      }
+      let unknown_provided_exports = relevant_exports
+        .iter()
+        .filter(|export_info_id| {
+          !matches!(
+            export_info_id
+              .get_export_info(&compilation.module_graph)
+              .provided,
+            Some(ExportInfoProvided::True)
+          )
+        })
+        .copied()
+        .collect::<Vec<_>>();
+      let mut can_be_root = true;
+      let mut can_be_inner = true;
+      if !unknown_provided_exports.is_empty() {
+        let names = Self::export_names(compilation, &unknown_provided_exports);
+        Self::set_bailout_reason(
+          compilation,
+          &module,
+          &format!("List of module exports is dynamic ({names})"),
+        );
+        can_be_root = false;
+      }
+      if compilation.chunk_graph.is_entry_module(&module) {
+        Self::set_bailout_reason(compilation, &module, "Module is an entry point");
+        can_be_inner = false;
+      }
+      if can_be_root {
+        relevant_modules.push(module);
+      }
+      if can_be_inner {
+        possible_inners.insert(module);
+      }
+    }
+
+    // Modules closer to the entries are tried first as root
+    relevant_modules.sort_by_key(|module| {
+      (
+        compilation
+          .module_graph
+          .get_pre_order_index(module)
+          .unwrap_or(u32::MAX),
+        *module,
+      )
+    });
+
+    let mut concat_configurations = vec![];
+    let mut used_as_inner = IdentifierSet::default();
+    for root_module in relevant_modules {
+      if used_as_inner.contains(&root_module) {
+        continue;
+      }
+      let mut failure_cache = IdentifierMap::default();
+      let mut config = ConcatConfiguration::new(root_module);
+      // The root module reexports are resolved to their final bindings as well
+      let mut candidates: VecDeque<ModuleIdentifier> =
+        Self::get_imports(compilation, &root_module, is_harmony_reexport_dependency)
+          .into_iter()
+          .chain(Self::get_imports(
+            compilation,
+            &root_module,
+            is_harmony_import_dependency,
+          ))
+          .collect();
+      let mut root_failed = false;
+      while let Some(import) = candidates.pop_front() {
+        let mut import_candidates = IdentifierLinkedSet::default();
+        if let Some(problem) = Self::try_to_add(
+          compilation,
+          &mut config,
+          import,
+          &possible_inners,
+          &mut import_candidates,
+          &mut failure_cache,
+          true,
+        ) {
+          failure_cache.insert(import, problem.clone());
+          config.add_warning(import, problem);
+          if Self::get_imports(compilation, &root_module, is_harmony_reexport_dependency)
+            .contains(&import)
+          {
+            root_failed = true;
+            break;
+          }
+        } else {
+          candidates.extend(import_candidates);
+        }
+      }
+      if !root_failed && !config.is_empty() {
+        for module in &config.modules {
+          if *module != root_module {
+            used_as_inner.insert(*module);
+          }
+        }
+        concat_configurations.push(config);
+      } else {
+        let mut warnings = config.warnings.into_iter().collect::<Vec<_>>();
+        warnings.sort_unstable_by_key(|(module, _)| *module);
+        for (module, problem) in warnings {
+          let warning = Self::format_bailout_warning(compilation, &module, &problem);
+          if let Some(mgm) = compilation
+            .module_graph
+            .module_graph_module_by_identifier_mut(&module)
+          {
+            mgm.optimization_bailout.push(warning);
+          }
+        }
+      }
+    }
+
+    // Bigger concatenations first, so their modules aren't taken by the smaller ones
+    concat_configurations.sort_by(|a, b| b.modules.len().cmp(&a.modules.len()));
+    let mut used_modules = IdentifierSet::default();
+    for config in concat_configurations {
+      if used_modules.contains(&config.root_module) {
+        continue;
+      }
+      let modules = Self::get_concatenation_list(compilation, &config);
+      let mut reexports: IdentifierMap<HashMap<_, _>> = IdentifierMap::default();
+      for module in &modules {
+        let export_info_ids = compilation
+          .module_graph
+          .get_exports_info(module)
+          .exports
+          .values()
+          .copied()
+          .collect::<Vec<_>>();
+        for export_info_id in export_info_ids {
+          let export_info = export_info_id.get_export_info(&compilation.module_graph);
+          if export_info.target.is_empty() {
+            continue;
+          }
+          let Some(name) = export_info.name.clone() else {
+            continue;
+          };
+          if let Some(target) = export_info_id.get_target(&mut compilation.module_graph, None)
+            && config.has(&target.module)
+          {
+            reexports.entry(*module).or_default().insert(
+              name,
+              ConcatenatedReexport {
+                module: target.module,
+                export: target.export,
+              },
+            );
+          }
+        }
+      }
+
+      let root_chunks = compilation
+        .chunk_graph
+        .get_module_chunks(config.root_module)
+        .iter()
+        .copied()
+        .collect::<Vec<_>>();
+      for module in &modules {
+        used_modules.insert(*module);
+        if *module == config.root_module {
+          continue;
+        }
+        for chunk in &root_chunks {
+          compilation
+            .chunk_graph
+            .disconnect_chunk_and_module(chunk, *module);
+        }
+      }
+      let concatenated_module =
+        ConcatenatedModule::new(config.root_module, modules, reexports, compilation);
+      compilation
+        .concatenated_modules
+        .insert(config.root_module, concatenated_module);
+    }
+    Ok(())
+  }
+}
@@ -222,6 +222,7 @@ impl Visit for ApiScanner<'_> {
           )));
       }
       WEBPACK_MODULE => {
+        self.build_info.module_concatenation_bailout = Some(WEBPACK_MODULE.to_string());
         self
           .presentational_dependencies
           .push(Box::new(ModuleArgumentDependency::new(
@@ -337,6 +338,7 @@ impl Visit for ApiScanner<'_> {
           Some(RuntimeGlobals::MODULE_CACHE),
         )));
     } else if expr_matcher::is_webpack_module_id(expr) {
+      self.build_info.module_concatenation_bailout = Some(format!("{WEBPACK_MODULE}.id"));
       self
         .presentational_dependencies
         .push(Box::new(RuntimeRequirementsDependency::new(
@@ -355,6 +357,12 @@ impl Visit for ApiScanner<'_> {
   }
 
   fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    if let Callee::Expr(box Expr::Ident(ident)) = &call_expr.callee
+      && ident.sym == *"eval"
+      && ident.span.ctxt == self.unresolved_ctxt
+    {
+      self.build_info.module_concatenation_bailout = Some("eval()".to_string());
+    }
     if let Callee::Expr(box Expr::Ident(ident)) = &call_expr.callee
       && ident.sym == WEBPACK_IS_INCLUDE
     {
@@ -608,6 +608,7 @@ mod test {
       all_star_exports: Default::default(),
       need_create_require: false,
       json_data: None,
+      module_concatenation_bailout: None,
     };
     let mut import_map = Default::default();
     let mut deps = vec![];
@@ -1,10 +1,13 @@
 use rspack_core::{
-  BoxDependency, BoxDependencyTemplate, BuildMeta, DependencyLocation, ErrorSpan, SpanExt,
+  BoxDependency, BoxDependencyTemplate, BuildInfo, BuildMeta, DependencyLocation, ErrorSpan,
+  SpanExt,
 };
+use rspack_error::miette::{diagnostic, Diagnostic, Severity};
+use rspack_error::DiagnosticExt;
 use swc_core::{
-  common::Spanned,
+  common::{Span, Spanned},
   ecma::{
-    ast::{CallExpr, Expr, Lit},
+    ast::{CallExpr, Expr, ExprOrSpread, Lit},
     atoms::JsWord,
     visit::{noop_visit_type, Visit, VisitWith},
   },
@@ -23,7 +26,9 @@ use crate::{
 pub struct HotModuleReplacementScanner<'a> {
   pub dependencies: &'a mut Vec<BoxDependency>,
   pub presentational_dependencies: &'a mut Vec<BoxDependencyTemplate>,
+  pub build_info: &'a mut BuildInfo,
   pub build_meta: &'a BuildMeta,
+  pub warning_diagnostics: &'a mut Vec<Box<dyn Diagnostic + Send + Sync>>,
   pub ignored: &'a mut Vec<DependencyLocation>,
 }
 
@@ -33,13 +38,17 @@ impl<'a> HotModuleReplacementScanner<'a> {
   pub fn new(
     dependencies: &'a mut Vec<BoxDependency>,
     presentational_dependencies: &'a mut Vec<BoxDependencyTemplate>,
+    build_info: &'a mut BuildInfo,
     build_meta: &'a BuildMeta,
+    warning_diagnostics: &'a mut Vec<Box<dyn Diagnostic + Send + Sync>>,
     ignored: &'a mut Vec<DependencyLocation>,
   ) -> Self {
     Self {
       dependencies,
       presentational_dependencies,
+      build_info,
       build_meta,
+      warning_diagnostics,
       ignored,
     }
   }
@@ -47,64 +56,149 @@ impl<'a> HotModuleReplacementScanner<'a> {
   pub fn collect_dependencies(
     &mut self,
     call_expr: &CallExpr,
+    api: &str,
     kind: &str,
     create_dependency: CreateDependency,
   ) {
     let mut dependencies: Vec<BoxDependency> = vec![];
 
     if let Some(first_arg) = call_expr.args.first() {
-      match &*first_arg.expr {
-        Expr::Lit(Lit::Str(s)) => {
+      let mut add_dependency = |expr: &Expr| {
+        if let Some((span, request)) = get_request(expr) {
           dependencies.push(create_dependency(
-            s.span.real_lo(),
-            s.span.real_hi(),
-            s.value.clone(),
-            Some(s.span.into()),
+            span.real_lo(),
+            span.real_hi(),
+            request,
+            Some(span.into()),
           ));
         }
-        Expr::Array(array_lit) => {
-          array_lit.elems.iter().for_each(|e| {
-            if let Some(expr) = e {
-              if let Expr::Lit(Lit::Str(s)) = &*expr.expr {
-                dependencies.push(create_dependency(
-                  s.span.real_lo(),
-                  s.span.real_hi(),
-                  s.value.clone(),
-                  Some(s.span.into()),
-                ));
-              }
-            }
-          });
-        }
-        _ => {}
+      };
+      if let Expr::Array(array_lit) = &*first_arg.expr {
+        array_lit
+          .elems
+          .iter()
+          .flatten()
+          .for_each(|e| add_dependency(&e.expr));
+      } else {
+        add_dependency(&first_arg.expr);
       }
     }
 
-    if self.build_meta.esm && kind == "accept" && !call_expr.args.is_empty() {
+    // Only the dependency form, e.g. `accept(["./a", "./b"], callback, errorHandler)`, refreshes
+    // the imported bindings. `accept(callback, errorHandler)` accepts the module itself.
+    if self.build_meta.esm && kind == "accept" && !dependencies.is_empty() {
       let dependency_ids = dependencies.iter().map(|dep| *dep.id()).collect::<Vec<_>>();
-      if let Some(callback_arg) = call_expr.args.get(1) {
-        self
+      match call_expr.args.get(1) {
+        // `accept("./a", undefined, errorHandler)` only passes an error handler, the placeholder is
+        // replaced by the function refreshing the bindings.
+        Some(callback_arg) if is_nullish(&callback_arg.expr) => self
           .presentational_dependencies
           .push(Box::new(HarmonyAcceptDependency::new(
             callback_arg.span().real_lo(),
             callback_arg.span().real_hi(),
-            true,
-            dependency_ids,
-          )));
-      } else {
-        self
-          .presentational_dependencies
-          .push(Box::new(HarmonyAcceptDependency::new(
-            call_expr.span().real_hi() - 1,
-            0,
             false,
             dependency_ids,
-          )));
+          ))),
+        Some(callback_arg) => {
+          self
+            .presentational_dependencies
+            .push(Box::new(HarmonyAcceptDependency::new(
+              callback_arg.span().real_lo(),
+              callback_arg.span().real_hi(),
+              true,
+              dependency_ids,
+            )))
+        }
+        None => {
+          let end = call_expr.args[0].span().real_hi();
+          self
+            .presentational_dependencies
+            .push(Box::new(HarmonyAcceptDependency::new(
+              end,
+              end,
+              false,
+              dependency_ids,
+            )))
+        }
+      }
+    }
+
+    if kind == "accept" {
+      // The error handler follows the callback in the dependency form and comes first otherwise.
+      let is_dependency_form = call_expr.args.first().is_some_and(|arg| {
+        matches!(
+          *arg.expr,
+          Expr::Array(_) | Expr::Lit(Lit::Str(_)) | Expr::Tpl(_)
+        )
+      });
+      let error_handler_index = if is_dependency_form { 2 } else { 0 };
+      if let Some(error_handler) = call_expr.args.get(error_handler_index)
+        && !is_nullish(&error_handler.expr)
+        && is_not_callable(&error_handler.expr)
+      {
+        self.warning_diagnostics.push(
+          diagnostic!(
+            severity = Severity::Warning,
+            "{api}() ignores the error handler because it is not a function"
+          )
+          .boxed(),
+        );
       }
     }
 
     self.dependencies.extend(dependencies);
   }
+
+  /// Handlers passed to `dispose` and `addStatusHandler` are only called during an update, so a
+  /// value that is obviously not a function fails late and far from the call.
+  fn check_handler(&mut self, api: &str, handler: Option<&ExprOrSpread>) {
+    if handler.map_or(true, |handler| is_not_callable(&handler.expr)) {
+      self.warning_diagnostics.push(
+        diagnostic!(
+          severity = Severity::Warning,
+          "{api}() expects a function as handler, it would fail once the module is updated"
+        )
+        .boxed(),
+      );
+    }
+  }
+
+  fn check_invalidate(&mut self, api: &str, call_expr: &CallExpr) {
+    if !call_expr.args.is_empty() {
+      self.warning_diagnostics.push(
+        diagnostic!(
+          severity = Severity::Warning,
+          "{api}() doesn't take arguments, they are ignored"
+        )
+        .boxed(),
+      );
+    }
+  }
+}
+
+fn is_nullish(expr: &Expr) -> bool {
+  matches!(expr, Expr::Lit(Lit::Null(_)))
+    || matches!(expr, Expr::Ident(ident) if &ident.sym == "undefined")
+}
+
+fn is_not_callable(expr: &Expr) -> bool {
+  is_nullish(expr)
+    || matches!(
+      expr,
+      Expr::Lit(_) | Expr::Tpl(_) | Expr::Array(_) | Expr::Object(_)
+    )
+}
+
+/// Returns the request of a string literal or a template literal without expressions.
+fn get_request(expr: &Expr) -> Option<(Span, JsWord)> {
+  match expr {
+    Expr::Lit(Lit::Str(s)) => Some((s.span, s.value.clone())),
+    Expr::Tpl(tpl) if tpl.exprs.is_empty() => {
+      let quasi = tpl.quasis.first()?;
+      Some((tpl.span, quasi.cooked.clone()?))
+    }
+    _ => None,
+  }
 }
 
 impl<'a> Visit for HotModuleReplacementScanner<'a> {
@@ -113,6 +207,7 @@ impl<'a> Visit for HotModuleReplacementScanner<'a> {
 
   fn visit_expr(&mut self, expr: &Expr) {
     if expr_matcher::is_module_hot(expr) || expr_matcher::is_import_meta_webpack_hot(expr) {
+      self.build_info.module_concatenation_bailout = Some("Hot Module Replacement".to_string());
       self
         .presentational_dependencies
         .push(Box::new(ModuleArgumentDependency::new(
@@ -126,26 +221,73 @@ impl<'a> Visit for HotModuleReplacementScanner<'a> {
 
   fn visit_call_expr(&mut self, call_expr: &CallExpr) {
     if is_module_hot_accept_call(call_expr) {
-      self.collect_dependencies(call_expr, "accept", |start, end, request, span| {
-        Box::new(ModuleHotAcceptDependency::new(start, end, request, span))
-      });
+      self.collect_dependencies(
+        call_expr,
+        "module.hot.accept",
+        "accept",
+        |start, end, request, span| {
+          Box::new(ModuleHotAcceptDependency::new(start, end, request, span))
+        },
+      );
     } else if is_module_hot_decline_call(call_expr) {
-      self.collect_dependencies(call_expr, "decline", |start, end, request, span| {
-        Box::new(ModuleHotDeclineDependency::new(start, end, request, span))
-      });
+      self.collect_dependencies(
+        call_expr,
+        "module.hot.decline",
+        "decline",
+        |start, end, request, span| {
+          Box::new(ModuleHotDeclineDependency::new(start, end, request, span))
+        },
+      );
     } else if is_import_meta_hot_accept_call(call_expr) {
-      self.collect_dependencies(call_expr, "accept", |start, end, request, span| {
-        Box::new(ImportMetaHotAcceptDependency::new(
-          start, end, request, span,
-        ))
-      });
+      self.collect_dependencies(
+        call_expr,
+        "import.meta.webpackHot.accept",
+        "accept",
+        |start, end, request, span| {
+          Box::new(ImportMetaHotAcceptDependency::new(
+            start, end, request, span,
+          ))
+        },
+      );
     } else if is_import_meta_hot_decline_call(call_expr) {
-      self.collect_dependencies(call_expr, "decline", |start, end, request, span| {
-        Box::new(ImportMetaHotDeclineDependency::new(
-          start, end, request, span,
-        ))
-      });
+      self.collect_dependencies(
+        call_expr,
+        "import.meta.webpackHot.decline",
+        "decline",
+        |start, end, request, span| {
+          Box::new(ImportMetaHotDeclineDependency::new(
+            start, end, request, span,
+          ))
+        },
+      );
+    } else if let Some(callee) = call_expr.callee.as_expr() {
+      if expr_matcher::is_module_hot_invalidate(callee) {
+        self.check_invalidate("module.hot.invalidate", call_expr);
+      } else if expr_matcher::is_import_meta_webpack_hot_invalidate(callee) {
+        self.check_invalidate("import.meta.webpackHot.invalidate", call_expr);
+      } else if let Some(api) = get_handler_api(callee) {
+        self.check_handler(api, call_expr.args.first());
+      }
     }
     call_expr.visit_children_with(self);
   }
 }
+
+/// Returns the name of the hot API registering the handler passed as the first argument.
+fn get_handler_api(callee: &Expr) -> Option<&'static str> {
+  if expr_matcher::is_module_hot_dispose(callee) {
+    Some("module.hot.dispose")
+  } else if expr_matcher::is_module_hot_add_dispose_handler(callee) {
+    Some("module.hot.addDisposeHandler")
+  } else if expr_matcher::is_module_hot_add_status_handler(callee) {
+    Some("module.hot.addStatusHandler")
+  } else if expr_matcher::is_import_meta_webpack_hot_dispose(callee) {
+    Some("import.meta.webpackHot.dispose")
+  } else if expr_matcher::is_import_meta_webpack_hot_add_dispose_handler(callee) {
+    Some("import.meta.webpackHot.addDisposeHandler")
+  } else if expr_matcher::is_import_meta_webpack_hot_add_status_handler(callee) {
+    Some("import.meta.webpackHot.addStatusHandler")
+  } else {
+    None
+  }
+}
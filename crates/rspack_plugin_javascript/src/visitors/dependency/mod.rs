@@ -246,7 +246,9 @@ pub fn scan_dependencies(
     program.visit_with(&mut HotModuleReplacementScanner::new(
       &mut dependencies,
       &mut presentational_dependencies,
+      build_info,
       build_meta,
+      &mut warning_diagnostics,
       &mut ignored,
     ));
   }
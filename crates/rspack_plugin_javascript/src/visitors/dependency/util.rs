@@ -85,6 +85,10 @@ pub(crate) mod expr_matcher {
     is_require_resolve_weak: "require.resolveWeak",
     is_module_hot_accept: "module.hot.accept",
     is_module_hot_decline: "module.hot.decline",
+    is_module_hot_invalidate: "module.hot.invalidate",
+    is_module_hot_dispose: "module.hot.dispose",
+    is_module_hot_add_dispose_handler: "module.hot.addDisposeHandler",
+    is_module_hot_add_status_handler: "module.hot.addStatusHandler",
     is_module_hot: "module.hot",
     is_module_id: "module.id",
     is_module_loaded: "module.loaded",
@@ -95,6 +99,10 @@ pub(crate) mod expr_matcher {
     is_import_meta_webpack_hot: "import.meta.webpackHot",
     is_import_meta_webpack_hot_accept: "import.meta.webpackHot.accept",
     is_import_meta_webpack_hot_decline: "import.meta.webpackHot.decline",
+    is_import_meta_webpack_hot_invalidate: "import.meta.webpackHot.invalidate",
+    is_import_meta_webpack_hot_dispose: "import.meta.webpackHot.dispose",
+    is_import_meta_webpack_hot_add_dispose_handler: "import.meta.webpackHot.addDisposeHandler",
+    is_import_meta_webpack_hot_add_status_handler: "import.meta.webpackHot.addStatusHandler",
     is_import_meta_webpack_context: "import.meta.webpackContext",
     is_import_meta_url: "import.meta.url",
     is_import_meta: "import.meta",
@@ -235,6 +243,11 @@ fn test() {
   let expr = *member_expr!(DUMMY_SP, module.hot.accept);
   assert!(expr_matcher::is_module_hot_accept(&expr));
   assert!(!expr_matcher::is_module_hot_decline(&expr));
+  assert!(expr_matcher::is_module_hot_dispose(&*member_expr!(
+    DUMMY_SP,
+    module.hot.dispose
+  )));
+  assert!(!expr_matcher::is_module_hot_invalidate(&expr));
   assert!(is_module_hot_accept_call(&CallExpr {
     span: DUMMY_SP,
     callee: expr.as_callee(),
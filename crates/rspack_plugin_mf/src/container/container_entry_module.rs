@@ -46,6 +46,10 @@ impl ContainerEntryModule {
       share_scope,
     }
   }
+
+  pub fn exposes(&self) -> &[(String, ExposeOptions)] {
+    &self.exposes
+  }
 }
 
 impl Identifiable for ContainerEntryModule {
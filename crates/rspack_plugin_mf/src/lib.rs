@@ -2,12 +2,16 @@
 #![feature(hash_raw_entry)]
 
 mod container;
+mod manifest;
 mod sharing;
 
 pub use container::container_plugin::{ContainerPlugin, ContainerPluginOptions, ExposeOptions};
 pub use container::container_reference_plugin::{
   ContainerReferencePlugin, ContainerReferencePluginOptions, RemoteOptions,
 };
+pub use manifest::manifest_plugin::{
+  ModuleFederationManifestPlugin, ModuleFederationManifestPluginOptions,
+};
 pub use sharing::consume_shared_plugin::{
   ConsumeOptions, ConsumeSharedPlugin, ConsumeSharedPluginOptions, ConsumeVersion,
 };
@@ -0,0 +1,113 @@
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Manifest {
+  pub id: String,
+  pub name: String,
+  pub meta_data: ManifestMetaData,
+  pub shared: Vec<ManifestShared>,
+  pub remotes: Vec<ManifestRemote>,
+  pub exposes: Vec<ManifestExpose>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestMetaData {
+  pub name: String,
+  pub global_name: Option<String>,
+  pub public_path: String,
+  /// `None` when the build has no container.
+  pub remote_entry: Option<ManifestRemoteEntry>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestRemoteEntry {
+  pub name: String,
+  #[serde(rename = "type")]
+  pub library_type: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestShared {
+  pub id: String,
+  pub name: String,
+  pub share_scope: String,
+  /// The provided version, `None` when the module is only consumed.
+  pub version: Option<String>,
+  pub required_version: Option<String>,
+  pub singleton: bool,
+  pub strict_version: bool,
+  pub eager: bool,
+  pub assets: ManifestAssets,
+}
+
+impl ManifestShared {
+  pub fn new(container_name: &str, share_scope: &str, share_key: &str) -> Self {
+    Self {
+      id: format!("{container_name}:{share_key}"),
+      name: share_key.to_string(),
+      share_scope: share_scope.to_string(),
+      version: None,
+      required_version: None,
+      singleton: false,
+      strict_version: false,
+      eager: false,
+      assets: Default::default(),
+    }
+  }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestRemote {
+  pub alias: String,
+  pub federation_container_name: Option<String>,
+  pub entry: Option<String>,
+  pub external_type: String,
+  pub share_scope: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestExpose {
+  pub id: String,
+  pub name: String,
+  pub path: String,
+  pub import: Vec<String>,
+  pub assets: ManifestAssets,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ManifestAssets {
+  pub js: ManifestAssetFiles,
+  pub css: ManifestAssetFiles,
+}
+
+impl ManifestAssets {
+  pub fn extend(&mut self, other: ManifestAssets) {
+    self.js.extend(other.js);
+    self.css.extend(other.css);
+  }
+}
+
+/// `sync` files are needed to load the module, `async` files are loaded on demand by it.
+#[derive(Debug, Default, Serialize)]
+pub struct ManifestAssetFiles {
+  pub sync: Vec<String>,
+  #[serde(rename = "async")]
+  pub async_files: Vec<String>,
+}
+
+impl ManifestAssetFiles {
+  pub fn extend(&mut self, other: ManifestAssetFiles) {
+    self.sync.extend(other.sync);
+    self.sync.sort();
+    self.sync.dedup();
+    self.async_files.extend(other.async_files);
+    self.async_files.sort();
+    self.async_files.dedup();
+  }
+}
@@ -0,0 +1,302 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rspack_core::{
+  extract_url_and_global,
+  rspack_sources::{RawSource, SourceExt},
+  BoxModule, ChunkUkey, Compilation, CompilationAsset, ExternalType, LibraryName,
+  LibraryNonUmdObject, Plugin, PluginContext, PluginProcessAssetsOutput, ProcessAssetsArgs,
+  PublicPath,
+};
+use rspack_error::error;
+use rustc_hash::FxHashSet as HashSet;
+
+use super::data::{
+  Manifest, ManifestAssets, ManifestExpose, ManifestMetaData, ManifestRemote, ManifestRemoteEntry,
+  ManifestShared,
+};
+use crate::{
+  container::container_entry_module::ContainerEntryModule,
+  sharing::{
+    consume_shared_module::ConsumeSharedModule, provide_shared_module::ProvideSharedModule,
+  },
+  ConsumeVersion, ProvideVersion, RemoteOptions,
+};
+
+static EXTERNAL_TYPE_PREFIX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^[a-z0-9-]+ ").expect("Invalid regex"));
+
+#[derive(Debug)]
+pub struct ModuleFederationManifestPluginOptions {
+  pub name: String,
+  pub filename: String,
+  pub remote_type: ExternalType,
+  pub remotes: Vec<(String, RemoteOptions)>,
+}
+
+/// Emits a manifest of the container, the remotes and the shared modules of the build, with the
+/// files to load for each expose and shared module.
+///
+/// Exposes and shared modules are read from the module graph, so only the shared modules which are
+/// provided or consumed end up in the manifest. Remotes are listed as configured.
+#[derive(Debug)]
+pub struct ModuleFederationManifestPlugin {
+  options: ModuleFederationManifestPluginOptions,
+}
+
+impl ModuleFederationManifestPlugin {
+  pub fn new(options: ModuleFederationManifestPluginOptions) -> Self {
+    Self { options }
+  }
+
+  fn meta_data(&self, compilation: &Compilation) -> ManifestMetaData {
+    let name = &self.options.name;
+    let library = compilation
+      .entries
+      .get(name)
+      .and_then(|entry| entry.options.library.as_ref());
+    let global_name = library
+      .and_then(|library| library.name.as_ref())
+      .and_then(|library_name| match library_name {
+        LibraryName::NonUmdObject(LibraryNonUmdObject::String(name)) => Some(name.clone()),
+        _ => None,
+      });
+    let remote_entry =
+      compilation
+        .entrypoints
+        .get(name)
+        .zip(library)
+        .and_then(|(entrypoint, library)| {
+          let entrypoint = compilation.chunk_group_by_ukey.expect_get(entrypoint);
+          let chunk = compilation
+            .chunk_by_ukey
+            .expect_get(&entrypoint.get_entry_point_chunk());
+          let mut files = chunk
+            .files
+            .iter()
+            .filter(|file| !is_css(file))
+            .collect::<Vec<_>>();
+          files.sort();
+          files.first().map(|file| ManifestRemoteEntry {
+            name: file.to_string(),
+            library_type: library.library_type.clone(),
+          })
+        });
+    ManifestMetaData {
+      name: name.clone(),
+      global_name,
+      public_path: match &compilation.options.output.public_path {
+        PublicPath::String(public_path) => public_path.clone(),
+        PublicPath::Auto => "auto".to_string(),
+      },
+      remote_entry,
+    }
+  }
+
+  fn exposes(&self, compilation: &Compilation) -> Vec<ManifestExpose> {
+    let module_graph = &compilation.module_graph;
+    let mut exposes = Vec::new();
+    for module in module_graph.modules().values() {
+      let Some(container) = module.downcast_ref::<ContainerEntryModule>() else {
+        continue;
+      };
+      // every expose is built into an async block in order
+      for ((key, options), block) in container.exposes().iter().zip(module.get_blocks()) {
+        let name = key.strip_prefix("./").unwrap_or(key);
+        let chunks = compilation
+          .chunk_graph
+          .get_block_chunk_group(block, &compilation.chunk_group_by_ukey)
+          .map(|chunk_group| chunk_group.chunks.iter().copied().collect())
+          .unwrap_or_default();
+        exposes.push(ManifestExpose {
+          id: format!("{}:{name}", self.options.name),
+          name: name.to_string(),
+          path: key.clone(),
+          import: options.import.clone(),
+          assets: get_assets(compilation, chunks),
+        });
+      }
+    }
+    exposes.sort_by(|a, b| a.id.cmp(&b.id));
+    exposes
+  }
+
+  fn shared(&self, compilation: &Compilation) -> Vec<ManifestShared> {
+    let module_graph = &compilation.module_graph;
+    let mut shared: BTreeMap<(String, String), ManifestShared> = BTreeMap::new();
+    for module in module_graph.modules().values() {
+      if let Some(provide) = module.downcast_ref::<ProvideSharedModule>() {
+        let shared = shared
+          .entry((
+            provide.share_scope().to_string(),
+            provide.share_key().to_string(),
+          ))
+          .or_insert_with(|| {
+            ManifestShared::new(
+              &self.options.name,
+              provide.share_scope(),
+              provide.share_key(),
+            )
+          });
+        if let ProvideVersion::Version(version) = provide.version() {
+          shared.version = Some(version.clone());
+        }
+        shared.eager |= provide.eager();
+        shared.assets.extend(get_assets(
+          compilation,
+          get_module_chunks(compilation, module),
+        ));
+      } else if let Some(consume) = module.downcast_ref::<ConsumeSharedModule>() {
+        let options = consume.options();
+        let shared = shared
+          .entry((options.share_scope.clone(), options.share_key.clone()))
+          .or_insert_with(|| {
+            ManifestShared::new(&self.options.name, &options.share_scope, &options.share_key)
+          });
+        if let Some(ConsumeVersion::Version(version)) = &options.required_version {
+          shared.required_version = Some(version.clone());
+        }
+        shared.singleton |= options.singleton;
+        shared.strict_version |= options.strict_version;
+        shared.eager |= options.eager;
+        shared.assets.extend(get_assets(
+          compilation,
+          get_module_chunks(compilation, module),
+        ));
+      }
+    }
+    shared.into_values().collect()
+  }
+
+  fn remotes(&self) -> Vec<ManifestRemote> {
+    let mut remotes = Vec::new();
+    for (alias, options) in &self.options.remotes {
+      for external in &options.external {
+        // internal fallbacks are modules of this build
+        if external.starts_with("internal ") {
+          continue;
+        }
+        let (external_type, request) = match EXTERNAL_TYPE_PREFIX.find(external) {
+          Some(prefix) => (prefix.as_str().trim_end(), &external[prefix.end()..]),
+          None => (self.options.remote_type.as_str(), external.as_str()),
+        };
+        let url_and_global = (external_type == "script")
+          .then(|| extract_url_and_global(request).ok())
+          .flatten();
+        remotes.push(ManifestRemote {
+          alias: alias.clone(),
+          federation_container_name: url_and_global.as_ref().map(|u| u.global.to_string()),
+          entry: url_and_global.as_ref().map(|u| u.url.to_string()),
+          external_type: external_type.to_string(),
+          share_scope: options.share_scope.clone(),
+        });
+      }
+    }
+    remotes
+  }
+}
+
+#[async_trait]
+impl Plugin for ModuleFederationManifestPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.ModuleFederationManifestPlugin"
+  }
+
+  async fn process_assets_stage_report(
+    &self,
+    _ctx: PluginContext,
+    args: ProcessAssetsArgs<'_>,
+  ) -> PluginProcessAssetsOutput {
+    let compilation = args.compilation;
+    let manifest = Manifest {
+      id: self.options.name.clone(),
+      name: self.options.name.clone(),
+      meta_data: self.meta_data(compilation),
+      shared: self.shared(compilation),
+      remotes: self.remotes(),
+      exposes: self.exposes(compilation),
+    };
+    let source = serde_json::to_string_pretty(&manifest).map_err(|e| error!(e.to_string()))?;
+    compilation.emit_asset(
+      self.options.filename.clone(),
+      CompilationAsset::from(RawSource::from(source).boxed()),
+    );
+    Ok(())
+  }
+}
+
+/// Chunks to load for a shared module: the chunks of its async block, or the chunks of the modules
+/// it depends on when it's eager.
+fn get_module_chunks(compilation: &Compilation, module: &BoxModule) -> HashSet<ChunkUkey> {
+  let chunk_graph = &compilation.chunk_graph;
+  let module_graph = &compilation.module_graph;
+  let block_chunks = module.get_blocks().iter().flat_map(|block| {
+    chunk_graph
+      .get_block_chunk_group(block, &compilation.chunk_group_by_ukey)
+      .map(|chunk_group| chunk_group.chunks.clone())
+      .unwrap_or_default()
+  });
+  let dependency_chunks = module
+    .get_dependencies()
+    .iter()
+    .filter_map(|dependency| module_graph.module_identifier_by_dependency_id(dependency))
+    .flat_map(|module| chunk_graph.get_module_chunks(*module).iter().copied());
+  block_chunks.chain(dependency_chunks).collect()
+}
+
+fn get_assets(compilation: &Compilation, chunks: HashSet<ChunkUkey>) -> ManifestAssets {
+  let mut async_chunks: HashSet<ChunkUkey> = HashSet::default();
+  for chunk in chunks.iter() {
+    for module in compilation
+      .chunk_graph
+      .get_chunk_modules(chunk, &compilation.module_graph)
+    {
+      for block in module.get_blocks() {
+        if let Some(chunk_group) = compilation
+          .chunk_graph
+          .get_block_chunk_group(block, &compilation.chunk_group_by_ukey)
+        {
+          async_chunks.extend(
+            chunk_group
+              .chunks
+              .iter()
+              .filter(|chunk| !chunks.contains(chunk)),
+          );
+        }
+      }
+    }
+  }
+
+  let mut assets = ManifestAssets::default();
+  let files = |chunks: &HashSet<ChunkUkey>| {
+    let mut files = chunks
+      .iter()
+      .flat_map(|chunk| compilation.chunk_by_ukey.expect_get(chunk).files.iter())
+      .cloned()
+      .collect::<Vec<_>>();
+    files.sort();
+    files.dedup();
+    files
+  };
+  for file in files(&chunks) {
+    if is_css(&file) {
+      assets.css.sync.push(file);
+    } else {
+      assets.js.sync.push(file);
+    }
+  }
+  for file in files(&async_chunks) {
+    if is_css(&file) {
+      assets.css.async_files.push(file);
+    } else {
+      assets.js.async_files.push(file);
+    }
+  }
+  assets
+}
+
+fn is_css(file: &str) -> bool {
+  file.ends_with(".css")
+}
@@ -70,6 +70,10 @@ impl ConsumeSharedModule {
       options,
     }
   }
+
+  pub fn options(&self) -> &ConsumeOptions {
+    &self.options
+  }
 }
 
 impl Identifiable for ConsumeSharedModule {
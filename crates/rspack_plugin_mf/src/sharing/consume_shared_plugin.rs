@@ -1,24 +1,28 @@
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use std::sync::Mutex;
 use std::{fmt, path::Path, sync::Arc};
 
 use async_trait::async_trait;
+use itertools::Itertools;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rspack_core::{
-  AdditionalChunkRuntimeRequirementsArgs, Compilation, CompilationParams, Context,
-  DependencyCategory, DependencyType, FactorizeArgs, ModuleExt, ModuleFactoryResult,
-  NormalModuleCreateData, Plugin, PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext,
-  PluginFactorizeHookOutput, PluginNormalModuleFactoryCreateModuleHookOutput,
-  PluginThisCompilationHookOutput, ResolveOptionsWithDependencyType, ResolveResult, Resolver,
-  RuntimeGlobals, ThisCompilationArgs,
+  contextify, AdditionalChunkRuntimeRequirementsArgs, BoxModule, Compilation, CompilationParams,
+  Context, DependenciesBlock, DependencyCategory, DependencyType, FactorizeArgs, ModuleExt,
+  ModuleFactoryResult, NormalModule, NormalModuleCreateData, Plugin,
+  PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, PluginFactorizeHookOutput,
+  PluginNormalModuleFactoryCreateModuleHookOutput, PluginThisCompilationHookOutput,
+  ResolveOptionsWithDependencyType, ResolveResult, Resolver, RuntimeGlobals, ThisCompilationArgs,
 };
-use rspack_error::{error, Diagnostic};
+use rspack_error::{error, Diagnostic, Result};
 use rustc_hash::FxHashMap;
 
 use super::{
   consume_shared_module::ConsumeSharedModule,
   consume_shared_runtime_module::ConsumeSharedRuntimeModule,
+  provide_shared_module::ProvideSharedModule, provide_shared_plugin::ProvideVersion,
+  semver::satisfy,
 };
 
 #[derive(Debug, Clone)]
@@ -134,6 +138,22 @@ fn get_required_version_from_description_file(
     .or_else(|| get_version_from_dependencies("devDependencies"))
 }
 
+/// The module the fallback of a consume shared module resolves to.
+fn get_fallback<'a>(compilation: &'a Compilation, module: &BoxModule) -> Option<&'a NormalModule> {
+  let module_graph = &compilation.module_graph;
+  let block_dependencies = module
+    .get_blocks()
+    .iter()
+    .filter_map(|block| module_graph.block_by_id(block))
+    .flat_map(|block| block.get_dependencies());
+  module
+    .get_dependencies()
+    .iter()
+    .chain(block_dependencies)
+    .filter_map(|dependency| module_graph.get_module(dependency))
+    .find_map(|module| module.as_normal_module())
+}
+
 #[derive(Debug)]
 pub struct ConsumeSharedPluginOptions {
   pub consumes: Vec<(String, Arc<ConsumeOptions>)>,
@@ -251,6 +271,114 @@ impl ConsumeSharedPlugin {
     }
   }
 
+  /// Whether the consume shared module is created from the config of this plugin.
+  fn is_consumed_by_self(&self, options: &ConsumeOptions) -> bool {
+    self.options.consumes.iter().any(|(request, config)| {
+      config.share_scope == options.share_scope
+        && if request.ends_with('/') {
+          options.share_key.starts_with(&config.share_key)
+        } else {
+          options.share_key == config.share_key
+        }
+    })
+  }
+
+  /// Check the required versions of the consumed modules against the versions provided in this
+  /// compilation and the versions in the description files of their fallbacks, mismatches are
+  /// only found at runtime otherwise.
+  fn check_required_versions(&self, compilation: &Compilation) -> Vec<Diagnostic> {
+    let module_graph = &compilation.module_graph;
+    let context = &compilation.options.context;
+    let mut provided_versions: FxHashMap<(&str, &str), Vec<(&str, String)>> = FxHashMap::default();
+    for module in module_graph.modules().values() {
+      if let Some(provide) = module.downcast_ref::<ProvideSharedModule>()
+        && let ProvideVersion::Version(version) = provide.version()
+      {
+        provided_versions
+          .entry((provide.share_scope(), provide.share_key()))
+          .or_default()
+          .push((version, contextify(context, provide.request())));
+      }
+    }
+
+    let mut diagnostics = vec![];
+    for module in module_graph.modules().values() {
+      let Some(consume) = module.downcast_ref::<ConsumeSharedModule>() else {
+        continue;
+      };
+      let options = consume.options();
+      let Some(ConsumeVersion::Version(required_version)) = &options.required_version else {
+        continue;
+      };
+      if !self.is_consumed_by_self(options) {
+        continue;
+      }
+
+      let fallback_description = get_fallback(compilation, module).and_then(|fallback| {
+        fallback
+          .resource_resolved_data()
+          .resource_description
+          .as_ref()
+      });
+      let fallback_version = fallback_description.and_then(|description| {
+        let version = description.json().get("version")?.as_str()?;
+        Some((
+          version,
+          contextify(context, &description.path().to_string_lossy()),
+        ))
+      });
+      let candidates = provided_versions
+        .get(&(options.share_scope.as_str(), options.share_key.as_str()))
+        .into_iter()
+        .flatten()
+        .map(|(version, from)| (*version, from.clone()))
+        .chain(fallback_version);
+
+      // the same version is only reported once, even if it is provided by its fallback
+      let mut unsatisfied_versions: BTreeMap<&str, String> = BTreeMap::new();
+      let mut has_satisfied_version = false;
+      for (version, from) in candidates {
+        match satisfy(required_version, version) {
+          Some(false) => {
+            unsatisfied_versions.entry(version).or_insert(from);
+          }
+          Some(true) => has_satisfied_version = true,
+          None => {}
+        }
+      }
+      // the runtime picks a satisfying version, unless only one version can be used
+      if unsatisfied_versions.is_empty()
+        || (has_satisfied_version && !options.singleton && !options.strict_version)
+      {
+        continue;
+      }
+
+      let requesters = module_graph
+        .get_incoming_connections(module)
+        .into_iter()
+        .filter_map(|connection| connection.original_module_identifier)
+        .filter_map(|identifier| module_graph.module_by_identifier(&identifier))
+        .map(|requester| requester.readable_identifier(context).into_owned())
+        .sorted()
+        .dedup()
+        .join(", ");
+      for (version, from) in unsatisfied_versions {
+        let message = format!(
+          "Unsatisfied version {version} from {from} of shared{} module {} (required {required_version}) in {requesters}",
+          if options.singleton { " singleton" } else { "" },
+          options.share_key,
+        );
+        let diagnostic = if options.strict_version {
+          Diagnostic::error(self.name().into(), message)
+        } else {
+          Diagnostic::warn(self.name().into(), message)
+        };
+        diagnostics.push(diagnostic.with_module_identifier(Some(module.identifier())));
+      }
+    }
+    diagnostics
+  }
+
   async fn create_consume_shared_module(
     &self,
     context: &Context,
@@ -403,6 +531,12 @@ impl Plugin for ConsumeSharedPlugin {
     Ok(None)
   }
 
+  async fn finish_modules(&self, compilation: &mut Compilation) -> Result<()> {
+    let diagnostics = self.check_required_versions(compilation);
+    compilation.push_batch_diagnostic(diagnostics);
+    Ok(())
+  }
+
   fn additional_tree_runtime_requirements(
     &self,
     _ctx: PluginContext,
@@ -7,5 +7,6 @@ pub mod provide_shared_dependency;
 pub mod provide_shared_module;
 pub mod provide_shared_module_factory;
 pub mod provide_shared_plugin;
+pub mod semver;
 pub mod share_runtime_module;
 pub mod share_runtime_plugin;
@@ -58,6 +58,26 @@ impl ProvideSharedModule {
       eager,
     }
   }
+
+  pub fn share_scope(&self) -> &str {
+    &self.share_scope
+  }
+
+  pub fn share_key(&self) -> &str {
+    &self.name
+  }
+
+  pub fn version(&self) -> &ProvideVersion {
+    &self.version
+  }
+
+  pub fn eager(&self) -> bool {
+    self.eager
+  }
+
+  pub fn request(&self) -> &str {
+    &self.request
+  }
 }
 
 impl Identifiable for ProvideSharedModule {
@@ -0,0 +1,363 @@
+//! Matching versions against npm version ranges, see https://docs.npmjs.com/cli/v6/using-npm/semver#ranges
+
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Identifier {
+  Numeric(u64),
+  Alphanumeric(String),
+}
+
+impl PartialOrd for Identifier {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for Identifier {
+  fn cmp(&self, other: &Self) -> Ordering {
+    match (self, other) {
+      (Identifier::Numeric(a), Identifier::Numeric(b)) => a.cmp(b),
+      (Identifier::Numeric(_), Identifier::Alphanumeric(_)) => Ordering::Less,
+      (Identifier::Alphanumeric(_), Identifier::Numeric(_)) => Ordering::Greater,
+      (Identifier::Alphanumeric(a), Identifier::Alphanumeric(b)) => a.cmp(b),
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Version {
+  major: u64,
+  minor: u64,
+  patch: u64,
+  pre: Vec<Identifier>,
+}
+
+impl Version {
+  fn new(major: u64, minor: u64, patch: u64) -> Self {
+    Self {
+      major,
+      minor,
+      patch,
+      pre: vec![],
+    }
+  }
+
+  fn parse(version: &str) -> Option<Self> {
+    let partial = Partial::parse(version)?;
+    Some(Self {
+      major: partial.major?,
+      minor: partial.minor?,
+      patch: partial.patch?,
+      pre: partial.pre,
+    })
+  }
+
+  fn same_release(&self, other: &Version) -> bool {
+    (self.major, self.minor, self.patch) == (other.major, other.minor, other.patch)
+  }
+}
+
+impl PartialOrd for Version {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for Version {
+  fn cmp(&self, other: &Self) -> Ordering {
+    (self.major, self.minor, self.patch)
+      .cmp(&(other.major, other.minor, other.patch))
+      .then_with(|| match (self.pre.is_empty(), other.pre.is_empty()) {
+        (true, true) => Ordering::Equal,
+        // a pre-release version has lower precedence than the release
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => self.pre.cmp(&other.pre),
+      })
+  }
+}
+
+/// A version with missing or wildcard (`x`, `X`, `*`) parts, e.g. `1.2` or `1.x`.
+#[derive(Debug)]
+struct Partial {
+  major: Option<u64>,
+  minor: Option<u64>,
+  patch: Option<u64>,
+  pre: Vec<Identifier>,
+}
+
+impl Partial {
+  fn parse(partial: &str) -> Option<Self> {
+    let partial = partial.trim();
+    let partial = partial
+      .strip_prefix(['v', '='])
+      .unwrap_or(partial)
+      .trim_start();
+    // build metadata is ignored in comparisons
+    let partial = partial
+      .split_once('+')
+      .map_or(partial, |(partial, _)| partial);
+    let (release, pre) = match partial.split_once('-') {
+      Some((release, pre)) => (release, Some(pre)),
+      None => (partial, None),
+    };
+    let mut parts = [None; 3];
+    if !release.is_empty() {
+      let mut wildcard = false;
+      for (index, part) in release.split('.').enumerate() {
+        if index >= parts.len() {
+          return None;
+        }
+        if matches!(part, "x" | "X" | "*") {
+          wildcard = true;
+        } else if wildcard {
+          // parts after a wildcard have to be wildcards too
+          return None;
+        } else {
+          parts[index] = Some(part.parse().ok()?);
+        }
+      }
+    }
+    let pre = match pre {
+      Some(pre) => pre
+        .split('.')
+        .map(|identifier| match identifier.parse() {
+          Ok(number) => Identifier::Numeric(number),
+          Err(_) => Identifier::Alphanumeric(identifier.to_string()),
+        })
+        .collect(),
+      None => vec![],
+    };
+    let [major, minor, patch] = parts;
+    Some(Self {
+      major,
+      minor: major.and(minor),
+      patch: major.and(minor).and(patch),
+      pre,
+    })
+  }
+
+  /// The lowest version matched by the partial.
+  fn floor(&self) -> Version {
+    Version {
+      major: self.major.unwrap_or(0),
+      minor: self.minor.unwrap_or(0),
+      patch: self.patch.unwrap_or(0),
+      pre: self.pre.clone(),
+    }
+  }
+
+  /// The lowest version above all versions matched by the partial, `None` when there is none.
+  fn ceil(&self) -> Option<Version> {
+    match (self.major, self.minor, self.patch) {
+      (None, _, _) => None,
+      (Some(major), None, _) => Some(Version::new(major + 1, 0, 0)),
+      (Some(major), Some(minor), None) => Some(Version::new(major, minor + 1, 0)),
+      (Some(_), Some(_), Some(_)) => None,
+    }
+  }
+
+  fn is_full(&self) -> bool {
+    self.patch.is_some()
+  }
+}
+
+#[derive(Debug)]
+enum Comparator {
+  Greater(Version),
+  GreaterOrEqual(Version),
+  Less(Version),
+  LessOrEqual(Version),
+  Equal(Version),
+}
+
+impl Comparator {
+  fn version(&self) -> &Version {
+    match self {
+      Comparator::Greater(v)
+      | Comparator::GreaterOrEqual(v)
+      | Comparator::Less(v)
+      | Comparator::LessOrEqual(v)
+      | Comparator::Equal(v) => v,
+    }
+  }
+
+  fn matches(&self, version: &Version) -> bool {
+    match self {
+      Comparator::Greater(v) => version > v,
+      Comparator::GreaterOrEqual(v) => version >= v,
+      Comparator::Less(v) => version < v,
+      Comparator::LessOrEqual(v) => version <= v,
+      Comparator::Equal(v) => version == v,
+    }
+  }
+}
+
+/// Comparators which all have to match.
+fn parse_comparators(range: &str) -> Option<Vec<Comparator>> {
+  let range = range.trim();
+  if let Some((from, to)) = range.split_once(" - ") {
+    let from = Partial::parse(from)?;
+    let to = Partial::parse(to)?;
+    let mut comparators = vec![Comparator::GreaterOrEqual(from.floor())];
+    if to.is_full() {
+      comparators.push(Comparator::LessOrEqual(to.floor()));
+    } else if let Some(ceil) = to.ceil() {
+      comparators.push(Comparator::Less(ceil));
+    }
+    return Some(comparators);
+  }
+
+  let mut comparators = vec![];
+  let mut tokens = range.split_whitespace();
+  while let Some(token) = tokens.next() {
+    // operators may be separated from their versions by spaces
+    let mut token = token.to_string();
+    if matches!(token.as_str(), "^" | "~" | "<" | "<=" | ">" | ">=" | "=") {
+      token.push_str(tokens.next()?);
+    }
+    comparators.extend(parse_comparator(&token)?);
+  }
+  Some(comparators)
+}
+
+fn parse_comparator(comparator: &str) -> Option<Vec<Comparator>> {
+  let operator_end = comparator
+    .find(|c| !matches!(c, '^' | '~' | '<' | '>' | '='))
+    .unwrap_or(comparator.len());
+  let (operator, partial) = comparator.split_at(operator_end);
+  let partial = Partial::parse(partial)?;
+  let floor = partial.floor();
+  let comparators = match operator {
+    "" | "=" => {
+      if partial.is_full() {
+        vec![Comparator::Equal(floor)]
+      } else {
+        x_range(&partial)
+      }
+    }
+    ">=" => vec![Comparator::GreaterOrEqual(floor)],
+    "<" => vec![Comparator::Less(floor)],
+    ">" => match partial.ceil() {
+      Some(ceil) => vec![Comparator::GreaterOrEqual(ceil)],
+      None if partial.is_full() => vec![Comparator::Greater(floor)],
+      // nothing is greater than `*`
+      None => vec![Comparator::Less(Version::new(0, 0, 0))],
+    },
+    "<=" => match partial.ceil() {
+      Some(ceil) => vec![Comparator::Less(ceil)],
+      None if partial.is_full() => vec![Comparator::LessOrEqual(floor)],
+      None => vec![],
+    },
+    "~" | "~>" => {
+      let ceil = match (partial.major, partial.minor) {
+        (None, _) => return Some(vec![]),
+        (Some(major), None) => Version::new(major + 1, 0, 0),
+        (Some(major), Some(minor)) => Version::new(major, minor + 1, 0),
+      };
+      vec![Comparator::GreaterOrEqual(floor), Comparator::Less(ceil)]
+    }
+    "^" => {
+      let ceil = match (partial.major, partial.minor, partial.patch) {
+        (None, _, _) => return Some(vec![]),
+        (Some(0), Some(0), Some(patch)) => Version::new(0, 0, patch + 1),
+        (Some(0), Some(minor), _) => Version::new(0, minor + 1, 0),
+        (Some(major), _, _) => Version::new(major + 1, 0, 0),
+      };
+      vec![Comparator::GreaterOrEqual(floor), Comparator::Less(ceil)]
+    }
+    _ => return None,
+  };
+  Some(comparators)
+}
+
+fn x_range(partial: &Partial) -> Vec<Comparator> {
+  match partial.ceil() {
+    Some(ceil) => vec![
+      Comparator::GreaterOrEqual(partial.floor()),
+      Comparator::Less(ceil),
+    ],
+    None => vec![],
+  }
+}
+
+/// Whether `version` is in `range`, `None` when either of them is not valid, e.g. a git url or a
+/// dist tag like `latest`.
+pub fn satisfy(range: &str, version: &str) -> Option<bool> {
+  let version = Version::parse(version)?;
+  let mut satisfied = false;
+  for range in range.split("||") {
+    let comparators = parse_comparators(range)?;
+    satisfied |= comparators
+      .iter()
+      .all(|comparator| comparator.matches(&version))
+      // a pre-release version only matches comparators with pre-releases of the same release
+      && (version.pre.is_empty()
+        || comparators.iter().any(|comparator| {
+          let v = comparator.version();
+          !v.pre.is_empty() && v.same_release(&version)
+        }));
+  }
+  Some(satisfied)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn should_satisfy_npm_ranges() {
+    let cases = [
+      ("*", "1.2.3", true),
+      ("", "1.2.3", true),
+      ("1.x", "1.9.0", true),
+      ("1.x", "2.0.0", false),
+      ("1.2", "1.2.9", true),
+      ("1.2", "1.3.0", false),
+      ("1.2.3", "1.2.3", true),
+      ("=1.2.3", "1.2.4", false),
+      ("v1.2.3", "1.2.3", true),
+      ("^18.2.0", "18.3.1", true),
+      ("^18.2.0", "17.0.2", false),
+      ("^18.2.0", "19.0.0", false),
+      ("^0.2.3", "0.2.9", true),
+      ("^0.2.3", "0.3.0", false),
+      ("^0.0.3", "0.0.4", false),
+      ("^1.2", "1.9.9", true),
+      ("~1.2.3", "1.2.9", true),
+      ("~1.2.3", "1.3.0", false),
+      ("~1", "1.9.0", true),
+      (">=1.2.3 <2.0.0", "1.5.0", true),
+      (">=1.2.3 <2.0.0", "2.0.0", false),
+      (">= 1.2.3", "1.2.3", true),
+      (">1.2", "1.2.9", false),
+      (">1.2", "1.3.0", true),
+      ("<=1.2", "1.2.9", true),
+      ("<1.2", "1.2.0", false),
+      ("1.2.3 - 2.3", "2.3.9", true),
+      ("1.2.3 - 2.3", "2.4.0", false),
+      ("1.2.3 - 2.3.4", "2.3.4", true),
+      ("^16.0.0 || ^17.0.0", "17.0.2", true),
+      ("^16.0.0 || ^17.0.0", "18.2.0", false),
+      ("^1.2.3-beta.2", "1.2.3-beta.4", true),
+      ("^1.2.3-beta.2", "1.2.4-beta.1", false),
+      ("^1.2.3", "1.2.4-beta.1", false),
+      ("^1.2.3", "1.5.0+build.1", true),
+    ];
+    for (range, version, expected) in cases {
+      assert_eq!(
+        satisfy(range, version),
+        Some(expected),
+        "{version} in {range}"
+      );
+    }
+  }
+
+  #[test]
+  fn should_skip_invalid_ranges_and_versions() {
+    assert_eq!(satisfy("latest", "1.2.3"), None);
+    assert_eq!(satisfy("github:facebook/react", "1.2.3"), None);
+    assert_eq!(satisfy("^1.2.3", "1.2"), None);
+  }
+}
@@ -23,8 +23,12 @@ impl RuntimeModule for GlobalRuntimeModule {
     self.id
   }
 
-  fn generate(&self, _compilation: &Compilation) -> BoxSource {
-    RawSource::from(include_str!("runtime/global.js")).boxed()
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    if compilation.options.target.properties.global_this == Some(true) {
+      RawSource::from("__webpack_require__.g = globalThis;").boxed()
+    } else {
+      RawSource::from(include_str!("runtime/global.js")).boxed()
+    }
   }
 }
 
@@ -1,25 +1,24 @@
-var oldTags = [];
-var newTags = [];
+// The stylesheets of the updated chunks, keyed by chunk id. The new `<link>` is loaded
+// disabled and swapped with the old one of the same chunk when the update is applied.
+var oldTags = {};
+var newTags = {};
 var applyHandler = function (options) {
 	return {
-		dispose: function () {},
+		dispose: function () {
+			Object.keys(oldTags).forEach(function (chunkId) {
+				var oldTag = oldTags[chunkId];
+				if (oldTag.parentNode) oldTag.parentNode.removeChild(oldTag);
+			});
+			oldTags = {};
+		},
 		apply: function () {
-			var moduleIds = [];
-			newTags.forEach(function (info) {
-				info[1].sheet.disabled = false;
+			Object.keys(newTags).forEach(function (chunkId) {
+				newTags[chunkId].sheet.disabled = false;
 			});
-			while (oldTags.length) {
-				var oldTag = oldTags.pop();
-				if (oldTag.parentNode) oldTag.parentNode.removeChild(oldTag);
-			}
-			while (newTags.length) {
-				var info = newTags.pop();
-				// var chunkModuleIds = loadCssChunkData(__webpack_require__.m, info[1], info[0]);
-				// chunkModuleIds.forEach(function(id) {
-				//     moduleIds.push(id)
-				// });
-			}
-			return moduleIds;
+			newTags = {};
+			// The js part of css modules is shipped with the js hot update chunk and
+			// accepts itself, so swapping the stylesheets doesn't add modules to apply.
+			return [];
 		}
 	};
 };
@@ -37,9 +36,19 @@ __webpack_require__.hmrC.css = function (
 	updatedModulesList
 ) {
 	applyHandlers.push(applyHandler);
+	removedChunks.forEach(function (chunkId) {
+		var oldTag = loadStylesheet(chunkId, __webpack_require__.p + __webpack_require__.k(chunkId));
+		if (oldTag) oldTags[chunkId] = oldTag;
+	});
 	chunkIds.forEach(function (chunkId) {
 		var filename = __webpack_require__.k(chunkId);
 		var url = __webpack_require__.p + filename;
+		// A link of an update which was never applied is replaced by this one
+		var pendingTag = newTags[chunkId];
+		if (pendingTag) {
+			if (pendingTag.parentNode) pendingTag.parentNode.removeChild(pendingTag);
+			delete newTags[chunkId];
+		}
 		var oldTag = loadStylesheet(chunkId, url);
 		if (!oldTag) return;
 		promises.push(
@@ -71,14 +80,9 @@ __webpack_require__.hmrC.css = function (
 									return resolve();
 								}
 							} catch (e) {}
-							// var factories = {};
-							// loadCssChunkData(factories, link, chunkId);
-							// Object.keys(factories).forEach(function(id) {
-							//     (updatedModulesList.push(id));
-							// });
 							link.sheet.disabled = true;
-							oldTags.push(oldTag);
-							newTags.push([chunkId, link]);
+							oldTags[chunkId] = oldTag;
+							newTags[chunkId] = link;
 							resolve();
 						}
 					},
@@ -0,0 +1,150 @@
+use std::{collections::BTreeMap, fmt};
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rspack_error::{error, Result};
+use serde_json::Value;
+use sha2::{Digest, Sha256, Sha512};
+use url::Url;
+
+const LOCKFILE_VERSION: u64 = 1;
+
+static UNSAFE_EDGE_CHARS: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^[^a-zA-Z0-9]+|[^a-zA-Z0-9]+$").expect("Invalid regex"));
+static UNSAFE_CHARS: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"[^a-zA-Z0-9._-]+").expect("Invalid regex"));
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockfileEntry {
+  pub resolved: String,
+  pub integrity: String,
+  pub content_type: String,
+}
+
+/// The lockfile of `HttpUriPlugin`, compatible with the `webpack.lock` of webpack.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Lockfile {
+  pub entries: BTreeMap<String, LockfileEntry>,
+}
+
+impl Lockfile {
+  pub fn parse(content: &str) -> Result<Self> {
+    let data: serde_json::Map<String, Value> =
+      serde_json::from_str(content).map_err(|e| error!("Failed to parse lockfile: {e}"))?;
+    let version = data.get("version").and_then(Value::as_u64);
+    if version != Some(LOCKFILE_VERSION) {
+      return Err(error!(
+        "Unsupported lockfile version {}",
+        data.get("version").cloned().unwrap_or(Value::Null)
+      ));
+    }
+    let mut entries = BTreeMap::new();
+    for (key, value) in data {
+      // `"no-cache"` entries are always fetched again, so they don't need to be kept.
+      let Value::Object(entry) = value else {
+        continue;
+      };
+      let get = |name: &str| {
+        entry
+          .get(name)
+          .and_then(Value::as_str)
+          .map(ToOwned::to_owned)
+      };
+      let (Some(integrity), Some(content_type)) = (get("integrity"), get("contentType")) else {
+        return Err(error!("Invalid lockfile entry for {key}"));
+      };
+      entries.insert(
+        key.clone(),
+        LockfileEntry {
+          resolved: get("resolved").unwrap_or(key),
+          integrity,
+          content_type,
+        },
+      );
+    }
+    Ok(Self { entries })
+  }
+}
+
+impl fmt::Display for Lockfile {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let json = |s: &str| Value::String(s.to_owned()).to_string();
+    writeln!(f, "{{")?;
+    for (key, entry) in &self.entries {
+      write!(f, "  {}: {{ ", json(key))?;
+      if &entry.resolved != key {
+        write!(f, "\"resolved\": {}, ", json(&entry.resolved))?;
+      }
+      writeln!(
+        f,
+        "\"integrity\": {}, \"contentType\": {} }},",
+        json(&entry.integrity),
+        json(&entry.content_type)
+      )?;
+    }
+    writeln!(f, "  \"version\": {LOCKFILE_VERSION}")?;
+    writeln!(f, "}}")
+  }
+}
+
+pub fn compute_integrity(content: &[u8]) -> String {
+  format!(
+    "sha512-{}",
+    rspack_base64::encode_to_string(Sha512::digest(content))
+  )
+}
+
+fn to_safe_path(s: &str) -> String {
+  UNSAFE_CHARS
+    .replace_all(&UNSAFE_EDGE_CHARS.replace_all(s, ""), "_")
+    .into_owned()
+}
+
+/// Path of the cached content of `url`, relative to the cache location.
+pub fn get_cache_key(url: &str) -> Result<String> {
+  let parsed = Url::parse(url).map_err(|e| error!("Invalid url {url}: {e}"))?;
+  let folder = to_safe_path(&parsed.origin().ascii_serialization());
+  let name = to_safe_path(parsed.path());
+  let query = to_safe_path(parsed.query().unwrap_or_default());
+  let ext = match name.rfind('.') {
+    Some(index) if index > 0 && name.len() - index <= 20 => &name[index..],
+    _ => "",
+  };
+  let basename = &name[..name.len() - ext.len()];
+  let digest = format!("{:x}", Sha256::digest(url.as_bytes()));
+  let query = if query.is_empty() {
+    String::new()
+  } else {
+    format!("_{query}")
+  };
+  Ok(format!("{folder}/{basename}{query}_{}{ext}", &digest[..10]))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn lockfile_roundtrip() {
+    let content = r#"{
+  "https://cdn.example.com/a.js": { "integrity": "sha512-a", "contentType": "text/javascript" },
+  "https://cdn.example.com/b": { "resolved": "https://cdn.example.com/b.js", "integrity": "sha512-b", "contentType": "text/javascript" },
+  "version": 1
+}
+"#;
+    let lockfile = Lockfile::parse(content).expect("should parse lockfile");
+    assert_eq!(
+      lockfile.entries["https://cdn.example.com/b"].resolved,
+      "https://cdn.example.com/b.js"
+    );
+    assert_eq!(lockfile.to_string(), content);
+  }
+
+  #[test]
+  fn cache_key() {
+    assert_eq!(
+      get_cache_key("https://cdn.example.com/lib/math.js?v=1").expect("should be valid url"),
+      "https_cdn.example.com/lib_math_v_1_faa0d8392c.js"
+    );
+  }
+}
@@ -0,0 +1,386 @@
+mod lockfile;
+
+use std::{
+  fmt,
+  path::{Path, PathBuf},
+  sync::{Arc, Mutex},
+};
+
+use futures::{future::BoxFuture, lock::Mutex as AsyncMutex};
+use rspack_core::{
+  CompilationParams, Content, Plugin, PluginContext,
+  PluginNormalModuleFactoryResolveForSchemeOutput, PluginReadResourceOutput,
+  PluginThisCompilationHookOutput, ResourceData, ThisCompilationArgs,
+};
+use rspack_error::{error, Result};
+use rspack_fs::{AsyncNativeFileSystem, AsyncReadableFileSystem, AsyncWritableFileSystem};
+use rspack_regex::{regexp_as_str, RspackRegex};
+use rustc_hash::FxHashMap as HashMap;
+use url::Url;
+
+pub use self::lockfile::{compute_integrity, get_cache_key, Lockfile, LockfileEntry};
+
+#[derive(Debug)]
+pub struct HttpResponse {
+  pub status: u16,
+  pub content_type: Option<String>,
+  pub body: Vec<u8>,
+}
+
+/// Fetches the given url, redirects should be followed by the client.
+pub type HttpClientFn =
+  Box<dyn Fn(String) -> BoxFuture<'static, Result<HttpResponse>> + Sync + Send>;
+
+#[derive(Debug)]
+pub enum AllowedUri {
+  /// Matches the uris starting with it.
+  String(String),
+  Regexp(RspackRegex),
+}
+
+impl AllowedUri {
+  pub fn matches(&self, uri: &str) -> bool {
+    match self {
+      Self::String(prefix) => uri.starts_with(prefix),
+      Self::Regexp(regexp) => regexp.test(uri),
+    }
+  }
+}
+
+impl fmt::Display for AllowedUri {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::String(prefix) => write!(f, "{prefix}"),
+      Self::Regexp(regexp) => write!(f, "/{}/", regexp_as_str(regexp)),
+    }
+  }
+}
+
+pub struct HttpUriPluginOptions {
+  pub allowed_uris: Vec<AllowedUri>,
+  pub lockfile_location: PathBuf,
+  /// Where to store the fetched contents, `None` to not cache them.
+  pub cache_location: Option<PathBuf>,
+  /// Never touch the network, every url must be in the lockfile and the cache.
+  pub frozen: bool,
+  /// Fetch the urls again and update the lockfile when their contents changed.
+  pub upgrade: bool,
+  pub http_client: Option<HttpClientFn>,
+}
+
+impl fmt::Debug for HttpUriPluginOptions {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("HttpUriPluginOptions")
+      .field("allowed_uris", &self.allowed_uris)
+      .field("lockfile_location", &self.lockfile_location)
+      .field("cache_location", &self.cache_location)
+      .field("frozen", &self.frozen)
+      .field("upgrade", &self.upgrade)
+      .field("http_client", &self.http_client.as_ref().map(|_| "Fn"))
+      .finish()
+  }
+}
+
+#[derive(Debug, Clone)]
+struct HttpResource {
+  content_type: String,
+  content: Arc<[u8]>,
+}
+
+#[derive(Debug)]
+pub struct HttpUriPlugin {
+  options: HttpUriPluginOptions,
+  /// The input filesystem of the current compilation.
+  input_filesystem: Mutex<Option<Arc<dyn AsyncReadableFileSystem>>>,
+  lockfile: Mutex<Option<Lockfile>>,
+  /// Held while the lockfile is written, so the last write has the latest entries.
+  lockfile_write: AsyncMutex<()>,
+  resources: Mutex<HashMap<String, HttpResource>>,
+  /// Held while the resource of the url is got, so each url is fetched once.
+  in_flight: Mutex<HashMap<String, Arc<AsyncMutex<()>>>>,
+}
+
+impl HttpUriPlugin {
+  pub fn new(options: HttpUriPluginOptions) -> Self {
+    Self {
+      options,
+      input_filesystem: Default::default(),
+      lockfile: Default::default(),
+      lockfile_write: Default::default(),
+      resources: Default::default(),
+      in_flight: Default::default(),
+    }
+  }
+
+  fn get_input_filesystem(&self) -> Arc<dyn AsyncReadableFileSystem> {
+    self
+      .input_filesystem
+      .lock()
+      .expect("should lock input filesystem")
+      .clone()
+      .unwrap_or_else(|| Arc::new(AsyncNativeFileSystem))
+  }
+
+  /// Reads the file, `None` if it doesn't exist.
+  async fn read_file(&self, path: &Path) -> Result<Option<Vec<u8>>> {
+    match self.get_input_filesystem().read(path).await {
+      Ok(content) => Ok(Some(content)),
+      Err(rspack_fs::Error::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+      Err(e) => Err(error!("Failed to read {}: {e}", path.display())),
+    }
+  }
+
+  async fn with_lockfile<R>(&self, f: impl FnOnce(&mut Lockfile) -> R) -> Result<R> {
+    let loaded = self
+      .lockfile
+      .lock()
+      .expect("should lock lockfile")
+      .is_some();
+    if !loaded {
+      let location = &self.options.lockfile_location;
+      let lockfile = match self.read_file(location).await? {
+        Some(content) => Lockfile::parse(&String::from_utf8_lossy(&content))?,
+        None => Lockfile::default(),
+      };
+      self
+        .lockfile
+        .lock()
+        .expect("should lock lockfile")
+        .get_or_insert(lockfile);
+    }
+    let mut lockfile = self.lockfile.lock().expect("should lock lockfile");
+    Ok(f(lockfile.as_mut().expect("should have lockfile")))
+  }
+
+  async fn update_lockfile(&self, url: &str, entry: LockfileEntry) -> Result<()> {
+    let _write = self.lockfile_write.lock().await;
+    let content = self
+      .with_lockfile(|lockfile| {
+        lockfile.entries.insert(url.to_owned(), entry);
+        lockfile.to_string()
+      })
+      .await?;
+    write_file(&self.options.lockfile_location, content.as_bytes()).await
+  }
+
+  fn cache_path(&self, url: &str) -> Result<Option<PathBuf>> {
+    self
+      .options
+      .cache_location
+      .as_ref()
+      .map(|location| Ok(location.join(get_cache_key(url)?)))
+      .transpose()
+  }
+
+  async fn fetch(&self, url: &str) -> Result<(String, Vec<u8>)> {
+    let Some(http_client) = &self.options.http_client else {
+      return Err(error!(
+        "{url} is not cached, but no http client is available to fetch it"
+      ));
+    };
+    let response = http_client(url.to_owned()).await?;
+    if !(200..300).contains(&response.status) {
+      return Err(error!(
+        "Failed to fetch {url}: status code {}",
+        response.status
+      ));
+    }
+    let content_type = response
+      .content_type
+      .unwrap_or_else(|| "application/octet-stream".to_owned());
+    if let Some(path) = self.cache_path(url)? {
+      write_file(&path, &response.body).await?;
+    }
+    Ok((content_type, response.body))
+  }
+
+  fn get_resource(&self, url: &str) -> Option<HttpResource> {
+    self
+      .resources
+      .lock()
+      .expect("should lock resources")
+      .get(url)
+      .cloned()
+  }
+
+  async fn get_info(&self, url: &str) -> Result<HttpResource> {
+    if let Some(resource) = self.get_resource(url) {
+      return Ok(resource);
+    }
+
+    let in_flight = self
+      .in_flight
+      .lock()
+      .expect("should lock in flight urls")
+      .entry(url.to_owned())
+      .or_default()
+      .clone();
+    let result = {
+      let _in_flight = in_flight.lock().await;
+      // the url may be got while waiting for another module importing it
+      match self.get_resource(url) {
+        Some(resource) => Ok(resource),
+        None => self.get_info_uncached(url).await,
+      }
+    };
+    self
+      .in_flight
+      .lock()
+      .expect("should lock in flight urls")
+      .remove(url);
+    result
+  }
+
+  async fn get_info_uncached(&self, url: &str) -> Result<HttpResource> {
+    let entry = self
+      .with_lockfile(|lockfile| lockfile.entries.get(url).cloned())
+      .await?;
+    let resource = match entry {
+      Some(entry) if !self.options.upgrade || self.options.frozen => {
+        let cached = match self.cache_path(&entry.resolved)? {
+          Some(path) => self.read_file(&path).await?,
+          None => None,
+        };
+        let content = match cached {
+          Some(content) => content,
+          None if self.options.frozen => {
+            return Err(error!(
+              "{url} is not in the cache, but the lockfile is frozen"
+            ))
+          }
+          None => self.fetch(&entry.resolved).await?.1,
+        };
+        let integrity = compute_integrity(&content);
+        if integrity != entry.integrity {
+          return Err(error!(
+            "Integrity mismatch of {url}, expected {} but got {integrity}. Enable `upgrade` to accept the new content",
+            entry.integrity
+          ));
+        }
+        HttpResource {
+          content_type: entry.content_type,
+          content: content.into(),
+        }
+      }
+      _ => {
+        if self.options.frozen {
+          return Err(error!(
+            "{url} has no lockfile entry, but the lockfile is frozen"
+          ));
+        }
+        let (content_type, content) = self.fetch(url).await?;
+        self
+          .update_lockfile(
+            url,
+            LockfileEntry {
+              resolved: url.to_owned(),
+              integrity: compute_integrity(&content),
+              content_type: content_type.clone(),
+            },
+          )
+          .await?;
+        HttpResource {
+          content_type,
+          content: content.into(),
+        }
+      }
+    };
+
+    self
+      .resources
+      .lock()
+      .expect("should lock resources")
+      .insert(url.to_owned(), resource.clone());
+    Ok(resource)
+  }
+}
+
+async fn write_file(path: &Path, content: &[u8]) -> Result<()> {
+  let fs = AsyncNativeFileSystem;
+  if let Some(dir) = path.parent() {
+    AsyncWritableFileSystem::create_dir_all(&fs, dir)
+      .await
+      .map_err(|e| error!("Failed to create directory {}: {e}", dir.display()))?;
+  }
+  AsyncWritableFileSystem::write(&fs, path, content)
+    .await
+    .map_err(|e| error!("Failed to write {}: {e}", path.display()))
+}
+
+#[async_trait::async_trait]
+impl Plugin for HttpUriPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.HttpUriPlugin"
+  }
+
+  async fn this_compilation(
+    &self,
+    args: ThisCompilationArgs<'_>,
+    _params: &CompilationParams,
+  ) -> PluginThisCompilationHookOutput {
+    *self
+      .input_filesystem
+      .lock()
+      .expect("should lock input filesystem") =
+      Some(args.this_compilation.input_filesystem.clone());
+    // Each compilation reads the lockfile again and, when upgrading, fetches the urls again, so
+    // a rebuild picks up changed contents.
+    self.lockfile.lock().expect("should lock lockfile").take();
+    self
+      .resources
+      .lock()
+      .expect("should lock resources")
+      .clear();
+    Ok(())
+  }
+
+  async fn normal_module_factory_resolve_for_scheme(
+    &self,
+    _ctx: PluginContext,
+    resource_data: ResourceData,
+  ) -> PluginNormalModuleFactoryResolveForSchemeOutput {
+    if !resource_data.get_scheme().is_http() {
+      return Ok((resource_data, false));
+    }
+    let url = resource_data.resource;
+    if !self
+      .options
+      .allowed_uris
+      .iter()
+      .any(|allowed| allowed.matches(&url))
+    {
+      return Err(error!(
+        "{url} doesn't match the allowedUris policy. These URIs are allowed:\n{}",
+        self
+          .options
+          .allowed_uris
+          .iter()
+          .map(|allowed| format!(" - {allowed}"))
+          .collect::<Vec<_>>()
+          .join("\n")
+      ));
+    }
+    let resource = self.get_info(&url).await?;
+
+    let mut parsed = Url::parse(&url).map_err(|e| error!("Invalid url {url}: {e}"))?;
+    let query = parsed.query().map(|q| format!("?{q}"));
+    let fragment = parsed.fragment().map(|f| format!("#{f}"));
+    parsed.set_query(None);
+    parsed.set_fragment(None);
+    Ok((
+      ResourceData::new(url, parsed.to_string().into())
+        .query_optional(query)
+        .fragment_optional(fragment)
+        .mimetype(resource.content_type),
+      true,
+    ))
+  }
+
+  async fn read_resource(&self, resource_data: &ResourceData) -> PluginReadResourceOutput {
+    if !resource_data.get_scheme().is_http() {
+      return Ok(None);
+    }
+    let resource = self.get_info(&resource_data.resource).await?;
+    Ok(Some(Content::Buffer(resource.content.to_vec())))
+  }
+}
@@ -2,6 +2,13 @@
 
 mod data_uri;
 mod file_uri;
+mod http_uri;
+mod virtual_modules;
 
 pub use data_uri::DataUriPlugin;
 pub use file_uri::FileUriPlugin;
+pub use http_uri::{
+  AllowedUri, HttpClientFn, HttpResponse, HttpUriPlugin, HttpUriPluginOptions, Lockfile,
+  LockfileEntry,
+};
+pub use virtual_modules::VirtualModulesPlugin;
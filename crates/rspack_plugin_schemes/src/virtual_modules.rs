@@ -0,0 +1,252 @@
+use std::{
+  path::{Path, PathBuf},
+  sync::{Arc, RwLock},
+};
+
+use once_cell::sync::OnceCell;
+use rspack_core::{
+  parse_resource, ApplyContext, CompilerOptions, Content, LoaderRunnerContext, NormalModule,
+  Plugin, PluginContext, PluginNormalModuleFactoryResolveForSchemeOutput,
+  PluginNormalModuleFactoryResolveOutput, PluginReadResourceOutput, Resolve, ResolveArgs,
+  ResolveResult, Resource, ResourceData, ResourceParsedData,
+};
+use rspack_error::Result;
+use rspack_loader_runner::{Identifiable, Identifier, Loader, LoaderContext};
+use rustc_hash::FxHashMap as HashMap;
+use sugar_path::SugarPath;
+
+const VIRTUAL_SCHEME: &str = "virtual:";
+const VIRTUAL_MODULES_LOADER_IDENTIFIER: &str = "builtin:virtual-modules-loader";
+
+/// Serves in-memory module sources, either under the `virtual:` scheme (e.g. `virtual:routes.js`)
+/// or under a path which doesn't need to exist on disk. Relative paths are resolved against the context,
+/// and requests without an extension are completed with `resolve.extensions`.
+///
+/// The plugin is a handle, keep a clone of it to update the modules between rebuilds.
+#[derive(Debug, Clone, Default)]
+pub struct VirtualModulesPlugin {
+  context: Arc<OnceCell<PathBuf>>,
+  resolve: Arc<OnceCell<Resolve>>,
+  modules: Arc<RwLock<HashMap<String, String>>>,
+}
+
+impl VirtualModulesPlugin {
+  pub fn new(modules: impl IntoIterator<Item = (String, String)>) -> Self {
+    Self {
+      context: Default::default(),
+      resolve: Default::default(),
+      modules: Arc::new(RwLock::new(modules.into_iter().collect())),
+    }
+  }
+
+  /// Updates the content of a virtual module.
+  ///
+  /// Returns the path to pass to `Compiler::rebuild` as changed, `None` when the content is unchanged.
+  pub fn write_module(&self, id: &str, content: String) -> Option<PathBuf> {
+    let id = self.normalize_id(id);
+    let mut modules = self.modules.write().expect("should lock modules");
+    if modules.get(&id) == Some(&content) {
+      return None;
+    }
+    let path = self.resource_path(&id);
+    modules.insert(id, content);
+    Some(path)
+  }
+
+  fn context(&self) -> &Path {
+    self
+      .context
+      .get()
+      .map(PathBuf::as_path)
+      .unwrap_or(Path::new(""))
+  }
+
+  fn normalize_id(&self, id: &str) -> String {
+    if id.starts_with(VIRTUAL_SCHEME) {
+      id.to_owned()
+    } else {
+      self
+        .context()
+        .join(id)
+        .absolutize()
+        .to_string_lossy()
+        .into_owned()
+    }
+  }
+
+  /// Modules of the `virtual:` scheme live in the context, so that their relative requests,
+  /// file dependencies and rule conditions behave like the ones of a real file.
+  fn resource_path(&self, id: &str) -> PathBuf {
+    self.context().join(id)
+  }
+
+  fn get(&self, id: &str) -> Option<String> {
+    self
+      .modules
+      .read()
+      .expect("should lock modules")
+      .get(id)
+      .cloned()
+  }
+
+  /// The id a module is registered with, the `virtual:` request or the resource path.
+  fn id_of(resource_data: &ResourceData) -> Option<String> {
+    if resource_data.resource.starts_with(VIRTUAL_SCHEME) {
+      parse_resource(&resource_data.resource)
+        .and_then(|parsed| parsed.path.to_str().map(ToOwned::to_owned))
+    } else {
+      resource_data.resource_path.to_str().map(ToOwned::to_owned)
+    }
+  }
+
+  fn contains(&self, id: &str) -> bool {
+    self
+      .modules
+      .read()
+      .expect("should lock modules")
+      .contains_key(id)
+  }
+
+  /// Finds the registered module of `path`, trying the path itself first, then with each
+  /// of `resolve.extensions` appended like the resolver does for a file.
+  fn resolve_path(&self, path: PathBuf, args: &ResolveArgs<'_>) -> Option<PathBuf> {
+    if self.contains(&path.to_string_lossy()) {
+      return Some(path);
+    }
+    let mut resolve = self.resolve.get().cloned().unwrap_or_default();
+    if let Some(resolve_options) = &args.resolve_options {
+      resolve = resolve.merge(*resolve_options.clone());
+    }
+    let resolve = resolve.merge_by_dependency(*args.dependency_category);
+    if resolve.fully_specified.unwrap_or(false) {
+      return None;
+    }
+    resolve
+      .extensions
+      .unwrap_or_default()
+      .into_iter()
+      .map(|extension| {
+        let mut path = path.clone().into_os_string();
+        path.push(extension);
+        PathBuf::from(path)
+      })
+      .find(|path| self.contains(&path.to_string_lossy()))
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for VirtualModulesPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.VirtualModulesPlugin"
+  }
+
+  fn apply(
+    &self,
+    _ctx: PluginContext<&mut ApplyContext>,
+    options: &mut CompilerOptions,
+  ) -> Result<()> {
+    let _ = self.context.set(PathBuf::from(options.context.as_str()));
+    let _ = self.resolve.set(options.resolve.clone());
+    let mut modules = self.modules.write().expect("should lock modules");
+    *modules = std::mem::take(&mut *modules)
+      .into_iter()
+      .map(|(id, content)| (self.normalize_id(&id), content))
+      .collect();
+    Ok(())
+  }
+
+  async fn normal_module_factory_resolve_for_scheme(
+    &self,
+    _ctx: PluginContext,
+    resource_data: ResourceData,
+  ) -> PluginNormalModuleFactoryResolveForSchemeOutput {
+    if let Some(ResourceParsedData {
+      path,
+      query,
+      fragment,
+    }) = parse_resource(&resource_data.resource)
+      && let Some(id) = path.to_str()
+      && id.starts_with(VIRTUAL_SCHEME)
+      && self.contains(id)
+    {
+      let resource_path = self.resource_path(id);
+      return Ok((
+        ResourceData::new(resource_data.resource, resource_path)
+          .query_optional(query)
+          .fragment_optional(fragment),
+        true,
+      ));
+    }
+    Ok((resource_data, false))
+  }
+
+  async fn normal_module_factory_resolve(
+    &self,
+    _ctx: PluginContext,
+    args: &ResolveArgs<'_>,
+  ) -> PluginNormalModuleFactoryResolveOutput {
+    let specifier = args.specifier;
+    if !(specifier.starts_with("./")
+      || specifier.starts_with("../")
+      || Path::new(specifier).is_absolute())
+    {
+      return Ok(None);
+    }
+    let Some(ResourceParsedData {
+      path,
+      query,
+      fragment,
+    }) = parse_resource(specifier)
+    else {
+      return Ok(None);
+    };
+    let path = Path::new(args.context.as_str())
+      .join(path)
+      .absolutize()
+      .into_owned();
+    let Some(path) = self.resolve_path(path, args) else {
+      return Ok(None);
+    };
+    Ok(Some(ResolveResult::Resource(Resource {
+      path,
+      query,
+      fragment,
+      description_data: None,
+    })))
+  }
+
+  async fn before_loaders(&self, module: &mut NormalModule) -> Result<()> {
+    if Self::id_of(module.resource_resolved_data()).is_some_and(|id| self.contains(&id)) {
+      module
+        .loaders_mut_vec()
+        .insert(0, Arc::new(VirtualModulesLoader));
+    }
+    Ok(())
+  }
+
+  async fn read_resource(&self, resource_data: &ResourceData) -> PluginReadResourceOutput {
+    Ok(
+      Self::id_of(resource_data)
+        .and_then(|id| self.get(&id))
+        .map(Content::String),
+    )
+  }
+}
+
+/// Virtual modules are missing on disk, so their snapshots can't tell when they are written.
+/// The loader marks them as not cacheable, they are built again whenever they are rebuilt.
+struct VirtualModulesLoader;
+
+#[async_trait::async_trait]
+impl Loader<LoaderRunnerContext> for VirtualModulesLoader {
+  async fn run(&self, loader_context: &mut LoaderContext<'_, LoaderRunnerContext>) -> Result<()> {
+    loader_context.cacheable = false;
+    Ok(())
+  }
+}
+
+impl Identifiable for VirtualModulesLoader {
+  fn identifier(&self) -> Identifier {
+    VIRTUAL_MODULES_LOADER_IDENTIFIER.into()
+  }
+}
@@ -0,0 +1,8 @@
+use std::path::PathBuf;
+
+use rspack_testing::{fixture, test_fixture};
+
+#[fixture("tests/fixtures/*")]
+fn schemes(fixture_path: PathBuf) {
+  test_fixture(&fixture_path, Box::new(|_, _| {}), None);
+}
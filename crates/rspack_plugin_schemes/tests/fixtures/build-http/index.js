@@ -0,0 +1,3 @@
+import { add, PI } from "https://cdn.example.com/lib/math.js";
+
+console.log(add(1, 2), PI);
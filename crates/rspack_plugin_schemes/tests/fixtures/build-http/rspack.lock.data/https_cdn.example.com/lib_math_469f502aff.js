@@ -0,0 +1,3 @@
+export { PI } from "./constants.js";
+
+export const add = (a, b) => a + b;
@@ -0,0 +1 @@
+export { default } from "https://cdn.example.com/shared.js";
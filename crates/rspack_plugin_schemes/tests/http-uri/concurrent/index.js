@@ -0,0 +1,6 @@
+import a from "./a";
+import b from "./b";
+import c from "https://cdn.example.com/c.js";
+import d from "https://cdn.example.com/d.js";
+
+console.log(a, b, c, d);
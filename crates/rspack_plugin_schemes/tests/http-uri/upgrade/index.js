@@ -0,0 +1,3 @@
+import value from "https://cdn.example.com/value.js";
+
+console.log(value);
@@ -0,0 +1,127 @@
+use std::{
+  collections::{HashMap, HashSet},
+  path::Path,
+  sync::{Arc, Mutex},
+  time::Duration,
+};
+
+use rspack_core::PluginExt;
+use rspack_plugin_schemes::{
+  AllowedUri, HttpResponse, HttpUriPlugin, HttpUriPluginOptions, Lockfile,
+};
+use rspack_testing::{copy_fixture, create_compiler_from_fixture, main_js};
+
+fn read_lockfile(path: &Path) -> Lockfile {
+  Lockfile::parse(&std::fs::read_to_string(path).expect("should read lockfile"))
+    .expect("should parse lockfile")
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn upgrade_changed_contents_on_rebuild() {
+  let fixture_path = copy_fixture(
+    &Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/http-uri/upgrade"),
+    Path::new(env!("CARGO_TARGET_TMPDIR")),
+  );
+  let lockfile_location = fixture_path.join("rspack.lock");
+  let remote = Arc::new(Mutex::new("export default 'v1';".to_string()));
+  let content = remote.clone();
+  let http_uri_plugin = HttpUriPlugin::new(HttpUriPluginOptions {
+    allowed_uris: vec![AllowedUri::String("https://cdn.example.com/".to_string())],
+    lockfile_location: lockfile_location.clone(),
+    cache_location: Some(fixture_path.join("rspack.lock.data")),
+    frozen: false,
+    upgrade: true,
+    http_client: Some(Box::new(move |_url| {
+      let body = content.lock().expect("should lock").clone().into_bytes();
+      Box::pin(async move {
+        Ok(HttpResponse {
+          status: 200,
+          content_type: Some("application/javascript".to_string()),
+          body,
+        })
+      })
+    })),
+  })
+  .boxed();
+  let mut compiler = create_compiler_from_fixture(&fixture_path, |plugins, _| {
+    plugins.retain(|plugin| plugin.name() != "rspack.HttpUriPlugin");
+    plugins.push(http_uri_plugin);
+  });
+
+  compiler.build().await.expect("should build");
+  assert!(compiler.compilation.get_errors().next().is_none());
+  assert!(main_js(&compiler.compilation).contains("'v1'"));
+  let url = "https://cdn.example.com/value.js";
+  let integrity = read_lockfile(&lockfile_location).entries[url]
+    .integrity
+    .clone();
+
+  *remote.lock().expect("should lock") = "export default 'v2';".to_string();
+  compiler
+    .rebuild(
+      HashSet::from([fixture_path.join("index.js").to_string_lossy().into_owned()]),
+      Default::default(),
+    )
+    .await
+    .expect("should rebuild");
+  assert!(compiler.compilation.get_errors().next().is_none());
+  assert!(main_js(&compiler.compilation).contains("'v2'"));
+  assert_ne!(
+    read_lockfile(&lockfile_location).entries[url].integrity,
+    integrity
+  );
+
+  let _ = std::fs::remove_dir_all(&fixture_path);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn fetch_each_url_once_and_lock_all_of_them() {
+  let fixture_path = copy_fixture(
+    &Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/http-uri/concurrent"),
+    Path::new(env!("CARGO_TARGET_TMPDIR")),
+  );
+  let lockfile_location = fixture_path.join("rspack.lock");
+  let fetched = Arc::new(Mutex::new(HashMap::<String, usize>::new()));
+  let counter = fetched.clone();
+  let http_uri_plugin = HttpUriPlugin::new(HttpUriPluginOptions {
+    allowed_uris: vec![AllowedUri::String("https://cdn.example.com/".to_string())],
+    lockfile_location: lockfile_location.clone(),
+    cache_location: Some(fixture_path.join("rspack.lock.data")),
+    frozen: false,
+    upgrade: false,
+    http_client: Some(Box::new(move |url| {
+      *counter
+        .lock()
+        .expect("should lock")
+        .entry(url.clone())
+        .or_default() += 1;
+      Box::pin(async move {
+        // keep the requests in flight while the other modules are resolved
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        Ok(HttpResponse {
+          status: 200,
+          content_type: Some("application/javascript".to_string()),
+          body: format!("export default {url:?};").into_bytes(),
+        })
+      })
+    })),
+  })
+  .boxed();
+  let mut compiler = create_compiler_from_fixture(&fixture_path, |plugins, _| {
+    plugins.retain(|plugin| plugin.name() != "rspack.HttpUriPlugin");
+    plugins.push(http_uri_plugin);
+  });
+  compiler.build().await.expect("should build");
+  assert!(compiler.compilation.get_errors().next().is_none());
+
+  let urls = ["c.js", "d.js", "shared.js"].map(|file| format!("https://cdn.example.com/{file}"));
+  let fetched = fetched.lock().expect("should lock");
+  assert_eq!(fetched.len(), urls.len(), "{fetched:#?}");
+  assert!(fetched.values().all(|count| *count == 1), "{fetched:#?}");
+  let lockfile = read_lockfile(&lockfile_location);
+  for url in &urls {
+    assert!(lockfile.entries.contains_key(url), "{url} should be locked");
+  }
+
+  let _ = std::fs::remove_dir_all(&fixture_path);
+}
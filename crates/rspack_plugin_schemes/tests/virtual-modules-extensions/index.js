@@ -0,0 +1,3 @@
+import { hello } from "./generated/i18n";
+
+console.log(hello);
@@ -0,0 +1,4 @@
+import routes from "virtual:routes.js";
+import { hello } from "./generated/i18n.js";
+
+console.log(routes, hello);
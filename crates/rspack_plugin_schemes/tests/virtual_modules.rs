@@ -0,0 +1,82 @@
+use std::{collections::HashSet, path::PathBuf};
+
+use rspack_core::{CacheOptions, MemoryCacheOptions, SnapshotStrategy};
+use rspack_plugin_schemes::VirtualModulesPlugin;
+use rspack_testing::{create_compiler_from_fixture, main_js};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn virtual_modules_rebuild_on_write() {
+  let fixture_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/virtual-modules");
+  let virtual_modules = VirtualModulesPlugin::new([
+    (
+      "virtual:routes.js".to_owned(),
+      "import home from './src/home.js';\nexport default [{ path: '/', component: home }];"
+        .to_owned(),
+    ),
+    (
+      "generated/i18n.js".to_owned(),
+      "export const hello = 'Hello';".to_owned(),
+    ),
+  ]);
+  let mut compiler = create_compiler_from_fixture(&fixture_path, |plugins, options| {
+    // virtual modules are missing on disk, the snapshots should still invalidate them on write
+    options.cache = CacheOptions::Memory(MemoryCacheOptions { max_generations: 1 });
+    options.snapshot.module = SnapshotStrategy {
+      hash: true,
+      timestamp: true,
+    };
+    plugins.push(Box::new(virtual_modules.clone()));
+  });
+
+  compiler.build().await.expect("should build");
+  let output = main_js(&compiler.compilation);
+  assert!(output.contains("\"virtual:routes.js\""));
+  assert!(output.contains("\"./src/home.js\""));
+  assert!(output.contains("'Hello'"));
+
+  assert_eq!(
+    virtual_modules.write_module(
+      "generated/i18n.js",
+      "export const hello = 'Hello';".to_owned()
+    ),
+    None
+  );
+  let changed = virtual_modules
+    .write_module(
+      "generated/i18n.js",
+      "export const hello = 'Bonjour';".to_owned(),
+    )
+    .expect("should change the content");
+  assert_eq!(changed, fixture_path.join("generated/i18n.js"));
+
+  compiler
+    .rebuild(
+      HashSet::from([changed.to_string_lossy().into_owned()]),
+      Default::default(),
+    )
+    .await
+    .expect("should rebuild");
+  let output = main_js(&compiler.compilation);
+  assert!(output.contains("'Bonjour'"));
+  assert!(!output.contains("'Hello'"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn virtual_modules_resolve_extensions() {
+  let fixture_path =
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/virtual-modules-extensions");
+  let virtual_modules = VirtualModulesPlugin::new([(
+    "generated/i18n.js".to_owned(),
+    "export const hello = 'Hello';".to_owned(),
+  )]);
+  let mut compiler = create_compiler_from_fixture(&fixture_path, |plugins, _| {
+    plugins.push(Box::new(virtual_modules.clone()));
+  });
+
+  compiler.build().await.expect("should build");
+  let errors = compiler.compilation.get_stats().get_errors();
+  assert!(errors.is_empty(), "{errors:#?}");
+  let output = main_js(&compiler.compilation);
+  assert!(output.contains("\"./generated/i18n.js\""), "{output}");
+  assert!(output.contains("'Hello'"), "{output}");
+}
@@ -0,0 +1,129 @@
+// Port of https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/performance/SizeLimitsPlugin.js
+
+use std::fmt::Write;
+
+use rspack_core::{
+  format_size, rspack_sources::Source, PerformanceHints, Plugin, PluginContext,
+  PluginProcessAssetsOutput, ProcessAssetsArgs,
+};
+use rspack_error::Diagnostic;
+
+/// Check the size of assets and entrypoints against `performance` after all assets are processed.
+#[derive(Debug)]
+pub struct SizeLimitsPlugin;
+
+#[async_trait::async_trait]
+impl Plugin for SizeLimitsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.SizeLimitsPlugin"
+  }
+
+  async fn after_process_assets(
+    &self,
+    _ctx: PluginContext,
+    args: ProcessAssetsArgs<'_>,
+  ) -> PluginProcessAssetsOutput {
+    let compilation = args.compilation;
+    let options = compilation.options.clone();
+    let performance = &options.performance;
+    let Some(hints) = performance.hints else {
+      return Ok(());
+    };
+
+    let mut assets_over_size_limit = vec![];
+    let mut included_assets = vec![];
+    for (name, asset) in compilation.assets() {
+      let Some(source) = asset.get_source() else {
+        continue;
+      };
+      if !performance
+        .is_asset_included(name, asset.get_info())
+        .await?
+      {
+        continue;
+      }
+      included_assets.push(name.clone());
+      let size = source.size() as f64;
+      if size > performance.max_asset_size {
+        assets_over_size_limit.push((name.clone(), size));
+      }
+    }
+
+    let mut entrypoints_over_size_limit = vec![];
+    for entrypoint in compilation.get_stats().get_entrypoints() {
+      let assets = entrypoint
+        .assets
+        .into_iter()
+        .filter(|asset| included_assets.contains(&asset.name))
+        .collect::<Vec<_>>();
+      let size = assets.iter().map(|asset| asset.size).sum::<f64>();
+      if size > performance.max_entrypoint_size {
+        let files = assets
+          .into_iter()
+          .map(|asset| asset.name)
+          .collect::<Vec<_>>();
+        entrypoints_over_size_limit.push((entrypoint.name, size, files));
+      }
+    }
+
+    let mut messages = vec![];
+    if !assets_over_size_limit.is_empty() {
+      let mut message = format!(
+        "asset size limit: The following asset(s) exceed the recommended size limit ({}).\nThis can impact web performance.\nAssets: ",
+        format_size(performance.max_asset_size)
+      );
+      for (name, size) in &assets_over_size_limit {
+        let _ = write!(message, "\n  {name} ({})", format_size(*size));
+      }
+      messages.push(("AssetsOverSizeLimitWarning", message));
+    }
+    if !entrypoints_over_size_limit.is_empty() {
+      let mut message = format!(
+        "entrypoint size limit: The following entrypoint(s) combined asset size exceeds the recommended limit ({}). This can impact web performance.\nEntrypoints:",
+        format_size(performance.max_entrypoint_size)
+      );
+      for (name, size, files) in &entrypoints_over_size_limit {
+        let _ = write!(message, "\n  {name} ({})", format_size(*size));
+        for file in files {
+          let _ = write!(message, "\n      {file}");
+        }
+      }
+      message.push('\n');
+      messages.push(("EntrypointsOverSizeLimitWarning", message));
+    }
+    if !messages.is_empty()
+      && compilation
+        .chunk_by_ukey
+        .values()
+        .all(|chunk| chunk.can_be_initial(&compilation.chunk_group_by_ukey))
+    {
+      messages.push((
+        "NoAsyncChunksWarning",
+        "rspack performance recommendations: \nYou can limit the size of your bundles by using import() to lazy load some parts of your application.\nFor more info visit https://www.rspack.dev/guide/code-splitting.html".to_string(),
+      ));
+    }
+
+    for (name, _) in assets_over_size_limit {
+      if let Some(asset) = compilation.assets_mut().get_mut(&name) {
+        asset.info.is_over_size_limit = Some(true);
+      }
+    }
+    for (name, ..) in entrypoints_over_size_limit {
+      if let Some(ukey) = compilation.entrypoints.get(&name) {
+        compilation
+          .chunk_group_by_ukey
+          .expect_get_mut(ukey)
+          .is_over_size_limit = Some(true);
+      }
+    }
+    let diagnostics = messages
+      .into_iter()
+      .map(|(title, message)| match hints {
+        PerformanceHints::Warning => Diagnostic::warn(title.to_string(), message),
+        PerformanceHints::Error => Diagnostic::error(title.to_string(), message),
+      })
+      .collect();
+    compilation.push_batch_diagnostic(diagnostics);
+    Ok(())
+  }
+}
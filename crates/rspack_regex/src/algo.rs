@@ -43,6 +43,18 @@ impl HashRegressRegex {
   fn find(&self, text: &str) -> Option<Match> {
     self.regex.find(text)
   }
+
+  fn exec(&self, text: &str) -> Option<Vec<String>> {
+    self.find(text).map(|m| {
+      m.groups()
+        .map(|group| {
+          group
+            .map(|range| text[range].to_string())
+            .unwrap_or_default()
+        })
+        .collect()
+    })
+  }
 }
 
 #[derive(Clone, Debug, Hash)]
@@ -53,6 +65,8 @@ pub enum Algo {
   /// See details at https://github.com/web-infra-dev/rspack/pull/3113
   EndWith {
     pats: Vec<String>,
+    /// Used by [Algo::exec], captures can't be extracted from the fast path.
+    regex: Box<HashRegressRegex>,
   },
   Regress(HashRegressRegex),
 }
@@ -60,10 +74,13 @@ pub enum Algo {
 impl Algo {
   pub(crate) fn new(expr: &str, flags: &str) -> Result<Algo, Error> {
     let ignore_case = flags.contains('i') || flags.contains('g') || flags.contains('y');
-    if let Some(algo) = Self::try_compile_to_end_with_fast_path(expr)
+    if let Some(pats) = Self::try_compile_to_end_with_fast_path(expr)
       && !ignore_case
     {
-      Ok(algo)
+      Ok(Algo::EndWith {
+        pats,
+        regex: Box::new(HashRegressRegex::new(expr, flags)?),
+      })
     } else {
       match HashRegressRegex::new(expr, flags) {
         Ok(regex) => Ok(Algo::Regress(regex)),
@@ -72,7 +89,7 @@ impl Algo {
     }
   }
 
-  fn try_compile_to_end_with_fast_path(expr: &str) -> Option<Algo> {
+  fn try_compile_to_end_with_fast_path(expr: &str) -> Option<Vec<String>> {
     let hir = regex_syntax::parse(expr).ok()?;
     let seq = regex_syntax::hir::literal::Extractor::new()
       .kind(ExtractKind::Suffix)
@@ -84,7 +101,7 @@ impl Algo {
         .map(|item| String::from_utf8_lossy(item.as_bytes()).to_string())
         .collect::<Vec<_>>();
 
-      Some(Algo::EndWith { pats })
+      Some(pats)
     } else {
       None
     }
@@ -93,7 +110,14 @@ impl Algo {
   pub(crate) fn test(&self, str: &str) -> bool {
     match self {
       Algo::Regress(regex) => regex.find(str).is_some(),
-      Algo::EndWith { pats } => pats.iter().any(|pat| str.ends_with(pat)),
+      Algo::EndWith { pats, .. } => pats.iter().any(|pat| str.ends_with(pat)),
+    }
+  }
+
+  pub(crate) fn exec(&self, str: &str) -> Option<Vec<String>> {
+    match self {
+      Algo::Regress(regex) => regex.exec(str),
+      Algo::EndWith { regex, .. } => regex.exec(str),
     }
   }
 
@@ -116,7 +140,7 @@ impl Algo {
 impl Algo {
   fn end_with_pats(&self) -> std::collections::HashSet<&str> {
     match self {
-      Algo::EndWith { pats } => pats.iter().map(|s| s.as_str()).collect(),
+      Algo::EndWith { pats, .. } => pats.iter().map(|s| s.as_str()).collect(),
       Algo::Regress(_) => panic!("expect EndWith"),
     }
   }
@@ -170,4 +194,24 @@ mod test_algo {
     // wildcard match
     assert!(Algo::new("\\..(svg|png)$", "").unwrap().is_regress());
   }
+
+  #[test]
+  fn exec_with_captures() {
+    let regex = crate::RspackRegex::new("\\.(jsx?|tsx?)$").unwrap();
+    assert!(regex.algo.is_end_with());
+    assert_eq!(
+      regex.exec("src/a.tsx"),
+      Some(vec![".tsx".to_string(), "tsx".to_string()])
+    );
+    let regex = crate::RspackRegex::new("([^/]+)(\\.module)?\\.css$").unwrap();
+    assert_eq!(
+      regex.exec("src/button.css"),
+      Some(vec![
+        "button.css".to_string(),
+        "button".to_string(),
+        String::new()
+      ])
+    );
+    assert_eq!(regex.exec("src/button.js"), None);
+  }
 }
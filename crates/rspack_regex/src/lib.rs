@@ -27,6 +27,12 @@ impl RspackRegex {
     self.algo.test(text)
   }
 
+  /// The whole match and the capture groups of the first match, like `RegExp.prototype.exec`,
+  /// groups that don't participate in the match are empty.
+  pub fn exec(&self, text: &str) -> Option<Vec<String>> {
+    self.algo.exec(text)
+  }
+
   pub fn global(&self) -> bool {
     self.algo.global()
   }
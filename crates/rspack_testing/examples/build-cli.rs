@@ -54,5 +54,10 @@ async fn main() {
     );
   }
 
-  println!("Build success");
+  println!(
+    "{}",
+    stats
+      .to_string(&compiler.options.stats)
+      .expect("failed to print stats")
+  );
 }
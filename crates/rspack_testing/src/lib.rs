@@ -4,8 +4,9 @@ mod run_fixture;
 mod test_config;
 pub use eval_raw::{evaluate_js, evaluate_to_json};
 pub use run_fixture::{
-  apply_from_fixture, test_fixture, test_fixture_css, test_fixture_css_modules, test_fixture_html,
-  test_fixture_insta, test_fixture_js, test_rebuild_fixture,
+  apply_from_fixture, copy_fixture, create_compiler_from_fixture, main_js, test_fixture,
+  test_fixture_css, test_fixture_css_modules, test_fixture_html, test_fixture_insta,
+  test_fixture_js, test_rebuild_fixture,
 };
 pub use test_config::TestConfig;
 pub use testing_macros::{self, fixture};
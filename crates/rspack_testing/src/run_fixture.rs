@@ -5,7 +5,7 @@ use cargo_rst::{helper::make_relative_from, rst::RstBuilder};
 use insta::{assert_snapshot, Settings};
 use itertools::Itertools;
 use rspack_binding_options::RawOptions;
-use rspack_core::{BoxPlugin, Compiler, CompilerOptions};
+use rspack_core::{BoxPlugin, Compilation, Compiler, CompilerOptions};
 use rspack_fs::AsyncNativeFileSystem;
 use rspack_tracing::enable_tracing_by_env;
 
@@ -26,6 +26,53 @@ pub fn apply_from_fixture(fixture_path: &Path) -> (CompilerOptions, Vec<BoxPlugi
   let test_config = TestConfig::from_config_path(&json_config);
   test_config.apply(fixture_path.to_path_buf())
 }
+
+/// Copy the fixture to a directory of its own under `tmp_dir`, for tests changing the files of a
+/// fixture. The directory is unique to the process, so concurrent test runs don't interfere.
+pub fn copy_fixture(fixture_path: &Path, tmp_dir: &Path) -> PathBuf {
+  fn copy_dir(from: &Path, to: &Path) {
+    std::fs::create_dir_all(to).expect("should create dir");
+    for entry in std::fs::read_dir(from).expect("should read dir") {
+      let entry = entry.expect("should read entry");
+      let path = entry.path();
+      if path.is_dir() {
+        copy_dir(&path, &to.join(entry.file_name()));
+      } else {
+        std::fs::copy(&path, to.join(entry.file_name())).expect("should copy file");
+      }
+    }
+  }
+
+  let name = fixture_path
+    .file_name()
+    .expect("should have name")
+    .to_string_lossy();
+  let target = tmp_dir.join(format!("{name}-{}", std::process::id()));
+  let _ = std::fs::remove_dir_all(&target);
+  copy_dir(fixture_path, &target);
+  target
+}
+
+/// Create a compiler from the fixture, `mut_settings` adjusts the plugins and options first.
+pub fn create_compiler_from_fixture(
+  fixture_path: &Path,
+  mut_settings: impl FnOnce(&mut Vec<BoxPlugin>, &mut CompilerOptions),
+) -> Compiler<AsyncNativeFileSystem> {
+  let (mut options, mut plugins) = apply_from_fixture(fixture_path);
+  mut_settings(&mut plugins, &mut options);
+  Compiler::new(options, plugins, AsyncNativeFileSystem)
+}
+
+/// The source of the `main.js` emitted by the compilation.
+pub fn main_js(compilation: &Compilation) -> String {
+  compilation
+    .assets()
+    .get("main.js")
+    .and_then(|asset| asset.get_source())
+    .map(|source| source.source().to_string())
+    .expect("should emit main.js")
+}
+
 #[tokio::main]
 pub async fn test_fixture_html(fixture_path: &Path) -> Compiler<AsyncNativeFileSystem> {
   test_fixture_share(
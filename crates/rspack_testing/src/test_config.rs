@@ -72,6 +72,10 @@ fn default_optimization_false_string_lit() -> String {
   "false".to_string()
 }
 
+fn default_performance_max_size() -> f64 {
+  250000.0
+}
+
 fn true_by_default() -> bool {
   true
 }
@@ -99,6 +103,8 @@ pub struct TestConfig {
   pub devtool: String,
   #[serde(default)]
   pub experiments: Experiments,
+  #[serde(default)]
+  pub performance: Performance,
 }
 
 #[derive(Debug, Default, JsonSchema, Deserialize)]
@@ -107,6 +113,39 @@ pub struct Experiments {
   // True by default to reduce code in snapshots.
   #[serde(default = "true_by_default")]
   pub async_web_assembly: bool,
+  #[serde(default)]
+  pub build_http: Option<BuildHttp>,
+}
+
+#[derive(Debug, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Performance {
+  /// "warning", "error" or "false"
+  #[serde(default = "default_optimization_false_string_lit")]
+  pub hints: String,
+  #[serde(default = "default_performance_max_size")]
+  pub max_asset_size: f64,
+  #[serde(default = "default_performance_max_size")]
+  pub max_entrypoint_size: f64,
+  #[serde(default)]
+  pub asset_filter: Option<String>,
+}
+
+impl Default for Performance {
+  fn default() -> Self {
+    Self {
+      hints: default_optimization_false_string_lit(),
+      max_asset_size: default_performance_max_size(),
+      max_entrypoint_size: default_performance_max_size(),
+      asset_filter: None,
+    }
+  }
+}
+
+#[derive(Debug, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct BuildHttp {
+  pub allowed_uris: Vec<String>,
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -131,6 +170,8 @@ pub struct Optimization {
   pub mangle_exports: String,
   #[serde(default = "default_optimization_false_string_lit")]
   pub used_exports: String,
+  #[serde(default)]
+  pub concatenate_modules: bool,
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -196,6 +237,8 @@ pub struct Builtins {
 pub struct Css {
   #[serde(default)]
   pub modules: ModulesConfig,
+  #[serde(default)]
+  pub ignore_order: bool,
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -204,6 +247,11 @@ pub struct ModulesConfig {
   pub locals_convention: String,
   pub local_ident_name: String,
   pub exports_only: bool,
+  pub mode: String,
+  pub hash_prefix: String,
+  pub local_ident_context: Option<String>,
+  pub local_ident_reg_exp: Option<String>,
+  pub auto: Option<String>,
 }
 
 impl Default for ModulesConfig {
@@ -212,6 +260,11 @@ impl Default for ModulesConfig {
       locals_convention: "asIs".to_string(),
       local_ident_name: "[path][name][ext]__[local]".to_string(),
       exports_only: false,
+      mode: "local".to_string(),
+      hash_prefix: String::new(),
+      local_ident_context: None,
+      local_ident_reg_exp: None,
+      auto: None,
     }
   }
 }
@@ -355,12 +408,14 @@ impl TestConfig {
     assert!(context.is_absolute());
 
     let root = c::Context::new(context.to_string_lossy().to_string());
+    let target = c::Target::new(&self.target, &root).expect("Can't construct target");
 
     let options = CompilerOptions {
       bail: false,
       context: root.clone(),
       output: c::OutputOptions {
         clean: self.output.clean,
+        clean_keep: None,
         filename: c::Filename::from_str(&self.output.filename).expect("Should exist"),
         chunk_filename: c::Filename::from_str(&self.output.chunk_filename).expect("Should exist"),
         cross_origin_loading: rspack_core::CrossOriginLoading::Disable,
@@ -373,12 +428,12 @@ impl TestConfig {
           .expect("Should exist"),
         hot_update_global: "rspack_testing".to_string(),
         asset_module_filename: c::Filename::from_str("[hash][ext][query]").expect("Should exist"),
-        wasm_loading: c::WasmLoading::Enable(c::WasmLoadingType::from("fetch")),
+        wasm_loading: target.properties.wasm_loading(false),
         webassembly_module_filename: c::Filename::from_str("[hash].module.wasm")
           .expect("Should exist"),
         public_path: c::PublicPath::String("/".to_string()),
         unique_name: "__rspack_test__".to_string(),
-        chunk_loading: c::ChunkLoading::Enable(c::ChunkLoadingType::Jsonp),
+        chunk_loading: target.properties.chunk_loading(false),
         chunk_loading_global: "webpackChunkwebpack".to_string(),
         path: context.join("dist"),
         library: self.output.library.map(|l| c::LibraryOptions {
@@ -391,7 +446,7 @@ impl TestConfig {
         }),
         enabled_library_types: Some(vec!["system".to_string()]),
         strict_module_error_handling: false,
-        global_object: "self".to_string(),
+        global_object: target.properties.global_object().to_string(),
         import_function_name: "import".to_string(),
         iife: true,
         module: false,
@@ -403,13 +458,13 @@ impl TestConfig {
         hash_digest_length: 16,
         hash_salt: c::HashSalt::None,
         async_chunks: true,
-        worker_chunk_loading: c::ChunkLoading::Enable(c::ChunkLoadingType::ImportScripts),
-        worker_wasm_loading: c::WasmLoading::Enable(c::WasmLoadingType::from("fetch")),
+        worker_chunk_loading: target.properties.worker_chunk_loading(false),
+        worker_wasm_loading: target.properties.wasm_loading(false),
         worker_public_path: String::new(),
         script_type: String::from("false"),
       },
       mode: c::Mode::from(self.mode),
-      target: c::Target::new(&self.target).expect("Can't construct target"),
+      target,
       resolve: c::Resolve {
         extensions: Some(
           [
@@ -451,6 +506,18 @@ impl TestConfig {
         inner_graph: self.optimization.inner_graph,
         used_exports: c::UsedExportsOption::from(self.optimization.used_exports.as_str()),
         mangle_exports: c::MangleExportsOption::from(self.optimization.mangle_exports.as_str()),
+        concatenate_modules: self.optimization.concatenate_modules,
+      },
+      performance: c::PerformanceOptions {
+        hints: match self.performance.hints.as_str() {
+          "false" => None,
+          hints => Some(c::PerformanceHints::from(hints)),
+        },
+        max_asset_size: self.performance.max_asset_size,
+        max_entrypoint_size: self.performance.max_entrypoint_size,
+        asset_filter: self.performance.asset_filter.map(|regexp| {
+          c::RuleSetCondition::Regexp(RspackRegex::new(&regexp).expect("should be valid regex"))
+        }),
       },
       profile: false,
     };
@@ -477,6 +544,9 @@ impl TestConfig {
       }
     }
     plugins.push(rspack_plugin_merge_duplicate_chunks::MergeDuplicateChunksPlugin.boxed());
+    if options.performance.hints.is_some() {
+      plugins.push(rspack_plugin_size_limits::SizeLimitsPlugin.boxed());
+    }
     if self.builtins.dev_friendly_split_chunks {
       plugins
         .push(rspack_plugin_dev_friendly_split_chunks::DevFriendlySplitChunksPlugin::new().boxed());
@@ -496,15 +566,51 @@ impl TestConfig {
             self.builtins.css.modules.local_ident_name,
           ),
           exports_only: self.builtins.css.modules.exports_only,
+          mode: rspack_plugin_css::plugin::ModulesModeGetter::Mode(
+            rspack_plugin_css::plugin::ModulesMode::from_str(&self.builtins.css.modules.mode)
+              .expect("Invalid css.modules.mode"),
+          ),
+          hash_prefix: self.builtins.css.modules.hash_prefix,
+          local_ident_context: self
+            .builtins
+            .css
+            .modules
+            .local_ident_context
+            .map(|local_ident_context| context.join(local_ident_context)),
+          local_ident_reg_exp: self
+            .builtins
+            .css
+            .modules
+            .local_ident_reg_exp
+            .map(|regexp| RspackRegex::new(&regexp).expect("should be valid regex")),
+          auto: self
+            .builtins
+            .css
+            .modules
+            .auto
+            .map(|auto| RspackRegex::new(&auto).expect("should be valid regex")),
         },
+        ignore_order: self.builtins.css.ignore_order,
       })
       .boxed(),
     );
     plugins.push(rspack_plugin_asset::AssetPlugin.boxed());
     plugins.push(rspack_plugin_json::JsonPlugin {}.boxed());
-    plugins.push(rspack_plugin_runtime::ArrayPushCallbackChunkFormatPlugin {}.boxed());
-    plugins.push(rspack_plugin_runtime::CssModulesPlugin {}.boxed());
-    plugins.push(rspack_plugin_runtime::JsonpChunkLoadingPlugin {}.boxed());
+    match options.target.properties.chunk_format(false) {
+      Some(c::ChunkFormat::ArrayPush) => {
+        plugins.push(rspack_plugin_runtime::ArrayPushCallbackChunkFormatPlugin {}.boxed())
+      }
+      Some(c::ChunkFormat::CommonJs) => {
+        plugins.push(rspack_plugin_runtime::CommonJsChunkFormatPlugin {}.boxed())
+      }
+      Some(c::ChunkFormat::Module) => {
+        plugins.push(rspack_plugin_runtime::ModuleChunkFormatPlugin {}.boxed())
+      }
+      None => {}
+    }
+    if let c::ChunkLoading::Enable(loading_type) = options.output.chunk_loading.clone() {
+      rspack_plugin_runtime::enable_chunk_loading_plugin(loading_type, &mut plugins);
+    }
     plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
     if options.dev_server.hot {
       plugins.push(rspack_plugin_hmr::HotModuleReplacementPlugin.boxed());
@@ -522,6 +628,8 @@ impl TestConfig {
             columns: !options.devtool.cheap(),
             no_sources: options.devtool.no_sources(),
             public_path: None,
+            module_filename_template: None,
+            fallback_module_filename_template: None,
           },
         )
         .boxed(),
@@ -548,9 +656,29 @@ impl TestConfig {
       plugins.push(rspack_plugin_wasm::FetchCompileAsyncWasmPlugin {}.boxed());
       plugins.push(rspack_plugin_wasm::AsyncWasmPlugin::new().boxed());
     }
-    plugins.push(rspack_plugin_externals::http_externals_rspack_plugin(
-      true, false,
-    ));
+    if let Some(build_http) = self.experiments.build_http {
+      let lockfile_location = context.join("rspack.lock");
+      plugins.push(
+        rspack_plugin_schemes::HttpUriPlugin::new(rspack_plugin_schemes::HttpUriPluginOptions {
+          allowed_uris: build_http
+            .allowed_uris
+            .into_iter()
+            .map(rspack_plugin_schemes::AllowedUri::String)
+            .collect(),
+          cache_location: Some(lockfile_location.with_extension("lock.data")),
+          lockfile_location,
+          // Tests never touch the network.
+          frozen: true,
+          upgrade: false,
+          http_client: None,
+        })
+        .boxed(),
+      );
+    } else {
+      plugins.push(rspack_plugin_externals::http_externals_rspack_plugin(
+        true, false,
+      ));
+    }
 
     // Support resolving builtin loaders on the Native side
     plugins.push(crate::loader::BuiltinLoaderResolver.boxed());
@@ -338,6 +338,18 @@ export class Compilation {
 			!context.forToString
 		);
 		options.source = optionOrLocalFallback(options.source, false);
+		options.usedExports = optionOrLocalFallback(
+			options.usedExports,
+			!context.forToString
+		);
+		options.providedExports = optionOrLocalFallback(
+			options.providedExports,
+			!context.forToString
+		);
+		options.optimizationBailout = optionOrLocalFallback(
+			options.optimizationBailout,
+			!context.forToString
+		);
 		options.logging = optionOrLocalFallback(
 			options.logging,
 			context.forToString ? "info" : true
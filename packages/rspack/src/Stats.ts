@@ -126,6 +126,28 @@ function presetToOptions(name?: boolean | string): StatsOptions {
 				all: true,
 				modulesSpace: Infinity
 			};
+		case "detailed":
+			return {
+				hash: true,
+				builtAt: true,
+				entrypoints: true,
+				chunkGroups: true,
+				ids: true,
+				children: true,
+				chunks: true,
+				chunkRelations: true,
+				chunkModules: false,
+				chunkOrigins: true,
+				moduleTrace: true,
+				usedExports: true,
+				providedExports: true,
+				optimizationBailout: true,
+				errorDetails: true,
+				publicPath: true,
+				logging: true,
+				runtimeModules: true,
+				modulesSpace: 1000
+			};
 		case "errors-only":
 			return {
 				all: false,
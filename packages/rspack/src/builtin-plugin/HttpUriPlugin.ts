@@ -0,0 +1,84 @@
+import path from "path";
+import http from "http";
+import https from "https";
+import { BuiltinPlugin, RawHttpResponse } from "@rspack/binding";
+import {
+	BuiltinPluginName,
+	RspackBuiltinPlugin,
+	createBuiltinPlugin
+} from "./base";
+import { Compiler } from "../Compiler";
+import { HttpUriOptions } from "../config";
+
+const MAX_REDIRECTS = 5;
+
+/**
+ * Fetches the url with the http modules of node, redirects are followed.
+ */
+export const defaultHttpClient = (
+	url: string,
+	redirects = 0
+): Promise<RawHttpResponse> =>
+	new Promise((resolve, reject) => {
+		const request = (url.startsWith("https:") ? https : http).get(
+			url,
+			res => {
+				const location = res.headers.location;
+				if (
+					res.statusCode &&
+					res.statusCode >= 300 &&
+					res.statusCode < 400 &&
+					location
+				) {
+					res.resume();
+					if (redirects >= MAX_REDIRECTS) {
+						reject(new Error(`Too many redirects when fetching ${url}`));
+						return;
+					}
+					defaultHttpClient(new URL(location, url).href, redirects + 1).then(
+						resolve,
+						reject
+					);
+					return;
+				}
+				const chunks: Buffer[] = [];
+				res.on("data", chunk => chunks.push(chunk));
+				res.on("end", () =>
+					resolve({
+						status: res.statusCode ?? 0,
+						contentType: res.headers["content-type"],
+						body: Buffer.concat(chunks)
+					})
+				);
+				res.on("error", reject);
+			}
+		);
+		request.on("error", reject);
+	});
+
+export class HttpUriPlugin extends RspackBuiltinPlugin {
+	name = BuiltinPluginName.HttpUriPlugin;
+	affectedHooks = "thisCompilation" as const;
+
+	constructor(private options: HttpUriOptions) {
+		super();
+	}
+
+	raw(compiler: Compiler): BuiltinPlugin {
+		const lockfileLocation =
+			this.options.lockfileLocation ??
+			path.resolve(compiler.context, "rspack.lock");
+		const cacheLocation =
+			this.options.cacheLocation === false
+				? undefined
+				: this.options.cacheLocation ?? `${lockfileLocation}.data`;
+		return createBuiltinPlugin(this.name, {
+			allowedUris: this.options.allowedUris,
+			lockfileLocation,
+			cacheLocation,
+			frozen: !!this.options.frozen,
+			upgrade: !!this.options.upgrade,
+			httpClient: this.options.httpClient ?? defaultHttpClient
+		});
+	}
+}
@@ -1,6 +1,13 @@
-import { RawSourceMapDevToolPluginOptions } from "@rspack/binding";
+import {
+	RawModuleFilenameTemplateFnCtx,
+	RawSourceMapDevToolPluginOptions
+} from "@rspack/binding";
 import { BuiltinPluginName, create } from "./base";
 
+export type ModuleFilenameTemplate =
+	| string
+	| ((info: RawModuleFilenameTemplateFnCtx) => string);
+
 export type SourceMapDevToolPluginOptions = {
 	filename?: false | null | string;
 	append?: boolean;
@@ -8,6 +15,8 @@ export type SourceMapDevToolPluginOptions = {
 	columns?: boolean;
 	noSources?: boolean;
 	publicPath?: string;
+	moduleFilenameTemplate?: ModuleFilenameTemplate;
+	fallbackModuleFilenameTemplate?: ModuleFilenameTemplate;
 };
 
 export const SourceMapDevToolPlugin = create(
@@ -21,7 +30,9 @@ export const SourceMapDevToolPlugin = create(
 			namespace: options.namespace ?? "",
 			columns: options.columns ?? true,
 			noSources: options.noSources ?? false,
-			publicPath: options.publicPath
+			publicPath: options.publicPath,
+			moduleFilenameTemplate: options.moduleFilenameTemplate,
+			fallbackModuleFilenameTemplate: options.fallbackModuleFilenameTemplate
 		};
 	},
 	"compilation"
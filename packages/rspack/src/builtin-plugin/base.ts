@@ -35,6 +35,7 @@ export enum BuiltinPluginName {
 	ContainerReferencePlugin = "ContainerReferencePlugin",
 	ProvideSharedPlugin = "ProvideSharedPlugin",
 	ConsumeSharedPlugin = "ConsumeSharedPlugin",
+	ModuleFederationManifestPlugin = "ModuleFederationManifestPlugin",
 	NamedModuleIdsPlugin = "NamedModuleIdsPlugin",
 	DeterministicModuleIdsPlugin = "DeterministicModuleIdsPlugin",
 	NamedChunkIdsPlugin = "NamedChunkIdsPlugin",
@@ -45,6 +46,7 @@ export enum BuiltinPluginName {
 	WarnCaseSensitiveModulesPlugin = "WarnCaseSensitiveModulesPlugin",
 	DataUriPlugin = "DataUriPlugin",
 	FileUriPlugin = "FileUriPlugin",
+	HttpUriPlugin = "HttpUriPlugin",
 	RuntimePlugin = "RuntimePlugin",
 	JsonModulesPlugin = "JsonModulesPlugin",
 	InferAsyncModulesPlugin = "InferAsyncModulesPlugin",
@@ -56,7 +58,8 @@ export enum BuiltinPluginName {
 	SideEffectsFlagPlugin = "SideEffectsFlagPlugin",
 	FlagDependencyExportsPlugin = "FlagDependencyExportsPlugin",
 	FlagDependencyUsagePlugin = "FlagDependencyUsagePlugin",
-	MangleExportsPlugin = "MangleExportsPlugin"
+	MangleExportsPlugin = "MangleExportsPlugin",
+	ModuleConcatenationPlugin = "ModuleConcatenationPlugin"
 }
 
 type AffectedHooks = keyof Compiler["hooks"];
@@ -32,6 +32,7 @@ export * from "./EnsureChunkConditionsPlugin";
 export * from "./WarnCaseSensitiveModulesPlugin";
 export * from "./DataUriPlugin";
 export * from "./FileUriPlugin";
+export * from "./HttpUriPlugin";
 export * from "./RuntimePlugin";
 export * from "./JsonModulesPlugin";
 export * from "./InferAsyncModulesPlugin";
@@ -44,6 +45,7 @@ export * from "./SideEffectsFlagPlugin";
 export * from "./FlagDependencyExportsPlugin";
 export * from "./FlagDependencyUsagePlugin";
 export * from "./MangleExportsPlugin";
+export * from "./ModuleConcatenationPlugin";
 
 export * from "./HtmlRspackPlugin";
 export * from "./CopyRspackPlugin";
@@ -89,10 +91,37 @@ import {
 	resolveRelay
 } from "../builtin-loader/swc";
 
+type CssModulesMode = "local" | "global" | "pure";
+
 type BuiltinsCssConfig = {
-	modules?: Partial<RawCssModulesConfig>;
+	modules?: Partial<
+		Omit<RawCssModulesConfig, "mode" | "localIdentRegExp" | "auto">
+	> & {
+		mode?: CssModulesMode | ((resourcePath: string) => CssModulesMode);
+		localIdentRegExp?: string | RegExp;
+		auto?: RegExp;
+	};
+	ignoreOrder?: boolean;
 };
 
+function resolveCssModules(
+	modules: BuiltinsCssConfig["modules"] = {}
+): Partial<RawCssModulesConfig> {
+	const { localIdentRegExp, auto, ...rest } = modules;
+	const toRegexMatcher = (regexp: RegExp) => ({
+		source: regexp.source,
+		flags: regexp.flags
+	});
+	return {
+		...rest,
+		localIdentRegExp:
+			typeof localIdentRegExp === "string"
+				? toRegexMatcher(new RegExp(localIdentRegExp))
+				: localIdentRegExp && toRegexMatcher(localIdentRegExp),
+		auto: auto && toRegexMatcher(auto)
+	};
+}
+
 type EmotionConfigImportMap = {
 	[packageName: string]: {
 		[exportName: string]: {
@@ -357,8 +386,9 @@ export function deprecated_resolveBuiltins(
 							? "[hash]"
 							: "[path][name][ext]__[local]",
 						exportsOnly: false,
-						...builtins.css?.modules
-					}
+						...resolveCssModules(builtins.css?.modules)
+					},
+					ignoreOrder: builtins.css?.ignoreOrder ?? false
 			  }
 			: undefined,
 		treeShaking: resolveTreeShaking(builtins.treeShaking, production),
@@ -17,7 +17,8 @@ import type {
 	RawRspackFuture,
 	RawLibraryName,
 	RawLibraryOptions,
-	RawModuleRuleUse
+	RawModuleRuleUse,
+	RawPathMatcher
 } from "@rspack/binding";
 import assert from "assert";
 import { Compiler } from "../Compiler";
@@ -32,10 +33,12 @@ import {
 	LoaderDefinitionFunction
 } from "./adapterRuleUse";
 import {
+	CacheOptions,
 	CrossOriginLoading,
 	LibraryOptions,
 	Node,
 	Optimization,
+	Performance,
 	Resolve,
 	RuleSetCondition,
 	RuleSetLogicalConditions,
@@ -104,20 +107,10 @@ export const getRawOptions = (
 			hot: options.devServer?.hot ?? false
 		},
 		snapshot: getRawSnapshotOptions(options.snapshot),
-		cache: {
-			type: options.cache ? "memory" : "disable",
-			// TODO: implement below cache options
-			maxGenerations: 0,
-			maxAge: 0,
-			profile: false,
-			buildDependencies: [],
-			cacheDirectory: "",
-			cacheLocation: "",
-			name: "",
-			version: ""
-		},
+		cache: getRawCacheOptions(options.cache!),
 		experiments,
 		node: getRawNode(options.node),
+		performance: getRawPerformance(options.performance),
 		// SAFETY: applied default value in `applyRspackOptionsDefaults`.
 		profile: options.profile!,
 		// SAFETY: applied default value in `applyRspackOptionsDefaults`.
@@ -191,6 +184,25 @@ function getRawCrossOriginLoading(
 	return { type: "string", stringPayload: crossOriginLoading };
 }
 
+function getRawCleanKeep(
+	clean: OutputNormalized["clean"]
+): RawOptions["output"]["cleanKeep"] {
+	if (typeof clean !== "object" || clean.keep === undefined) {
+		return undefined;
+	}
+	return getRawPathMatcher(clean.keep);
+}
+
+function getRawPathMatcher(matcher: string | RegExp): RawPathMatcher {
+	if (typeof matcher === "string") {
+		return { type: "string", stringMatcher: matcher };
+	}
+	return {
+		type: "regexp",
+		regexpMatcher: { source: matcher.source, flags: matcher.flags }
+	};
+}
+
 function getRawOutput(output: OutputNormalized): RawOptions["output"] {
 	const chunkLoading = output.chunkLoading!;
 	const wasmLoading = output.wasmLoading!;
@@ -199,7 +211,8 @@ function getRawOutput(output: OutputNormalized): RawOptions["output"] {
 	return {
 		path: output.path!,
 		publicPath: output.publicPath!,
-		clean: output.clean!,
+		clean: !!output.clean,
+		cleanKeep: getRawCleanKeep(output.clean),
 		assetModuleFilename: output.assetModuleFilename!,
 		filename: output.filename!,
 		chunkFilename: output.chunkFilename!,
@@ -717,14 +730,15 @@ function getRawOptimization(
 		usedExports: String(optimization.usedExports),
 		providedExports: optimization.providedExports,
 		innerGraph: optimization.innerGraph,
-		mangleExports: String(optimization.mangleExports)
+		mangleExports: String(optimization.mangleExports),
+		concatenateModules: !!optimization.concatenateModules
 	};
 }
 
 function getRawSnapshotOptions(
 	snapshot: SnapshotOptions
 ): RawOptions["snapshot"] {
-	const { resolve, module } = snapshot;
+	const { resolve, module, immutablePaths, managedPaths } = snapshot;
 	assert(!isNil(resolve) && !isNil(module));
 	const { timestamp: resolveTimestamp, hash: resolveHash } = resolve;
 	const { timestamp: moduleTimestamp, hash: moduleHash } = module;
@@ -742,7 +756,67 @@ function getRawSnapshotOptions(
 		module: {
 			timestamp: moduleTimestamp,
 			hash: moduleHash
-		}
+		},
+		immutablePaths: immutablePaths?.map(getRawPathMatcher),
+		managedPaths: managedPaths?.map(getRawPathMatcher)
+	};
+}
+
+function getRawCacheOptions(cache: CacheOptions): RawOptions["cache"] {
+	const disabled: RawOptions["cache"] = {
+		type: "disable",
+		maxGenerations: 0,
+		maxAge: 0,
+		profile: false,
+		buildDependencies: [],
+		cacheDirectory: "",
+		cacheLocation: "",
+		name: "",
+		version: ""
+	};
+	if (cache === false) {
+		return disabled;
+	}
+	if (cache === true || cache.type === "memory") {
+		return {
+			...disabled,
+			type: "memory",
+			maxGenerations: cache === true ? 0 : cache.maxGenerations ?? 0
+		};
+	}
+	const {
+		buildDependencies,
+		cacheDirectory,
+		cacheLocation,
+		compression,
+		idleTimeout,
+		maxAge,
+		maxSize,
+		name,
+		profile,
+		version
+	} = cache;
+	assert(
+		!isNil(cacheDirectory) &&
+			!isNil(cacheLocation) &&
+			!isNil(name) &&
+			!isNil(version) &&
+			!isNil(maxAge) &&
+			!isNil(profile)
+	);
+	return {
+		type: "filesystem",
+		maxGenerations: 0,
+		maxAge,
+		profile,
+		buildDependencies: Object.values(buildDependencies ?? {}).flat(),
+		cacheDirectory,
+		cacheLocation,
+		name,
+		version,
+		compression: compression || undefined,
+		idleTimeout,
+		maxSize
 	};
 }
 
@@ -810,10 +884,59 @@ function getRawNode(node: Node): RawOptions["node"] {
 	};
 }
 
+function getRawPerformance(
+	performance: Performance
+): RawOptions["performance"] {
+	if (performance === false || !performance.hints) {
+		// the size limits are not checked without hints
+		return {
+			hints: undefined,
+			maxAssetSize: 0,
+			maxEntrypointSize: 0
+		};
+	}
+	assert(
+		!isNil(performance.maxAssetSize) && !isNil(performance.maxEntrypointSize)
+	);
+	return {
+		hints: performance.hints,
+		maxAssetSize: performance.maxAssetSize,
+		maxEntrypointSize: performance.maxEntrypointSize,
+		assetFilter: performance.assetFilter
+			? getRawRuleSetCondition(performance.assetFilter)
+			: undefined
+	};
+}
+
 function getRawStats(stats: StatsValue): RawOptions["stats"] {
 	const statsOptions = normalizeStatsPreset(stats);
 	return {
-		colors: statsOptions.colors ?? false
+		colors: statsOptions.colors ?? false,
+		all: statsOptions.all,
+		assets: statsOptions.assets,
+		chunks: statsOptions.chunks,
+		chunkModules: statsOptions.chunkModules,
+		chunkRelations: statsOptions.chunkRelations,
+		modules: statsOptions.modules,
+		nestedModules: statsOptions.nestedModules,
+		runtimeModules: statsOptions.runtimeModules,
+		reasons: statsOptions.reasons,
+		moduleAssets: statsOptions.moduleAssets,
+		source: statsOptions.source,
+		optimizationBailout: statsOptions.optimizationBailout,
+		usedExports: statsOptions.usedExports,
+		providedExports: statsOptions.providedExports,
+		entrypoints: statsOptions.entrypoints,
+		chunkGroups: statsOptions.chunkGroups,
+		errors: statsOptions.errors,
+		errorsCount: statsOptions.errorsCount,
+		warnings: statsOptions.warnings,
+		warningsCount: statsOptions.warningsCount,
+		hash: statsOptions.hash,
+		timings: statsOptions.timings,
+		builtAt: statsOptions.builtAt,
+		publicPath: statsOptions.publicPath,
+		outputPath: statsOptions.outputPath
 	};
 }
 
@@ -19,6 +19,7 @@ import {
 	getTargetsProperties
 } from "./target";
 import type {
+	CacheOptions,
 	Context,
 	ExternalsPresets,
 	InfrastructureLogging,
@@ -29,6 +30,7 @@ import type {
 	ModuleOptions,
 	Node,
 	Optimization,
+	Performance,
 	ResolveOptions,
 	RuleSetRules,
 	SnapshotOptions
@@ -80,9 +82,15 @@ export const applyRspackOptionsDefaults = (
 
 	const futureDefaults = options.experiments.futureDefaults ?? false;
 	F(options, "cache", () => development);
+	applyCacheDefaults(options.cache!, {
+		name: options.name || "default",
+		mode: mode || "production",
+		context: options.context!
+	});
 
 	applyExperimentsDefaults(options.experiments, {
-		cache: options.cache!
+		cache: options.cache!,
+		production
 	});
 
 	applySnapshotDefaults(options.snapshot, { production });
@@ -123,7 +131,13 @@ export const applyRspackOptionsDefaults = (
 
 	applyNodeDefaults(options.node, { targetProperties });
 
-	applyOptimizationDefaults(options.optimization, { production, development });
+	applyPerformanceDefaults(options.performance, { production });
+
+	applyOptimizationDefaults(options.optimization, {
+		production,
+		development,
+		css: options.experiments.css!
+	});
 
 	options.resolve = cleverMerge(
 		getResolveDefaults({
@@ -158,9 +172,29 @@ const applyInfrastructureLoggingDefaults = (
 	D(infrastructureLogging, "appendOnly", !tty);
 };
 
+const applyCacheDefaults = (
+	cache: CacheOptions,
+	{ name, mode, context }: { name: string; mode: Mode; context: Context }
+) => {
+	if (typeof cache !== "object" || cache.type !== "filesystem") return;
+	F(cache, "name", () => `${name}-${mode}`);
+	D(cache, "version", "");
+	F(cache, "cacheDirectory", () =>
+		path.resolve(context, "node_modules/.cache/rspack")
+	);
+	F(cache, "cacheLocation", () =>
+		path.resolve(cache.cacheDirectory!, cache.name!)
+	);
+	D(cache, "buildDependencies", {});
+	D(cache, "compression", false);
+	D(cache, "profile", false);
+	D(cache, "idleTimeout", 60000);
+	D(cache, "maxAge", 1000 * 60 * 60 * 24 * 30); // 1 month
+};
+
 const applyExperimentsDefaults = (
 	experiments: ExperimentsNormalized,
-	{ cache }: { cache: boolean }
+	{ cache, production }: { cache: CacheOptions; production: boolean }
 ) => {
 	D(experiments, "lazyCompilation", false);
 	D(experiments, "asyncWebAssembly", false);
@@ -189,6 +223,11 @@ const applyExperimentsDefaults = (
 		D(experiments.rspackFuture, "disableTransformByDefault", true);
 		D(experiments.rspackFuture, "disableApplyEntryLazily", false);
 	}
+
+	if (typeof experiments.buildHttp === "object") {
+		D(experiments.buildHttp, "frozen", production);
+		D(experiments.buildHttp, "upgrade", false);
+	}
 };
 
 const applySnapshotDefaults = (
@@ -733,9 +772,23 @@ const applyNodeDefaults = (
 	});
 };
 
+const applyPerformanceDefaults = (
+	performance: Performance,
+	{ production }: { production: boolean }
+) => {
+	if (performance === false) return;
+	D(performance, "maxAssetSize", 250000);
+	D(performance, "maxEntrypointSize", 250000);
+	F(performance, "hints", () => (production ? "warning" : false));
+};
+
 const applyOptimizationDefaults = (
 	optimization: Optimization,
-	{ production, development }: { production: boolean; development: boolean }
+	{
+		production,
+		development,
+		css
+	}: { production: boolean; development: boolean; css: boolean }
 ) => {
 	D(optimization, "removeAvailableModules", true);
 	D(optimization, "removeEmptyChunks", true);
@@ -750,6 +803,7 @@ const applyOptimizationDefaults = (
 	D(optimization, "providedExports", true);
 	D(optimization, "usedExports", production);
 	D(optimization, "innerGraph", production);
+	D(optimization, "concatenateModules", production);
 	D(optimization, "runtimeChunk", false);
 	D(optimization, "realContentHash", production);
 	D(optimization, "minimize", production);
@@ -770,9 +824,9 @@ const applyOptimizationDefaults = (
 	});
 	const { splitChunks } = optimization;
 	if (splitChunks) {
-		// A(splitChunks, "defaultSizeTypes", () =>
-		// 	css ? ["javascript", "css", "unknown"] : ["javascript", "unknown"]
-		// );
+		A(splitChunks, "defaultSizeTypes", () =>
+			css ? ["javascript", "css", "unknown"] : ["javascript", "unknown"]
+		);
 		D(splitChunks, "hidePathInfo", production);
 		D(splitChunks, "chunks", "async");
 		// D(splitChunks, "usedExports", optimization.usedExports === true);
@@ -14,6 +14,7 @@ import type {
 	Context,
 	Dependencies,
 	Node,
+	Performance,
 	DevTool,
 	EntryStatic,
 	Externals,
@@ -75,9 +76,12 @@ import type {
 	GeneratorOptionsByModuleType,
 	IncrementalRebuildOptions,
 	RspackFutureOptions,
+	HttpUriOptions,
 	HotUpdateGlobal,
 	ScriptType,
-	DevtoolNamespace
+	DevtoolNamespace,
+	DevtoolModuleFilenameTemplate,
+	DevtoolFallbackModuleFilenameTemplate
 } from "./zod";
 
 export const getNormalizedRspackOptions = (
@@ -218,7 +222,10 @@ export const getNormalizedRspackOptions = (
 				workerWasmLoading: output.workerWasmLoading,
 				workerPublicPath: output.workerPublicPath,
 				scriptType: output.scriptType,
-				devtoolNamespace: output.devtoolNamespace
+				devtoolNamespace: output.devtoolNamespace,
+				devtoolModuleFilenameTemplate: output.devtoolModuleFilenameTemplate,
+				devtoolFallbackModuleFilenameTemplate:
+					output.devtoolFallbackModuleFilenameTemplate
 			};
 		}),
 		resolve: nestedConfig(config.resolve, resolve => ({
@@ -254,6 +261,13 @@ export const getNormalizedRspackOptions = (
 					...node
 				}
 		),
+		performance: nestedConfig(
+			config.performance,
+			performance =>
+				performance && {
+					...performance
+				}
+		),
 		snapshot: nestedConfig(config.snapshot, snapshot => ({
 			resolve: optionalNestedConfig(snapshot.resolve, resolve => ({
 				timestamp: resolve.timestamp,
@@ -262,9 +276,15 @@ export const getNormalizedRspackOptions = (
 			module: optionalNestedConfig(snapshot.module, module => ({
 				timestamp: module.timestamp,
 				hash: module.hash
-			}))
+			})),
+			immutablePaths: optionalNestedArray(snapshot.immutablePaths, p => [...p]),
+			managedPaths: optionalNestedArray(snapshot.managedPaths, p => [...p])
 		})),
-		cache: optionalNestedConfig(config.cache, cache => cache),
+		cache: optionalNestedConfig(config.cache, cache =>
+			typeof cache === "object" && cache.type === "filesystem"
+				? { ...cache, buildDependencies: cloneObject(cache.buildDependencies) }
+				: cache
+		),
 		stats: nestedConfig(config.stats, stats => {
 			if (stats === false) {
 				return {
@@ -307,6 +327,9 @@ export const getNormalizedRspackOptions = (
 			incrementalRebuild: optionalNestedConfig(
 				experiments.incrementalRebuild,
 				options => (options === true ? {} : options)
+			),
+			buildHttp: optionalNestedConfig(experiments.buildHttp, options =>
+				Array.isArray(options) ? { allowedUris: options } : options
 			)
 		})),
 		watch: config.watch,
@@ -484,6 +507,8 @@ export interface OutputNormalized {
 	workerPublicPath?: WorkerPublicPath;
 	scriptType?: ScriptType;
 	devtoolNamespace?: DevtoolNamespace;
+	devtoolModuleFilenameTemplate?: DevtoolModuleFilenameTemplate;
+	devtoolFallbackModuleFilenameTemplate?: DevtoolFallbackModuleFilenameTemplate;
 }
 
 export interface ModuleOptionsNormalized {
@@ -503,6 +528,7 @@ export interface ExperimentsNormalized {
 	css?: boolean;
 	futureDefaults?: boolean;
 	rspackFuture?: RspackFutureOptions;
+	buildHttp?: HttpUriOptions;
 }
 
 export type IgnoreWarningsNormalized = ((
@@ -533,6 +559,7 @@ export interface RspackOptionsNormalized {
 	infrastructureLogging: InfrastructureLogging;
 	devtool?: DevTool;
 	node: Node;
+	performance: Performance;
 	snapshot: SnapshotOptions;
 	cache?: CacheOptions;
 	stats: StatsValue;
@@ -1,4 +1,4 @@
-import { RawFuncUseCtx } from "@rspack/binding";
+import { RawFuncUseCtx, RawHttpResponse } from "@rspack/binding";
 import { z } from "zod";
 import { Compilation, Compiler } from "..";
 import type * as oldBuiltins from "../builtin-plugin";
@@ -228,7 +228,12 @@ export type ChunkLoadingGlobal = z.infer<typeof chunkLoadingGlobal>;
 const enabledLibraryTypes = z.array(libraryType);
 export type EnabledLibraryTypes = z.infer<typeof enabledLibraryTypes>;
 
-const clean = z.boolean();
+const clean = z.union([
+	z.boolean(),
+	z.strictObject({
+		keep: z.string().or(z.instanceof(RegExp)).optional()
+	})
+]);
 export type Clean = z.infer<typeof clean>;
 
 const outputModule = z.boolean();
@@ -288,6 +293,18 @@ export type SourceMapFilename = z.infer<typeof sourceMapFilename>;
 const devtoolNamespace = z.string();
 export type DevtoolNamespace = z.infer<typeof devtoolNamespace>;
 
+const devtoolModuleFilenameTemplate = z
+	.string()
+	.or(z.function().args(z.any()).returns(z.any()));
+export type DevtoolModuleFilenameTemplate = z.infer<
+	typeof devtoolModuleFilenameTemplate
+>;
+
+const devtoolFallbackModuleFilenameTemplate = devtoolModuleFilenameTemplate;
+export type DevtoolFallbackModuleFilenameTemplate = z.infer<
+	typeof devtoolFallbackModuleFilenameTemplate
+>;
+
 const output = z.strictObject({
 	path: path.optional(),
 	clean: clean.optional(),
@@ -333,7 +350,10 @@ const output = z.strictObject({
 	workerWasmLoading: wasmLoading.optional(),
 	workerPublicPath: workerPublicPath.optional(),
 	scriptType: scriptType.optional(),
-	devtoolNamespace: devtoolNamespace.optional()
+	devtoolNamespace: devtoolNamespace.optional(),
+	devtoolModuleFilenameTemplate: devtoolModuleFilenameTemplate.optional(),
+	devtoolFallbackModuleFilenameTemplate:
+		devtoolFallbackModuleFilenameTemplate.optional()
 });
 export type Output = z.infer<typeof output>;
 //#endregion
@@ -833,6 +853,19 @@ const node = z.literal(false).or(nodeOptions);
 export type Node = z.infer<typeof node>;
 //#endregion
 
+//#region Performance
+const performanceOptions = z.strictObject({
+	assetFilter: z.function().args(z.string()).returns(z.boolean()).optional(),
+	hints: z.enum(["error", "warning"]).or(z.literal(false)).optional(),
+	maxAssetSize: z.number().optional(),
+	maxEntrypointSize: z.number().optional()
+});
+export type PerformanceOptions = z.infer<typeof performanceOptions>;
+
+const performance = z.literal(false).or(performanceOptions);
+export type Performance = z.infer<typeof performance>;
+//#endregion
+
 //#region Snapshot
 const snapshotOptions = z.strictObject({
 	module: z
@@ -846,13 +879,42 @@ const snapshotOptions = z.strictObject({
 			hash: z.boolean().optional(),
 			timestamp: z.boolean().optional()
 		})
-		.optional()
+		.optional(),
+	immutablePaths: z.array(z.string().or(z.instanceof(RegExp))).optional(),
+	managedPaths: z.array(z.string().or(z.instanceof(RegExp))).optional()
 });
 export type SnapshotOptions = z.infer<typeof snapshotOptions>;
 //#endregion
 
 //#region Cache
-const cacheOptions = z.boolean();
+const memoryCacheOptions = z.strictObject({
+	type: z.literal("memory"),
+	maxGenerations: z.number().optional()
+});
+export type MemoryCacheOptions = z.infer<typeof memoryCacheOptions>;
+
+const fileSystemCacheOptions = z.strictObject({
+	type: z.literal("filesystem"),
+	buildDependencies: z.record(z.array(z.string())).optional(),
+	cacheDirectory: z.string().optional(),
+	cacheLocation: z.string().optional(),
+	compression: z
+		.literal(false)
+		.or(z.enum(["gzip", "brotli", "zstd"]))
+		.optional(),
+	idleTimeout: z.number().optional(),
+	maxAge: z.number().optional(),
+	maxSize: z.number().optional(),
+	name: z.string().optional(),
+	profile: z.boolean().optional(),
+	version: z.string().optional()
+});
+export type FileSystemCacheOptions = z.infer<typeof fileSystemCacheOptions>;
+
+const cacheOptions = z
+	.boolean()
+	.or(memoryCacheOptions)
+	.or(fileSystemCacheOptions);
 export type CacheOptions = z.infer<typeof cacheOptions>;
 //#endregion
 
@@ -860,7 +922,14 @@ export type CacheOptions = z.infer<typeof cacheOptions>;
 const statsOptions = z.strictObject({
 	all: z.boolean().optional(),
 	preset: z
-		.enum(["normal", "none", "verbose", "errors-only", "errors-warnings"])
+		.enum([
+			"normal",
+			"none",
+			"verbose",
+			"detailed",
+			"errors-only",
+			"errors-warnings"
+		])
 		.optional(),
 	assets: z.boolean().optional(),
 	chunks: z.boolean().optional(),
@@ -871,18 +940,24 @@ const statsOptions = z.strictObject({
 	warningsCount: z.boolean().optional(),
 	errors: z.boolean().optional(),
 	errorsCount: z.boolean().optional(),
+	errorDetails: z.boolean().optional(),
 	colors: z.boolean().optional(),
 	hash: z.boolean().optional(),
 	version: z.boolean().optional(),
 	reasons: z.boolean().optional(),
+	optimizationBailout: z.boolean().optional(),
+	usedExports: z.boolean().optional(),
+	providedExports: z.boolean().optional(),
 	publicPath: z.boolean().optional(),
 	outputPath: z.boolean().optional(),
 	chunkModules: z.boolean().optional(),
+	chunkOrigins: z.boolean().optional(),
 	chunkRelations: z.boolean().optional(),
 	ids: z.boolean().optional(),
 	timings: z.boolean().optional(),
 	builtAt: z.boolean().optional(),
 	moduleAssets: z.boolean().optional(),
+	moduleTrace: z.boolean().optional(),
 	modulesSpace: z.number().optional(),
 	nestedModules: z.boolean().optional(),
 	source: z.boolean().optional(),
@@ -898,7 +973,14 @@ const statsOptions = z.strictObject({
 export type StatsOptions = z.infer<typeof statsOptions>;
 
 const statsValue = z
-	.enum(["none", "errors-only", "errors-warnings", "normal", "verbose"])
+	.enum([
+		"none",
+		"errors-only",
+		"errors-warnings",
+		"normal",
+		"detailed",
+		"verbose"
+	])
 	.or(z.boolean())
 	.or(statsOptions);
 export type StatsValue = z.infer<typeof statsValue>;
@@ -985,12 +1067,25 @@ export type OptimizationSplitChunksCacheGroup = z.infer<
 	typeof optimizationSplitChunksCacheGroup
 >;
 
+const optimizationSplitChunksSizeType = z.enum([
+	"javascript",
+	"css",
+	"wasm",
+	"asset",
+	"expose",
+	"remote",
+	"share-init",
+	"consume-shared",
+	"unknown"
+]);
+
 const optimizationSplitChunksOptions = z.strictObject({
 	cacheGroups: z
 		.record(z.literal(false).or(optimizationSplitChunksCacheGroup))
 		.optional(),
 	maxAsyncRequests: z.number().optional(),
 	maxInitialRequests: z.number().optional(),
+	defaultSizeTypes: z.array(optimizationSplitChunksSizeType).optional(),
 	fallbackCacheGroup: z
 		.strictObject({
 			chunks: optimizationSplitChunksChunks.optional(),
@@ -1024,6 +1119,7 @@ const optimization = z.strictObject({
 	innerGraph: z.boolean().optional(),
 	usedExports: z.enum(["global"]).or(z.boolean()).optional(),
 	mangleExports: z.enum(["size", "deterministic"]).or(z.boolean()).optional(),
+	concatenateModules: z.boolean().optional(),
 	nodeEnv: z.union([z.string(), z.literal(false)]).optional()
 });
 export type Optimization = z.infer<typeof optimization>;
@@ -1061,6 +1157,20 @@ const rspackFutureOptions = z.strictObject({
 });
 export type RspackFutureOptions = z.infer<typeof rspackFutureOptions>;
 
+const httpUriOptions = z.strictObject({
+	allowedUris: z.array(z.string().or(z.instanceof(RegExp))),
+	lockfileLocation: z.string().optional(),
+	cacheLocation: z.string().or(z.literal(false)).optional(),
+	frozen: z.boolean().optional(),
+	upgrade: z.boolean().optional(),
+	httpClient: z
+		.function()
+		.args(z.string())
+		.returns(z.promise(z.custom<RawHttpResponse>()))
+		.optional()
+});
+export type HttpUriOptions = z.infer<typeof httpUriOptions>;
+
 const experiments = z.strictObject({
 	lazyCompilation: z.boolean().optional(),
 	incrementalRebuild: z
@@ -1100,7 +1210,10 @@ const experiments = z.strictObject({
 		}),
 	css: z.boolean().optional(),
 	futureDefaults: z.boolean().optional(),
-	rspackFuture: rspackFutureOptions.optional()
+	rspackFuture: rspackFutureOptions.optional(),
+	buildHttp: httpUriOptions
+		.or(z.array(z.string().or(z.instanceof(RegExp))))
+		.optional()
 });
 export type Experiments = z.infer<typeof experiments>;
 //#endregion
@@ -1177,6 +1290,7 @@ export const rspackOptions = z.strictObject({
 	context: context.optional(),
 	devtool: devTool.optional(),
 	node: node.optional(),
+	performance: performance.optional(),
 	ignoreWarnings: ignoreWarnings.optional(),
 	watchOptions: watchOptions.optional(),
 	watch: watch.optional(),
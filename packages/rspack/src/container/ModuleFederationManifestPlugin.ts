@@ -0,0 +1,61 @@
+import {
+	BuiltinPlugin,
+	RawModuleFederationManifestPluginOptions
+} from "@rspack/binding";
+import {
+	BuiltinPluginName,
+	RspackBuiltinPlugin,
+	createBuiltinPlugin
+} from "../builtin-plugin/base";
+import { Compiler } from "../Compiler";
+import { ExternalsType } from "../config";
+import { parseOptions } from "./options";
+import { Remotes } from "./ContainerReferencePlugin";
+
+export type ModuleFederationManifestPluginOptions = {
+	name: string;
+	fileName?: string;
+	remoteType?: ExternalsType;
+	remotes?: Remotes;
+	shareScope?: string;
+};
+
+export class ModuleFederationManifestPlugin extends RspackBuiltinPlugin {
+	name = BuiltinPluginName.ModuleFederationManifestPlugin;
+	_options;
+
+	constructor(options: ModuleFederationManifestPluginOptions) {
+		super();
+		this._options = {
+			name: options.name,
+			filename: options.fileName ?? "mf-manifest.json",
+			remoteType: options.remoteType ?? "script",
+			remotes: options.remotes
+				? parseOptions(
+						options.remotes,
+						item => ({
+							external: Array.isArray(item) ? item : [item],
+							shareScope: options.shareScope || "default"
+						}),
+						item => ({
+							external: Array.isArray(item.external)
+								? item.external
+								: [item.external],
+							shareScope: item.shareScope || options.shareScope || "default"
+						})
+				  )
+				: []
+		};
+	}
+
+	raw(compiler: Compiler): BuiltinPlugin {
+		const { name, filename, remoteType, remotes } = this._options;
+		const rawOptions: RawModuleFederationManifestPluginOptions = {
+			name,
+			filename,
+			remoteType,
+			remotes: remotes.map(([key, r]) => ({ key, ...r }))
+		};
+		return createBuiltinPlugin(this.name, rawOptions);
+	}
+}
@@ -9,6 +9,7 @@ import { SharePlugin, Shared } from "../sharing/SharePlugin";
 import { isValidate } from "../util/validate";
 import { ContainerPlugin, Exposes } from "./ContainerPlugin";
 import { ContainerReferencePlugin, Remotes } from "./ContainerReferencePlugin";
+import { ModuleFederationManifestPlugin } from "./ModuleFederationManifestPlugin";
 
 export interface ModuleFederationPluginV1Options {
 	exposes?: Exposes;
@@ -21,6 +22,7 @@ export interface ModuleFederationPluginV1Options {
 	shareScope?: string;
 	shared?: Shared;
 	enhanced?: boolean;
+	manifest?: boolean | { fileName?: string };
 }
 
 export class ModuleFederationPluginV1 {
@@ -79,6 +81,18 @@ export class ModuleFederationPluginV1 {
 					enhanced
 				}).apply(compiler);
 			}
+			if (options.manifest) {
+				new ModuleFederationManifestPlugin({
+					name: options.name,
+					fileName:
+						typeof options.manifest === "object"
+							? options.manifest.fileName
+							: undefined,
+					remoteType,
+					remotes: options.remotes,
+					shareScope: options.shareScope
+				}).apply(compiler);
+			}
 		});
 	}
 }
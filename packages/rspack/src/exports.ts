@@ -127,6 +127,7 @@ import { ModuleFederationPluginV1 } from "./container/ModuleFederationPluginV1";
 export type { ModuleFederationPluginV1Options } from "./container/ModuleFederationPluginV1";
 import { ContainerPlugin } from "./container/ContainerPlugin";
 import { ContainerReferencePlugin } from "./container/ContainerReferencePlugin";
+import { ModuleFederationManifestPlugin } from "./container/ModuleFederationManifestPlugin";
 export type {
 	ContainerPluginOptions,
 	Exposes,
@@ -143,9 +144,11 @@ export type {
 	RemotesObject,
 	RemotesConfig
 } from "./container/ContainerReferencePlugin";
+export type { ModuleFederationManifestPluginOptions } from "./container/ModuleFederationManifestPlugin";
 export const container = {
 	ContainerPlugin,
 	ContainerReferencePlugin,
+	ModuleFederationManifestPlugin,
 	ModuleFederationPlugin,
 	ModuleFederationPluginV1
 };
@@ -8,14 +8,31 @@ export interface ThreadsafeWritableNodeFS {
 	mkdir: (...args: any[]) => any;
 	mkdirp: (...args: any[]) => any;
 	removeDirAll: (...args: any[]) => any;
+	rename: (...args: any[]) => any;
+	readFile: (...args: any[]) => any;
+	stat: (...args: any[]) => any;
+	lstat: (...args: any[]) => any;
+	readdir: (...args: any[]) => any;
+	readlink: (...args: any[]) => any;
+	realpath: (...args: any[]) => any;
 }
 
+const toNodeFsStats = (stats: import("fs").Stats) => ({
+	isFile: stats.isFile(),
+	isDirectory: stats.isDirectory(),
+	isSymlink: stats.isSymbolicLink(),
+	mtimeMs: stats.mtimeMs,
+	size: stats.size
+});
+
 function createThreadsafeNodeFSFromRaw(
 	fs: typeof import("fs")
 ): ThreadsafeWritableNodeFS {
 	let writeFile = memoizeFn(() => util.promisify(fs.writeFile.bind(fs)));
 	let removeFile = memoizeFn(() => util.promisify(fs.unlink.bind(fs)));
 	let mkdir = memoizeFn(() => util.promisify(fs.mkdir.bind(fs)));
+	let stat = memoizeFn(() => util.promisify(fs.stat.bind(fs)));
+	let lstat = memoizeFn(() => util.promisify(fs.lstat.bind(fs)));
 	return {
 		writeFile,
 		removeFile,
@@ -28,7 +45,14 @@ function createThreadsafeNodeFSFromRaw(
 		removeDirAll: dir => {
 			// memfs don't support rmSync
 			return rmrfBuild(fs)(dir);
-		}
+		},
+		rename: memoizeFn(() => util.promisify(fs.rename.bind(fs))),
+		readFile: memoizeFn(() => util.promisify(fs.readFile.bind(fs))),
+		stat: async (path: string) => toNodeFsStats(await stat(path)),
+		lstat: async (path: string) => toNodeFsStats(await lstat(path)),
+		readdir: memoizeFn(() => util.promisify(fs.readdir.bind(fs))),
+		readlink: memoizeFn(() => util.promisify(fs.readlink.bind(fs))),
+		realpath: memoizeFn(() => util.promisify(fs.realpath.bind(fs)))
 	};
 }
 
@@ -48,6 +48,7 @@ import {
 	WarnCaseSensitiveModulesPlugin,
 	DataUriPlugin,
 	FileUriPlugin,
+	HttpUriPlugin,
 	JavascriptModulesPlugin,
 	JsonModulesPlugin,
 	AsyncWebAssemblyModulesPlugin,
@@ -58,6 +59,7 @@ import {
 	SourceMapDevToolPlugin,
 	AssetModulesPlugin,
 	MangleExportsPlugin,
+	ModuleConcatenationPlugin,
 	FlagDependencyExportsPlugin,
 	FlagDependencyUsagePlugin,
 	SideEffectsFlagPlugin
@@ -222,7 +224,10 @@ export class RspackOptionsApply {
 					append: hidden ? false : undefined,
 					columns: cheap ? false : true,
 					noSources: noSources,
-					namespace: options.output.devtoolNamespace
+					namespace: options.output.devtoolNamespace,
+					moduleFilenameTemplate: options.output.devtoolModuleFilenameTemplate,
+					fallbackModuleFilenameTemplate:
+						options.output.devtoolFallbackModuleFilenameTemplate
 				}).apply(compiler);
 			}
 		}
@@ -249,6 +254,9 @@ export class RspackOptionsApply {
 
 		new DataUriPlugin().apply(compiler);
 		new FileUriPlugin().apply(compiler);
+		if (options.experiments.buildHttp) {
+			new HttpUriPlugin(options.experiments.buildHttp).apply(compiler);
+		}
 
 		new EnsureChunkConditionsPlugin().apply(compiler);
 		if (options.optimization.mergeDuplicateChunks) {
@@ -273,6 +281,9 @@ export class RspackOptionsApply {
 					options.optimization.usedExports === "global"
 				).apply(compiler);
 			}
+			if (options.optimization.concatenateModules) {
+				new ModuleConcatenationPlugin().apply(compiler);
+			}
 		}
 		if (options.optimization.mangleExports) {
 			new MangleExportsPlugin(
@@ -596,7 +596,10 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 				options.reasons!,
 				options.moduleAssets!,
 				options.nestedModules!,
-				options.source!
+				options.source!,
+				options.optimizationBailout!,
+				options.usedExports!,
+				options.providedExports!
 			);
 			object.chunks = factory.create(`${type}.chunks`, chunks, context);
 		},
@@ -612,7 +615,10 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 				options.reasons!,
 				options.moduleAssets!,
 				options.nestedModules!,
-				options.source!
+				options.source!,
+				options.optimizationBailout!,
+				options.usedExports!,
+				options.providedExports!
 			);
 			const groupedModules = factory.create(`${type}.modules`, array, context);
 			const limited = spaceLimited(groupedModules, options.modulesSpace!);
@@ -780,6 +786,15 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 		},
 		source: (object, module) => {
 			object.source = module.source;
+		},
+		usedExports: (object, module) => {
+			object.usedExports = module.usedExports ?? null;
+		},
+		providedExports: (object, module) => {
+			object.providedExports = module.providedExports ?? null;
+		},
+		optimizationBailout: (object, module) => {
+			object.optimizationBailout = module.optimizationBailout;
 		}
 	},
 	profile: {
@@ -108,8 +108,10 @@ describe("snapshots", () => {
 		-   "mode": "none",
 		+   "mode": undefined,
 		@@ ... @@
+		-     "concatenateModules": false,
 		-     "innerGraph": false,
 		-     "mangleExports": false,
+		+     "concatenateModules": true,
 		+     "innerGraph": true,
 		+     "mangleExports": true,
 		@@ ... @@
@@ -140,6 +142,9 @@ describe("snapshots", () => {
 		-     "usedExports": false,
 		+     "usedExports": true,
 		@@ ... @@
+		-     "hints": false,
+		+     "hints": "warning",
+		@@ ... @@
 		-       "hash": false,
 		+       "hash": true,
 		@@ ... @@
@@ -156,8 +161,10 @@ describe("snapshots", () => {
 		-   "mode": "none",
 		+   "mode": "production",
 		@@ ... @@
+		-     "concatenateModules": false,
 		-     "innerGraph": false,
 		-     "mangleExports": false,
+		+     "concatenateModules": true,
 		+     "innerGraph": true,
 		+     "mangleExports": true,
 		@@ ... @@
@@ -188,6 +195,9 @@ describe("snapshots", () => {
 		-     "usedExports": false,
 		+     "usedExports": true,
 		@@ ... @@
+		-     "hints": false,
+		+     "hints": "warning",
+		@@ ... @@
 		-       "hash": false,
 		+       "hash": true,
 		@@ ... @@
@@ -949,7 +959,16 @@ describe("snapshots", () => {
 		@@ ... @@
 		-   "cache": false,
 		+   "cache": Object {
+		+     "buildDependencies": Object {},
+		+     "cacheDirectory": "<cwd>/node_modules/.cache/rspack",
+		+     "cacheLocation": "<cwd>/node_modules/.cache/rspack/default-none",
+		+     "compression": false,
+		+     "idleTimeout": 60000,
+		+     "maxAge": 2592000000,
+		+     "name": "default-none",
+		+     "profile": false,
 		+     "type": "filesystem",
+		+     "version": "",
 		+   },
 		@@ ... @@
 		-       "make": false,
@@ -967,7 +986,16 @@ describe("snapshots", () => {
 			@@ ... @@
 			-   "cache": false,
 			+   "cache": Object {
+			+     "buildDependencies": Object {},
+			+     "cacheDirectory": "<cwd>/node_modules/.cache/rspack",
+			+     "cacheLocation": "<cwd>/node_modules/.cache/rspack/default-development",
+			+     "compression": false,
+			+     "idleTimeout": 60000,
+			+     "maxAge": 2592000000,
+			+     "name": "default-development",
+			+     "profile": false,
 			+     "type": "filesystem",
+			+     "version": "",
 			+   },
 			@@ ... @@
 			-       "make": false,
@@ -1125,7 +1153,16 @@ describe("snapshots", () => {
 			-   "cache": false,
 			-   "context": "<cwd>",
 			+   "cache": Object {
+			+     "buildDependencies": Object {},
+			+     "cacheDirectory": "<cwd>/tests/fixtures/node_modules/.cache/rspack",
+			+     "cacheLocation": "<cwd>/tests/fixtures/node_modules/.cache/rspack/default-none",
+			+     "compression": false,
+			+     "idleTimeout": 60000,
+			+     "maxAge": 2592000000,
+			+     "name": "default-none",
+			+     "profile": false,
 			+     "type": "filesystem",
+			+     "version": "",
 			+   },
 			+   "context": "<cwd>/tests/fixtures",
 			@@ ... @@
@@ -1251,6 +1288,8 @@ describe("snapshots", () => {
 			-       },
 			-       Object {
 			@@ ... @@
+			-         "css",
+			@@ ... @@
 			-     "hashDigestLength": 20,
 			-     "hashFunction": "md4",
 			+     "hashDigestLength": 16,
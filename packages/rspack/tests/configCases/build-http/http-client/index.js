@@ -0,0 +1,11 @@
+import answer from "https://cdn.example.com/answer.js";
+
+it("should fetch http imports with the given http client", () => {
+	expect(answer).toBe(42);
+});
+
+it("should fetch the resolved url of the lockfile", () => {
+	return import("https://cdn.example.com/renamed.js").then(m => {
+		expect(m.default).toBe("moved");
+	});
+});
@@ -0,0 +1,28 @@
+const path = require("path");
+
+const contents = {
+	"https://cdn.example.com/answer.js": "export default 42;",
+	"https://cdn.example.com/moved.js": "export default 'moved';"
+};
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	experiments: {
+		buildHttp: {
+			allowedUris: ["https://cdn.example.com/"],
+			lockfileLocation: path.resolve(__dirname, "rspack.lock"),
+			cacheLocation: false,
+			frozen: false,
+			async httpClient(url) {
+				const content = contents[url];
+				return content === undefined
+					? { status: 404, body: Buffer.from("") }
+					: {
+							status: 200,
+							contentType: "text/javascript",
+							body: Buffer.from(content)
+					  };
+			}
+		}
+	}
+};
@@ -0,0 +1,9 @@
+it("should get the mode of css modules from a function", () => {
+	const local = require("./local.css");
+	const global = require("./global.css");
+	expect(local.foo).toBeDefined();
+	expect(local.foo).not.toBe("foo");
+	expect(global.foo).toBeUndefined();
+	expect(global.bar).toBeDefined();
+	expect(global.bar).not.toBe("bar");
+});
@@ -0,0 +1,18 @@
+module.exports = {
+	builtins: {
+		css: {
+			modules: {
+				mode: resourcePath =>
+					resourcePath.endsWith("global.css") ? "global" : "local"
+			}
+		}
+	},
+	module: {
+		rules: [
+			{
+				test: /\.css$/,
+				type: "css/module"
+			}
+		]
+	}
+};
@@ -0,0 +1,44 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should emit the module federation manifest", () => {
+	const manifest = JSON.parse(
+		fs.readFileSync(path.join(__dirname, "mf-manifest.json"), "utf-8")
+	);
+	expect(manifest.name).toBe("container");
+	expect(manifest.metaData.remoteEntry).toEqual({
+		name: "container.js",
+		type: "commonjs-module"
+	});
+
+	expect(manifest.exposes).toHaveLength(1);
+	const [expose] = manifest.exposes;
+	expect(expose.id).toBe("container:module");
+	expect(expose.path).toBe("./module");
+	expect(expose.assets.js.sync.length).toBeGreaterThan(0);
+	for (const file of expose.assets.js.sync) {
+		expect(fs.existsSync(path.join(__dirname, file))).toBe(true);
+	}
+
+	expect(manifest.shared).toHaveLength(1);
+	const [shared] = manifest.shared;
+	expect(shared).toMatchObject({
+		id: "container:react",
+		name: "react",
+		shareScope: "default",
+		version: "18.2.0",
+		requiredVersion: "^18.2.0",
+		singleton: true
+	});
+	expect(shared.assets.js.sync.length).toBeGreaterThan(0);
+
+	expect(manifest.remotes).toEqual([
+		{
+			alias: "remote",
+			federationContainerName: "remote",
+			entry: "http://localhost:3000/remoteEntry.js",
+			externalType: "script",
+			shareScope: "default"
+		}
+	]);
+});
@@ -0,0 +1,3 @@
+import react from "react";
+
+export default () => `module rendered with ${react}`;
@@ -0,0 +1,30 @@
+const { ModuleFederationPluginV1 } = require("../../../../").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		filename: "[name].js"
+	},
+	plugins: [
+		new ModuleFederationPluginV1({
+			name: "container",
+			library: { type: "commonjs-module" },
+			filename: "container.js",
+			exposes: {
+				"./module": "./module"
+			},
+			remoteType: "script",
+			remotes: {
+				remote: "remote@http://localhost:3000/remoteEntry.js"
+			},
+			shared: {
+				react: {
+					version: "18.2.0",
+					requiredVersion: "^18.2.0",
+					singleton: true
+				}
+			},
+			manifest: true
+		})
+	]
+};
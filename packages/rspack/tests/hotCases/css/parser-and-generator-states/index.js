@@ -2,9 +2,8 @@ import style from './index.module.css';
 
 it("should store and resume css parser and generator states", (done) => {
 	expect(style['btnInfoIsDisabled']).toBe('index-module-css__btn-info_is-disabled');
-	module.hot.accept("./index.module.css", () => {
+	NEXT(require("../../update")(done, true, () => {
 		expect(style['btnInfoIsDisabled']).toBe('index-module-css__btn-info_is-disabled');
 		done();
-	});
-	NEXT(require("../../update")(done));
+	}));
 });
@@ -0,0 +1,22 @@
+import style from './styles';
+
+it("should self-accept css modules and invalidate them when their locals change", (done) => {
+	let accepted = false;
+	expect(style.button).toBeDefined();
+	expect(style.link).toBeUndefined();
+	module.hot.accept("./styles", () => {
+		accepted = true;
+		expect(style.link).toBeDefined();
+		done();
+	});
+	NEXT(require("../../update")(done, true, () => {
+		// Only the stylesheet changed, the css module accepts the update itself
+		expect(accepted).toBe(false);
+		expect(style.link).toBeUndefined();
+		if (__webpack_require__.hmrC.css) {
+			expect(document.head.children[0].href).toContain("main.css?hmr");
+		}
+		// The locals changed, the css module invalidates itself and the update bubbles up
+		NEXT(require("../../update")(done));
+	}));
+});
@@ -0,0 +1,3 @@
+import style from './index.module.css';
+
+export default style;
@@ -0,0 +1,10 @@
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /\.module\.css$/,
+				type: "css/module"
+			}
+		]
+	}
+};
@@ -0,0 +1,16 @@
+import { value } from "./file";
+
+it("should refresh imported values when accepting with a template literal and an error handler", (done) => {
+	expect(value).toBe(1);
+	expect(require("./commonjs")).toBe(10);
+	module.hot.accept(
+		[`./file`, "./commonjs"],
+		() => {
+			expect(value).toBe(2);
+			expect(require("./commonjs")).toBe(20);
+			done();
+		},
+		err => done(err)
+	);
+	NEXT(require("../../update")(done));
+});
@@ -0,0 +1,7 @@
+// TODO: remove this file after cache.
+const path = require('path');
+
+module.exports = [
+  path.resolve(__dirname, './file.js'),
+  path.resolve(__dirname, './other.js')
+]
@@ -0,0 +1,27 @@
+import { value } from "./file";
+import { value as other } from "./other";
+
+it("should refresh imported values when accepting with an error handler", (done) => {
+	expect(value).toBe(1);
+	expect(other).toBe(1);
+	let handled = false;
+	module.hot.accept("./file", undefined, err => done(err));
+	module.hot.accept(
+		["./other"],
+		() => {
+			throw new Error("callback failed");
+		},
+		(err, { moduleId, dependencyId }) => {
+			expect(err.message).toBe("callback failed");
+			expect(moduleId).toBe(module.id);
+			expect(dependencyId).toBe(require.resolve("./other"));
+			expect(other).toBe(2);
+			handled = true;
+		}
+	);
+	NEXT(require("../../update")(done, true, () => {
+		expect(value).toBe(2);
+		expect(handled).toBe(true);
+		done();
+	}));
+});
@@ -0,0 +1,5 @@
+export function invalidate() {
+	import.meta.webpackHot.invalidate();
+}
+
+export const value = {};
@@ -0,0 +1,11 @@
+export function invalidate() {
+	import.meta.webpackHot.invalidate();
+}
+
+export const value = import.meta.webpackHot.data ? import.meta.webpackHot.data.value : {};
+
+import.meta.webpackHot.dispose(data => {
+	data.value = value;
+});
+
+import.meta.webpackHot.accept();
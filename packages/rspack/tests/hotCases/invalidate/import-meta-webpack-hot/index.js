@@ -0,0 +1,21 @@
+import { a, b } from "./module";
+
+it("should allow to invalidate and reload a file with import.meta.webpackHot", () => {
+	const oldA = a.value;
+	const oldB = b.value;
+	const statuses = [];
+	import.meta.webpackHot.addStatusHandler(status => {
+		statuses.push(status);
+	});
+	expect(import.meta.webpackHot.status()).toBe("idle");
+	a.invalidate();
+	expect(import.meta.webpackHot.status()).toBe("ready");
+	b.invalidate();
+	expect(import.meta.webpackHot.status()).toBe("ready");
+	return import.meta.webpackHot.apply().then(function () {
+		expect(import.meta.webpackHot.status()).toBe("idle");
+		expect(a.value).not.toBe(oldA);
+		expect(b.value).toBe(oldB);
+		expect(statuses).toEqual(["ready", "dispose", "apply", "idle"]);
+	});
+});
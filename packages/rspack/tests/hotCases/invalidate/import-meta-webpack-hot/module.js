@@ -0,0 +1,6 @@
+import * as a from "./a";
+import * as b from "./b";
+
+export { a, b };
+
+import.meta.webpackHot.accept(["./a", "./b"]);